@@ -0,0 +1,15 @@
+use std::env;
+
+// main re-emits the `ffmpeg_X_Y` cfgs libav-sys set for the linked libavcodec, see emit_version in
+// libav-sys/build.rs, so the safe layer can select the matching api
+fn main() {
+    let cfgs = |var: &str| -> Vec<String> {
+        env::var(var).unwrap().split(',').filter(|cfg| !cfg.is_empty()).map(String::from).collect()
+    };
+    for cfg in cfgs("DEP_FFMPEG_RELEASE_CFGS") {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+    }
+    for cfg in cfgs("DEP_FFMPEG_CFGS") {
+        println!("cargo:rustc-cfg={}", cfg);
+    }
+}
@@ -1,45 +1,426 @@
+#[cfg(feature = "bindgen")]
 extern crate bindgen;
+extern crate pkg_config;
 
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
+#[cfg(feature = "bindgen")]
 use bindgen::builder;
 
+// ffmpeg version of the bindings shipped in src/bindings, identified by the libavcodec major version
+const PREGENERATED_VERSION: &str = "4.4";
+const PREGENERATED_AVCODEC_VERSION: (u32, u32) = (58, 134);
+
+// first libavcodec (major, minor) of each ffmpeg release the safe layer distinguishes. For every release at or
+// below the linked libavcodec a `ffmpeg_X_Y` cfg is emitted, so `#[cfg(ffmpeg_5_1)]` reads "5.1 or later".
+const RELEASES: &[(&str, (u32, u32))] = &[
+    ("ffmpeg_5_0", (59, 18)),
+    ("ffmpeg_5_1", (59, 37)),
+];
+
 fn main() {
     let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
 
-    let cuda_path = PathBuf::from(env!("CUDA_PATH"));
-    let include_path = cuda_path.join("include");
-
-    println!("cargo:rerun-if-changed=wrapper_headers/avcodec.h");
-    println!("cargo:rerun-if-changed=wrapper_headers/avutil.h");
-    println!("cargo:rustc-link-search=native={}", dir.join("build/lib").display());
-    println!("cargo:rustc-link-search={}", cuda_path.join("lib/x64").display());
-    println!("cargo:rustc-link-lib=static=avcodec");
-    println!("cargo:rustc-link-lib=static=avdevice");
-    println!("cargo:rustc-link-lib=static=avfilter");
-    println!("cargo:rustc-link-lib=static=avformat");
-    println!("cargo:rustc-link-lib=static=avutil");
-    println!("cargo:rustc-link-lib=static=swresample");
-    println!("cargo:rustc-link-lib=static=swscale");
-    println!("cargo:rustc-link-lib=static=nppig");
-    println!("cargo:rustc-link-lib=static=nppicc");
-    println!("cargo:rustc-link-lib=dylib=Bcrypt");
-    println!("cargo:rustc-link-lib=dylib=User32");
-
-
-    let bindings = builder()
+    for header in &["avcodec.h", "avutil.h", "avformat.h", "avfilter.h", "avdevice.h", "swscale.h", "swresample.h", "cuda.h"] {
+        println!("cargo:rerun-if-changed=wrapper_headers/{}", header);
+    }
+    println!("cargo:rerun-if-env-changed=FFMPEG_DIR");
+    println!("cargo:rerun-if-env-changed=CUDA_PATH");
+    println!("cargo:rerun-if-env-changed=LIBAV_SYS_UPDATE_BINDINGS");
+
+    // docs.rs has no ffmpeg, documentation is built from the shipped bindings without linking anything
+    if env::var_os("DOCS_RS").is_some() {
+        emit_version(PREGENERATED_AVCODEC_VERSION);
+        copy_pregenerated_bindings(&dir, "x86_64-unknown-linux-gnu");
+        return;
+    }
+
+    let libraries = libraries();
+    let mut include_paths = if cfg!(feature = "vendored") {
+        build_vendored(&dir, &libraries)
+    } else if let Some(prefix) = prebuilt_dir(&dir, &libraries) {
+        link_prebuilt(&prefix, &libraries)
+    } else {
+        link_pkg_config(&libraries)
+    };
+
+    if cfg!(feature = "cuda") {
+        let cuda_path = cuda_path();
+        include_paths.push(cuda_path.join("include"));
+        if cfg!(feature = "npp") {
+            link_npp(&cuda_path);
+        }
+    }
+
+    let version = avcodec_version(&include_paths).unwrap_or(PREGENERATED_AVCODEC_VERSION);
+    emit_version(version);
+
+    if cfg!(feature = "bindgen") {
+        generate_bindings(&dir, &include_paths);
+    } else {
+        check_pregenerated_version(&include_paths);
+        copy_pregenerated_bindings(&dir, &env::var("TARGET").unwrap());
+    }
+}
+
+// libraries returns the ffmpeg libraries enabled through cargo features in static link order,
+// dependents before their dependencies.
+fn libraries() -> Vec<&'static str> {
+    let mut libraries = Vec::new();
+    if cfg!(feature = "avdevice") {
+        libraries.push("avdevice");
+    }
+    if cfg!(feature = "avfilter") {
+        libraries.push("avfilter");
+    }
+    if cfg!(feature = "avformat") {
+        libraries.push("avformat");
+    }
+    libraries.push("avcodec");
+    if cfg!(feature = "swresample") {
+        libraries.push("swresample");
+    }
+    if cfg!(feature = "swscale") {
+        libraries.push("swscale");
+    }
+    libraries.push("avutil");
+    libraries
+}
+
+fn target_os() -> String {
+    env::var("CARGO_CFG_TARGET_OS").unwrap()
+}
+
+// prebuilt_dir returns the install prefix of a prebuilt static ffmpeg. FFMPEG_DIR takes precedence over
+// the in-tree `build` directory, which is only used when it actually contains the libraries.
+fn prebuilt_dir(dir: &Path, libraries: &[&str]) -> Option<PathBuf> {
+    if let Some(ffmpeg_dir) = env::var_os("FFMPEG_DIR") {
+        return Some(PathBuf::from(ffmpeg_dir));
+    }
+    let build_dir = dir.join("build");
+    let complete = libraries.iter().all(|lib| {
+        build_dir.join("lib").join(format!("lib{}.a", lib)).exists() || build_dir.join("lib").join(format!("{}.lib", lib)).exists()
+    });
+    if complete {
+        Some(build_dir)
+    } else {
+        None
+    }
+}
+
+fn link_prebuilt(prefix: &Path, libraries: &[&str]) -> Vec<PathBuf> {
+    println!("cargo:rustc-link-search=native={}", prefix.join("lib").display());
+    for lib in libraries {
+        println!("cargo:rustc-link-lib=static={}", lib);
+    }
+    link_platform_libs();
+    vec![prefix.join("include")]
+}
+
+fn link_pkg_config(libraries: &[&str]) -> Vec<PathBuf> {
+    let mut include_paths = Vec::new();
+    for lib in libraries {
+        let library = pkg_config::Config::new()
+            .probe(&format!("lib{}", lib))
+            .unwrap_or_else(|e| panic!("unable to find lib{} with pkg-config, set FFMPEG_DIR or enable the `vendored` feature: {}", lib, e));
+        for path in library.include_paths {
+            if !include_paths.contains(&path) {
+                include_paths.push(path);
+            }
+        }
+    }
+    include_paths
+}
+
+// link_platform_libs links the system libraries a static ffmpeg build depends on
+fn link_platform_libs() {
+    match target_os().as_str() {
+        "windows" => {
+            println!("cargo:rustc-link-lib=dylib=Bcrypt");
+            println!("cargo:rustc-link-lib=dylib=User32");
+            println!("cargo:rustc-link-lib=dylib=Ole32");
+        }
+        "macos" | "ios" => {
+            for framework in &["CoreFoundation", "CoreMedia", "CoreVideo", "VideoToolbox", "AudioToolbox", "Security"] {
+                println!("cargo:rustc-link-lib=framework={}", framework);
+            }
+            println!("cargo:rustc-link-lib=dylib=z");
+            println!("cargo:rustc-link-lib=dylib=iconv");
+        }
+        _ => {
+            println!("cargo:rustc-link-lib=dylib=m");
+            println!("cargo:rustc-link-lib=dylib=pthread");
+            println!("cargo:rustc-link-lib=dylib=dl");
+        }
+    }
+}
+
+// cuda_path returns the CUDA toolkit location, CUDA_PATH is set by the windows installer
+fn cuda_path() -> PathBuf {
+    match env::var_os("CUDA_PATH") {
+        Some(path) => PathBuf::from(path),
+        None if target_os() != "windows" => PathBuf::from("/usr/local/cuda"),
+        None => panic!("the `cuda` feature needs CUDA_PATH to point to the CUDA toolkit"),
+    }
+}
+
+fn link_npp(cuda_path: &Path) {
+    if target_os() == "windows" {
+        println!("cargo:rustc-link-search=native={}", cuda_path.join("lib/x64").display());
+    } else {
+        println!("cargo:rustc-link-search=native={}", cuda_path.join("lib64").display());
+    }
+    println!("cargo:rustc-link-lib=nppig");
+    println!("cargo:rustc-link-lib=nppicc");
+}
+
+// component lists selectable for vendored builds, each read from LIBAV_SYS_<KIND> as a comma separated
+// list of ffmpeg component names. Unset keeps ffmpeg's default of enabling everything, an empty value
+// disables the whole kind.
+const COMPONENTS: &[(&str, &str)] = &[
+    ("LIBAV_SYS_ENCODERS", "encoder"),
+    ("LIBAV_SYS_DECODERS", "decoder"),
+    ("LIBAV_SYS_MUXERS", "muxer"),
+    ("LIBAV_SYS_DEMUXERS", "demuxer"),
+    ("LIBAV_SYS_PARSERS", "parser"),
+    ("LIBAV_SYS_BSFS", "bsf"),
+    ("LIBAV_SYS_FILTERS", "filter"),
+    ("LIBAV_SYS_PROTOCOLS", "protocol"),
+];
+
+// build_vendored configures and compiles the ffmpeg tree in `ffmpeg/` into OUT_DIR and links it statically
+fn build_vendored(dir: &Path, libraries: &[&str]) -> Vec<PathBuf> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let source_dir = dir.join("ffmpeg");
+    let build_dir = out_dir.join("ffmpeg-build");
+    let prefix = out_dir.join("ffmpeg");
+
+    println!("cargo:rerun-if-changed={}", source_dir.join("configure").display());
+    println!("cargo:rerun-if-env-changed=LIBAV_SYS_CONFIGURE_FLAGS");
+    for (var, _) in COMPONENTS {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    let args = configure_args(&prefix, libraries);
+    // configure is only rerun when the selection changed, the stamp records the arguments of the last build
+    let stamp = build_dir.join("configure.args");
+    let up_to_date = prefix.join("lib").join("libavcodec.a").exists()
+        && std::fs::read_to_string(&stamp).map(|old| old == args.join("\n")).unwrap_or(false);
+
+    if !up_to_date {
+        if build_dir.exists() {
+            std::fs::remove_dir_all(&build_dir).expect("unable to clean ffmpeg build directory");
+        }
+        if prefix.exists() {
+            std::fs::remove_dir_all(&prefix).expect("unable to clean ffmpeg install directory");
+        }
+        std::fs::create_dir_all(&build_dir).expect("unable to create ffmpeg build directory");
+
+        run(Command::new(source_dir.join("configure")).current_dir(&build_dir).args(&args), "configure");
+        let jobs = env::var("NUM_JOBS").unwrap_or_else(|_| String::from("1"));
+        run(Command::new("make").current_dir(&build_dir).arg(format!("-j{}", jobs)), "make");
+        run(Command::new("make").current_dir(&build_dir).arg("install"), "make install");
+        std::fs::write(&stamp, args.join("\n")).expect("unable to write configure stamp");
+    }
+
+    // ffmpeg writes pkg-config files with the private dependencies it detected, use them for linking
+    env::set_var("PKG_CONFIG_PATH", prefix.join("lib").join("pkgconfig"));
+    for lib in libraries {
+        pkg_config::Config::new()
+            .statik(true)
+            .probe(&format!("lib{}", lib))
+            .unwrap_or_else(|e| panic!("unable to link vendored lib{}: {}", lib, e));
+    }
+    vec![prefix.join("include")]
+}
+
+// configure_args assembles the ffmpeg configure command line from cargo features and LIBAV_SYS_* variables
+fn configure_args(prefix: &Path, libraries: &[&str]) -> Vec<String> {
+    let mut args: Vec<String> = vec![
+        format!("--prefix={}", prefix.display()),
+        "--enable-static".into(),
+        "--disable-shared".into(),
+        "--enable-pic".into(),
+        "--disable-programs".into(),
+        "--disable-doc".into(),
+        "--disable-autodetect".into(),
+    ];
+    for lib in &["avdevice", "avfilter", "avformat", "swresample", "swscale"] {
+        if !libraries.contains(lib) {
+            args.push(format!("--disable-{}", lib));
+        }
+    }
+
+    if cfg!(feature = "gpl") {
+        args.push("--enable-gpl".into());
+    } else {
+        args.push("--disable-gpl".into());
+    }
+    if cfg!(feature = "nonfree") {
+        args.push("--enable-nonfree".into());
+    }
+    if cfg!(feature = "cuda") {
+        args.push("--enable-cuda".into());
+        args.push("--enable-ffnvcodec".into());
+    }
+    if cfg!(feature = "npp") {
+        if !cfg!(feature = "nonfree") {
+            panic!("libnpp is not redistributable, the `npp` feature needs `nonfree` for vendored builds");
+        }
+        let cuda_path = cuda_path();
+        args.push("--enable-libnpp".into());
+        args.push(format!("--extra-cflags=-I{}", cuda_path.join("include").display()));
+        args.push(format!("--extra-ldflags=-L{}", cuda_path.join("lib64").display()));
+    }
+
+    for (var, kind) in COMPONENTS {
+        if let Ok(value) = env::var(var) {
+            args.push(format!("--disable-{}s", kind));
+            let names: Vec<&str> = value.split(',').map(str::trim).filter(|name| !name.is_empty()).collect();
+            if !names.is_empty() {
+                args.push(format!("--enable-{}={}", kind, names.join(",")));
+            }
+        }
+    }
+
+    // the vendored tree ships libavformat/asfdec_o.c empty, so the demuxer it defines can not be linked
+    args.push("--disable-demuxer=asf_o".into());
+
+    if Command::new("nasm").arg("-v").output().is_err() {
+        println!("cargo:warning=nasm not found, building ffmpeg without x86 assembly");
+        args.push("--disable-x86asm".into());
+        // the inline mmx scalers of swscale give wrong output in builds without the external asm
+        args.push("--disable-inline-asm".into());
+    }
+
+    let target = env::var("TARGET").unwrap();
+    if target != env::var("HOST").unwrap() {
+        args.push("--enable-cross-compile".into());
+        args.push(format!("--arch={}", env::var("CARGO_CFG_TARGET_ARCH").unwrap()));
+        args.push(format!("--target-os={}", target_os()));
+        if let Ok(cc) = env::var(format!("CC_{}", target.replace('-', "_"))).or_else(|_| env::var("TARGET_CC")) {
+            args.push(format!("--cc={}", cc));
+        }
+    }
+
+    if let Ok(flags) = env::var("LIBAV_SYS_CONFIGURE_FLAGS") {
+        args.extend(flags.split_whitespace().map(String::from));
+    }
+    args
+}
+
+fn run(cmd: &mut Command, name: &str) {
+    let status = cmd.status().unwrap_or_else(|e| panic!("unable to run ffmpeg {}: {}", name, e));
+    if !status.success() {
+        panic!("ffmpeg {} failed: {}", name, status);
+    }
+}
+
+// avcodec_version reads the libavcodec (major, minor) version from the headers in the include paths. Since 5.1
+// the major version lives in version_major.h.
+fn avcodec_version(include_paths: &[PathBuf]) -> Option<(u32, u32)> {
+    let dir = include_paths.iter()
+        .map(|path| path.join("libavcodec"))
+        .find(|path| path.join("version.h").exists())?;
+    let mut contents = std::fs::read_to_string(dir.join("version.h")).ok()?;
+    if let Ok(major) = std::fs::read_to_string(dir.join("version_major.h")) {
+        contents.push_str(&major);
+    }
+    let define = |name: &str| {
+        contents.lines()
+            .filter_map(|line| line.strip_prefix(name))
+            .find_map(|value| value.trim().parse().ok())
+    };
+    Some((define("#define LIBAVCODEC_VERSION_MAJOR")?, define("#define LIBAVCODEC_VERSION_MINOR")?))
+}
+
+// emit_version passes the release cfgs to this crate and, through the `links` metadata, to dependents:
+// DEP_FFMPEG_RELEASE_CFGS lists every cfg that can be set, DEP_FFMPEG_CFGS the ones set for the linked version
+// and DEP_FFMPEG_AVCODEC_MAJOR/DEP_FFMPEG_AVCODEC_MINOR hold the version itself
+fn emit_version(version: (u32, u32)) {
+    let mut enabled = Vec::new();
+    for (cfg, release) in RELEASES {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+        if version >= *release {
+            println!("cargo:rustc-cfg={}", cfg);
+            enabled.push(*cfg);
+        }
+    }
+    let all: Vec<&str> = RELEASES.iter().map(|(cfg, _)| *cfg).collect();
+    println!("cargo:release_cfgs={}", all.join(","));
+    println!("cargo:cfgs={}", enabled.join(","));
+    println!("cargo:avcodec_major={}", version.0);
+    println!("cargo:avcodec_minor={}", version.1);
+}
+
+fn check_pregenerated_version(include_paths: &[PathBuf]) {
+    if let Some((major, _)) = avcodec_version(include_paths) {
+        if major != PREGENERATED_AVCODEC_VERSION.0 {
+            panic!("the shipped bindings are for ffmpeg {} (libavcodec {}) but libavcodec {} was found, enable the `bindgen` feature",
+                   PREGENERATED_VERSION, PREGENERATED_AVCODEC_VERSION.0, major);
+        }
+    }
+}
+
+fn pregenerated_path(dir: &Path, target: &str) -> PathBuf {
+    dir.join("src").join("bindings").join(format!("ffmpeg-{}", PREGENERATED_VERSION)).join(format!("{}.rs", target))
+}
+
+fn copy_pregenerated_bindings(dir: &Path, target: &str) {
+    let bindings = pregenerated_path(dir, target);
+    println!("cargo:rerun-if-changed={}", bindings.display());
+    if !bindings.exists() {
+        panic!("no pregenerated bindings for {}, enable the `bindgen` feature to generate them", target);
+    }
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::copy(&bindings, out_path.join("avcodec.rs")).expect("Couldn't copy bindings!");
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_dir: &Path, _include_paths: &[PathBuf]) {
+    unreachable!()
+}
+
+// generate_bindings runs bindgen over the wrapper headers. With LIBAV_SYS_UPDATE_BINDINGS set the bindings of all
+// libraries are also written to src/bindings so they can be committed for the current target.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(dir: &Path, include_paths: &[PathBuf]) {
+    let update = env::var_os("LIBAV_SYS_UPDATE_BINDINGS").is_some();
+    let mut bindings = builder()
         .header("wrapper_headers/avcodec.h")
-        .header("wrapper_headers/avutil.h")
-        .header("wrapper_headers/avfilter.h")
-        .clang_arg("-Ibuild/include")
-        .clang_arg(format!("-I{}",include_path.display()))
+        .header("wrapper_headers/avutil.h");
+    for (enabled, header) in &[
+        (update || cfg!(feature = "avformat"), "wrapper_headers/avformat.h"),
+        (update || cfg!(feature = "avfilter"), "wrapper_headers/avfilter.h"),
+        (update || cfg!(feature = "avdevice"), "wrapper_headers/avdevice.h"),
+        (update || cfg!(feature = "swscale"), "wrapper_headers/swscale.h"),
+        (update || cfg!(feature = "swresample"), "wrapper_headers/swresample.h"),
+        (!update && cfg!(feature = "cuda"), "wrapper_headers/cuda.h"),
+    ] {
+        if *enabled {
+            bindings = bindings.header(*header);
+        }
+    }
+    for path in include_paths {
+        bindings = bindings.clang_arg(format!("-I{}", path.display()));
+    }
+    let bindings = bindings
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .generate().expect("unable to generate bindings");
 
-
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bindings
         .write_to_file(out_path.join("avcodec.rs"))
         .expect("Couldn't write bindings!");
+
+    if update {
+        if avcodec_version(include_paths).map(|version| version.0) != Some(PREGENERATED_AVCODEC_VERSION.0) {
+            panic!("LIBAV_SYS_UPDATE_BINDINGS needs ffmpeg {} headers", PREGENERATED_VERSION);
+        }
+        let path = pregenerated_path(dir, &env::var("TARGET").unwrap());
+        std::fs::create_dir_all(path.parent().unwrap()).expect("unable to create bindings directory");
+        bindings.write_to_file(path).expect("Couldn't write bindings!");
+    }
 }
@@ -0,0 +1,19014 @@
+/* automatically generated from the ffmpeg 4.4 headers, see README.md */
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+
+        let mask = 1 << bit_index;
+
+        byte & mask == mask
+    }
+
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+
+        let mask = 1 << bit_index;
+        if val {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+
+        let mut val = 0;
+
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+
+        val
+    }
+
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+
+pub const _STDC_PREDEF_H: u32 = 1;
+pub const __STDC_IEC_559__: u32 = 1;
+pub const __STDC_IEC_60559_BFP__: u32 = 201404;
+pub const __STDC_IEC_559_COMPLEX__: u32 = 1;
+pub const __STDC_IEC_60559_COMPLEX__: u32 = 201404;
+pub const __STDC_ISO_10646__: u32 = 201706;
+pub const _ERRNO_H: u32 = 1;
+pub const _FEATURES_H: u32 = 1;
+pub const _DEFAULT_SOURCE: u32 = 1;
+pub const __GLIBC_USE_ISOC2X: u32 = 0;
+pub const __USE_ISOC11: u32 = 1;
+pub const __USE_ISOC99: u32 = 1;
+pub const __USE_ISOC95: u32 = 1;
+pub const __USE_POSIX_IMPLICITLY: u32 = 1;
+pub const _POSIX_SOURCE: u32 = 1;
+pub const _POSIX_C_SOURCE: u32 = 200809;
+pub const __USE_POSIX: u32 = 1;
+pub const __USE_POSIX2: u32 = 1;
+pub const __USE_POSIX199309: u32 = 1;
+pub const __USE_POSIX199506: u32 = 1;
+pub const __USE_XOPEN2K: u32 = 1;
+pub const __USE_XOPEN2K8: u32 = 1;
+pub const _ATFILE_SOURCE: u32 = 1;
+pub const __WORDSIZE: u32 = 64;
+pub const __WORDSIZE_TIME64_COMPAT32: u32 = 1;
+pub const __SYSCALL_WORDSIZE: u32 = 64;
+pub const __TIMESIZE: u32 = 64;
+pub const __USE_MISC: u32 = 1;
+pub const __USE_ATFILE: u32 = 1;
+pub const __USE_FORTIFY_LEVEL: u32 = 0;
+pub const __GLIBC_USE_DEPRECATED_GETS: u32 = 0;
+pub const __GLIBC_USE_DEPRECATED_SCANF: u32 = 0;
+pub const __GNU_LIBRARY__: u32 = 6;
+pub const __GLIBC__: u32 = 2;
+pub const __GLIBC_MINOR__: u32 = 36;
+pub const _SYS_CDEFS_H: u32 = 1;
+pub const __glibc_c99_flexarr_available: u32 = 1;
+pub const __LDOUBLE_REDIRECTS_TO_FLOAT128_ABI: u32 = 0;
+pub const __HAVE_GENERIC_SELECTION: u32 = 1;
+pub const _BITS_ERRNO_H: u32 = 1;
+pub const EPERM: u32 = 1;
+pub const ENOENT: u32 = 2;
+pub const ESRCH: u32 = 3;
+pub const EINTR: u32 = 4;
+pub const EIO: u32 = 5;
+pub const ENXIO: u32 = 6;
+pub const E2BIG: u32 = 7;
+pub const ENOEXEC: u32 = 8;
+pub const EBADF: u32 = 9;
+pub const ECHILD: u32 = 10;
+pub const EAGAIN: u32 = 11;
+pub const ENOMEM: u32 = 12;
+pub const EACCES: u32 = 13;
+pub const EFAULT: u32 = 14;
+pub const ENOTBLK: u32 = 15;
+pub const EBUSY: u32 = 16;
+pub const EEXIST: u32 = 17;
+pub const EXDEV: u32 = 18;
+pub const ENODEV: u32 = 19;
+pub const ENOTDIR: u32 = 20;
+pub const EISDIR: u32 = 21;
+pub const EINVAL: u32 = 22;
+pub const ENFILE: u32 = 23;
+pub const EMFILE: u32 = 24;
+pub const ENOTTY: u32 = 25;
+pub const ETXTBSY: u32 = 26;
+pub const EFBIG: u32 = 27;
+pub const ENOSPC: u32 = 28;
+pub const ESPIPE: u32 = 29;
+pub const EROFS: u32 = 30;
+pub const EMLINK: u32 = 31;
+pub const EPIPE: u32 = 32;
+pub const EDOM: u32 = 33;
+pub const ERANGE: u32 = 34;
+pub const EDEADLK: u32 = 35;
+pub const ENAMETOOLONG: u32 = 36;
+pub const ENOLCK: u32 = 37;
+pub const ENOSYS: u32 = 38;
+pub const ENOTEMPTY: u32 = 39;
+pub const ELOOP: u32 = 40;
+pub const EWOULDBLOCK: u32 = 11;
+pub const ENOMSG: u32 = 42;
+pub const EIDRM: u32 = 43;
+pub const ECHRNG: u32 = 44;
+pub const EL2NSYNC: u32 = 45;
+pub const EL3HLT: u32 = 46;
+pub const EL3RST: u32 = 47;
+pub const ELNRNG: u32 = 48;
+pub const EUNATCH: u32 = 49;
+pub const ENOCSI: u32 = 50;
+pub const EL2HLT: u32 = 51;
+pub const EBADE: u32 = 52;
+pub const EBADR: u32 = 53;
+pub const EXFULL: u32 = 54;
+pub const ENOANO: u32 = 55;
+pub const EBADRQC: u32 = 56;
+pub const EBADSLT: u32 = 57;
+pub const EDEADLOCK: u32 = 35;
+pub const EBFONT: u32 = 59;
+pub const ENOSTR: u32 = 60;
+pub const ENODATA: u32 = 61;
+pub const ETIME: u32 = 62;
+pub const ENOSR: u32 = 63;
+pub const ENONET: u32 = 64;
+pub const ENOPKG: u32 = 65;
+pub const EREMOTE: u32 = 66;
+pub const ENOLINK: u32 = 67;
+pub const EADV: u32 = 68;
+pub const ESRMNT: u32 = 69;
+pub const ECOMM: u32 = 70;
+pub const EPROTO: u32 = 71;
+pub const EMULTIHOP: u32 = 72;
+pub const EDOTDOT: u32 = 73;
+pub const EBADMSG: u32 = 74;
+pub const EOVERFLOW: u32 = 75;
+pub const ENOTUNIQ: u32 = 76;
+pub const EBADFD: u32 = 77;
+pub const EREMCHG: u32 = 78;
+pub const ELIBACC: u32 = 79;
+pub const ELIBBAD: u32 = 80;
+pub const ELIBSCN: u32 = 81;
+pub const ELIBMAX: u32 = 82;
+pub const ELIBEXEC: u32 = 83;
+pub const EILSEQ: u32 = 84;
+pub const ERESTART: u32 = 85;
+pub const ESTRPIPE: u32 = 86;
+pub const EUSERS: u32 = 87;
+pub const ENOTSOCK: u32 = 88;
+pub const EDESTADDRREQ: u32 = 89;
+pub const EMSGSIZE: u32 = 90;
+pub const EPROTOTYPE: u32 = 91;
+pub const ENOPROTOOPT: u32 = 92;
+pub const EPROTONOSUPPORT: u32 = 93;
+pub const ESOCKTNOSUPPORT: u32 = 94;
+pub const EOPNOTSUPP: u32 = 95;
+pub const EPFNOSUPPORT: u32 = 96;
+pub const EAFNOSUPPORT: u32 = 97;
+pub const EADDRINUSE: u32 = 98;
+pub const EADDRNOTAVAIL: u32 = 99;
+pub const ENETDOWN: u32 = 100;
+pub const ENETUNREACH: u32 = 101;
+pub const ENETRESET: u32 = 102;
+pub const ECONNABORTED: u32 = 103;
+pub const ECONNRESET: u32 = 104;
+pub const ENOBUFS: u32 = 105;
+pub const EISCONN: u32 = 106;
+pub const ENOTCONN: u32 = 107;
+pub const ESHUTDOWN: u32 = 108;
+pub const ETOOMANYREFS: u32 = 109;
+pub const ETIMEDOUT: u32 = 110;
+pub const ECONNREFUSED: u32 = 111;
+pub const EHOSTDOWN: u32 = 112;
+pub const EHOSTUNREACH: u32 = 113;
+pub const EALREADY: u32 = 114;
+pub const EINPROGRESS: u32 = 115;
+pub const ESTALE: u32 = 116;
+pub const EUCLEAN: u32 = 117;
+pub const ENOTNAM: u32 = 118;
+pub const ENAVAIL: u32 = 119;
+pub const EISNAM: u32 = 120;
+pub const EREMOTEIO: u32 = 121;
+pub const EDQUOT: u32 = 122;
+pub const ENOMEDIUM: u32 = 123;
+pub const EMEDIUMTYPE: u32 = 124;
+pub const ECANCELED: u32 = 125;
+pub const ENOKEY: u32 = 126;
+pub const EKEYEXPIRED: u32 = 127;
+pub const EKEYREVOKED: u32 = 128;
+pub const EKEYREJECTED: u32 = 129;
+pub const EOWNERDEAD: u32 = 130;
+pub const ENOTRECOVERABLE: u32 = 131;
+pub const ERFKILL: u32 = 132;
+pub const EHWPOISON: u32 = 133;
+pub const ENOTSUP: u32 = 95;
+pub const _STDINT_H: u32 = 1;
+pub const __GLIBC_USE_LIB_EXT2: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_BFP_EXT: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_BFP_EXT_C2X: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_EXT: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_FUNCS_EXT: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_FUNCS_EXT_C2X: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_TYPES_EXT: u32 = 0;
+pub const _BITS_TYPES_H: u32 = 1;
+pub const _BITS_TYPESIZES_H: u32 = 1;
+pub const __OFF_T_MATCHES_OFF64_T: u32 = 1;
+pub const __INO_T_MATCHES_INO64_T: u32 = 1;
+pub const __RLIM_T_MATCHES_RLIM64_T: u32 = 1;
+pub const __STATFS_MATCHES_STATFS64: u32 = 1;
+pub const __KERNEL_OLD_TIMEVAL_MATCHES_TIMEVAL64: u32 = 1;
+pub const __FD_SETSIZE: u32 = 1024;
+pub const _BITS_TIME64_H: u32 = 1;
+pub const _BITS_WCHAR_H: u32 = 1;
+pub const _BITS_STDINT_INTN_H: u32 = 1;
+pub const _BITS_STDINT_UINTN_H: u32 = 1;
+pub const INT8_MIN: i32 = -128;
+pub const INT16_MIN: i32 = -32768;
+pub const INT32_MIN: i32 = -2147483648;
+pub const INT8_MAX: u32 = 127;
+pub const INT16_MAX: u32 = 32767;
+pub const INT32_MAX: u32 = 2147483647;
+pub const UINT8_MAX: u32 = 255;
+pub const UINT16_MAX: u32 = 65535;
+pub const UINT32_MAX: u32 = 4294967295;
+pub const INT_LEAST8_MIN: i32 = -128;
+pub const INT_LEAST16_MIN: i32 = -32768;
+pub const INT_LEAST32_MIN: i32 = -2147483648;
+pub const INT_LEAST8_MAX: u32 = 127;
+pub const INT_LEAST16_MAX: u32 = 32767;
+pub const INT_LEAST32_MAX: u32 = 2147483647;
+pub const UINT_LEAST8_MAX: u32 = 255;
+pub const UINT_LEAST16_MAX: u32 = 65535;
+pub const UINT_LEAST32_MAX: u32 = 4294967295;
+pub const INT_FAST8_MIN: i32 = -128;
+pub const INT_FAST16_MIN: i64 = -9223372036854775808;
+pub const INT_FAST32_MIN: i64 = -9223372036854775808;
+pub const INT_FAST8_MAX: u32 = 127;
+pub const INT_FAST16_MAX: u64 = 9223372036854775807;
+pub const INT_FAST32_MAX: u64 = 9223372036854775807;
+pub const UINT_FAST8_MAX: u32 = 255;
+pub const UINT_FAST16_MAX: i32 = -1;
+pub const UINT_FAST32_MAX: i32 = -1;
+pub const INTPTR_MIN: i64 = -9223372036854775808;
+pub const INTPTR_MAX: u64 = 9223372036854775807;
+pub const UINTPTR_MAX: i32 = -1;
+pub const PTRDIFF_MIN: i64 = -9223372036854775808;
+pub const PTRDIFF_MAX: u64 = 9223372036854775807;
+pub const SIG_ATOMIC_MIN: i32 = -2147483648;
+pub const SIG_ATOMIC_MAX: u32 = 2147483647;
+pub const SIZE_MAX: i32 = -1;
+pub const WINT_MIN: u32 = 0;
+pub const WINT_MAX: u32 = 4294967295;
+pub const FF_LAMBDA_SHIFT: u32 = 7;
+pub const FF_LAMBDA_SCALE: u32 = 128;
+pub const FF_QP2LAMBDA: u32 = 118;
+pub const FF_LAMBDA_MAX: u32 = 32767;
+pub const FF_QUALITY_SCALE: u32 = 128;
+pub const AV_TIME_BASE: u32 = 1000000;
+pub const _INTTYPES_H: u32 = 1;
+pub const ____gwchar_t_defined: u32 = 1;
+pub const __PRI64_PREFIX: &'static [u8; 2usize] = b"l\0";
+pub const __PRIPTR_PREFIX: &'static [u8; 2usize] = b"l\0";
+pub const PRId8: &'static [u8; 2usize] = b"d\0";
+pub const PRId16: &'static [u8; 2usize] = b"d\0";
+pub const PRId32: &'static [u8; 2usize] = b"d\0";
+pub const PRId64: &'static [u8; 3usize] = b"ld\0";
+pub const PRIdLEAST8: &'static [u8; 2usize] = b"d\0";
+pub const PRIdLEAST16: &'static [u8; 2usize] = b"d\0";
+pub const PRIdLEAST32: &'static [u8; 2usize] = b"d\0";
+pub const PRIdLEAST64: &'static [u8; 3usize] = b"ld\0";
+pub const PRIdFAST8: &'static [u8; 2usize] = b"d\0";
+pub const PRIdFAST16: &'static [u8; 3usize] = b"ld\0";
+pub const PRIdFAST32: &'static [u8; 3usize] = b"ld\0";
+pub const PRIdFAST64: &'static [u8; 3usize] = b"ld\0";
+pub const PRIi8: &'static [u8; 2usize] = b"i\0";
+pub const PRIi16: &'static [u8; 2usize] = b"i\0";
+pub const PRIi32: &'static [u8; 2usize] = b"i\0";
+pub const PRIi64: &'static [u8; 3usize] = b"li\0";
+pub const PRIiLEAST8: &'static [u8; 2usize] = b"i\0";
+pub const PRIiLEAST16: &'static [u8; 2usize] = b"i\0";
+pub const PRIiLEAST32: &'static [u8; 2usize] = b"i\0";
+pub const PRIiLEAST64: &'static [u8; 3usize] = b"li\0";
+pub const PRIiFAST8: &'static [u8; 2usize] = b"i\0";
+pub const PRIiFAST16: &'static [u8; 3usize] = b"li\0";
+pub const PRIiFAST32: &'static [u8; 3usize] = b"li\0";
+pub const PRIiFAST64: &'static [u8; 3usize] = b"li\0";
+pub const PRIo8: &'static [u8; 2usize] = b"o\0";
+pub const PRIo16: &'static [u8; 2usize] = b"o\0";
+pub const PRIo32: &'static [u8; 2usize] = b"o\0";
+pub const PRIo64: &'static [u8; 3usize] = b"lo\0";
+pub const PRIoLEAST8: &'static [u8; 2usize] = b"o\0";
+pub const PRIoLEAST16: &'static [u8; 2usize] = b"o\0";
+pub const PRIoLEAST32: &'static [u8; 2usize] = b"o\0";
+pub const PRIoLEAST64: &'static [u8; 3usize] = b"lo\0";
+pub const PRIoFAST8: &'static [u8; 2usize] = b"o\0";
+pub const PRIoFAST16: &'static [u8; 3usize] = b"lo\0";
+pub const PRIoFAST32: &'static [u8; 3usize] = b"lo\0";
+pub const PRIoFAST64: &'static [u8; 3usize] = b"lo\0";
+pub const PRIu8: &'static [u8; 2usize] = b"u\0";
+pub const PRIu16: &'static [u8; 2usize] = b"u\0";
+pub const PRIu32: &'static [u8; 2usize] = b"u\0";
+pub const PRIu64: &'static [u8; 3usize] = b"lu\0";
+pub const PRIuLEAST8: &'static [u8; 2usize] = b"u\0";
+pub const PRIuLEAST16: &'static [u8; 2usize] = b"u\0";
+pub const PRIuLEAST32: &'static [u8; 2usize] = b"u\0";
+pub const PRIuLEAST64: &'static [u8; 3usize] = b"lu\0";
+pub const PRIuFAST8: &'static [u8; 2usize] = b"u\0";
+pub const PRIuFAST16: &'static [u8; 3usize] = b"lu\0";
+pub const PRIuFAST32: &'static [u8; 3usize] = b"lu\0";
+pub const PRIuFAST64: &'static [u8; 3usize] = b"lu\0";
+pub const PRIx8: &'static [u8; 2usize] = b"x\0";
+pub const PRIx16: &'static [u8; 2usize] = b"x\0";
+pub const PRIx32: &'static [u8; 2usize] = b"x\0";
+pub const PRIx64: &'static [u8; 3usize] = b"lx\0";
+pub const PRIxLEAST8: &'static [u8; 2usize] = b"x\0";
+pub const PRIxLEAST16: &'static [u8; 2usize] = b"x\0";
+pub const PRIxLEAST32: &'static [u8; 2usize] = b"x\0";
+pub const PRIxLEAST64: &'static [u8; 3usize] = b"lx\0";
+pub const PRIxFAST8: &'static [u8; 2usize] = b"x\0";
+pub const PRIxFAST16: &'static [u8; 3usize] = b"lx\0";
+pub const PRIxFAST32: &'static [u8; 3usize] = b"lx\0";
+pub const PRIxFAST64: &'static [u8; 3usize] = b"lx\0";
+pub const PRIX8: &'static [u8; 2usize] = b"X\0";
+pub const PRIX16: &'static [u8; 2usize] = b"X\0";
+pub const PRIX32: &'static [u8; 2usize] = b"X\0";
+pub const PRIX64: &'static [u8; 3usize] = b"lX\0";
+pub const PRIXLEAST8: &'static [u8; 2usize] = b"X\0";
+pub const PRIXLEAST16: &'static [u8; 2usize] = b"X\0";
+pub const PRIXLEAST32: &'static [u8; 2usize] = b"X\0";
+pub const PRIXLEAST64: &'static [u8; 3usize] = b"lX\0";
+pub const PRIXFAST8: &'static [u8; 2usize] = b"X\0";
+pub const PRIXFAST16: &'static [u8; 3usize] = b"lX\0";
+pub const PRIXFAST32: &'static [u8; 3usize] = b"lX\0";
+pub const PRIXFAST64: &'static [u8; 3usize] = b"lX\0";
+pub const PRIdMAX: &'static [u8; 3usize] = b"ld\0";
+pub const PRIiMAX: &'static [u8; 3usize] = b"li\0";
+pub const PRIoMAX: &'static [u8; 3usize] = b"lo\0";
+pub const PRIuMAX: &'static [u8; 3usize] = b"lu\0";
+pub const PRIxMAX: &'static [u8; 3usize] = b"lx\0";
+pub const PRIXMAX: &'static [u8; 3usize] = b"lX\0";
+pub const PRIdPTR: &'static [u8; 3usize] = b"ld\0";
+pub const PRIiPTR: &'static [u8; 3usize] = b"li\0";
+pub const PRIoPTR: &'static [u8; 3usize] = b"lo\0";
+pub const PRIuPTR: &'static [u8; 3usize] = b"lu\0";
+pub const PRIxPTR: &'static [u8; 3usize] = b"lx\0";
+pub const PRIXPTR: &'static [u8; 3usize] = b"lX\0";
+pub const SCNd8: &'static [u8; 4usize] = b"hhd\0";
+pub const SCNd16: &'static [u8; 3usize] = b"hd\0";
+pub const SCNd32: &'static [u8; 2usize] = b"d\0";
+pub const SCNd64: &'static [u8; 3usize] = b"ld\0";
+pub const SCNdLEAST8: &'static [u8; 4usize] = b"hhd\0";
+pub const SCNdLEAST16: &'static [u8; 3usize] = b"hd\0";
+pub const SCNdLEAST32: &'static [u8; 2usize] = b"d\0";
+pub const SCNdLEAST64: &'static [u8; 3usize] = b"ld\0";
+pub const SCNdFAST8: &'static [u8; 4usize] = b"hhd\0";
+pub const SCNdFAST16: &'static [u8; 3usize] = b"ld\0";
+pub const SCNdFAST32: &'static [u8; 3usize] = b"ld\0";
+pub const SCNdFAST64: &'static [u8; 3usize] = b"ld\0";
+pub const SCNi8: &'static [u8; 4usize] = b"hhi\0";
+pub const SCNi16: &'static [u8; 3usize] = b"hi\0";
+pub const SCNi32: &'static [u8; 2usize] = b"i\0";
+pub const SCNi64: &'static [u8; 3usize] = b"li\0";
+pub const SCNiLEAST8: &'static [u8; 4usize] = b"hhi\0";
+pub const SCNiLEAST16: &'static [u8; 3usize] = b"hi\0";
+pub const SCNiLEAST32: &'static [u8; 2usize] = b"i\0";
+pub const SCNiLEAST64: &'static [u8; 3usize] = b"li\0";
+pub const SCNiFAST8: &'static [u8; 4usize] = b"hhi\0";
+pub const SCNiFAST16: &'static [u8; 3usize] = b"li\0";
+pub const SCNiFAST32: &'static [u8; 3usize] = b"li\0";
+pub const SCNiFAST64: &'static [u8; 3usize] = b"li\0";
+pub const SCNu8: &'static [u8; 4usize] = b"hhu\0";
+pub const SCNu16: &'static [u8; 3usize] = b"hu\0";
+pub const SCNu32: &'static [u8; 2usize] = b"u\0";
+pub const SCNu64: &'static [u8; 3usize] = b"lu\0";
+pub const SCNuLEAST8: &'static [u8; 4usize] = b"hhu\0";
+pub const SCNuLEAST16: &'static [u8; 3usize] = b"hu\0";
+pub const SCNuLEAST32: &'static [u8; 2usize] = b"u\0";
+pub const SCNuLEAST64: &'static [u8; 3usize] = b"lu\0";
+pub const SCNuFAST8: &'static [u8; 4usize] = b"hhu\0";
+pub const SCNuFAST16: &'static [u8; 3usize] = b"lu\0";
+pub const SCNuFAST32: &'static [u8; 3usize] = b"lu\0";
+pub const SCNuFAST64: &'static [u8; 3usize] = b"lu\0";
+pub const SCNo8: &'static [u8; 4usize] = b"hho\0";
+pub const SCNo16: &'static [u8; 3usize] = b"ho\0";
+pub const SCNo32: &'static [u8; 2usize] = b"o\0";
+pub const SCNo64: &'static [u8; 3usize] = b"lo\0";
+pub const SCNoLEAST8: &'static [u8; 4usize] = b"hho\0";
+pub const SCNoLEAST16: &'static [u8; 3usize] = b"ho\0";
+pub const SCNoLEAST32: &'static [u8; 2usize] = b"o\0";
+pub const SCNoLEAST64: &'static [u8; 3usize] = b"lo\0";
+pub const SCNoFAST8: &'static [u8; 4usize] = b"hho\0";
+pub const SCNoFAST16: &'static [u8; 3usize] = b"lo\0";
+pub const SCNoFAST32: &'static [u8; 3usize] = b"lo\0";
+pub const SCNoFAST64: &'static [u8; 3usize] = b"lo\0";
+pub const SCNx8: &'static [u8; 4usize] = b"hhx\0";
+pub const SCNx16: &'static [u8; 3usize] = b"hx\0";
+pub const SCNx32: &'static [u8; 2usize] = b"x\0";
+pub const SCNx64: &'static [u8; 3usize] = b"lx\0";
+pub const SCNxLEAST8: &'static [u8; 4usize] = b"hhx\0";
+pub const SCNxLEAST16: &'static [u8; 3usize] = b"hx\0";
+pub const SCNxLEAST32: &'static [u8; 2usize] = b"x\0";
+pub const SCNxLEAST64: &'static [u8; 3usize] = b"lx\0";
+pub const SCNxFAST8: &'static [u8; 4usize] = b"hhx\0";
+pub const SCNxFAST16: &'static [u8; 3usize] = b"lx\0";
+pub const SCNxFAST32: &'static [u8; 3usize] = b"lx\0";
+pub const SCNxFAST64: &'static [u8; 3usize] = b"lx\0";
+pub const SCNdMAX: &'static [u8; 3usize] = b"ld\0";
+pub const SCNiMAX: &'static [u8; 3usize] = b"li\0";
+pub const SCNoMAX: &'static [u8; 3usize] = b"lo\0";
+pub const SCNuMAX: &'static [u8; 3usize] = b"lu\0";
+pub const SCNxMAX: &'static [u8; 3usize] = b"lx\0";
+pub const SCNdPTR: &'static [u8; 3usize] = b"ld\0";
+pub const SCNiPTR: &'static [u8; 3usize] = b"li\0";
+pub const SCNoPTR: &'static [u8; 3usize] = b"lo\0";
+pub const SCNuPTR: &'static [u8; 3usize] = b"lu\0";
+pub const SCNxPTR: &'static [u8; 3usize] = b"lx\0";
+pub const _LIBC_LIMITS_H_: u32 = 1;
+pub const MB_LEN_MAX: u32 = 16;
+pub const _BITS_POSIX1_LIM_H: u32 = 1;
+pub const _POSIX_AIO_LISTIO_MAX: u32 = 2;
+pub const _POSIX_AIO_MAX: u32 = 1;
+pub const _POSIX_ARG_MAX: u32 = 4096;
+pub const _POSIX_CHILD_MAX: u32 = 25;
+pub const _POSIX_DELAYTIMER_MAX: u32 = 32;
+pub const _POSIX_HOST_NAME_MAX: u32 = 255;
+pub const _POSIX_LINK_MAX: u32 = 8;
+pub const _POSIX_LOGIN_NAME_MAX: u32 = 9;
+pub const _POSIX_MAX_CANON: u32 = 255;
+pub const _POSIX_MAX_INPUT: u32 = 255;
+pub const _POSIX_MQ_OPEN_MAX: u32 = 8;
+pub const _POSIX_MQ_PRIO_MAX: u32 = 32;
+pub const _POSIX_NAME_MAX: u32 = 14;
+pub const _POSIX_NGROUPS_MAX: u32 = 8;
+pub const _POSIX_OPEN_MAX: u32 = 20;
+pub const _POSIX_PATH_MAX: u32 = 256;
+pub const _POSIX_PIPE_BUF: u32 = 512;
+pub const _POSIX_RE_DUP_MAX: u32 = 255;
+pub const _POSIX_RTSIG_MAX: u32 = 8;
+pub const _POSIX_SEM_NSEMS_MAX: u32 = 256;
+pub const _POSIX_SEM_VALUE_MAX: u32 = 32767;
+pub const _POSIX_SIGQUEUE_MAX: u32 = 32;
+pub const _POSIX_SSIZE_MAX: u32 = 32767;
+pub const _POSIX_STREAM_MAX: u32 = 8;
+pub const _POSIX_SYMLINK_MAX: u32 = 255;
+pub const _POSIX_SYMLOOP_MAX: u32 = 8;
+pub const _POSIX_TIMER_MAX: u32 = 32;
+pub const _POSIX_TTY_NAME_MAX: u32 = 9;
+pub const _POSIX_TZNAME_MAX: u32 = 6;
+pub const _POSIX_CLOCKRES_MIN: u32 = 20000000;
+pub const NR_OPEN: u32 = 1024;
+pub const NGROUPS_MAX: u32 = 65536;
+pub const ARG_MAX: u32 = 131072;
+pub const LINK_MAX: u32 = 127;
+pub const MAX_CANON: u32 = 255;
+pub const MAX_INPUT: u32 = 255;
+pub const NAME_MAX: u32 = 255;
+pub const PATH_MAX: u32 = 4096;
+pub const PIPE_BUF: u32 = 4096;
+pub const XATTR_NAME_MAX: u32 = 255;
+pub const XATTR_SIZE_MAX: u32 = 65536;
+pub const XATTR_LIST_MAX: u32 = 65536;
+pub const RTSIG_MAX: u32 = 32;
+pub const _POSIX_THREAD_KEYS_MAX: u32 = 128;
+pub const PTHREAD_KEYS_MAX: u32 = 1024;
+pub const _POSIX_THREAD_DESTRUCTOR_ITERATIONS: u32 = 4;
+pub const PTHREAD_DESTRUCTOR_ITERATIONS: u32 = 4;
+pub const _POSIX_THREAD_THREADS_MAX: u32 = 64;
+pub const AIO_PRIO_DELTA_MAX: u32 = 20;
+pub const PTHREAD_STACK_MIN: u32 = 16384;
+pub const DELAYTIMER_MAX: u32 = 2147483647;
+pub const TTY_NAME_MAX: u32 = 32;
+pub const LOGIN_NAME_MAX: u32 = 256;
+pub const HOST_NAME_MAX: u32 = 64;
+pub const MQ_PRIO_MAX: u32 = 32768;
+pub const SEM_VALUE_MAX: u32 = 2147483647;
+pub const _BITS_POSIX2_LIM_H: u32 = 1;
+pub const _POSIX2_BC_BASE_MAX: u32 = 99;
+pub const _POSIX2_BC_DIM_MAX: u32 = 2048;
+pub const _POSIX2_BC_SCALE_MAX: u32 = 99;
+pub const _POSIX2_BC_STRING_MAX: u32 = 1000;
+pub const _POSIX2_COLL_WEIGHTS_MAX: u32 = 2;
+pub const _POSIX2_EXPR_NEST_MAX: u32 = 32;
+pub const _POSIX2_LINE_MAX: u32 = 2048;
+pub const _POSIX2_RE_DUP_MAX: u32 = 255;
+pub const _POSIX2_CHARCLASS_NAME_MAX: u32 = 14;
+pub const BC_BASE_MAX: u32 = 99;
+pub const BC_DIM_MAX: u32 = 2048;
+pub const BC_SCALE_MAX: u32 = 99;
+pub const BC_STRING_MAX: u32 = 1000;
+pub const COLL_WEIGHTS_MAX: u32 = 255;
+pub const EXPR_NEST_MAX: u32 = 32;
+pub const LINE_MAX: u32 = 2048;
+pub const CHARCLASS_NAME_MAX: u32 = 2048;
+pub const RE_DUP_MAX: u32 = 32767;
+pub const _MATH_H: u32 = 1;
+pub const _BITS_LIBM_SIMD_DECL_STUBS_H: u32 = 1;
+pub const __HAVE_FLOAT128: u32 = 1;
+pub const __HAVE_DISTINCT_FLOAT128: u32 = 1;
+pub const __HAVE_FLOAT64X: u32 = 1;
+pub const __HAVE_FLOAT64X_LONG_DOUBLE: u32 = 1;
+pub const __HAVE_FLOAT16: u32 = 0;
+pub const __HAVE_FLOAT32: u32 = 1;
+pub const __HAVE_FLOAT64: u32 = 1;
+pub const __HAVE_FLOAT32X: u32 = 1;
+pub const __HAVE_FLOAT128X: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT16: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT32: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT64: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT32X: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT64X: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT128X: u32 = 0;
+pub const __HAVE_FLOATN_NOT_TYPEDEF: u32 = 1;
+pub const __FP_LOGB0_IS_MIN: u32 = 1;
+pub const __FP_LOGBNAN_IS_MIN: u32 = 1;
+pub const FP_ILOGB0: i32 = -2147483648;
+pub const FP_ILOGBNAN: i32 = -2147483648;
+pub const __MATH_DECLARING_DOUBLE: u32 = 1;
+pub const __MATH_DECLARING_FLOATN: u32 = 0;
+pub const __MATH_DECLARE_LDOUBLE: u32 = 1;
+pub const FP_NAN: u32 = 0;
+pub const FP_INFINITE: u32 = 1;
+pub const FP_ZERO: u32 = 2;
+pub const FP_SUBNORMAL: u32 = 3;
+pub const FP_NORMAL: u32 = 4;
+pub const MATH_ERRNO: u32 = 1;
+pub const MATH_ERREXCEPT: u32 = 2;
+pub const math_errhandling: u32 = 3;
+pub const M_E: f64 = 2.718281828459045;
+pub const M_LOG2E: f64 = 1.4426950408889634;
+pub const M_LOG10E: f64 = 0.4342944819032518;
+pub const M_LN2: f64 = 0.6931471805599453;
+pub const M_LN10: f64 = 2.302585092994046;
+pub const M_PI: f64 = 3.141592653589793;
+pub const M_PI_2: f64 = 1.5707963267948966;
+pub const M_PI_4: f64 = 0.7853981633974483;
+pub const M_1_PI: f64 = 0.3183098861837907;
+pub const M_2_PI: f64 = 0.6366197723675814;
+pub const M_2_SQRTPI: f64 = 1.1283791670955126;
+pub const M_SQRT2: f64 = 1.4142135623730951;
+pub const M_SQRT1_2: f64 = 0.7071067811865476;
+pub const _STDIO_H: u32 = 1;
+pub const _____fpos_t_defined: u32 = 1;
+pub const ____mbstate_t_defined: u32 = 1;
+pub const _____fpos64_t_defined: u32 = 1;
+pub const ____FILE_defined: u32 = 1;
+pub const __FILE_defined: u32 = 1;
+pub const __struct_FILE_defined: u32 = 1;
+pub const _IO_EOF_SEEN: u32 = 16;
+pub const _IO_ERR_SEEN: u32 = 32;
+pub const _IO_USER_LOCK: u32 = 32768;
+pub const _IOFBF: u32 = 0;
+pub const _IOLBF: u32 = 1;
+pub const _IONBF: u32 = 2;
+pub const BUFSIZ: u32 = 8192;
+pub const EOF: i32 = -1;
+pub const SEEK_SET: u32 = 0;
+pub const SEEK_CUR: u32 = 1;
+pub const SEEK_END: u32 = 2;
+pub const P_tmpdir: &'static [u8; 5usize] = b"/tmp\0";
+pub const _BITS_STDIO_LIM_H: u32 = 1;
+pub const L_tmpnam: u32 = 20;
+pub const TMP_MAX: u32 = 238328;
+pub const FILENAME_MAX: u32 = 4096;
+pub const L_ctermid: u32 = 9;
+pub const FOPEN_MAX: u32 = 16;
+pub const _STDLIB_H: u32 = 1;
+pub const WNOHANG: u32 = 1;
+pub const WUNTRACED: u32 = 2;
+pub const WSTOPPED: u32 = 2;
+pub const WEXITED: u32 = 4;
+pub const WCONTINUED: u32 = 8;
+pub const WNOWAIT: u32 = 16777216;
+pub const __WNOTHREAD: u32 = 536870912;
+pub const __WALL: u32 = 1073741824;
+pub const __WCLONE: u32 = 2147483648;
+pub const __W_CONTINUED: u32 = 65535;
+pub const __WCOREFLAG: u32 = 128;
+pub const __ldiv_t_defined: u32 = 1;
+pub const __lldiv_t_defined: u32 = 1;
+pub const RAND_MAX: u32 = 2147483647;
+pub const EXIT_FAILURE: u32 = 1;
+pub const EXIT_SUCCESS: u32 = 0;
+pub const _SYS_TYPES_H: u32 = 1;
+pub const __clock_t_defined: u32 = 1;
+pub const __clockid_t_defined: u32 = 1;
+pub const __time_t_defined: u32 = 1;
+pub const __timer_t_defined: u32 = 1;
+pub const __BIT_TYPES_DEFINED__: u32 = 1;
+pub const _ENDIAN_H: u32 = 1;
+pub const _BITS_ENDIAN_H: u32 = 1;
+pub const __LITTLE_ENDIAN: u32 = 1234;
+pub const __BIG_ENDIAN: u32 = 4321;
+pub const __PDP_ENDIAN: u32 = 3412;
+pub const _BITS_ENDIANNESS_H: u32 = 1;
+pub const __BYTE_ORDER: u32 = 1234;
+pub const __FLOAT_WORD_ORDER: u32 = 1234;
+pub const LITTLE_ENDIAN: u32 = 1234;
+pub const BIG_ENDIAN: u32 = 4321;
+pub const PDP_ENDIAN: u32 = 3412;
+pub const BYTE_ORDER: u32 = 1234;
+pub const _BITS_BYTESWAP_H: u32 = 1;
+pub const _BITS_UINTN_IDENTITY_H: u32 = 1;
+pub const _SYS_SELECT_H: u32 = 1;
+pub const __sigset_t_defined: u32 = 1;
+pub const __timeval_defined: u32 = 1;
+pub const _STRUCT_TIMESPEC: u32 = 1;
+pub const FD_SETSIZE: u32 = 1024;
+pub const _BITS_PTHREADTYPES_COMMON_H: u32 = 1;
+pub const _THREAD_SHARED_TYPES_H: u32 = 1;
+pub const _BITS_PTHREADTYPES_ARCH_H: u32 = 1;
+pub const __SIZEOF_PTHREAD_MUTEX_T: u32 = 40;
+pub const __SIZEOF_PTHREAD_ATTR_T: u32 = 56;
+pub const __SIZEOF_PTHREAD_RWLOCK_T: u32 = 56;
+pub const __SIZEOF_PTHREAD_BARRIER_T: u32 = 32;
+pub const __SIZEOF_PTHREAD_MUTEXATTR_T: u32 = 4;
+pub const __SIZEOF_PTHREAD_COND_T: u32 = 48;
+pub const __SIZEOF_PTHREAD_CONDATTR_T: u32 = 4;
+pub const __SIZEOF_PTHREAD_RWLOCKATTR_T: u32 = 8;
+pub const __SIZEOF_PTHREAD_BARRIERATTR_T: u32 = 4;
+pub const _THREAD_MUTEX_INTERNAL_H: u32 = 1;
+pub const __PTHREAD_MUTEX_HAVE_PREV: u32 = 1;
+pub const __have_pthread_attr_t: u32 = 1;
+pub const _ALLOCA_H: u32 = 1;
+pub const _STRING_H: u32 = 1;
+pub const _BITS_TYPES_LOCALE_T_H: u32 = 1;
+pub const _BITS_TYPES___LOCALE_T_H: u32 = 1;
+pub const _STRINGS_H: u32 = 1;
+pub const LIBAVUTIL_VERSION_MAJOR: u32 = 56;
+pub const LIBAVUTIL_VERSION_MINOR: u32 = 70;
+pub const LIBAVUTIL_VERSION_MICRO: u32 = 100;
+pub const AV_HAVE_BIGENDIAN: u32 = 0;
+pub const AV_HAVE_FAST_UNALIGNED: u32 = 1;
+pub const AVERROR_EXPERIMENTAL: i32 = -733130664;
+pub const AVERROR_INPUT_CHANGED: i32 = -1668179713;
+pub const AVERROR_OUTPUT_CHANGED: i32 = -1668179714;
+pub const AV_ERROR_MAX_STRING_SIZE: u32 = 64;
+pub const M_LOG2_10: f64 = 3.321928094887362;
+pub const M_PHI: f64 = 1.618033988749895;
+pub const AV_LOG_QUIET: i32 = -8;
+pub const AV_LOG_PANIC: u32 = 0;
+pub const AV_LOG_FATAL: u32 = 8;
+pub const AV_LOG_ERROR: u32 = 16;
+pub const AV_LOG_WARNING: u32 = 24;
+pub const AV_LOG_INFO: u32 = 32;
+pub const AV_LOG_VERBOSE: u32 = 40;
+pub const AV_LOG_DEBUG: u32 = 48;
+pub const AV_LOG_TRACE: u32 = 56;
+pub const AV_LOG_MAX_OFFSET: u32 = 64;
+pub const AV_LOG_SKIP_REPEATED: u32 = 1;
+pub const AV_LOG_PRINT_LEVEL: u32 = 2;
+pub const AVPALETTE_SIZE: u32 = 1024;
+pub const AVPALETTE_COUNT: u32 = 256;
+pub const AV_FOURCC_MAX_STRING_SIZE: u32 = 32;
+pub const AV_BUFFER_FLAG_READONLY: u32 = 1;
+pub const AV_CPU_FLAG_FORCE: u32 = 2147483648;
+pub const AV_CPU_FLAG_MMX: u32 = 1;
+pub const AV_CPU_FLAG_MMXEXT: u32 = 2;
+pub const AV_CPU_FLAG_MMX2: u32 = 2;
+pub const AV_CPU_FLAG_3DNOW: u32 = 4;
+pub const AV_CPU_FLAG_SSE: u32 = 8;
+pub const AV_CPU_FLAG_SSE2: u32 = 16;
+pub const AV_CPU_FLAG_SSE2SLOW: u32 = 1073741824;
+pub const AV_CPU_FLAG_3DNOWEXT: u32 = 32;
+pub const AV_CPU_FLAG_SSE3: u32 = 64;
+pub const AV_CPU_FLAG_SSE3SLOW: u32 = 536870912;
+pub const AV_CPU_FLAG_SSSE3: u32 = 128;
+pub const AV_CPU_FLAG_SSSE3SLOW: u32 = 67108864;
+pub const AV_CPU_FLAG_ATOM: u32 = 268435456;
+pub const AV_CPU_FLAG_SSE4: u32 = 256;
+pub const AV_CPU_FLAG_SSE42: u32 = 512;
+pub const AV_CPU_FLAG_AESNI: u32 = 524288;
+pub const AV_CPU_FLAG_AVX: u32 = 16384;
+pub const AV_CPU_FLAG_AVXSLOW: u32 = 134217728;
+pub const AV_CPU_FLAG_XOP: u32 = 1024;
+pub const AV_CPU_FLAG_FMA4: u32 = 2048;
+pub const AV_CPU_FLAG_CMOV: u32 = 4096;
+pub const AV_CPU_FLAG_AVX2: u32 = 32768;
+pub const AV_CPU_FLAG_FMA3: u32 = 65536;
+pub const AV_CPU_FLAG_BMI1: u32 = 131072;
+pub const AV_CPU_FLAG_BMI2: u32 = 262144;
+pub const AV_CPU_FLAG_AVX512: u32 = 1048576;
+pub const AV_CPU_FLAG_ALTIVEC: u32 = 1;
+pub const AV_CPU_FLAG_VSX: u32 = 2;
+pub const AV_CPU_FLAG_POWER8: u32 = 4;
+pub const AV_CPU_FLAG_ARMV5TE: u32 = 1;
+pub const AV_CPU_FLAG_ARMV6: u32 = 2;
+pub const AV_CPU_FLAG_ARMV6T2: u32 = 4;
+pub const AV_CPU_FLAG_VFP: u32 = 8;
+pub const AV_CPU_FLAG_VFPV3: u32 = 16;
+pub const AV_CPU_FLAG_NEON: u32 = 32;
+pub const AV_CPU_FLAG_ARMV8: u32 = 64;
+pub const AV_CPU_FLAG_VFP_VM: u32 = 128;
+pub const AV_CPU_FLAG_SETEND: u32 = 65536;
+pub const AV_CPU_FLAG_MMI: u32 = 1;
+pub const AV_CPU_FLAG_MSA: u32 = 2;
+pub const AV_CH_FRONT_LEFT: u32 = 1;
+pub const AV_CH_FRONT_RIGHT: u32 = 2;
+pub const AV_CH_FRONT_CENTER: u32 = 4;
+pub const AV_CH_LOW_FREQUENCY: u32 = 8;
+pub const AV_CH_BACK_LEFT: u32 = 16;
+pub const AV_CH_BACK_RIGHT: u32 = 32;
+pub const AV_CH_FRONT_LEFT_OF_CENTER: u32 = 64;
+pub const AV_CH_FRONT_RIGHT_OF_CENTER: u32 = 128;
+pub const AV_CH_BACK_CENTER: u32 = 256;
+pub const AV_CH_SIDE_LEFT: u32 = 512;
+pub const AV_CH_SIDE_RIGHT: u32 = 1024;
+pub const AV_CH_TOP_CENTER: u32 = 2048;
+pub const AV_CH_TOP_FRONT_LEFT: u32 = 4096;
+pub const AV_CH_TOP_FRONT_CENTER: u32 = 8192;
+pub const AV_CH_TOP_FRONT_RIGHT: u32 = 16384;
+pub const AV_CH_TOP_BACK_LEFT: u32 = 32768;
+pub const AV_CH_TOP_BACK_CENTER: u32 = 65536;
+pub const AV_CH_TOP_BACK_RIGHT: u32 = 131072;
+pub const AV_CH_STEREO_LEFT: u32 = 536870912;
+pub const AV_CH_STEREO_RIGHT: u32 = 1073741824;
+pub const AV_CH_WIDE_LEFT: u32 = 2147483648;
+pub const AV_CH_WIDE_RIGHT: u64 = 4294967296;
+pub const AV_CH_SURROUND_DIRECT_LEFT: u64 = 8589934592;
+pub const AV_CH_SURROUND_DIRECT_RIGHT: u64 = 17179869184;
+pub const AV_CH_LOW_FREQUENCY_2: u64 = 34359738368;
+pub const AV_CH_TOP_SIDE_LEFT: u64 = 68719476736;
+pub const AV_CH_TOP_SIDE_RIGHT: u64 = 137438953472;
+pub const AV_CH_BOTTOM_FRONT_CENTER: u64 = 274877906944;
+pub const AV_CH_BOTTOM_FRONT_LEFT: u64 = 549755813888;
+pub const AV_CH_BOTTOM_FRONT_RIGHT: u64 = 1099511627776;
+pub const AV_CH_LAYOUT_NATIVE: i64 = -9223372036854775808;
+pub const AV_CH_LAYOUT_MONO: u32 = 4;
+pub const AV_CH_LAYOUT_STEREO: u32 = 3;
+pub const AV_CH_LAYOUT_2POINT1: u32 = 11;
+pub const AV_CH_LAYOUT_2_1: u32 = 259;
+pub const AV_CH_LAYOUT_SURROUND: u32 = 7;
+pub const AV_CH_LAYOUT_3POINT1: u32 = 15;
+pub const AV_CH_LAYOUT_4POINT0: u32 = 263;
+pub const AV_CH_LAYOUT_4POINT1: u32 = 271;
+pub const AV_CH_LAYOUT_2_2: u32 = 1539;
+pub const AV_CH_LAYOUT_QUAD: u32 = 51;
+pub const AV_CH_LAYOUT_5POINT0: u32 = 1543;
+pub const AV_CH_LAYOUT_5POINT1: u32 = 1551;
+pub const AV_CH_LAYOUT_5POINT0_BACK: u32 = 55;
+pub const AV_CH_LAYOUT_5POINT1_BACK: u32 = 63;
+pub const AV_CH_LAYOUT_6POINT0: u32 = 1799;
+pub const AV_CH_LAYOUT_6POINT0_FRONT: u32 = 1731;
+pub const AV_CH_LAYOUT_HEXAGONAL: u32 = 311;
+pub const AV_CH_LAYOUT_6POINT1: u32 = 1807;
+pub const AV_CH_LAYOUT_6POINT1_BACK: u32 = 319;
+pub const AV_CH_LAYOUT_6POINT1_FRONT: u32 = 1739;
+pub const AV_CH_LAYOUT_7POINT0: u32 = 1591;
+pub const AV_CH_LAYOUT_7POINT0_FRONT: u32 = 1735;
+pub const AV_CH_LAYOUT_7POINT1: u32 = 1599;
+pub const AV_CH_LAYOUT_7POINT1_WIDE: u32 = 1743;
+pub const AV_CH_LAYOUT_7POINT1_WIDE_BACK: u32 = 255;
+pub const AV_CH_LAYOUT_OCTAGONAL: u32 = 1847;
+pub const AV_CH_LAYOUT_HEXADECAGONAL: u64 = 6442710839;
+pub const AV_CH_LAYOUT_STEREO_DOWNMIX: u32 = 1610612736;
+pub const AV_CH_LAYOUT_22POINT2: u64 = 2164663779327;
+pub const AV_DICT_MATCH_CASE: u32 = 1;
+pub const AV_DICT_IGNORE_SUFFIX: u32 = 2;
+pub const AV_DICT_DONT_STRDUP_KEY: u32 = 4;
+pub const AV_DICT_DONT_STRDUP_VAL: u32 = 8;
+pub const AV_DICT_DONT_OVERWRITE: u32 = 16;
+pub const AV_DICT_APPEND: u32 = 32;
+pub const AV_DICT_MULTIKEY: u32 = 64;
+pub const AV_NUM_DATA_POINTERS: u32 = 8;
+pub const AV_FRAME_FLAG_CORRUPT: u32 = 1;
+pub const AV_FRAME_FLAG_DISCARD: u32 = 4;
+pub const FF_DECODE_ERROR_INVALID_BITSTREAM: u32 = 1;
+pub const FF_DECODE_ERROR_MISSING_REFERENCE: u32 = 2;
+pub const FF_DECODE_ERROR_CONCEALMENT_ACTIVE: u32 = 4;
+pub const FF_DECODE_ERROR_DECODE_SLICES: u32 = 8;
+pub const LIBAVCODEC_VERSION_MAJOR: u32 = 58;
+pub const LIBAVCODEC_VERSION_MINOR: u32 = 134;
+pub const LIBAVCODEC_VERSION_MICRO: u32 = 100;
+pub const AV_PKT_FLAG_KEY: u32 = 1;
+pub const AV_PKT_FLAG_CORRUPT: u32 = 2;
+pub const AV_PKT_FLAG_DISCARD: u32 = 4;
+pub const AV_PKT_FLAG_TRUSTED: u32 = 8;
+pub const AV_PKT_FLAG_DISPOSABLE: u32 = 16;
+pub const AV_CODEC_CAP_DRAW_HORIZ_BAND: u32 = 1;
+pub const AV_CODEC_CAP_DR1: u32 = 2;
+pub const AV_CODEC_CAP_TRUNCATED: u32 = 8;
+pub const AV_CODEC_CAP_DELAY: u32 = 32;
+pub const AV_CODEC_CAP_SMALL_LAST_FRAME: u32 = 64;
+pub const AV_CODEC_CAP_SUBFRAMES: u32 = 256;
+pub const AV_CODEC_CAP_EXPERIMENTAL: u32 = 512;
+pub const AV_CODEC_CAP_CHANNEL_CONF: u32 = 1024;
+pub const AV_CODEC_CAP_FRAME_THREADS: u32 = 4096;
+pub const AV_CODEC_CAP_SLICE_THREADS: u32 = 8192;
+pub const AV_CODEC_CAP_PARAM_CHANGE: u32 = 16384;
+pub const AV_CODEC_CAP_OTHER_THREADS: u32 = 32768;
+pub const AV_CODEC_CAP_AUTO_THREADS: u32 = 32768;
+pub const AV_CODEC_CAP_VARIABLE_FRAME_SIZE: u32 = 65536;
+pub const AV_CODEC_CAP_AVOID_PROBING: u32 = 131072;
+pub const AV_CODEC_CAP_INTRA_ONLY: u32 = 1073741824;
+pub const AV_CODEC_CAP_LOSSLESS: u32 = 2147483648;
+pub const AV_CODEC_CAP_HARDWARE: u32 = 262144;
+pub const AV_CODEC_CAP_HYBRID: u32 = 524288;
+pub const AV_CODEC_CAP_ENCODER_REORDERED_OPAQUE: u32 = 1048576;
+pub const AV_CODEC_CAP_ENCODER_FLUSH: u32 = 2097152;
+pub const AV_CODEC_PROP_INTRA_ONLY: u32 = 1;
+pub const AV_CODEC_PROP_LOSSY: u32 = 2;
+pub const AV_CODEC_PROP_LOSSLESS: u32 = 4;
+pub const AV_CODEC_PROP_REORDER: u32 = 8;
+pub const AV_CODEC_PROP_BITMAP_SUB: u32 = 65536;
+pub const AV_CODEC_PROP_TEXT_SUB: u32 = 131072;
+pub const AV_INPUT_BUFFER_PADDING_SIZE: u32 = 64;
+pub const AV_INPUT_BUFFER_MIN_SIZE: u32 = 16384;
+pub const AV_CODEC_FLAG_UNALIGNED: u32 = 1;
+pub const AV_CODEC_FLAG_QSCALE: u32 = 2;
+pub const AV_CODEC_FLAG_4MV: u32 = 4;
+pub const AV_CODEC_FLAG_OUTPUT_CORRUPT: u32 = 8;
+pub const AV_CODEC_FLAG_QPEL: u32 = 16;
+pub const AV_CODEC_FLAG_DROPCHANGED: u32 = 32;
+pub const AV_CODEC_FLAG_PASS1: u32 = 512;
+pub const AV_CODEC_FLAG_PASS2: u32 = 1024;
+pub const AV_CODEC_FLAG_LOOP_FILTER: u32 = 2048;
+pub const AV_CODEC_FLAG_GRAY: u32 = 8192;
+pub const AV_CODEC_FLAG_PSNR: u32 = 32768;
+pub const AV_CODEC_FLAG_TRUNCATED: u32 = 65536;
+pub const AV_CODEC_FLAG_INTERLACED_DCT: u32 = 262144;
+pub const AV_CODEC_FLAG_LOW_DELAY: u32 = 524288;
+pub const AV_CODEC_FLAG_GLOBAL_HEADER: u32 = 4194304;
+pub const AV_CODEC_FLAG_BITEXACT: u32 = 8388608;
+pub const AV_CODEC_FLAG_AC_PRED: u32 = 16777216;
+pub const AV_CODEC_FLAG_INTERLACED_ME: u32 = 536870912;
+pub const AV_CODEC_FLAG_CLOSED_GOP: u32 = 2147483648;
+pub const AV_CODEC_FLAG2_FAST: u32 = 1;
+pub const AV_CODEC_FLAG2_NO_OUTPUT: u32 = 4;
+pub const AV_CODEC_FLAG2_LOCAL_HEADER: u32 = 8;
+pub const AV_CODEC_FLAG2_DROP_FRAME_TIMECODE: u32 = 8192;
+pub const AV_CODEC_FLAG2_CHUNKS: u32 = 32768;
+pub const AV_CODEC_FLAG2_IGNORE_CROP: u32 = 65536;
+pub const AV_CODEC_FLAG2_SHOW_ALL: u32 = 4194304;
+pub const AV_CODEC_FLAG2_EXPORT_MVS: u32 = 268435456;
+pub const AV_CODEC_FLAG2_SKIP_MANUAL: u32 = 536870912;
+pub const AV_CODEC_FLAG2_RO_FLUSH_NOOP: u32 = 1073741824;
+pub const AV_CODEC_EXPORT_DATA_MVS: u32 = 1;
+pub const AV_CODEC_EXPORT_DATA_PRFT: u32 = 2;
+pub const AV_CODEC_EXPORT_DATA_VIDEO_ENC_PARAMS: u32 = 4;
+pub const AV_CODEC_EXPORT_DATA_FILM_GRAIN: u32 = 8;
+pub const AV_GET_BUFFER_FLAG_REF: u32 = 1;
+pub const AV_GET_ENCODE_BUFFER_FLAG_REF: u32 = 1;
+pub const FF_COMPRESSION_DEFAULT: i32 = -1;
+pub const FF_PRED_LEFT: u32 = 0;
+pub const FF_PRED_PLANE: u32 = 1;
+pub const FF_PRED_MEDIAN: u32 = 2;
+pub const FF_CMP_SAD: u32 = 0;
+pub const FF_CMP_SSE: u32 = 1;
+pub const FF_CMP_SATD: u32 = 2;
+pub const FF_CMP_DCT: u32 = 3;
+pub const FF_CMP_PSNR: u32 = 4;
+pub const FF_CMP_BIT: u32 = 5;
+pub const FF_CMP_RD: u32 = 6;
+pub const FF_CMP_ZERO: u32 = 7;
+pub const FF_CMP_VSAD: u32 = 8;
+pub const FF_CMP_VSSE: u32 = 9;
+pub const FF_CMP_NSSE: u32 = 10;
+pub const FF_CMP_W53: u32 = 11;
+pub const FF_CMP_W97: u32 = 12;
+pub const FF_CMP_DCTMAX: u32 = 13;
+pub const FF_CMP_DCT264: u32 = 14;
+pub const FF_CMP_MEDIAN_SAD: u32 = 15;
+pub const FF_CMP_CHROMA: u32 = 256;
+pub const SLICE_FLAG_CODED_ORDER: u32 = 1;
+pub const SLICE_FLAG_ALLOW_FIELD: u32 = 2;
+pub const SLICE_FLAG_ALLOW_PLANE: u32 = 4;
+pub const FF_MB_DECISION_SIMPLE: u32 = 0;
+pub const FF_MB_DECISION_BITS: u32 = 1;
+pub const FF_MB_DECISION_RD: u32 = 2;
+pub const FF_CODER_TYPE_VLC: u32 = 0;
+pub const FF_CODER_TYPE_AC: u32 = 1;
+pub const FF_CODER_TYPE_RAW: u32 = 2;
+pub const FF_CODER_TYPE_RLE: u32 = 3;
+pub const FF_BUG_AUTODETECT: u32 = 1;
+pub const FF_BUG_XVID_ILACE: u32 = 4;
+pub const FF_BUG_UMP4: u32 = 8;
+pub const FF_BUG_NO_PADDING: u32 = 16;
+pub const FF_BUG_AMV: u32 = 32;
+pub const FF_BUG_QPEL_CHROMA: u32 = 64;
+pub const FF_BUG_STD_QPEL: u32 = 128;
+pub const FF_BUG_QPEL_CHROMA2: u32 = 256;
+pub const FF_BUG_DIRECT_BLOCKSIZE: u32 = 512;
+pub const FF_BUG_EDGE: u32 = 1024;
+pub const FF_BUG_HPEL_CHROMA: u32 = 2048;
+pub const FF_BUG_DC_CLIP: u32 = 4096;
+pub const FF_BUG_MS: u32 = 8192;
+pub const FF_BUG_TRUNCATED: u32 = 16384;
+pub const FF_BUG_IEDGE: u32 = 32768;
+pub const FF_COMPLIANCE_VERY_STRICT: u32 = 2;
+pub const FF_COMPLIANCE_STRICT: u32 = 1;
+pub const FF_COMPLIANCE_NORMAL: u32 = 0;
+pub const FF_COMPLIANCE_UNOFFICIAL: i32 = -1;
+pub const FF_COMPLIANCE_EXPERIMENTAL: i32 = -2;
+pub const FF_EC_GUESS_MVS: u32 = 1;
+pub const FF_EC_DEBLOCK: u32 = 2;
+pub const FF_EC_FAVOR_INTER: u32 = 256;
+pub const FF_DEBUG_PICT_INFO: u32 = 1;
+pub const FF_DEBUG_RC: u32 = 2;
+pub const FF_DEBUG_BITSTREAM: u32 = 4;
+pub const FF_DEBUG_MB_TYPE: u32 = 8;
+pub const FF_DEBUG_QP: u32 = 16;
+pub const FF_DEBUG_DCT_COEFF: u32 = 64;
+pub const FF_DEBUG_SKIP: u32 = 128;
+pub const FF_DEBUG_STARTCODE: u32 = 256;
+pub const FF_DEBUG_ER: u32 = 1024;
+pub const FF_DEBUG_MMCO: u32 = 2048;
+pub const FF_DEBUG_BUGS: u32 = 4096;
+pub const FF_DEBUG_BUFFERS: u32 = 32768;
+pub const FF_DEBUG_THREADS: u32 = 65536;
+pub const FF_DEBUG_GREEN_MD: u32 = 8388608;
+pub const FF_DEBUG_NOMC: u32 = 16777216;
+pub const AV_EF_CRCCHECK: u32 = 1;
+pub const AV_EF_BITSTREAM: u32 = 2;
+pub const AV_EF_BUFFER: u32 = 4;
+pub const AV_EF_EXPLODE: u32 = 8;
+pub const AV_EF_IGNORE_ERR: u32 = 32768;
+pub const AV_EF_CAREFUL: u32 = 65536;
+pub const AV_EF_COMPLIANT: u32 = 131072;
+pub const AV_EF_AGGRESSIVE: u32 = 262144;
+pub const FF_DCT_AUTO: u32 = 0;
+pub const FF_DCT_FASTINT: u32 = 1;
+pub const FF_DCT_INT: u32 = 2;
+pub const FF_DCT_MMX: u32 = 3;
+pub const FF_DCT_ALTIVEC: u32 = 5;
+pub const FF_DCT_FAAN: u32 = 6;
+pub const FF_IDCT_AUTO: u32 = 0;
+pub const FF_IDCT_INT: u32 = 1;
+pub const FF_IDCT_SIMPLE: u32 = 2;
+pub const FF_IDCT_SIMPLEMMX: u32 = 3;
+pub const FF_IDCT_ARM: u32 = 7;
+pub const FF_IDCT_ALTIVEC: u32 = 8;
+pub const FF_IDCT_SIMPLEARM: u32 = 10;
+pub const FF_IDCT_XVID: u32 = 14;
+pub const FF_IDCT_SIMPLEARMV5TE: u32 = 16;
+pub const FF_IDCT_SIMPLEARMV6: u32 = 17;
+pub const FF_IDCT_FAAN: u32 = 20;
+pub const FF_IDCT_SIMPLENEON: u32 = 22;
+pub const FF_IDCT_NONE: u32 = 24;
+pub const FF_IDCT_SIMPLEAUTO: u32 = 128;
+pub const FF_THREAD_FRAME: u32 = 1;
+pub const FF_THREAD_SLICE: u32 = 2;
+pub const FF_PROFILE_UNKNOWN: i32 = -99;
+pub const FF_PROFILE_RESERVED: i32 = -100;
+pub const FF_PROFILE_AAC_MAIN: u32 = 0;
+pub const FF_PROFILE_AAC_LOW: u32 = 1;
+pub const FF_PROFILE_AAC_SSR: u32 = 2;
+pub const FF_PROFILE_AAC_LTP: u32 = 3;
+pub const FF_PROFILE_AAC_HE: u32 = 4;
+pub const FF_PROFILE_AAC_HE_V2: u32 = 28;
+pub const FF_PROFILE_AAC_LD: u32 = 22;
+pub const FF_PROFILE_AAC_ELD: u32 = 38;
+pub const FF_PROFILE_MPEG2_AAC_LOW: u32 = 128;
+pub const FF_PROFILE_MPEG2_AAC_HE: u32 = 131;
+pub const FF_PROFILE_DNXHD: u32 = 0;
+pub const FF_PROFILE_DNXHR_LB: u32 = 1;
+pub const FF_PROFILE_DNXHR_SQ: u32 = 2;
+pub const FF_PROFILE_DNXHR_HQ: u32 = 3;
+pub const FF_PROFILE_DNXHR_HQX: u32 = 4;
+pub const FF_PROFILE_DNXHR_444: u32 = 5;
+pub const FF_PROFILE_DTS: u32 = 20;
+pub const FF_PROFILE_DTS_ES: u32 = 30;
+pub const FF_PROFILE_DTS_96_24: u32 = 40;
+pub const FF_PROFILE_DTS_HD_HRA: u32 = 50;
+pub const FF_PROFILE_DTS_HD_MA: u32 = 60;
+pub const FF_PROFILE_DTS_EXPRESS: u32 = 70;
+pub const FF_PROFILE_MPEG2_422: u32 = 0;
+pub const FF_PROFILE_MPEG2_HIGH: u32 = 1;
+pub const FF_PROFILE_MPEG2_SS: u32 = 2;
+pub const FF_PROFILE_MPEG2_SNR_SCALABLE: u32 = 3;
+pub const FF_PROFILE_MPEG2_MAIN: u32 = 4;
+pub const FF_PROFILE_MPEG2_SIMPLE: u32 = 5;
+pub const FF_PROFILE_H264_CONSTRAINED: u32 = 512;
+pub const FF_PROFILE_H264_INTRA: u32 = 2048;
+pub const FF_PROFILE_H264_BASELINE: u32 = 66;
+pub const FF_PROFILE_H264_CONSTRAINED_BASELINE: u32 = 578;
+pub const FF_PROFILE_H264_MAIN: u32 = 77;
+pub const FF_PROFILE_H264_EXTENDED: u32 = 88;
+pub const FF_PROFILE_H264_HIGH: u32 = 100;
+pub const FF_PROFILE_H264_HIGH_10: u32 = 110;
+pub const FF_PROFILE_H264_HIGH_10_INTRA: u32 = 2158;
+pub const FF_PROFILE_H264_MULTIVIEW_HIGH: u32 = 118;
+pub const FF_PROFILE_H264_HIGH_422: u32 = 122;
+pub const FF_PROFILE_H264_HIGH_422_INTRA: u32 = 2170;
+pub const FF_PROFILE_H264_STEREO_HIGH: u32 = 128;
+pub const FF_PROFILE_H264_HIGH_444: u32 = 144;
+pub const FF_PROFILE_H264_HIGH_444_PREDICTIVE: u32 = 244;
+pub const FF_PROFILE_H264_HIGH_444_INTRA: u32 = 2292;
+pub const FF_PROFILE_H264_CAVLC_444: u32 = 44;
+pub const FF_PROFILE_VC1_SIMPLE: u32 = 0;
+pub const FF_PROFILE_VC1_MAIN: u32 = 1;
+pub const FF_PROFILE_VC1_COMPLEX: u32 = 2;
+pub const FF_PROFILE_VC1_ADVANCED: u32 = 3;
+pub const FF_PROFILE_MPEG4_SIMPLE: u32 = 0;
+pub const FF_PROFILE_MPEG4_SIMPLE_SCALABLE: u32 = 1;
+pub const FF_PROFILE_MPEG4_CORE: u32 = 2;
+pub const FF_PROFILE_MPEG4_MAIN: u32 = 3;
+pub const FF_PROFILE_MPEG4_N_BIT: u32 = 4;
+pub const FF_PROFILE_MPEG4_SCALABLE_TEXTURE: u32 = 5;
+pub const FF_PROFILE_MPEG4_SIMPLE_FACE_ANIMATION: u32 = 6;
+pub const FF_PROFILE_MPEG4_BASIC_ANIMATED_TEXTURE: u32 = 7;
+pub const FF_PROFILE_MPEG4_HYBRID: u32 = 8;
+pub const FF_PROFILE_MPEG4_ADVANCED_REAL_TIME: u32 = 9;
+pub const FF_PROFILE_MPEG4_CORE_SCALABLE: u32 = 10;
+pub const FF_PROFILE_MPEG4_ADVANCED_CODING: u32 = 11;
+pub const FF_PROFILE_MPEG4_ADVANCED_CORE: u32 = 12;
+pub const FF_PROFILE_MPEG4_ADVANCED_SCALABLE_TEXTURE: u32 = 13;
+pub const FF_PROFILE_MPEG4_SIMPLE_STUDIO: u32 = 14;
+pub const FF_PROFILE_MPEG4_ADVANCED_SIMPLE: u32 = 15;
+pub const FF_PROFILE_JPEG2000_CSTREAM_RESTRICTION_0: u32 = 1;
+pub const FF_PROFILE_JPEG2000_CSTREAM_RESTRICTION_1: u32 = 2;
+pub const FF_PROFILE_JPEG2000_CSTREAM_NO_RESTRICTION: u32 = 32768;
+pub const FF_PROFILE_JPEG2000_DCINEMA_2K: u32 = 3;
+pub const FF_PROFILE_JPEG2000_DCINEMA_4K: u32 = 4;
+pub const FF_PROFILE_VP9_0: u32 = 0;
+pub const FF_PROFILE_VP9_1: u32 = 1;
+pub const FF_PROFILE_VP9_2: u32 = 2;
+pub const FF_PROFILE_VP9_3: u32 = 3;
+pub const FF_PROFILE_HEVC_MAIN: u32 = 1;
+pub const FF_PROFILE_HEVC_MAIN_10: u32 = 2;
+pub const FF_PROFILE_HEVC_MAIN_STILL_PICTURE: u32 = 3;
+pub const FF_PROFILE_HEVC_REXT: u32 = 4;
+pub const FF_PROFILE_VVC_MAIN_10: u32 = 1;
+pub const FF_PROFILE_VVC_MAIN_10_444: u32 = 33;
+pub const FF_PROFILE_AV1_MAIN: u32 = 0;
+pub const FF_PROFILE_AV1_HIGH: u32 = 1;
+pub const FF_PROFILE_AV1_PROFESSIONAL: u32 = 2;
+pub const FF_PROFILE_MJPEG_HUFFMAN_BASELINE_DCT: u32 = 192;
+pub const FF_PROFILE_MJPEG_HUFFMAN_EXTENDED_SEQUENTIAL_DCT: u32 = 193;
+pub const FF_PROFILE_MJPEG_HUFFMAN_PROGRESSIVE_DCT: u32 = 194;
+pub const FF_PROFILE_MJPEG_HUFFMAN_LOSSLESS: u32 = 195;
+pub const FF_PROFILE_MJPEG_JPEG_LS: u32 = 247;
+pub const FF_PROFILE_SBC_MSBC: u32 = 1;
+pub const FF_PROFILE_PRORES_PROXY: u32 = 0;
+pub const FF_PROFILE_PRORES_LT: u32 = 1;
+pub const FF_PROFILE_PRORES_STANDARD: u32 = 2;
+pub const FF_PROFILE_PRORES_HQ: u32 = 3;
+pub const FF_PROFILE_PRORES_4444: u32 = 4;
+pub const FF_PROFILE_PRORES_XQ: u32 = 5;
+pub const FF_PROFILE_ARIB_PROFILE_A: u32 = 0;
+pub const FF_PROFILE_ARIB_PROFILE_C: u32 = 1;
+pub const FF_PROFILE_KLVA_SYNC: u32 = 0;
+pub const FF_PROFILE_KLVA_ASYNC: u32 = 1;
+pub const FF_LEVEL_UNKNOWN: i32 = -99;
+pub const FF_SUB_CHARENC_MODE_DO_NOTHING: i32 = -1;
+pub const FF_SUB_CHARENC_MODE_AUTOMATIC: u32 = 0;
+pub const FF_SUB_CHARENC_MODE_PRE_DECODER: u32 = 1;
+pub const FF_SUB_CHARENC_MODE_IGNORE: u32 = 2;
+pub const FF_DEBUG_VIS_MV_P_FOR: u32 = 1;
+pub const FF_DEBUG_VIS_MV_B_FOR: u32 = 2;
+pub const FF_DEBUG_VIS_MV_B_BACK: u32 = 4;
+pub const FF_CODEC_PROPERTY_LOSSLESS: u32 = 1;
+pub const FF_CODEC_PROPERTY_CLOSED_CAPTIONS: u32 = 2;
+pub const FF_SUB_TEXT_FMT_ASS: u32 = 0;
+pub const FF_SUB_TEXT_FMT_ASS_WITH_TIMINGS: u32 = 1;
+pub const AV_HWACCEL_CODEC_CAP_EXPERIMENTAL: u32 = 512;
+pub const AV_HWACCEL_FLAG_IGNORE_LEVEL: u32 = 1;
+pub const AV_HWACCEL_FLAG_ALLOW_HIGH_DEPTH: u32 = 2;
+pub const AV_HWACCEL_FLAG_ALLOW_PROFILE_MISMATCH: u32 = 4;
+pub const AV_SUBTITLE_FLAG_FORCED: u32 = 1;
+pub const AV_PARSER_PTS_NB: u32 = 4;
+pub const PARSER_FLAG_COMPLETE_FRAMES: u32 = 1;
+pub const PARSER_FLAG_ONCE: u32 = 2;
+pub const PARSER_FLAG_FETCHED_OFFSET: u32 = 4;
+pub const PARSER_FLAG_USE_CODEC_TS: u32 = 4096;
+pub const AV_OPT_FLAG_ENCODING_PARAM: u32 = 1;
+pub const AV_OPT_FLAG_DECODING_PARAM: u32 = 2;
+pub const AV_OPT_FLAG_AUDIO_PARAM: u32 = 8;
+pub const AV_OPT_FLAG_VIDEO_PARAM: u32 = 16;
+pub const AV_OPT_FLAG_SUBTITLE_PARAM: u32 = 32;
+pub const AV_OPT_FLAG_EXPORT: u32 = 64;
+pub const AV_OPT_FLAG_READONLY: u32 = 128;
+pub const AV_OPT_FLAG_BSF_PARAM: u32 = 256;
+pub const AV_OPT_FLAG_RUNTIME_PARAM: u32 = 32768;
+pub const AV_OPT_FLAG_FILTERING_PARAM: u32 = 65536;
+pub const AV_OPT_FLAG_DEPRECATED: u32 = 131072;
+pub const AV_OPT_FLAG_CHILD_CONSTS: u32 = 262144;
+pub const AV_OPT_SEARCH_CHILDREN: u32 = 1;
+pub const AV_OPT_SEARCH_FAKE_OBJ: u32 = 2;
+pub const AV_OPT_ALLOW_NULL: u32 = 4;
+pub const AV_OPT_MULTI_COMPONENT_RANGE: u32 = 4096;
+pub const AV_OPT_SERIALIZE_SKIP_DEFAULTS: u32 = 1;
+pub const AV_OPT_SERIALIZE_OPT_FLAGS_EXACT: u32 = 2;
+pub const AV_PIX_FMT_FLAG_BE: u32 = 1;
+pub const AV_PIX_FMT_FLAG_PAL: u32 = 2;
+pub const AV_PIX_FMT_FLAG_BITSTREAM: u32 = 4;
+pub const AV_PIX_FMT_FLAG_HWACCEL: u32 = 8;
+pub const AV_PIX_FMT_FLAG_PLANAR: u32 = 16;
+pub const AV_PIX_FMT_FLAG_RGB: u32 = 32;
+pub const AV_PIX_FMT_FLAG_PSEUDOPAL: u32 = 64;
+pub const AV_PIX_FMT_FLAG_ALPHA: u32 = 128;
+pub const AV_PIX_FMT_FLAG_BAYER: u32 = 256;
+pub const AV_PIX_FMT_FLAG_FLOAT: u32 = 512;
+pub const FF_LOSS_RESOLUTION: u32 = 1;
+pub const FF_LOSS_DEPTH: u32 = 2;
+pub const FF_LOSS_COLORSPACE: u32 = 4;
+pub const FF_LOSS_ALPHA: u32 = 8;
+pub const FF_LOSS_COLORQUANT: u32 = 16;
+pub const FF_LOSS_CHROMA: u32 = 32;
+pub const _TIME_H: u32 = 1;
+pub const _BITS_TIME_H: u32 = 1;
+pub const CLOCK_REALTIME: u32 = 0;
+pub const CLOCK_MONOTONIC: u32 = 1;
+pub const CLOCK_PROCESS_CPUTIME_ID: u32 = 2;
+pub const CLOCK_THREAD_CPUTIME_ID: u32 = 3;
+pub const CLOCK_MONOTONIC_RAW: u32 = 4;
+pub const CLOCK_REALTIME_COARSE: u32 = 5;
+pub const CLOCK_MONOTONIC_COARSE: u32 = 6;
+pub const CLOCK_BOOTTIME: u32 = 7;
+pub const CLOCK_REALTIME_ALARM: u32 = 8;
+pub const CLOCK_BOOTTIME_ALARM: u32 = 9;
+pub const CLOCK_TAI: u32 = 11;
+pub const TIMER_ABSTIME: u32 = 1;
+pub const __struct_tm_defined: u32 = 1;
+pub const __itimerspec_defined: u32 = 1;
+pub const TIME_UTC: u32 = 1;
+pub const LIBAVFORMAT_VERSION_MAJOR: u32 = 58;
+pub const LIBAVFORMAT_VERSION_MINOR: u32 = 76;
+pub const LIBAVFORMAT_VERSION_MICRO: u32 = 100;
+pub const FF_API_R_FRAME_RATE: u32 = 1;
+pub const AVIO_SEEKABLE_NORMAL: u32 = 1;
+pub const AVIO_SEEKABLE_TIME: u32 = 2;
+pub const AVSEEK_SIZE: u32 = 65536;
+pub const AVSEEK_FORCE: u32 = 131072;
+pub const AVIO_FLAG_READ: u32 = 1;
+pub const AVIO_FLAG_WRITE: u32 = 2;
+pub const AVIO_FLAG_READ_WRITE: u32 = 3;
+pub const AVIO_FLAG_NONBLOCK: u32 = 8;
+pub const AVIO_FLAG_DIRECT: u32 = 32768;
+pub const AVPROBE_SCORE_EXTENSION: u32 = 50;
+pub const AVPROBE_SCORE_MIME: u32 = 75;
+pub const AVPROBE_SCORE_MAX: u32 = 100;
+pub const AVPROBE_PADDING_SIZE: u32 = 32;
+pub const AVFMT_NOFILE: u32 = 1;
+pub const AVFMT_NEEDNUMBER: u32 = 2;
+pub const AVFMT_SHOW_IDS: u32 = 8;
+pub const AVFMT_GLOBALHEADER: u32 = 64;
+pub const AVFMT_NOTIMESTAMPS: u32 = 128;
+pub const AVFMT_GENERIC_INDEX: u32 = 256;
+pub const AVFMT_TS_DISCONT: u32 = 512;
+pub const AVFMT_VARIABLE_FPS: u32 = 1024;
+pub const AVFMT_NODIMENSIONS: u32 = 2048;
+pub const AVFMT_NOSTREAMS: u32 = 4096;
+pub const AVFMT_NOBINSEARCH: u32 = 8192;
+pub const AVFMT_NOGENSEARCH: u32 = 16384;
+pub const AVFMT_NO_BYTE_SEEK: u32 = 32768;
+pub const AVFMT_ALLOW_FLUSH: u32 = 65536;
+pub const AVFMT_TS_NONSTRICT: u32 = 131072;
+pub const AVFMT_TS_NEGATIVE: u32 = 262144;
+pub const AVFMT_SEEK_TO_PTS: u32 = 67108864;
+pub const AVINDEX_KEYFRAME: u32 = 1;
+pub const AVINDEX_DISCARD_FRAME: u32 = 2;
+pub const AV_DISPOSITION_DEFAULT: u32 = 1;
+pub const AV_DISPOSITION_DUB: u32 = 2;
+pub const AV_DISPOSITION_ORIGINAL: u32 = 4;
+pub const AV_DISPOSITION_COMMENT: u32 = 8;
+pub const AV_DISPOSITION_LYRICS: u32 = 16;
+pub const AV_DISPOSITION_KARAOKE: u32 = 32;
+pub const AV_DISPOSITION_FORCED: u32 = 64;
+pub const AV_DISPOSITION_HEARING_IMPAIRED: u32 = 128;
+pub const AV_DISPOSITION_VISUAL_IMPAIRED: u32 = 256;
+pub const AV_DISPOSITION_CLEAN_EFFECTS: u32 = 512;
+pub const AV_DISPOSITION_ATTACHED_PIC: u32 = 1024;
+pub const AV_DISPOSITION_TIMED_THUMBNAILS: u32 = 2048;
+pub const AV_DISPOSITION_CAPTIONS: u32 = 65536;
+pub const AV_DISPOSITION_DESCRIPTIONS: u32 = 131072;
+pub const AV_DISPOSITION_METADATA: u32 = 262144;
+pub const AV_DISPOSITION_DEPENDENT: u32 = 524288;
+pub const AV_DISPOSITION_STILL_IMAGE: u32 = 1048576;
+pub const AV_PTS_WRAP_IGNORE: u32 = 0;
+pub const AV_PTS_WRAP_ADD_OFFSET: u32 = 1;
+pub const AV_PTS_WRAP_SUB_OFFSET: i32 = -1;
+pub const AVSTREAM_EVENT_FLAG_METADATA_UPDATED: u32 = 1;
+pub const AVSTREAM_EVENT_FLAG_NEW_PACKETS: u32 = 2;
+pub const AV_PROGRAM_RUNNING: u32 = 1;
+pub const AVFMTCTX_NOHEADER: u32 = 1;
+pub const AVFMTCTX_UNSEEKABLE: u32 = 2;
+pub const AVFMT_FLAG_GENPTS: u32 = 1;
+pub const AVFMT_FLAG_IGNIDX: u32 = 2;
+pub const AVFMT_FLAG_NONBLOCK: u32 = 4;
+pub const AVFMT_FLAG_IGNDTS: u32 = 8;
+pub const AVFMT_FLAG_NOFILLIN: u32 = 16;
+pub const AVFMT_FLAG_NOPARSE: u32 = 32;
+pub const AVFMT_FLAG_NOBUFFER: u32 = 64;
+pub const AVFMT_FLAG_CUSTOM_IO: u32 = 128;
+pub const AVFMT_FLAG_DISCARD_CORRUPT: u32 = 256;
+pub const AVFMT_FLAG_FLUSH_PACKETS: u32 = 512;
+pub const AVFMT_FLAG_BITEXACT: u32 = 1024;
+pub const AVFMT_FLAG_MP4A_LATM: u32 = 32768;
+pub const AVFMT_FLAG_SORT_DTS: u32 = 65536;
+pub const AVFMT_FLAG_PRIV_OPT: u32 = 131072;
+pub const AVFMT_FLAG_KEEP_SIDE_DATA: u32 = 262144;
+pub const AVFMT_FLAG_FAST_SEEK: u32 = 524288;
+pub const AVFMT_FLAG_SHORTEST: u32 = 1048576;
+pub const AVFMT_FLAG_AUTO_BSF: u32 = 2097152;
+pub const FF_FDEBUG_TS: u32 = 1;
+pub const AVFMT_EVENT_FLAG_METADATA_UPDATED: u32 = 1;
+pub const AVFMT_AVOID_NEG_TS_AUTO: i32 = -1;
+pub const AVFMT_AVOID_NEG_TS_MAKE_NON_NEGATIVE: u32 = 1;
+pub const AVFMT_AVOID_NEG_TS_MAKE_ZERO: u32 = 2;
+pub const AVSEEK_FLAG_BACKWARD: u32 = 1;
+pub const AVSEEK_FLAG_BYTE: u32 = 2;
+pub const AVSEEK_FLAG_ANY: u32 = 4;
+pub const AVSEEK_FLAG_FRAME: u32 = 8;
+pub const AVSTREAM_INIT_IN_WRITE_HEADER: u32 = 0;
+pub const AVSTREAM_INIT_IN_INIT_OUTPUT: u32 = 1;
+pub const AV_FRAME_FILENAME_FLAGS_MULTIPLE: u32 = 1;
+pub const LIBAVFILTER_VERSION_MAJOR: u32 = 7;
+pub const LIBAVFILTER_VERSION_MINOR: u32 = 110;
+pub const LIBAVFILTER_VERSION_MICRO: u32 = 100;
+pub const AVFILTER_FLAG_DYNAMIC_INPUTS: u32 = 1;
+pub const AVFILTER_FLAG_DYNAMIC_OUTPUTS: u32 = 2;
+pub const AVFILTER_FLAG_SLICE_THREADS: u32 = 4;
+pub const AVFILTER_FLAG_SUPPORT_TIMELINE_GENERIC: u32 = 65536;
+pub const AVFILTER_FLAG_SUPPORT_TIMELINE_INTERNAL: u32 = 131072;
+pub const AVFILTER_FLAG_SUPPORT_TIMELINE: u32 = 196608;
+pub const AVFILTER_THREAD_SLICE: u32 = 1;
+pub const AVFILTER_CMD_FLAG_ONE: u32 = 1;
+pub const AVFILTER_CMD_FLAG_FAST: u32 = 2;
+pub const AV_BUFFERSINK_FLAG_PEEK: u32 = 1;
+pub const AV_BUFFERSINK_FLAG_NO_REQUEST: u32 = 2;
+pub const LIBAVDEVICE_VERSION_MAJOR: u32 = 58;
+pub const LIBAVDEVICE_VERSION_MINOR: u32 = 13;
+pub const LIBAVDEVICE_VERSION_MICRO: u32 = 100;
+pub const LIBSWSCALE_VERSION_MAJOR: u32 = 5;
+pub const LIBSWSCALE_VERSION_MINOR: u32 = 9;
+pub const LIBSWSCALE_VERSION_MICRO: u32 = 100;
+pub const SWS_FAST_BILINEAR: u32 = 1;
+pub const SWS_BILINEAR: u32 = 2;
+pub const SWS_BICUBIC: u32 = 4;
+pub const SWS_X: u32 = 8;
+pub const SWS_POINT: u32 = 16;
+pub const SWS_AREA: u32 = 32;
+pub const SWS_BICUBLIN: u32 = 64;
+pub const SWS_GAUSS: u32 = 128;
+pub const SWS_SINC: u32 = 256;
+pub const SWS_LANCZOS: u32 = 512;
+pub const SWS_SPLINE: u32 = 1024;
+pub const SWS_SRC_V_CHR_DROP_MASK: u32 = 196608;
+pub const SWS_SRC_V_CHR_DROP_SHIFT: u32 = 16;
+pub const SWS_PARAM_DEFAULT: u32 = 123456;
+pub const SWS_PRINT_INFO: u32 = 4096;
+pub const SWS_FULL_CHR_H_INT: u32 = 8192;
+pub const SWS_FULL_CHR_H_INP: u32 = 16384;
+pub const SWS_DIRECT_BGR: u32 = 32768;
+pub const SWS_ACCURATE_RND: u32 = 262144;
+pub const SWS_BITEXACT: u32 = 524288;
+pub const SWS_ERROR_DIFFUSION: u32 = 8388608;
+pub const SWS_MAX_REDUCE_CUTOFF: f64 = 0.002;
+pub const SWS_CS_ITU709: u32 = 1;
+pub const SWS_CS_FCC: u32 = 4;
+pub const SWS_CS_ITU601: u32 = 5;
+pub const SWS_CS_ITU624: u32 = 5;
+pub const SWS_CS_SMPTE170M: u32 = 5;
+pub const SWS_CS_SMPTE240M: u32 = 7;
+pub const SWS_CS_DEFAULT: u32 = 5;
+pub const SWS_CS_BT2020: u32 = 9;
+pub const LIBSWRESAMPLE_VERSION_MAJOR: u32 = 3;
+pub const LIBSWRESAMPLE_VERSION_MINOR: u32 = 9;
+pub const LIBSWRESAMPLE_VERSION_MICRO: u32 = 100;
+pub const SWR_FLAG_RESAMPLE: u32 = 1;
+pub type __off_t = ::std::os::raw::c_long;
+pub type __off64_t = ::std::os::raw::c_long;
+pub const AVMediaType_AVMEDIA_TYPE_UNKNOWN: AVMediaType = -1;
+pub const AVMediaType_AVMEDIA_TYPE_VIDEO: AVMediaType = 0;
+pub const AVMediaType_AVMEDIA_TYPE_AUDIO: AVMediaType = 1;
+pub const AVMediaType_AVMEDIA_TYPE_DATA: AVMediaType = 2;
+pub const AVMediaType_AVMEDIA_TYPE_SUBTITLE: AVMediaType = 3;
+pub const AVMediaType_AVMEDIA_TYPE_ATTACHMENT: AVMediaType = 4;
+pub const AVMediaType_AVMEDIA_TYPE_NB: AVMediaType = 5;
+pub type AVMediaType = ::std::os::raw::c_int;
+pub const AVPictureType_AV_PICTURE_TYPE_NONE: AVPictureType = 0;
+pub const AVPictureType_AV_PICTURE_TYPE_I: AVPictureType = 1;
+pub const AVPictureType_AV_PICTURE_TYPE_P: AVPictureType = 2;
+pub const AVPictureType_AV_PICTURE_TYPE_B: AVPictureType = 3;
+pub const AVPictureType_AV_PICTURE_TYPE_S: AVPictureType = 4;
+pub const AVPictureType_AV_PICTURE_TYPE_SI: AVPictureType = 5;
+pub const AVPictureType_AV_PICTURE_TYPE_SP: AVPictureType = 6;
+pub const AVPictureType_AV_PICTURE_TYPE_BI: AVPictureType = 7;
+pub type AVPictureType = ::std::os::raw::c_uint;
+pub type size_t = ::std::os::raw::c_ulong;
+pub type __gnuc_va_list = __builtin_va_list;
+pub type FILE = _IO_FILE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_marker {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_codecvt {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_wide_data {
+    _unused: [u8; 0],
+}
+pub type _IO_lock_t = ::std::os::raw::c_void;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_FILE {
+    pub _flags: ::std::os::raw::c_int,
+    pub _IO_read_ptr: *mut ::std::os::raw::c_char,
+    pub _IO_read_end: *mut ::std::os::raw::c_char,
+    pub _IO_read_base: *mut ::std::os::raw::c_char,
+    pub _IO_write_base: *mut ::std::os::raw::c_char,
+    pub _IO_write_ptr: *mut ::std::os::raw::c_char,
+    pub _IO_write_end: *mut ::std::os::raw::c_char,
+    pub _IO_buf_base: *mut ::std::os::raw::c_char,
+    pub _IO_buf_end: *mut ::std::os::raw::c_char,
+    pub _IO_save_base: *mut ::std::os::raw::c_char,
+    pub _IO_backup_base: *mut ::std::os::raw::c_char,
+    pub _IO_save_end: *mut ::std::os::raw::c_char,
+    pub _markers: *mut _IO_marker,
+    pub _chain: *mut _IO_FILE,
+    pub _fileno: ::std::os::raw::c_int,
+    pub _flags2: ::std::os::raw::c_int,
+    pub _old_offset: __off_t,
+    pub _cur_column: ::std::os::raw::c_ushort,
+    pub _vtable_offset: ::std::os::raw::c_schar,
+    pub _shortbuf: [::std::os::raw::c_char; 1usize],
+    pub _lock: *mut _IO_lock_t,
+    pub _offset: __off64_t,
+    pub _codecvt: *mut _IO_codecvt,
+    pub _wide_data: *mut _IO_wide_data,
+    pub _freeres_list: *mut _IO_FILE,
+    pub _freeres_buf: *mut ::std::os::raw::c_void,
+    pub __pad5: size_t,
+    pub _mode: ::std::os::raw::c_int,
+    pub _unused2: [::std::os::raw::c_char; 20usize],
+}
+#[test]
+fn bindgen_test_layout__IO_FILE() {
+    const UNINIT: ::std::mem::MaybeUninit<_IO_FILE> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_IO_FILE>(),
+        216usize,
+        concat!("Size of: ", stringify!(_IO_FILE))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_IO_FILE>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_IO_FILE))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._flags) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_read_ptr) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_read_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_read_end) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_read_end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_read_base) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_read_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_write_base) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_write_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_write_ptr) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_write_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_write_end) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_write_end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_buf_base) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_buf_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_buf_end) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_buf_end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_save_base) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_save_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_backup_base) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_backup_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._IO_save_end) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_IO_save_end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._markers) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_markers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._chain) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_chain)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._fileno) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_fileno)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._flags2) as usize - ptr as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_flags2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._old_offset) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_old_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._cur_column) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_cur_column)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._vtable_offset) as usize - ptr as usize },
+        130usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_vtable_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._shortbuf) as usize - ptr as usize },
+        131usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_shortbuf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._lock) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_lock)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._offset) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._codecvt) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_codecvt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._wide_data) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_wide_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._freeres_list) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_freeres_list)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._freeres_buf) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_freeres_buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).__pad5) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(__pad5)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._mode) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_mode)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._unused2) as usize - ptr as usize },
+        196usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_IO_FILE),
+            "::",
+            stringify!(_unused2)
+        )
+    );
+}
+pub type va_list = __gnuc_va_list;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union av_intfloat32 {
+    pub i: u32,
+    pub f: f32,
+}
+#[test]
+fn bindgen_test_layout_av_intfloat32() {
+    const UNINIT: ::std::mem::MaybeUninit<av_intfloat32> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<av_intfloat32>(),
+        4usize,
+        concat!("Size of: ", stringify!(av_intfloat32))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<av_intfloat32>(),
+        4usize,
+        concat!("Alignment of ", stringify!(av_intfloat32))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(av_intfloat32),
+            "::",
+            stringify!(i)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).f) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(av_intfloat32),
+            "::",
+            stringify!(f)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union av_intfloat64 {
+    pub i: u64,
+    pub f: f64,
+}
+#[test]
+fn bindgen_test_layout_av_intfloat64() {
+    const UNINIT: ::std::mem::MaybeUninit<av_intfloat64> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<av_intfloat64>(),
+        8usize,
+        concat!("Size of: ", stringify!(av_intfloat64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<av_intfloat64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(av_intfloat64))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(av_intfloat64),
+            "::",
+            stringify!(i)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).f) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(av_intfloat64),
+            "::",
+            stringify!(f)
+        )
+    );
+}
+pub const AVRounding_AV_ROUND_ZERO: AVRounding = 0;
+pub const AVRounding_AV_ROUND_INF: AVRounding = 1;
+pub const AVRounding_AV_ROUND_DOWN: AVRounding = 2;
+pub const AVRounding_AV_ROUND_UP: AVRounding = 3;
+pub const AVRounding_AV_ROUND_NEAR_INF: AVRounding = 5;
+pub const AVRounding_AV_ROUND_PASS_MINMAX: AVRounding = 8192;
+pub type AVRounding = ::std::os::raw::c_uint;
+pub const AVPixelFormat_AV_PIX_FMT_NONE: AVPixelFormat = -1;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P: AVPixelFormat = 0;
+pub const AVPixelFormat_AV_PIX_FMT_YUYV422: AVPixelFormat = 1;
+pub const AVPixelFormat_AV_PIX_FMT_RGB24: AVPixelFormat = 2;
+pub const AVPixelFormat_AV_PIX_FMT_BGR24: AVPixelFormat = 3;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P: AVPixelFormat = 4;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P: AVPixelFormat = 5;
+pub const AVPixelFormat_AV_PIX_FMT_YUV410P: AVPixelFormat = 6;
+pub const AVPixelFormat_AV_PIX_FMT_YUV411P: AVPixelFormat = 7;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY8: AVPixelFormat = 8;
+pub const AVPixelFormat_AV_PIX_FMT_MONOWHITE: AVPixelFormat = 9;
+pub const AVPixelFormat_AV_PIX_FMT_MONOBLACK: AVPixelFormat = 10;
+pub const AVPixelFormat_AV_PIX_FMT_PAL8: AVPixelFormat = 11;
+pub const AVPixelFormat_AV_PIX_FMT_YUVJ420P: AVPixelFormat = 12;
+pub const AVPixelFormat_AV_PIX_FMT_YUVJ422P: AVPixelFormat = 13;
+pub const AVPixelFormat_AV_PIX_FMT_YUVJ444P: AVPixelFormat = 14;
+pub const AVPixelFormat_AV_PIX_FMT_UYVY422: AVPixelFormat = 15;
+pub const AVPixelFormat_AV_PIX_FMT_UYYVYY411: AVPixelFormat = 16;
+pub const AVPixelFormat_AV_PIX_FMT_BGR8: AVPixelFormat = 17;
+pub const AVPixelFormat_AV_PIX_FMT_BGR4: AVPixelFormat = 18;
+pub const AVPixelFormat_AV_PIX_FMT_BGR4_BYTE: AVPixelFormat = 19;
+pub const AVPixelFormat_AV_PIX_FMT_RGB8: AVPixelFormat = 20;
+pub const AVPixelFormat_AV_PIX_FMT_RGB4: AVPixelFormat = 21;
+pub const AVPixelFormat_AV_PIX_FMT_RGB4_BYTE: AVPixelFormat = 22;
+pub const AVPixelFormat_AV_PIX_FMT_NV12: AVPixelFormat = 23;
+pub const AVPixelFormat_AV_PIX_FMT_NV21: AVPixelFormat = 24;
+pub const AVPixelFormat_AV_PIX_FMT_ARGB: AVPixelFormat = 25;
+pub const AVPixelFormat_AV_PIX_FMT_RGBA: AVPixelFormat = 26;
+pub const AVPixelFormat_AV_PIX_FMT_ABGR: AVPixelFormat = 27;
+pub const AVPixelFormat_AV_PIX_FMT_BGRA: AVPixelFormat = 28;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY16BE: AVPixelFormat = 29;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY16LE: AVPixelFormat = 30;
+pub const AVPixelFormat_AV_PIX_FMT_YUV440P: AVPixelFormat = 31;
+pub const AVPixelFormat_AV_PIX_FMT_YUVJ440P: AVPixelFormat = 32;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P: AVPixelFormat = 33;
+pub const AVPixelFormat_AV_PIX_FMT_RGB48BE: AVPixelFormat = 34;
+pub const AVPixelFormat_AV_PIX_FMT_RGB48LE: AVPixelFormat = 35;
+pub const AVPixelFormat_AV_PIX_FMT_RGB565BE: AVPixelFormat = 36;
+pub const AVPixelFormat_AV_PIX_FMT_RGB565LE: AVPixelFormat = 37;
+pub const AVPixelFormat_AV_PIX_FMT_RGB555BE: AVPixelFormat = 38;
+pub const AVPixelFormat_AV_PIX_FMT_RGB555LE: AVPixelFormat = 39;
+pub const AVPixelFormat_AV_PIX_FMT_BGR565BE: AVPixelFormat = 40;
+pub const AVPixelFormat_AV_PIX_FMT_BGR565LE: AVPixelFormat = 41;
+pub const AVPixelFormat_AV_PIX_FMT_BGR555BE: AVPixelFormat = 42;
+pub const AVPixelFormat_AV_PIX_FMT_BGR555LE: AVPixelFormat = 43;
+pub const AVPixelFormat_AV_PIX_FMT_VAAPI_MOCO: AVPixelFormat = 44;
+pub const AVPixelFormat_AV_PIX_FMT_VAAPI_IDCT: AVPixelFormat = 45;
+pub const AVPixelFormat_AV_PIX_FMT_VAAPI_VLD: AVPixelFormat = 46;
+pub const AVPixelFormat_AV_PIX_FMT_VAAPI: AVPixelFormat = 46;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P16LE: AVPixelFormat = 47;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P16BE: AVPixelFormat = 48;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P16LE: AVPixelFormat = 49;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P16BE: AVPixelFormat = 50;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P16LE: AVPixelFormat = 51;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P16BE: AVPixelFormat = 52;
+pub const AVPixelFormat_AV_PIX_FMT_DXVA2_VLD: AVPixelFormat = 53;
+pub const AVPixelFormat_AV_PIX_FMT_RGB444LE: AVPixelFormat = 54;
+pub const AVPixelFormat_AV_PIX_FMT_RGB444BE: AVPixelFormat = 55;
+pub const AVPixelFormat_AV_PIX_FMT_BGR444LE: AVPixelFormat = 56;
+pub const AVPixelFormat_AV_PIX_FMT_BGR444BE: AVPixelFormat = 57;
+pub const AVPixelFormat_AV_PIX_FMT_YA8: AVPixelFormat = 58;
+pub const AVPixelFormat_AV_PIX_FMT_Y400A: AVPixelFormat = 58;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY8A: AVPixelFormat = 58;
+pub const AVPixelFormat_AV_PIX_FMT_BGR48BE: AVPixelFormat = 59;
+pub const AVPixelFormat_AV_PIX_FMT_BGR48LE: AVPixelFormat = 60;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P9BE: AVPixelFormat = 61;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P9LE: AVPixelFormat = 62;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P10BE: AVPixelFormat = 63;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P10LE: AVPixelFormat = 64;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P10BE: AVPixelFormat = 65;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P10LE: AVPixelFormat = 66;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P9BE: AVPixelFormat = 67;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P9LE: AVPixelFormat = 68;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P10BE: AVPixelFormat = 69;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P10LE: AVPixelFormat = 70;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P9BE: AVPixelFormat = 71;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P9LE: AVPixelFormat = 72;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP: AVPixelFormat = 73;
+pub const AVPixelFormat_AV_PIX_FMT_GBR24P: AVPixelFormat = 73;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP9BE: AVPixelFormat = 74;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP9LE: AVPixelFormat = 75;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP10BE: AVPixelFormat = 76;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP10LE: AVPixelFormat = 77;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP16BE: AVPixelFormat = 78;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP16LE: AVPixelFormat = 79;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P: AVPixelFormat = 80;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P: AVPixelFormat = 81;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P9BE: AVPixelFormat = 82;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P9LE: AVPixelFormat = 83;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P9BE: AVPixelFormat = 84;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P9LE: AVPixelFormat = 85;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P9BE: AVPixelFormat = 86;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P9LE: AVPixelFormat = 87;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P10BE: AVPixelFormat = 88;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P10LE: AVPixelFormat = 89;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P10BE: AVPixelFormat = 90;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P10LE: AVPixelFormat = 91;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P10BE: AVPixelFormat = 92;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P10LE: AVPixelFormat = 93;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P16BE: AVPixelFormat = 94;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA420P16LE: AVPixelFormat = 95;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P16BE: AVPixelFormat = 96;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P16LE: AVPixelFormat = 97;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P16BE: AVPixelFormat = 98;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P16LE: AVPixelFormat = 99;
+pub const AVPixelFormat_AV_PIX_FMT_VDPAU: AVPixelFormat = 100;
+pub const AVPixelFormat_AV_PIX_FMT_XYZ12LE: AVPixelFormat = 101;
+pub const AVPixelFormat_AV_PIX_FMT_XYZ12BE: AVPixelFormat = 102;
+pub const AVPixelFormat_AV_PIX_FMT_NV16: AVPixelFormat = 103;
+pub const AVPixelFormat_AV_PIX_FMT_NV20LE: AVPixelFormat = 104;
+pub const AVPixelFormat_AV_PIX_FMT_NV20BE: AVPixelFormat = 105;
+pub const AVPixelFormat_AV_PIX_FMT_RGBA64BE: AVPixelFormat = 106;
+pub const AVPixelFormat_AV_PIX_FMT_RGBA64LE: AVPixelFormat = 107;
+pub const AVPixelFormat_AV_PIX_FMT_BGRA64BE: AVPixelFormat = 108;
+pub const AVPixelFormat_AV_PIX_FMT_BGRA64LE: AVPixelFormat = 109;
+pub const AVPixelFormat_AV_PIX_FMT_YVYU422: AVPixelFormat = 110;
+pub const AVPixelFormat_AV_PIX_FMT_YA16BE: AVPixelFormat = 111;
+pub const AVPixelFormat_AV_PIX_FMT_YA16LE: AVPixelFormat = 112;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP: AVPixelFormat = 113;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP16BE: AVPixelFormat = 114;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP16LE: AVPixelFormat = 115;
+pub const AVPixelFormat_AV_PIX_FMT_QSV: AVPixelFormat = 116;
+pub const AVPixelFormat_AV_PIX_FMT_MMAL: AVPixelFormat = 117;
+pub const AVPixelFormat_AV_PIX_FMT_D3D11VA_VLD: AVPixelFormat = 118;
+pub const AVPixelFormat_AV_PIX_FMT_CUDA: AVPixelFormat = 119;
+pub const AVPixelFormat_AV_PIX_FMT_0RGB: AVPixelFormat = 120;
+pub const AVPixelFormat_AV_PIX_FMT_RGB0: AVPixelFormat = 121;
+pub const AVPixelFormat_AV_PIX_FMT_0BGR: AVPixelFormat = 122;
+pub const AVPixelFormat_AV_PIX_FMT_BGR0: AVPixelFormat = 123;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P12BE: AVPixelFormat = 124;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P12LE: AVPixelFormat = 125;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P14BE: AVPixelFormat = 126;
+pub const AVPixelFormat_AV_PIX_FMT_YUV420P14LE: AVPixelFormat = 127;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P12BE: AVPixelFormat = 128;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P12LE: AVPixelFormat = 129;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P14BE: AVPixelFormat = 130;
+pub const AVPixelFormat_AV_PIX_FMT_YUV422P14LE: AVPixelFormat = 131;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P12BE: AVPixelFormat = 132;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P12LE: AVPixelFormat = 133;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P14BE: AVPixelFormat = 134;
+pub const AVPixelFormat_AV_PIX_FMT_YUV444P14LE: AVPixelFormat = 135;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP12BE: AVPixelFormat = 136;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP12LE: AVPixelFormat = 137;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP14BE: AVPixelFormat = 138;
+pub const AVPixelFormat_AV_PIX_FMT_GBRP14LE: AVPixelFormat = 139;
+pub const AVPixelFormat_AV_PIX_FMT_YUVJ411P: AVPixelFormat = 140;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_BGGR8: AVPixelFormat = 141;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_RGGB8: AVPixelFormat = 142;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_GBRG8: AVPixelFormat = 143;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_GRBG8: AVPixelFormat = 144;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_BGGR16LE: AVPixelFormat = 145;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_BGGR16BE: AVPixelFormat = 146;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_RGGB16LE: AVPixelFormat = 147;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_RGGB16BE: AVPixelFormat = 148;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_GBRG16LE: AVPixelFormat = 149;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_GBRG16BE: AVPixelFormat = 150;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_GRBG16LE: AVPixelFormat = 151;
+pub const AVPixelFormat_AV_PIX_FMT_BAYER_GRBG16BE: AVPixelFormat = 152;
+pub const AVPixelFormat_AV_PIX_FMT_XVMC: AVPixelFormat = 153;
+pub const AVPixelFormat_AV_PIX_FMT_YUV440P10LE: AVPixelFormat = 154;
+pub const AVPixelFormat_AV_PIX_FMT_YUV440P10BE: AVPixelFormat = 155;
+pub const AVPixelFormat_AV_PIX_FMT_YUV440P12LE: AVPixelFormat = 156;
+pub const AVPixelFormat_AV_PIX_FMT_YUV440P12BE: AVPixelFormat = 157;
+pub const AVPixelFormat_AV_PIX_FMT_AYUV64LE: AVPixelFormat = 158;
+pub const AVPixelFormat_AV_PIX_FMT_AYUV64BE: AVPixelFormat = 159;
+pub const AVPixelFormat_AV_PIX_FMT_VIDEOTOOLBOX: AVPixelFormat = 160;
+pub const AVPixelFormat_AV_PIX_FMT_P010LE: AVPixelFormat = 161;
+pub const AVPixelFormat_AV_PIX_FMT_P010BE: AVPixelFormat = 162;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP12BE: AVPixelFormat = 163;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP12LE: AVPixelFormat = 164;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP10BE: AVPixelFormat = 165;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAP10LE: AVPixelFormat = 166;
+pub const AVPixelFormat_AV_PIX_FMT_MEDIACODEC: AVPixelFormat = 167;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY12BE: AVPixelFormat = 168;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY12LE: AVPixelFormat = 169;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY10BE: AVPixelFormat = 170;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY10LE: AVPixelFormat = 171;
+pub const AVPixelFormat_AV_PIX_FMT_P016LE: AVPixelFormat = 172;
+pub const AVPixelFormat_AV_PIX_FMT_P016BE: AVPixelFormat = 173;
+pub const AVPixelFormat_AV_PIX_FMT_D3D11: AVPixelFormat = 174;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY9BE: AVPixelFormat = 175;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY9LE: AVPixelFormat = 176;
+pub const AVPixelFormat_AV_PIX_FMT_GBRPF32BE: AVPixelFormat = 177;
+pub const AVPixelFormat_AV_PIX_FMT_GBRPF32LE: AVPixelFormat = 178;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAPF32BE: AVPixelFormat = 179;
+pub const AVPixelFormat_AV_PIX_FMT_GBRAPF32LE: AVPixelFormat = 180;
+pub const AVPixelFormat_AV_PIX_FMT_DRM_PRIME: AVPixelFormat = 181;
+pub const AVPixelFormat_AV_PIX_FMT_OPENCL: AVPixelFormat = 182;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY14BE: AVPixelFormat = 183;
+pub const AVPixelFormat_AV_PIX_FMT_GRAY14LE: AVPixelFormat = 184;
+pub const AVPixelFormat_AV_PIX_FMT_GRAYF32BE: AVPixelFormat = 185;
+pub const AVPixelFormat_AV_PIX_FMT_GRAYF32LE: AVPixelFormat = 186;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P12BE: AVPixelFormat = 187;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA422P12LE: AVPixelFormat = 188;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P12BE: AVPixelFormat = 189;
+pub const AVPixelFormat_AV_PIX_FMT_YUVA444P12LE: AVPixelFormat = 190;
+pub const AVPixelFormat_AV_PIX_FMT_NV24: AVPixelFormat = 191;
+pub const AVPixelFormat_AV_PIX_FMT_NV42: AVPixelFormat = 192;
+pub const AVPixelFormat_AV_PIX_FMT_VULKAN: AVPixelFormat = 193;
+pub const AVPixelFormat_AV_PIX_FMT_Y210BE: AVPixelFormat = 194;
+pub const AVPixelFormat_AV_PIX_FMT_Y210LE: AVPixelFormat = 195;
+pub const AVPixelFormat_AV_PIX_FMT_X2RGB10LE: AVPixelFormat = 196;
+pub const AVPixelFormat_AV_PIX_FMT_X2RGB10BE: AVPixelFormat = 197;
+pub const AVPixelFormat_AV_PIX_FMT_NB: AVPixelFormat = 198;
+pub type AVPixelFormat = ::std::os::raw::c_int;
+pub const AVColorPrimaries_AVCOL_PRI_RESERVED0: AVColorPrimaries = 0;
+pub const AVColorPrimaries_AVCOL_PRI_BT709: AVColorPrimaries = 1;
+pub const AVColorPrimaries_AVCOL_PRI_UNSPECIFIED: AVColorPrimaries = 2;
+pub const AVColorPrimaries_AVCOL_PRI_RESERVED: AVColorPrimaries = 3;
+pub const AVColorPrimaries_AVCOL_PRI_BT470M: AVColorPrimaries = 4;
+pub const AVColorPrimaries_AVCOL_PRI_BT470BG: AVColorPrimaries = 5;
+pub const AVColorPrimaries_AVCOL_PRI_SMPTE170M: AVColorPrimaries = 6;
+pub const AVColorPrimaries_AVCOL_PRI_SMPTE240M: AVColorPrimaries = 7;
+pub const AVColorPrimaries_AVCOL_PRI_FILM: AVColorPrimaries = 8;
+pub const AVColorPrimaries_AVCOL_PRI_BT2020: AVColorPrimaries = 9;
+pub const AVColorPrimaries_AVCOL_PRI_SMPTE428: AVColorPrimaries = 10;
+pub const AVColorPrimaries_AVCOL_PRI_SMPTEST428_1: AVColorPrimaries = 10;
+pub const AVColorPrimaries_AVCOL_PRI_SMPTE431: AVColorPrimaries = 11;
+pub const AVColorPrimaries_AVCOL_PRI_SMPTE432: AVColorPrimaries = 12;
+pub const AVColorPrimaries_AVCOL_PRI_EBU3213: AVColorPrimaries = 22;
+pub const AVColorPrimaries_AVCOL_PRI_JEDEC_P22: AVColorPrimaries = 22;
+pub const AVColorPrimaries_AVCOL_PRI_NB: AVColorPrimaries = 23;
+pub type AVColorPrimaries = ::std::os::raw::c_uint;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_RESERVED0: AVColorTransferCharacteristic = 0;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_BT709: AVColorTransferCharacteristic = 1;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_UNSPECIFIED: AVColorTransferCharacteristic = 2;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_RESERVED: AVColorTransferCharacteristic = 3;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_GAMMA22: AVColorTransferCharacteristic = 4;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_GAMMA28: AVColorTransferCharacteristic = 5;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_SMPTE170M: AVColorTransferCharacteristic = 6;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_SMPTE240M: AVColorTransferCharacteristic = 7;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_LINEAR: AVColorTransferCharacteristic = 8;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_LOG: AVColorTransferCharacteristic = 9;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_LOG_SQRT: AVColorTransferCharacteristic = 10;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_IEC61966_2_4: AVColorTransferCharacteristic = 11;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_BT1361_ECG: AVColorTransferCharacteristic = 12;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_IEC61966_2_1: AVColorTransferCharacteristic = 13;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_BT2020_10: AVColorTransferCharacteristic = 14;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_BT2020_12: AVColorTransferCharacteristic = 15;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_SMPTE2084: AVColorTransferCharacteristic = 16;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_SMPTEST2084: AVColorTransferCharacteristic = 16;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_SMPTE428: AVColorTransferCharacteristic = 17;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_SMPTEST428_1: AVColorTransferCharacteristic = 17;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_ARIB_STD_B67: AVColorTransferCharacteristic = 18;
+pub const AVColorTransferCharacteristic_AVCOL_TRC_NB: AVColorTransferCharacteristic = 19;
+pub type AVColorTransferCharacteristic = ::std::os::raw::c_uint;
+pub const AVColorSpace_AVCOL_SPC_RGB: AVColorSpace = 0;
+pub const AVColorSpace_AVCOL_SPC_BT709: AVColorSpace = 1;
+pub const AVColorSpace_AVCOL_SPC_UNSPECIFIED: AVColorSpace = 2;
+pub const AVColorSpace_AVCOL_SPC_RESERVED: AVColorSpace = 3;
+pub const AVColorSpace_AVCOL_SPC_FCC: AVColorSpace = 4;
+pub const AVColorSpace_AVCOL_SPC_BT470BG: AVColorSpace = 5;
+pub const AVColorSpace_AVCOL_SPC_SMPTE170M: AVColorSpace = 6;
+pub const AVColorSpace_AVCOL_SPC_SMPTE240M: AVColorSpace = 7;
+pub const AVColorSpace_AVCOL_SPC_YCGCO: AVColorSpace = 8;
+pub const AVColorSpace_AVCOL_SPC_YCOCG: AVColorSpace = 8;
+pub const AVColorSpace_AVCOL_SPC_BT2020_NCL: AVColorSpace = 9;
+pub const AVColorSpace_AVCOL_SPC_BT2020_CL: AVColorSpace = 10;
+pub const AVColorSpace_AVCOL_SPC_SMPTE2085: AVColorSpace = 11;
+pub const AVColorSpace_AVCOL_SPC_CHROMA_DERIVED_NCL: AVColorSpace = 12;
+pub const AVColorSpace_AVCOL_SPC_CHROMA_DERIVED_CL: AVColorSpace = 13;
+pub const AVColorSpace_AVCOL_SPC_ICTCP: AVColorSpace = 14;
+pub const AVColorSpace_AVCOL_SPC_NB: AVColorSpace = 15;
+pub type AVColorSpace = ::std::os::raw::c_uint;
+pub const AVColorRange_AVCOL_RANGE_UNSPECIFIED: AVColorRange = 0;
+pub const AVColorRange_AVCOL_RANGE_MPEG: AVColorRange = 1;
+pub const AVColorRange_AVCOL_RANGE_JPEG: AVColorRange = 2;
+pub const AVColorRange_AVCOL_RANGE_NB: AVColorRange = 3;
+pub type AVColorRange = ::std::os::raw::c_uint;
+pub const AVChromaLocation_AVCHROMA_LOC_UNSPECIFIED: AVChromaLocation = 0;
+pub const AVChromaLocation_AVCHROMA_LOC_LEFT: AVChromaLocation = 1;
+pub const AVChromaLocation_AVCHROMA_LOC_CENTER: AVChromaLocation = 2;
+pub const AVChromaLocation_AVCHROMA_LOC_TOPLEFT: AVChromaLocation = 3;
+pub const AVChromaLocation_AVCHROMA_LOC_TOP: AVChromaLocation = 4;
+pub const AVChromaLocation_AVCHROMA_LOC_BOTTOMLEFT: AVChromaLocation = 5;
+pub const AVChromaLocation_AVCHROMA_LOC_BOTTOM: AVChromaLocation = 6;
+pub const AVChromaLocation_AVCHROMA_LOC_NB: AVChromaLocation = 7;
+pub type AVChromaLocation = ::std::os::raw::c_uint;
+pub const AVSampleFormat_AV_SAMPLE_FMT_NONE: AVSampleFormat = -1;
+pub const AVSampleFormat_AV_SAMPLE_FMT_U8: AVSampleFormat = 0;
+pub const AVSampleFormat_AV_SAMPLE_FMT_S16: AVSampleFormat = 1;
+pub const AVSampleFormat_AV_SAMPLE_FMT_S32: AVSampleFormat = 2;
+pub const AVSampleFormat_AV_SAMPLE_FMT_FLT: AVSampleFormat = 3;
+pub const AVSampleFormat_AV_SAMPLE_FMT_DBL: AVSampleFormat = 4;
+pub const AVSampleFormat_AV_SAMPLE_FMT_U8P: AVSampleFormat = 5;
+pub const AVSampleFormat_AV_SAMPLE_FMT_S16P: AVSampleFormat = 6;
+pub const AVSampleFormat_AV_SAMPLE_FMT_S32P: AVSampleFormat = 7;
+pub const AVSampleFormat_AV_SAMPLE_FMT_FLTP: AVSampleFormat = 8;
+pub const AVSampleFormat_AV_SAMPLE_FMT_DBLP: AVSampleFormat = 9;
+pub const AVSampleFormat_AV_SAMPLE_FMT_S64: AVSampleFormat = 10;
+pub const AVSampleFormat_AV_SAMPLE_FMT_S64P: AVSampleFormat = 11;
+pub const AVSampleFormat_AV_SAMPLE_FMT_NB: AVSampleFormat = 12;
+pub type AVSampleFormat = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBuffer {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBufferPool {
+    _unused: [u8; 0],
+}
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_NONE: AVMatrixEncoding = 0;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_DOLBY: AVMatrixEncoding = 1;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_DPLII: AVMatrixEncoding = 2;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_DPLIIX: AVMatrixEncoding = 3;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_DPLIIZ: AVMatrixEncoding = 4;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_DOLBYEX: AVMatrixEncoding = 5;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_DOLBYHEADPHONE: AVMatrixEncoding = 6;
+pub const AVMatrixEncoding_AV_MATRIX_ENCODING_NB: AVMatrixEncoding = 7;
+pub type AVMatrixEncoding = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBPrint {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVDictionary {
+    _unused: [u8; 0],
+}
+pub const AVFrameSideDataType_AV_FRAME_DATA_PANSCAN: AVFrameSideDataType = 0;
+pub const AVFrameSideDataType_AV_FRAME_DATA_A53_CC: AVFrameSideDataType = 1;
+pub const AVFrameSideDataType_AV_FRAME_DATA_STEREO3D: AVFrameSideDataType = 2;
+pub const AVFrameSideDataType_AV_FRAME_DATA_MATRIXENCODING: AVFrameSideDataType = 3;
+pub const AVFrameSideDataType_AV_FRAME_DATA_DOWNMIX_INFO: AVFrameSideDataType = 4;
+pub const AVFrameSideDataType_AV_FRAME_DATA_REPLAYGAIN: AVFrameSideDataType = 5;
+pub const AVFrameSideDataType_AV_FRAME_DATA_DISPLAYMATRIX: AVFrameSideDataType = 6;
+pub const AVFrameSideDataType_AV_FRAME_DATA_AFD: AVFrameSideDataType = 7;
+pub const AVFrameSideDataType_AV_FRAME_DATA_MOTION_VECTORS: AVFrameSideDataType = 8;
+pub const AVFrameSideDataType_AV_FRAME_DATA_SKIP_SAMPLES: AVFrameSideDataType = 9;
+pub const AVFrameSideDataType_AV_FRAME_DATA_AUDIO_SERVICE_TYPE: AVFrameSideDataType = 10;
+pub const AVFrameSideDataType_AV_FRAME_DATA_MASTERING_DISPLAY_METADATA: AVFrameSideDataType = 11;
+pub const AVFrameSideDataType_AV_FRAME_DATA_GOP_TIMECODE: AVFrameSideDataType = 12;
+pub const AVFrameSideDataType_AV_FRAME_DATA_SPHERICAL: AVFrameSideDataType = 13;
+pub const AVFrameSideDataType_AV_FRAME_DATA_CONTENT_LIGHT_LEVEL: AVFrameSideDataType = 14;
+pub const AVFrameSideDataType_AV_FRAME_DATA_ICC_PROFILE: AVFrameSideDataType = 15;
+pub const AVFrameSideDataType_AV_FRAME_DATA_QP_TABLE_PROPERTIES: AVFrameSideDataType = 16;
+pub const AVFrameSideDataType_AV_FRAME_DATA_QP_TABLE_DATA: AVFrameSideDataType = 17;
+pub const AVFrameSideDataType_AV_FRAME_DATA_S12M_TIMECODE: AVFrameSideDataType = 18;
+pub const AVFrameSideDataType_AV_FRAME_DATA_DYNAMIC_HDR_PLUS: AVFrameSideDataType = 19;
+pub const AVFrameSideDataType_AV_FRAME_DATA_REGIONS_OF_INTEREST: AVFrameSideDataType = 20;
+pub const AVFrameSideDataType_AV_FRAME_DATA_VIDEO_ENC_PARAMS: AVFrameSideDataType = 21;
+pub const AVFrameSideDataType_AV_FRAME_DATA_SEI_UNREGISTERED: AVFrameSideDataType = 22;
+pub const AVFrameSideDataType_AV_FRAME_DATA_FILM_GRAIN_PARAMS: AVFrameSideDataType = 23;
+pub type AVFrameSideDataType = ::std::os::raw::c_uint;
+pub const AVActiveFormatDescription_AV_AFD_SAME: AVActiveFormatDescription = 8;
+pub const AVActiveFormatDescription_AV_AFD_4_3: AVActiveFormatDescription = 9;
+pub const AVActiveFormatDescription_AV_AFD_16_9: AVActiveFormatDescription = 10;
+pub const AVActiveFormatDescription_AV_AFD_14_9: AVActiveFormatDescription = 11;
+pub const AVActiveFormatDescription_AV_AFD_4_3_SP_14_9: AVActiveFormatDescription = 13;
+pub const AVActiveFormatDescription_AV_AFD_16_9_SP_14_9: AVActiveFormatDescription = 14;
+pub const AVActiveFormatDescription_AV_AFD_SP_4_3: AVActiveFormatDescription = 15;
+pub type AVActiveFormatDescription = ::std::os::raw::c_uint;
+pub const AV_FRAME_CROP_UNALIGNED: _bindgen_ty_2 = 1;
+pub type _bindgen_ty_2 = ::std::os::raw::c_uint;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_NONE: AVHWDeviceType = 0;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_VDPAU: AVHWDeviceType = 1;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_CUDA: AVHWDeviceType = 2;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_VAAPI: AVHWDeviceType = 3;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_DXVA2: AVHWDeviceType = 4;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_QSV: AVHWDeviceType = 5;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_VIDEOTOOLBOX: AVHWDeviceType = 6;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_D3D11VA: AVHWDeviceType = 7;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_DRM: AVHWDeviceType = 8;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_OPENCL: AVHWDeviceType = 9;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_MEDIACODEC: AVHWDeviceType = 10;
+pub const AVHWDeviceType_AV_HWDEVICE_TYPE_VULKAN: AVHWDeviceType = 11;
+pub type AVHWDeviceType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVHWDeviceInternal {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVHWFramesInternal {
+    _unused: [u8; 0],
+}
+pub const AVHWFrameTransferDirection_AV_HWFRAME_TRANSFER_DIRECTION_FROM:
+    AVHWFrameTransferDirection = 0;
+pub const AVHWFrameTransferDirection_AV_HWFRAME_TRANSFER_DIRECTION_TO: AVHWFrameTransferDirection =
+    1;
+pub type AVHWFrameTransferDirection = ::std::os::raw::c_uint;
+pub const AV_HWFRAME_MAP_READ: _bindgen_ty_3 = 1;
+pub const AV_HWFRAME_MAP_WRITE: _bindgen_ty_3 = 2;
+pub const AV_HWFRAME_MAP_OVERWRITE: _bindgen_ty_3 = 4;
+pub const AV_HWFRAME_MAP_DIRECT: _bindgen_ty_3 = 8;
+pub type _bindgen_ty_3 = ::std::os::raw::c_uint;
+pub const AVCodecID_AV_CODEC_ID_NONE: AVCodecID = 0;
+pub const AVCodecID_AV_CODEC_ID_MPEG1VIDEO: AVCodecID = 1;
+pub const AVCodecID_AV_CODEC_ID_MPEG2VIDEO: AVCodecID = 2;
+pub const AVCodecID_AV_CODEC_ID_H261: AVCodecID = 3;
+pub const AVCodecID_AV_CODEC_ID_H263: AVCodecID = 4;
+pub const AVCodecID_AV_CODEC_ID_RV10: AVCodecID = 5;
+pub const AVCodecID_AV_CODEC_ID_RV20: AVCodecID = 6;
+pub const AVCodecID_AV_CODEC_ID_MJPEG: AVCodecID = 7;
+pub const AVCodecID_AV_CODEC_ID_MJPEGB: AVCodecID = 8;
+pub const AVCodecID_AV_CODEC_ID_LJPEG: AVCodecID = 9;
+pub const AVCodecID_AV_CODEC_ID_SP5X: AVCodecID = 10;
+pub const AVCodecID_AV_CODEC_ID_JPEGLS: AVCodecID = 11;
+pub const AVCodecID_AV_CODEC_ID_MPEG4: AVCodecID = 12;
+pub const AVCodecID_AV_CODEC_ID_RAWVIDEO: AVCodecID = 13;
+pub const AVCodecID_AV_CODEC_ID_MSMPEG4V1: AVCodecID = 14;
+pub const AVCodecID_AV_CODEC_ID_MSMPEG4V2: AVCodecID = 15;
+pub const AVCodecID_AV_CODEC_ID_MSMPEG4V3: AVCodecID = 16;
+pub const AVCodecID_AV_CODEC_ID_WMV1: AVCodecID = 17;
+pub const AVCodecID_AV_CODEC_ID_WMV2: AVCodecID = 18;
+pub const AVCodecID_AV_CODEC_ID_H263P: AVCodecID = 19;
+pub const AVCodecID_AV_CODEC_ID_H263I: AVCodecID = 20;
+pub const AVCodecID_AV_CODEC_ID_FLV1: AVCodecID = 21;
+pub const AVCodecID_AV_CODEC_ID_SVQ1: AVCodecID = 22;
+pub const AVCodecID_AV_CODEC_ID_SVQ3: AVCodecID = 23;
+pub const AVCodecID_AV_CODEC_ID_DVVIDEO: AVCodecID = 24;
+pub const AVCodecID_AV_CODEC_ID_HUFFYUV: AVCodecID = 25;
+pub const AVCodecID_AV_CODEC_ID_CYUV: AVCodecID = 26;
+pub const AVCodecID_AV_CODEC_ID_H264: AVCodecID = 27;
+pub const AVCodecID_AV_CODEC_ID_INDEO3: AVCodecID = 28;
+pub const AVCodecID_AV_CODEC_ID_VP3: AVCodecID = 29;
+pub const AVCodecID_AV_CODEC_ID_THEORA: AVCodecID = 30;
+pub const AVCodecID_AV_CODEC_ID_ASV1: AVCodecID = 31;
+pub const AVCodecID_AV_CODEC_ID_ASV2: AVCodecID = 32;
+pub const AVCodecID_AV_CODEC_ID_FFV1: AVCodecID = 33;
+pub const AVCodecID_AV_CODEC_ID_4XM: AVCodecID = 34;
+pub const AVCodecID_AV_CODEC_ID_VCR1: AVCodecID = 35;
+pub const AVCodecID_AV_CODEC_ID_CLJR: AVCodecID = 36;
+pub const AVCodecID_AV_CODEC_ID_MDEC: AVCodecID = 37;
+pub const AVCodecID_AV_CODEC_ID_ROQ: AVCodecID = 38;
+pub const AVCodecID_AV_CODEC_ID_INTERPLAY_VIDEO: AVCodecID = 39;
+pub const AVCodecID_AV_CODEC_ID_XAN_WC3: AVCodecID = 40;
+pub const AVCodecID_AV_CODEC_ID_XAN_WC4: AVCodecID = 41;
+pub const AVCodecID_AV_CODEC_ID_RPZA: AVCodecID = 42;
+pub const AVCodecID_AV_CODEC_ID_CINEPAK: AVCodecID = 43;
+pub const AVCodecID_AV_CODEC_ID_WS_VQA: AVCodecID = 44;
+pub const AVCodecID_AV_CODEC_ID_MSRLE: AVCodecID = 45;
+pub const AVCodecID_AV_CODEC_ID_MSVIDEO1: AVCodecID = 46;
+pub const AVCodecID_AV_CODEC_ID_IDCIN: AVCodecID = 47;
+pub const AVCodecID_AV_CODEC_ID_8BPS: AVCodecID = 48;
+pub const AVCodecID_AV_CODEC_ID_SMC: AVCodecID = 49;
+pub const AVCodecID_AV_CODEC_ID_FLIC: AVCodecID = 50;
+pub const AVCodecID_AV_CODEC_ID_TRUEMOTION1: AVCodecID = 51;
+pub const AVCodecID_AV_CODEC_ID_VMDVIDEO: AVCodecID = 52;
+pub const AVCodecID_AV_CODEC_ID_MSZH: AVCodecID = 53;
+pub const AVCodecID_AV_CODEC_ID_ZLIB: AVCodecID = 54;
+pub const AVCodecID_AV_CODEC_ID_QTRLE: AVCodecID = 55;
+pub const AVCodecID_AV_CODEC_ID_TSCC: AVCodecID = 56;
+pub const AVCodecID_AV_CODEC_ID_ULTI: AVCodecID = 57;
+pub const AVCodecID_AV_CODEC_ID_QDRAW: AVCodecID = 58;
+pub const AVCodecID_AV_CODEC_ID_VIXL: AVCodecID = 59;
+pub const AVCodecID_AV_CODEC_ID_QPEG: AVCodecID = 60;
+pub const AVCodecID_AV_CODEC_ID_PNG: AVCodecID = 61;
+pub const AVCodecID_AV_CODEC_ID_PPM: AVCodecID = 62;
+pub const AVCodecID_AV_CODEC_ID_PBM: AVCodecID = 63;
+pub const AVCodecID_AV_CODEC_ID_PGM: AVCodecID = 64;
+pub const AVCodecID_AV_CODEC_ID_PGMYUV: AVCodecID = 65;
+pub const AVCodecID_AV_CODEC_ID_PAM: AVCodecID = 66;
+pub const AVCodecID_AV_CODEC_ID_FFVHUFF: AVCodecID = 67;
+pub const AVCodecID_AV_CODEC_ID_RV30: AVCodecID = 68;
+pub const AVCodecID_AV_CODEC_ID_RV40: AVCodecID = 69;
+pub const AVCodecID_AV_CODEC_ID_VC1: AVCodecID = 70;
+pub const AVCodecID_AV_CODEC_ID_WMV3: AVCodecID = 71;
+pub const AVCodecID_AV_CODEC_ID_LOCO: AVCodecID = 72;
+pub const AVCodecID_AV_CODEC_ID_WNV1: AVCodecID = 73;
+pub const AVCodecID_AV_CODEC_ID_AASC: AVCodecID = 74;
+pub const AVCodecID_AV_CODEC_ID_INDEO2: AVCodecID = 75;
+pub const AVCodecID_AV_CODEC_ID_FRAPS: AVCodecID = 76;
+pub const AVCodecID_AV_CODEC_ID_TRUEMOTION2: AVCodecID = 77;
+pub const AVCodecID_AV_CODEC_ID_BMP: AVCodecID = 78;
+pub const AVCodecID_AV_CODEC_ID_CSCD: AVCodecID = 79;
+pub const AVCodecID_AV_CODEC_ID_MMVIDEO: AVCodecID = 80;
+pub const AVCodecID_AV_CODEC_ID_ZMBV: AVCodecID = 81;
+pub const AVCodecID_AV_CODEC_ID_AVS: AVCodecID = 82;
+pub const AVCodecID_AV_CODEC_ID_SMACKVIDEO: AVCodecID = 83;
+pub const AVCodecID_AV_CODEC_ID_NUV: AVCodecID = 84;
+pub const AVCodecID_AV_CODEC_ID_KMVC: AVCodecID = 85;
+pub const AVCodecID_AV_CODEC_ID_FLASHSV: AVCodecID = 86;
+pub const AVCodecID_AV_CODEC_ID_CAVS: AVCodecID = 87;
+pub const AVCodecID_AV_CODEC_ID_JPEG2000: AVCodecID = 88;
+pub const AVCodecID_AV_CODEC_ID_VMNC: AVCodecID = 89;
+pub const AVCodecID_AV_CODEC_ID_VP5: AVCodecID = 90;
+pub const AVCodecID_AV_CODEC_ID_VP6: AVCodecID = 91;
+pub const AVCodecID_AV_CODEC_ID_VP6F: AVCodecID = 92;
+pub const AVCodecID_AV_CODEC_ID_TARGA: AVCodecID = 93;
+pub const AVCodecID_AV_CODEC_ID_DSICINVIDEO: AVCodecID = 94;
+pub const AVCodecID_AV_CODEC_ID_TIERTEXSEQVIDEO: AVCodecID = 95;
+pub const AVCodecID_AV_CODEC_ID_TIFF: AVCodecID = 96;
+pub const AVCodecID_AV_CODEC_ID_GIF: AVCodecID = 97;
+pub const AVCodecID_AV_CODEC_ID_DXA: AVCodecID = 98;
+pub const AVCodecID_AV_CODEC_ID_DNXHD: AVCodecID = 99;
+pub const AVCodecID_AV_CODEC_ID_THP: AVCodecID = 100;
+pub const AVCodecID_AV_CODEC_ID_SGI: AVCodecID = 101;
+pub const AVCodecID_AV_CODEC_ID_C93: AVCodecID = 102;
+pub const AVCodecID_AV_CODEC_ID_BETHSOFTVID: AVCodecID = 103;
+pub const AVCodecID_AV_CODEC_ID_PTX: AVCodecID = 104;
+pub const AVCodecID_AV_CODEC_ID_TXD: AVCodecID = 105;
+pub const AVCodecID_AV_CODEC_ID_VP6A: AVCodecID = 106;
+pub const AVCodecID_AV_CODEC_ID_AMV: AVCodecID = 107;
+pub const AVCodecID_AV_CODEC_ID_VB: AVCodecID = 108;
+pub const AVCodecID_AV_CODEC_ID_PCX: AVCodecID = 109;
+pub const AVCodecID_AV_CODEC_ID_SUNRAST: AVCodecID = 110;
+pub const AVCodecID_AV_CODEC_ID_INDEO4: AVCodecID = 111;
+pub const AVCodecID_AV_CODEC_ID_INDEO5: AVCodecID = 112;
+pub const AVCodecID_AV_CODEC_ID_MIMIC: AVCodecID = 113;
+pub const AVCodecID_AV_CODEC_ID_RL2: AVCodecID = 114;
+pub const AVCodecID_AV_CODEC_ID_ESCAPE124: AVCodecID = 115;
+pub const AVCodecID_AV_CODEC_ID_DIRAC: AVCodecID = 116;
+pub const AVCodecID_AV_CODEC_ID_BFI: AVCodecID = 117;
+pub const AVCodecID_AV_CODEC_ID_CMV: AVCodecID = 118;
+pub const AVCodecID_AV_CODEC_ID_MOTIONPIXELS: AVCodecID = 119;
+pub const AVCodecID_AV_CODEC_ID_TGV: AVCodecID = 120;
+pub const AVCodecID_AV_CODEC_ID_TGQ: AVCodecID = 121;
+pub const AVCodecID_AV_CODEC_ID_TQI: AVCodecID = 122;
+pub const AVCodecID_AV_CODEC_ID_AURA: AVCodecID = 123;
+pub const AVCodecID_AV_CODEC_ID_AURA2: AVCodecID = 124;
+pub const AVCodecID_AV_CODEC_ID_V210X: AVCodecID = 125;
+pub const AVCodecID_AV_CODEC_ID_TMV: AVCodecID = 126;
+pub const AVCodecID_AV_CODEC_ID_V210: AVCodecID = 127;
+pub const AVCodecID_AV_CODEC_ID_DPX: AVCodecID = 128;
+pub const AVCodecID_AV_CODEC_ID_MAD: AVCodecID = 129;
+pub const AVCodecID_AV_CODEC_ID_FRWU: AVCodecID = 130;
+pub const AVCodecID_AV_CODEC_ID_FLASHSV2: AVCodecID = 131;
+pub const AVCodecID_AV_CODEC_ID_CDGRAPHICS: AVCodecID = 132;
+pub const AVCodecID_AV_CODEC_ID_R210: AVCodecID = 133;
+pub const AVCodecID_AV_CODEC_ID_ANM: AVCodecID = 134;
+pub const AVCodecID_AV_CODEC_ID_BINKVIDEO: AVCodecID = 135;
+pub const AVCodecID_AV_CODEC_ID_IFF_ILBM: AVCodecID = 136;
+pub const AVCodecID_AV_CODEC_ID_KGV1: AVCodecID = 137;
+pub const AVCodecID_AV_CODEC_ID_YOP: AVCodecID = 138;
+pub const AVCodecID_AV_CODEC_ID_VP8: AVCodecID = 139;
+pub const AVCodecID_AV_CODEC_ID_PICTOR: AVCodecID = 140;
+pub const AVCodecID_AV_CODEC_ID_ANSI: AVCodecID = 141;
+pub const AVCodecID_AV_CODEC_ID_A64_MULTI: AVCodecID = 142;
+pub const AVCodecID_AV_CODEC_ID_A64_MULTI5: AVCodecID = 143;
+pub const AVCodecID_AV_CODEC_ID_R10K: AVCodecID = 144;
+pub const AVCodecID_AV_CODEC_ID_MXPEG: AVCodecID = 145;
+pub const AVCodecID_AV_CODEC_ID_LAGARITH: AVCodecID = 146;
+pub const AVCodecID_AV_CODEC_ID_PRORES: AVCodecID = 147;
+pub const AVCodecID_AV_CODEC_ID_JV: AVCodecID = 148;
+pub const AVCodecID_AV_CODEC_ID_DFA: AVCodecID = 149;
+pub const AVCodecID_AV_CODEC_ID_WMV3IMAGE: AVCodecID = 150;
+pub const AVCodecID_AV_CODEC_ID_VC1IMAGE: AVCodecID = 151;
+pub const AVCodecID_AV_CODEC_ID_UTVIDEO: AVCodecID = 152;
+pub const AVCodecID_AV_CODEC_ID_BMV_VIDEO: AVCodecID = 153;
+pub const AVCodecID_AV_CODEC_ID_VBLE: AVCodecID = 154;
+pub const AVCodecID_AV_CODEC_ID_DXTORY: AVCodecID = 155;
+pub const AVCodecID_AV_CODEC_ID_V410: AVCodecID = 156;
+pub const AVCodecID_AV_CODEC_ID_XWD: AVCodecID = 157;
+pub const AVCodecID_AV_CODEC_ID_CDXL: AVCodecID = 158;
+pub const AVCodecID_AV_CODEC_ID_XBM: AVCodecID = 159;
+pub const AVCodecID_AV_CODEC_ID_ZEROCODEC: AVCodecID = 160;
+pub const AVCodecID_AV_CODEC_ID_MSS1: AVCodecID = 161;
+pub const AVCodecID_AV_CODEC_ID_MSA1: AVCodecID = 162;
+pub const AVCodecID_AV_CODEC_ID_TSCC2: AVCodecID = 163;
+pub const AVCodecID_AV_CODEC_ID_MTS2: AVCodecID = 164;
+pub const AVCodecID_AV_CODEC_ID_CLLC: AVCodecID = 165;
+pub const AVCodecID_AV_CODEC_ID_MSS2: AVCodecID = 166;
+pub const AVCodecID_AV_CODEC_ID_VP9: AVCodecID = 167;
+pub const AVCodecID_AV_CODEC_ID_AIC: AVCodecID = 168;
+pub const AVCodecID_AV_CODEC_ID_ESCAPE130: AVCodecID = 169;
+pub const AVCodecID_AV_CODEC_ID_G2M: AVCodecID = 170;
+pub const AVCodecID_AV_CODEC_ID_WEBP: AVCodecID = 171;
+pub const AVCodecID_AV_CODEC_ID_HNM4_VIDEO: AVCodecID = 172;
+pub const AVCodecID_AV_CODEC_ID_HEVC: AVCodecID = 173;
+pub const AVCodecID_AV_CODEC_ID_FIC: AVCodecID = 174;
+pub const AVCodecID_AV_CODEC_ID_ALIAS_PIX: AVCodecID = 175;
+pub const AVCodecID_AV_CODEC_ID_BRENDER_PIX: AVCodecID = 176;
+pub const AVCodecID_AV_CODEC_ID_PAF_VIDEO: AVCodecID = 177;
+pub const AVCodecID_AV_CODEC_ID_EXR: AVCodecID = 178;
+pub const AVCodecID_AV_CODEC_ID_VP7: AVCodecID = 179;
+pub const AVCodecID_AV_CODEC_ID_SANM: AVCodecID = 180;
+pub const AVCodecID_AV_CODEC_ID_SGIRLE: AVCodecID = 181;
+pub const AVCodecID_AV_CODEC_ID_MVC1: AVCodecID = 182;
+pub const AVCodecID_AV_CODEC_ID_MVC2: AVCodecID = 183;
+pub const AVCodecID_AV_CODEC_ID_HQX: AVCodecID = 184;
+pub const AVCodecID_AV_CODEC_ID_TDSC: AVCodecID = 185;
+pub const AVCodecID_AV_CODEC_ID_HQ_HQA: AVCodecID = 186;
+pub const AVCodecID_AV_CODEC_ID_HAP: AVCodecID = 187;
+pub const AVCodecID_AV_CODEC_ID_DDS: AVCodecID = 188;
+pub const AVCodecID_AV_CODEC_ID_DXV: AVCodecID = 189;
+pub const AVCodecID_AV_CODEC_ID_SCREENPRESSO: AVCodecID = 190;
+pub const AVCodecID_AV_CODEC_ID_RSCC: AVCodecID = 191;
+pub const AVCodecID_AV_CODEC_ID_AVS2: AVCodecID = 192;
+pub const AVCodecID_AV_CODEC_ID_PGX: AVCodecID = 193;
+pub const AVCodecID_AV_CODEC_ID_AVS3: AVCodecID = 194;
+pub const AVCodecID_AV_CODEC_ID_MSP2: AVCodecID = 195;
+pub const AVCodecID_AV_CODEC_ID_VVC: AVCodecID = 196;
+pub const AVCodecID_AV_CODEC_ID_Y41P: AVCodecID = 32768;
+pub const AVCodecID_AV_CODEC_ID_AVRP: AVCodecID = 32769;
+pub const AVCodecID_AV_CODEC_ID_012V: AVCodecID = 32770;
+pub const AVCodecID_AV_CODEC_ID_AVUI: AVCodecID = 32771;
+pub const AVCodecID_AV_CODEC_ID_AYUV: AVCodecID = 32772;
+pub const AVCodecID_AV_CODEC_ID_TARGA_Y216: AVCodecID = 32773;
+pub const AVCodecID_AV_CODEC_ID_V308: AVCodecID = 32774;
+pub const AVCodecID_AV_CODEC_ID_V408: AVCodecID = 32775;
+pub const AVCodecID_AV_CODEC_ID_YUV4: AVCodecID = 32776;
+pub const AVCodecID_AV_CODEC_ID_AVRN: AVCodecID = 32777;
+pub const AVCodecID_AV_CODEC_ID_CPIA: AVCodecID = 32778;
+pub const AVCodecID_AV_CODEC_ID_XFACE: AVCodecID = 32779;
+pub const AVCodecID_AV_CODEC_ID_SNOW: AVCodecID = 32780;
+pub const AVCodecID_AV_CODEC_ID_SMVJPEG: AVCodecID = 32781;
+pub const AVCodecID_AV_CODEC_ID_APNG: AVCodecID = 32782;
+pub const AVCodecID_AV_CODEC_ID_DAALA: AVCodecID = 32783;
+pub const AVCodecID_AV_CODEC_ID_CFHD: AVCodecID = 32784;
+pub const AVCodecID_AV_CODEC_ID_TRUEMOTION2RT: AVCodecID = 32785;
+pub const AVCodecID_AV_CODEC_ID_M101: AVCodecID = 32786;
+pub const AVCodecID_AV_CODEC_ID_MAGICYUV: AVCodecID = 32787;
+pub const AVCodecID_AV_CODEC_ID_SHEERVIDEO: AVCodecID = 32788;
+pub const AVCodecID_AV_CODEC_ID_YLC: AVCodecID = 32789;
+pub const AVCodecID_AV_CODEC_ID_PSD: AVCodecID = 32790;
+pub const AVCodecID_AV_CODEC_ID_PIXLET: AVCodecID = 32791;
+pub const AVCodecID_AV_CODEC_ID_SPEEDHQ: AVCodecID = 32792;
+pub const AVCodecID_AV_CODEC_ID_FMVC: AVCodecID = 32793;
+pub const AVCodecID_AV_CODEC_ID_SCPR: AVCodecID = 32794;
+pub const AVCodecID_AV_CODEC_ID_CLEARVIDEO: AVCodecID = 32795;
+pub const AVCodecID_AV_CODEC_ID_XPM: AVCodecID = 32796;
+pub const AVCodecID_AV_CODEC_ID_AV1: AVCodecID = 32797;
+pub const AVCodecID_AV_CODEC_ID_BITPACKED: AVCodecID = 32798;
+pub const AVCodecID_AV_CODEC_ID_MSCC: AVCodecID = 32799;
+pub const AVCodecID_AV_CODEC_ID_SRGC: AVCodecID = 32800;
+pub const AVCodecID_AV_CODEC_ID_SVG: AVCodecID = 32801;
+pub const AVCodecID_AV_CODEC_ID_GDV: AVCodecID = 32802;
+pub const AVCodecID_AV_CODEC_ID_FITS: AVCodecID = 32803;
+pub const AVCodecID_AV_CODEC_ID_IMM4: AVCodecID = 32804;
+pub const AVCodecID_AV_CODEC_ID_PROSUMER: AVCodecID = 32805;
+pub const AVCodecID_AV_CODEC_ID_MWSC: AVCodecID = 32806;
+pub const AVCodecID_AV_CODEC_ID_WCMV: AVCodecID = 32807;
+pub const AVCodecID_AV_CODEC_ID_RASC: AVCodecID = 32808;
+pub const AVCodecID_AV_CODEC_ID_HYMT: AVCodecID = 32809;
+pub const AVCodecID_AV_CODEC_ID_ARBC: AVCodecID = 32810;
+pub const AVCodecID_AV_CODEC_ID_AGM: AVCodecID = 32811;
+pub const AVCodecID_AV_CODEC_ID_LSCR: AVCodecID = 32812;
+pub const AVCodecID_AV_CODEC_ID_VP4: AVCodecID = 32813;
+pub const AVCodecID_AV_CODEC_ID_IMM5: AVCodecID = 32814;
+pub const AVCodecID_AV_CODEC_ID_MVDV: AVCodecID = 32815;
+pub const AVCodecID_AV_CODEC_ID_MVHA: AVCodecID = 32816;
+pub const AVCodecID_AV_CODEC_ID_CDTOONS: AVCodecID = 32817;
+pub const AVCodecID_AV_CODEC_ID_MV30: AVCodecID = 32818;
+pub const AVCodecID_AV_CODEC_ID_NOTCHLC: AVCodecID = 32819;
+pub const AVCodecID_AV_CODEC_ID_PFM: AVCodecID = 32820;
+pub const AVCodecID_AV_CODEC_ID_MOBICLIP: AVCodecID = 32821;
+pub const AVCodecID_AV_CODEC_ID_PHOTOCD: AVCodecID = 32822;
+pub const AVCodecID_AV_CODEC_ID_IPU: AVCodecID = 32823;
+pub const AVCodecID_AV_CODEC_ID_ARGO: AVCodecID = 32824;
+pub const AVCodecID_AV_CODEC_ID_CRI: AVCodecID = 32825;
+pub const AVCodecID_AV_CODEC_ID_SIMBIOSIS_IMX: AVCodecID = 32826;
+pub const AVCodecID_AV_CODEC_ID_SGA_VIDEO: AVCodecID = 32827;
+pub const AVCodecID_AV_CODEC_ID_FIRST_AUDIO: AVCodecID = 65536;
+pub const AVCodecID_AV_CODEC_ID_PCM_S16LE: AVCodecID = 65536;
+pub const AVCodecID_AV_CODEC_ID_PCM_S16BE: AVCodecID = 65537;
+pub const AVCodecID_AV_CODEC_ID_PCM_U16LE: AVCodecID = 65538;
+pub const AVCodecID_AV_CODEC_ID_PCM_U16BE: AVCodecID = 65539;
+pub const AVCodecID_AV_CODEC_ID_PCM_S8: AVCodecID = 65540;
+pub const AVCodecID_AV_CODEC_ID_PCM_U8: AVCodecID = 65541;
+pub const AVCodecID_AV_CODEC_ID_PCM_MULAW: AVCodecID = 65542;
+pub const AVCodecID_AV_CODEC_ID_PCM_ALAW: AVCodecID = 65543;
+pub const AVCodecID_AV_CODEC_ID_PCM_S32LE: AVCodecID = 65544;
+pub const AVCodecID_AV_CODEC_ID_PCM_S32BE: AVCodecID = 65545;
+pub const AVCodecID_AV_CODEC_ID_PCM_U32LE: AVCodecID = 65546;
+pub const AVCodecID_AV_CODEC_ID_PCM_U32BE: AVCodecID = 65547;
+pub const AVCodecID_AV_CODEC_ID_PCM_S24LE: AVCodecID = 65548;
+pub const AVCodecID_AV_CODEC_ID_PCM_S24BE: AVCodecID = 65549;
+pub const AVCodecID_AV_CODEC_ID_PCM_U24LE: AVCodecID = 65550;
+pub const AVCodecID_AV_CODEC_ID_PCM_U24BE: AVCodecID = 65551;
+pub const AVCodecID_AV_CODEC_ID_PCM_S24DAUD: AVCodecID = 65552;
+pub const AVCodecID_AV_CODEC_ID_PCM_ZORK: AVCodecID = 65553;
+pub const AVCodecID_AV_CODEC_ID_PCM_S16LE_PLANAR: AVCodecID = 65554;
+pub const AVCodecID_AV_CODEC_ID_PCM_DVD: AVCodecID = 65555;
+pub const AVCodecID_AV_CODEC_ID_PCM_F32BE: AVCodecID = 65556;
+pub const AVCodecID_AV_CODEC_ID_PCM_F32LE: AVCodecID = 65557;
+pub const AVCodecID_AV_CODEC_ID_PCM_F64BE: AVCodecID = 65558;
+pub const AVCodecID_AV_CODEC_ID_PCM_F64LE: AVCodecID = 65559;
+pub const AVCodecID_AV_CODEC_ID_PCM_BLURAY: AVCodecID = 65560;
+pub const AVCodecID_AV_CODEC_ID_PCM_LXF: AVCodecID = 65561;
+pub const AVCodecID_AV_CODEC_ID_S302M: AVCodecID = 65562;
+pub const AVCodecID_AV_CODEC_ID_PCM_S8_PLANAR: AVCodecID = 65563;
+pub const AVCodecID_AV_CODEC_ID_PCM_S24LE_PLANAR: AVCodecID = 65564;
+pub const AVCodecID_AV_CODEC_ID_PCM_S32LE_PLANAR: AVCodecID = 65565;
+pub const AVCodecID_AV_CODEC_ID_PCM_S16BE_PLANAR: AVCodecID = 65566;
+pub const AVCodecID_AV_CODEC_ID_PCM_S64LE: AVCodecID = 67584;
+pub const AVCodecID_AV_CODEC_ID_PCM_S64BE: AVCodecID = 67585;
+pub const AVCodecID_AV_CODEC_ID_PCM_F16LE: AVCodecID = 67586;
+pub const AVCodecID_AV_CODEC_ID_PCM_F24LE: AVCodecID = 67587;
+pub const AVCodecID_AV_CODEC_ID_PCM_VIDC: AVCodecID = 67588;
+pub const AVCodecID_AV_CODEC_ID_PCM_SGA: AVCodecID = 67589;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_QT: AVCodecID = 69632;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_WAV: AVCodecID = 69633;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_DK3: AVCodecID = 69634;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_DK4: AVCodecID = 69635;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_WS: AVCodecID = 69636;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_SMJPEG: AVCodecID = 69637;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_MS: AVCodecID = 69638;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_4XM: AVCodecID = 69639;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_XA: AVCodecID = 69640;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_ADX: AVCodecID = 69641;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_EA: AVCodecID = 69642;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_G726: AVCodecID = 69643;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_CT: AVCodecID = 69644;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_SWF: AVCodecID = 69645;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_YAMAHA: AVCodecID = 69646;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_SBPRO_4: AVCodecID = 69647;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_SBPRO_3: AVCodecID = 69648;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_SBPRO_2: AVCodecID = 69649;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_THP: AVCodecID = 69650;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_AMV: AVCodecID = 69651;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_EA_R1: AVCodecID = 69652;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_EA_R3: AVCodecID = 69653;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_EA_R2: AVCodecID = 69654;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_EA_SEAD: AVCodecID = 69655;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_EA_EACS: AVCodecID = 69656;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_EA_XAS: AVCodecID = 69657;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_EA_MAXIS_XA: AVCodecID = 69658;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_ISS: AVCodecID = 69659;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_G722: AVCodecID = 69660;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_APC: AVCodecID = 69661;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_VIMA: AVCodecID = 69662;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_AFC: AVCodecID = 71680;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_OKI: AVCodecID = 71681;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_DTK: AVCodecID = 71682;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_RAD: AVCodecID = 71683;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_G726LE: AVCodecID = 71684;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_THP_LE: AVCodecID = 71685;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_PSX: AVCodecID = 71686;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_AICA: AVCodecID = 71687;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_DAT4: AVCodecID = 71688;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_MTAF: AVCodecID = 71689;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_AGM: AVCodecID = 71690;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_ARGO: AVCodecID = 71691;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_SSI: AVCodecID = 71692;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_ZORK: AVCodecID = 71693;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_APM: AVCodecID = 71694;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_ALP: AVCodecID = 71695;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_MTF: AVCodecID = 71696;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_CUNNING: AVCodecID = 71697;
+pub const AVCodecID_AV_CODEC_ID_ADPCM_IMA_MOFLEX: AVCodecID = 71698;
+pub const AVCodecID_AV_CODEC_ID_AMR_NB: AVCodecID = 73728;
+pub const AVCodecID_AV_CODEC_ID_AMR_WB: AVCodecID = 73729;
+pub const AVCodecID_AV_CODEC_ID_RA_144: AVCodecID = 77824;
+pub const AVCodecID_AV_CODEC_ID_RA_288: AVCodecID = 77825;
+pub const AVCodecID_AV_CODEC_ID_ROQ_DPCM: AVCodecID = 81920;
+pub const AVCodecID_AV_CODEC_ID_INTERPLAY_DPCM: AVCodecID = 81921;
+pub const AVCodecID_AV_CODEC_ID_XAN_DPCM: AVCodecID = 81922;
+pub const AVCodecID_AV_CODEC_ID_SOL_DPCM: AVCodecID = 81923;
+pub const AVCodecID_AV_CODEC_ID_SDX2_DPCM: AVCodecID = 83968;
+pub const AVCodecID_AV_CODEC_ID_GREMLIN_DPCM: AVCodecID = 83969;
+pub const AVCodecID_AV_CODEC_ID_DERF_DPCM: AVCodecID = 83970;
+pub const AVCodecID_AV_CODEC_ID_MP2: AVCodecID = 86016;
+pub const AVCodecID_AV_CODEC_ID_MP3: AVCodecID = 86017;
+pub const AVCodecID_AV_CODEC_ID_AAC: AVCodecID = 86018;
+pub const AVCodecID_AV_CODEC_ID_AC3: AVCodecID = 86019;
+pub const AVCodecID_AV_CODEC_ID_DTS: AVCodecID = 86020;
+pub const AVCodecID_AV_CODEC_ID_VORBIS: AVCodecID = 86021;
+pub const AVCodecID_AV_CODEC_ID_DVAUDIO: AVCodecID = 86022;
+pub const AVCodecID_AV_CODEC_ID_WMAV1: AVCodecID = 86023;
+pub const AVCodecID_AV_CODEC_ID_WMAV2: AVCodecID = 86024;
+pub const AVCodecID_AV_CODEC_ID_MACE3: AVCodecID = 86025;
+pub const AVCodecID_AV_CODEC_ID_MACE6: AVCodecID = 86026;
+pub const AVCodecID_AV_CODEC_ID_VMDAUDIO: AVCodecID = 86027;
+pub const AVCodecID_AV_CODEC_ID_FLAC: AVCodecID = 86028;
+pub const AVCodecID_AV_CODEC_ID_MP3ADU: AVCodecID = 86029;
+pub const AVCodecID_AV_CODEC_ID_MP3ON4: AVCodecID = 86030;
+pub const AVCodecID_AV_CODEC_ID_SHORTEN: AVCodecID = 86031;
+pub const AVCodecID_AV_CODEC_ID_ALAC: AVCodecID = 86032;
+pub const AVCodecID_AV_CODEC_ID_WESTWOOD_SND1: AVCodecID = 86033;
+pub const AVCodecID_AV_CODEC_ID_GSM: AVCodecID = 86034;
+pub const AVCodecID_AV_CODEC_ID_QDM2: AVCodecID = 86035;
+pub const AVCodecID_AV_CODEC_ID_COOK: AVCodecID = 86036;
+pub const AVCodecID_AV_CODEC_ID_TRUESPEECH: AVCodecID = 86037;
+pub const AVCodecID_AV_CODEC_ID_TTA: AVCodecID = 86038;
+pub const AVCodecID_AV_CODEC_ID_SMACKAUDIO: AVCodecID = 86039;
+pub const AVCodecID_AV_CODEC_ID_QCELP: AVCodecID = 86040;
+pub const AVCodecID_AV_CODEC_ID_WAVPACK: AVCodecID = 86041;
+pub const AVCodecID_AV_CODEC_ID_DSICINAUDIO: AVCodecID = 86042;
+pub const AVCodecID_AV_CODEC_ID_IMC: AVCodecID = 86043;
+pub const AVCodecID_AV_CODEC_ID_MUSEPACK7: AVCodecID = 86044;
+pub const AVCodecID_AV_CODEC_ID_MLP: AVCodecID = 86045;
+pub const AVCodecID_AV_CODEC_ID_GSM_MS: AVCodecID = 86046;
+pub const AVCodecID_AV_CODEC_ID_ATRAC3: AVCodecID = 86047;
+pub const AVCodecID_AV_CODEC_ID_APE: AVCodecID = 86048;
+pub const AVCodecID_AV_CODEC_ID_NELLYMOSER: AVCodecID = 86049;
+pub const AVCodecID_AV_CODEC_ID_MUSEPACK8: AVCodecID = 86050;
+pub const AVCodecID_AV_CODEC_ID_SPEEX: AVCodecID = 86051;
+pub const AVCodecID_AV_CODEC_ID_WMAVOICE: AVCodecID = 86052;
+pub const AVCodecID_AV_CODEC_ID_WMAPRO: AVCodecID = 86053;
+pub const AVCodecID_AV_CODEC_ID_WMALOSSLESS: AVCodecID = 86054;
+pub const AVCodecID_AV_CODEC_ID_ATRAC3P: AVCodecID = 86055;
+pub const AVCodecID_AV_CODEC_ID_EAC3: AVCodecID = 86056;
+pub const AVCodecID_AV_CODEC_ID_SIPR: AVCodecID = 86057;
+pub const AVCodecID_AV_CODEC_ID_MP1: AVCodecID = 86058;
+pub const AVCodecID_AV_CODEC_ID_TWINVQ: AVCodecID = 86059;
+pub const AVCodecID_AV_CODEC_ID_TRUEHD: AVCodecID = 86060;
+pub const AVCodecID_AV_CODEC_ID_MP4ALS: AVCodecID = 86061;
+pub const AVCodecID_AV_CODEC_ID_ATRAC1: AVCodecID = 86062;
+pub const AVCodecID_AV_CODEC_ID_BINKAUDIO_RDFT: AVCodecID = 86063;
+pub const AVCodecID_AV_CODEC_ID_BINKAUDIO_DCT: AVCodecID = 86064;
+pub const AVCodecID_AV_CODEC_ID_AAC_LATM: AVCodecID = 86065;
+pub const AVCodecID_AV_CODEC_ID_QDMC: AVCodecID = 86066;
+pub const AVCodecID_AV_CODEC_ID_CELT: AVCodecID = 86067;
+pub const AVCodecID_AV_CODEC_ID_G723_1: AVCodecID = 86068;
+pub const AVCodecID_AV_CODEC_ID_G729: AVCodecID = 86069;
+pub const AVCodecID_AV_CODEC_ID_8SVX_EXP: AVCodecID = 86070;
+pub const AVCodecID_AV_CODEC_ID_8SVX_FIB: AVCodecID = 86071;
+pub const AVCodecID_AV_CODEC_ID_BMV_AUDIO: AVCodecID = 86072;
+pub const AVCodecID_AV_CODEC_ID_RALF: AVCodecID = 86073;
+pub const AVCodecID_AV_CODEC_ID_IAC: AVCodecID = 86074;
+pub const AVCodecID_AV_CODEC_ID_ILBC: AVCodecID = 86075;
+pub const AVCodecID_AV_CODEC_ID_OPUS: AVCodecID = 86076;
+pub const AVCodecID_AV_CODEC_ID_COMFORT_NOISE: AVCodecID = 86077;
+pub const AVCodecID_AV_CODEC_ID_TAK: AVCodecID = 86078;
+pub const AVCodecID_AV_CODEC_ID_METASOUND: AVCodecID = 86079;
+pub const AVCodecID_AV_CODEC_ID_PAF_AUDIO: AVCodecID = 86080;
+pub const AVCodecID_AV_CODEC_ID_ON2AVC: AVCodecID = 86081;
+pub const AVCodecID_AV_CODEC_ID_DSS_SP: AVCodecID = 86082;
+pub const AVCodecID_AV_CODEC_ID_CODEC2: AVCodecID = 86083;
+pub const AVCodecID_AV_CODEC_ID_FFWAVESYNTH: AVCodecID = 88064;
+pub const AVCodecID_AV_CODEC_ID_SONIC: AVCodecID = 88065;
+pub const AVCodecID_AV_CODEC_ID_SONIC_LS: AVCodecID = 88066;
+pub const AVCodecID_AV_CODEC_ID_EVRC: AVCodecID = 88067;
+pub const AVCodecID_AV_CODEC_ID_SMV: AVCodecID = 88068;
+pub const AVCodecID_AV_CODEC_ID_DSD_LSBF: AVCodecID = 88069;
+pub const AVCodecID_AV_CODEC_ID_DSD_MSBF: AVCodecID = 88070;
+pub const AVCodecID_AV_CODEC_ID_DSD_LSBF_PLANAR: AVCodecID = 88071;
+pub const AVCodecID_AV_CODEC_ID_DSD_MSBF_PLANAR: AVCodecID = 88072;
+pub const AVCodecID_AV_CODEC_ID_4GV: AVCodecID = 88073;
+pub const AVCodecID_AV_CODEC_ID_INTERPLAY_ACM: AVCodecID = 88074;
+pub const AVCodecID_AV_CODEC_ID_XMA1: AVCodecID = 88075;
+pub const AVCodecID_AV_CODEC_ID_XMA2: AVCodecID = 88076;
+pub const AVCodecID_AV_CODEC_ID_DST: AVCodecID = 88077;
+pub const AVCodecID_AV_CODEC_ID_ATRAC3AL: AVCodecID = 88078;
+pub const AVCodecID_AV_CODEC_ID_ATRAC3PAL: AVCodecID = 88079;
+pub const AVCodecID_AV_CODEC_ID_DOLBY_E: AVCodecID = 88080;
+pub const AVCodecID_AV_CODEC_ID_APTX: AVCodecID = 88081;
+pub const AVCodecID_AV_CODEC_ID_APTX_HD: AVCodecID = 88082;
+pub const AVCodecID_AV_CODEC_ID_SBC: AVCodecID = 88083;
+pub const AVCodecID_AV_CODEC_ID_ATRAC9: AVCodecID = 88084;
+pub const AVCodecID_AV_CODEC_ID_HCOM: AVCodecID = 88085;
+pub const AVCodecID_AV_CODEC_ID_ACELP_KELVIN: AVCodecID = 88086;
+pub const AVCodecID_AV_CODEC_ID_MPEGH_3D_AUDIO: AVCodecID = 88087;
+pub const AVCodecID_AV_CODEC_ID_SIREN: AVCodecID = 88088;
+pub const AVCodecID_AV_CODEC_ID_HCA: AVCodecID = 88089;
+pub const AVCodecID_AV_CODEC_ID_FASTAUDIO: AVCodecID = 88090;
+pub const AVCodecID_AV_CODEC_ID_FIRST_SUBTITLE: AVCodecID = 94208;
+pub const AVCodecID_AV_CODEC_ID_DVD_SUBTITLE: AVCodecID = 94208;
+pub const AVCodecID_AV_CODEC_ID_DVB_SUBTITLE: AVCodecID = 94209;
+pub const AVCodecID_AV_CODEC_ID_TEXT: AVCodecID = 94210;
+pub const AVCodecID_AV_CODEC_ID_XSUB: AVCodecID = 94211;
+pub const AVCodecID_AV_CODEC_ID_SSA: AVCodecID = 94212;
+pub const AVCodecID_AV_CODEC_ID_MOV_TEXT: AVCodecID = 94213;
+pub const AVCodecID_AV_CODEC_ID_HDMV_PGS_SUBTITLE: AVCodecID = 94214;
+pub const AVCodecID_AV_CODEC_ID_DVB_TELETEXT: AVCodecID = 94215;
+pub const AVCodecID_AV_CODEC_ID_SRT: AVCodecID = 94216;
+pub const AVCodecID_AV_CODEC_ID_MICRODVD: AVCodecID = 96256;
+pub const AVCodecID_AV_CODEC_ID_EIA_608: AVCodecID = 96257;
+pub const AVCodecID_AV_CODEC_ID_JACOSUB: AVCodecID = 96258;
+pub const AVCodecID_AV_CODEC_ID_SAMI: AVCodecID = 96259;
+pub const AVCodecID_AV_CODEC_ID_REALTEXT: AVCodecID = 96260;
+pub const AVCodecID_AV_CODEC_ID_STL: AVCodecID = 96261;
+pub const AVCodecID_AV_CODEC_ID_SUBVIEWER1: AVCodecID = 96262;
+pub const AVCodecID_AV_CODEC_ID_SUBVIEWER: AVCodecID = 96263;
+pub const AVCodecID_AV_CODEC_ID_SUBRIP: AVCodecID = 96264;
+pub const AVCodecID_AV_CODEC_ID_WEBVTT: AVCodecID = 96265;
+pub const AVCodecID_AV_CODEC_ID_MPL2: AVCodecID = 96266;
+pub const AVCodecID_AV_CODEC_ID_VPLAYER: AVCodecID = 96267;
+pub const AVCodecID_AV_CODEC_ID_PJS: AVCodecID = 96268;
+pub const AVCodecID_AV_CODEC_ID_ASS: AVCodecID = 96269;
+pub const AVCodecID_AV_CODEC_ID_HDMV_TEXT_SUBTITLE: AVCodecID = 96270;
+pub const AVCodecID_AV_CODEC_ID_TTML: AVCodecID = 96271;
+pub const AVCodecID_AV_CODEC_ID_ARIB_CAPTION: AVCodecID = 96272;
+pub const AVCodecID_AV_CODEC_ID_FIRST_UNKNOWN: AVCodecID = 98304;
+pub const AVCodecID_AV_CODEC_ID_TTF: AVCodecID = 98304;
+pub const AVCodecID_AV_CODEC_ID_SCTE_35: AVCodecID = 98305;
+pub const AVCodecID_AV_CODEC_ID_EPG: AVCodecID = 98306;
+pub const AVCodecID_AV_CODEC_ID_BINTEXT: AVCodecID = 100352;
+pub const AVCodecID_AV_CODEC_ID_XBIN: AVCodecID = 100353;
+pub const AVCodecID_AV_CODEC_ID_IDF: AVCodecID = 100354;
+pub const AVCodecID_AV_CODEC_ID_OTF: AVCodecID = 100355;
+pub const AVCodecID_AV_CODEC_ID_SMPTE_KLV: AVCodecID = 100356;
+pub const AVCodecID_AV_CODEC_ID_DVD_NAV: AVCodecID = 100357;
+pub const AVCodecID_AV_CODEC_ID_TIMED_ID3: AVCodecID = 100358;
+pub const AVCodecID_AV_CODEC_ID_BIN_DATA: AVCodecID = 100359;
+pub const AVCodecID_AV_CODEC_ID_PROBE: AVCodecID = 102400;
+pub const AVCodecID_AV_CODEC_ID_MPEG2TS: AVCodecID = 131072;
+pub const AVCodecID_AV_CODEC_ID_MPEG4SYSTEMS: AVCodecID = 131073;
+pub const AVCodecID_AV_CODEC_ID_FFMETADATA: AVCodecID = 135168;
+pub const AVCodecID_AV_CODEC_ID_WRAPPED_AVFRAME: AVCodecID = 135169;
+pub type AVCodecID = ::std::os::raw::c_uint;
+pub const AVFieldOrder_AV_FIELD_UNKNOWN: AVFieldOrder = 0;
+pub const AVFieldOrder_AV_FIELD_PROGRESSIVE: AVFieldOrder = 1;
+pub const AVFieldOrder_AV_FIELD_TT: AVFieldOrder = 2;
+pub const AVFieldOrder_AV_FIELD_BB: AVFieldOrder = 3;
+pub const AVFieldOrder_AV_FIELD_TB: AVFieldOrder = 4;
+pub const AVFieldOrder_AV_FIELD_BT: AVFieldOrder = 5;
+pub type AVFieldOrder = ::std::os::raw::c_uint;
+pub const AVPacketSideDataType_AV_PKT_DATA_PALETTE: AVPacketSideDataType = 0;
+pub const AVPacketSideDataType_AV_PKT_DATA_NEW_EXTRADATA: AVPacketSideDataType = 1;
+pub const AVPacketSideDataType_AV_PKT_DATA_PARAM_CHANGE: AVPacketSideDataType = 2;
+pub const AVPacketSideDataType_AV_PKT_DATA_H263_MB_INFO: AVPacketSideDataType = 3;
+pub const AVPacketSideDataType_AV_PKT_DATA_REPLAYGAIN: AVPacketSideDataType = 4;
+pub const AVPacketSideDataType_AV_PKT_DATA_DISPLAYMATRIX: AVPacketSideDataType = 5;
+pub const AVPacketSideDataType_AV_PKT_DATA_STEREO3D: AVPacketSideDataType = 6;
+pub const AVPacketSideDataType_AV_PKT_DATA_AUDIO_SERVICE_TYPE: AVPacketSideDataType = 7;
+pub const AVPacketSideDataType_AV_PKT_DATA_QUALITY_STATS: AVPacketSideDataType = 8;
+pub const AVPacketSideDataType_AV_PKT_DATA_FALLBACK_TRACK: AVPacketSideDataType = 9;
+pub const AVPacketSideDataType_AV_PKT_DATA_CPB_PROPERTIES: AVPacketSideDataType = 10;
+pub const AVPacketSideDataType_AV_PKT_DATA_SKIP_SAMPLES: AVPacketSideDataType = 11;
+pub const AVPacketSideDataType_AV_PKT_DATA_JP_DUALMONO: AVPacketSideDataType = 12;
+pub const AVPacketSideDataType_AV_PKT_DATA_STRINGS_METADATA: AVPacketSideDataType = 13;
+pub const AVPacketSideDataType_AV_PKT_DATA_SUBTITLE_POSITION: AVPacketSideDataType = 14;
+pub const AVPacketSideDataType_AV_PKT_DATA_MATROSKA_BLOCKADDITIONAL: AVPacketSideDataType = 15;
+pub const AVPacketSideDataType_AV_PKT_DATA_WEBVTT_IDENTIFIER: AVPacketSideDataType = 16;
+pub const AVPacketSideDataType_AV_PKT_DATA_WEBVTT_SETTINGS: AVPacketSideDataType = 17;
+pub const AVPacketSideDataType_AV_PKT_DATA_METADATA_UPDATE: AVPacketSideDataType = 18;
+pub const AVPacketSideDataType_AV_PKT_DATA_MPEGTS_STREAM_ID: AVPacketSideDataType = 19;
+pub const AVPacketSideDataType_AV_PKT_DATA_MASTERING_DISPLAY_METADATA: AVPacketSideDataType = 20;
+pub const AVPacketSideDataType_AV_PKT_DATA_SPHERICAL: AVPacketSideDataType = 21;
+pub const AVPacketSideDataType_AV_PKT_DATA_CONTENT_LIGHT_LEVEL: AVPacketSideDataType = 22;
+pub const AVPacketSideDataType_AV_PKT_DATA_A53_CC: AVPacketSideDataType = 23;
+pub const AVPacketSideDataType_AV_PKT_DATA_ENCRYPTION_INIT_INFO: AVPacketSideDataType = 24;
+pub const AVPacketSideDataType_AV_PKT_DATA_ENCRYPTION_INFO: AVPacketSideDataType = 25;
+pub const AVPacketSideDataType_AV_PKT_DATA_AFD: AVPacketSideDataType = 26;
+pub const AVPacketSideDataType_AV_PKT_DATA_PRFT: AVPacketSideDataType = 27;
+pub const AVPacketSideDataType_AV_PKT_DATA_ICC_PROFILE: AVPacketSideDataType = 28;
+pub const AVPacketSideDataType_AV_PKT_DATA_DOVI_CONF: AVPacketSideDataType = 29;
+pub const AVPacketSideDataType_AV_PKT_DATA_S12M_TIMECODE: AVPacketSideDataType = 30;
+pub const AVPacketSideDataType_AV_PKT_DATA_NB: AVPacketSideDataType = 31;
+pub type AVPacketSideDataType = ::std::os::raw::c_uint;
+pub const AVSideDataParamChangeFlags_AV_SIDE_DATA_PARAM_CHANGE_CHANNEL_COUNT:
+    AVSideDataParamChangeFlags = 1;
+pub const AVSideDataParamChangeFlags_AV_SIDE_DATA_PARAM_CHANGE_CHANNEL_LAYOUT:
+    AVSideDataParamChangeFlags = 2;
+pub const AVSideDataParamChangeFlags_AV_SIDE_DATA_PARAM_CHANGE_SAMPLE_RATE:
+    AVSideDataParamChangeFlags = 4;
+pub const AVSideDataParamChangeFlags_AV_SIDE_DATA_PARAM_CHANGE_DIMENSIONS:
+    AVSideDataParamChangeFlags = 8;
+pub type AVSideDataParamChangeFlags = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBSFInternal {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBSFList {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecDefault {
+    _unused: [u8; 0],
+}
+pub const AV_CODEC_HW_CONFIG_METHOD_HW_DEVICE_CTX: _bindgen_ty_4 = 1;
+pub const AV_CODEC_HW_CONFIG_METHOD_HW_FRAMES_CTX: _bindgen_ty_4 = 2;
+pub const AV_CODEC_HW_CONFIG_METHOD_INTERNAL: _bindgen_ty_4 = 4;
+pub const AV_CODEC_HW_CONFIG_METHOD_AD_HOC: _bindgen_ty_4 = 8;
+pub type _bindgen_ty_4 = ::std::os::raw::c_uint;
+pub const AVDiscard_AVDISCARD_NONE: AVDiscard = -16;
+pub const AVDiscard_AVDISCARD_DEFAULT: AVDiscard = 0;
+pub const AVDiscard_AVDISCARD_NONREF: AVDiscard = 8;
+pub const AVDiscard_AVDISCARD_BIDIR: AVDiscard = 16;
+pub const AVDiscard_AVDISCARD_NONINTRA: AVDiscard = 24;
+pub const AVDiscard_AVDISCARD_NONKEY: AVDiscard = 32;
+pub const AVDiscard_AVDISCARD_ALL: AVDiscard = 48;
+pub type AVDiscard = ::std::os::raw::c_int;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_MAIN: AVAudioServiceType = 0;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_EFFECTS: AVAudioServiceType = 1;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_VISUALLY_IMPAIRED: AVAudioServiceType = 2;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_HEARING_IMPAIRED: AVAudioServiceType = 3;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_DIALOGUE: AVAudioServiceType = 4;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_COMMENTARY: AVAudioServiceType = 5;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_EMERGENCY: AVAudioServiceType = 6;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_VOICE_OVER: AVAudioServiceType = 7;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_KARAOKE: AVAudioServiceType = 8;
+pub const AVAudioServiceType_AV_AUDIO_SERVICE_TYPE_NB: AVAudioServiceType = 9;
+pub type AVAudioServiceType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecInternal {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct MpegEncContext {
+    _unused: [u8; 0],
+}
+pub const AVSubtitleType_SUBTITLE_NONE: AVSubtitleType = 0;
+pub const AVSubtitleType_SUBTITLE_BITMAP: AVSubtitleType = 1;
+pub const AVSubtitleType_SUBTITLE_TEXT: AVSubtitleType = 2;
+pub const AVSubtitleType_SUBTITLE_ASS: AVSubtitleType = 3;
+pub type AVSubtitleType = ::std::os::raw::c_uint;
+pub const AVPictureStructure_AV_PICTURE_STRUCTURE_UNKNOWN: AVPictureStructure = 0;
+pub const AVPictureStructure_AV_PICTURE_STRUCTURE_TOP_FIELD: AVPictureStructure = 1;
+pub const AVPictureStructure_AV_PICTURE_STRUCTURE_BOTTOM_FIELD: AVPictureStructure = 2;
+pub const AVPictureStructure_AV_PICTURE_STRUCTURE_FRAME: AVPictureStructure = 3;
+pub type AVPictureStructure = ::std::os::raw::c_uint;
+pub const AVLockOp_AV_LOCK_CREATE: AVLockOp = 0;
+pub const AVLockOp_AV_LOCK_OBTAIN: AVLockOp = 1;
+pub const AVLockOp_AV_LOCK_RELEASE: AVLockOp = 2;
+pub const AVLockOp_AV_LOCK_DESTROY: AVLockOp = 3;
+pub type AVLockOp = ::std::os::raw::c_uint;
+pub const AVOptionType_AV_OPT_TYPE_FLAGS: AVOptionType = 0;
+pub const AVOptionType_AV_OPT_TYPE_INT: AVOptionType = 1;
+pub const AVOptionType_AV_OPT_TYPE_INT64: AVOptionType = 2;
+pub const AVOptionType_AV_OPT_TYPE_DOUBLE: AVOptionType = 3;
+pub const AVOptionType_AV_OPT_TYPE_FLOAT: AVOptionType = 4;
+pub const AVOptionType_AV_OPT_TYPE_STRING: AVOptionType = 5;
+pub const AVOptionType_AV_OPT_TYPE_RATIONAL: AVOptionType = 6;
+pub const AVOptionType_AV_OPT_TYPE_BINARY: AVOptionType = 7;
+pub const AVOptionType_AV_OPT_TYPE_DICT: AVOptionType = 8;
+pub const AVOptionType_AV_OPT_TYPE_UINT64: AVOptionType = 9;
+pub const AVOptionType_AV_OPT_TYPE_CONST: AVOptionType = 10;
+pub const AVOptionType_AV_OPT_TYPE_IMAGE_SIZE: AVOptionType = 11;
+pub const AVOptionType_AV_OPT_TYPE_PIXEL_FMT: AVOptionType = 12;
+pub const AVOptionType_AV_OPT_TYPE_SAMPLE_FMT: AVOptionType = 13;
+pub const AVOptionType_AV_OPT_TYPE_VIDEO_RATE: AVOptionType = 14;
+pub const AVOptionType_AV_OPT_TYPE_DURATION: AVOptionType = 15;
+pub const AVOptionType_AV_OPT_TYPE_COLOR: AVOptionType = 16;
+pub const AVOptionType_AV_OPT_TYPE_CHANNEL_LAYOUT: AVOptionType = 17;
+pub const AVOptionType_AV_OPT_TYPE_BOOL: AVOptionType = 18;
+pub type AVOptionType = ::std::os::raw::c_uint;
+pub const AV_OPT_FLAG_IMPLICIT_KEY: _bindgen_ty_5 = 1;
+pub type _bindgen_ty_5 = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVAudioFifo {
+    _unused: [u8; 0],
+}
+pub const AVIODirEntryType_AVIO_ENTRY_UNKNOWN: AVIODirEntryType = 0;
+pub const AVIODirEntryType_AVIO_ENTRY_BLOCK_DEVICE: AVIODirEntryType = 1;
+pub const AVIODirEntryType_AVIO_ENTRY_CHARACTER_DEVICE: AVIODirEntryType = 2;
+pub const AVIODirEntryType_AVIO_ENTRY_DIRECTORY: AVIODirEntryType = 3;
+pub const AVIODirEntryType_AVIO_ENTRY_NAMED_PIPE: AVIODirEntryType = 4;
+pub const AVIODirEntryType_AVIO_ENTRY_SYMBOLIC_LINK: AVIODirEntryType = 5;
+pub const AVIODirEntryType_AVIO_ENTRY_SOCKET: AVIODirEntryType = 6;
+pub const AVIODirEntryType_AVIO_ENTRY_FILE: AVIODirEntryType = 7;
+pub const AVIODirEntryType_AVIO_ENTRY_SERVER: AVIODirEntryType = 8;
+pub const AVIODirEntryType_AVIO_ENTRY_SHARE: AVIODirEntryType = 9;
+pub const AVIODirEntryType_AVIO_ENTRY_WORKGROUP: AVIODirEntryType = 10;
+pub type AVIODirEntryType = ::std::os::raw::c_uint;
+pub const AVIODataMarkerType_AVIO_DATA_MARKER_HEADER: AVIODataMarkerType = 0;
+pub const AVIODataMarkerType_AVIO_DATA_MARKER_SYNC_POINT: AVIODataMarkerType = 1;
+pub const AVIODataMarkerType_AVIO_DATA_MARKER_BOUNDARY_POINT: AVIODataMarkerType = 2;
+pub const AVIODataMarkerType_AVIO_DATA_MARKER_UNKNOWN: AVIODataMarkerType = 3;
+pub const AVIODataMarkerType_AVIO_DATA_MARKER_TRAILER: AVIODataMarkerType = 4;
+pub const AVIODataMarkerType_AVIO_DATA_MARKER_FLUSH_POINT: AVIODataMarkerType = 5;
+pub type AVIODataMarkerType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecTag {
+    _unused: [u8; 0],
+}
+pub const AVStreamParseType_AVSTREAM_PARSE_NONE: AVStreamParseType = 0;
+pub const AVStreamParseType_AVSTREAM_PARSE_FULL: AVStreamParseType = 1;
+pub const AVStreamParseType_AVSTREAM_PARSE_HEADERS: AVStreamParseType = 2;
+pub const AVStreamParseType_AVSTREAM_PARSE_TIMESTAMPS: AVStreamParseType = 3;
+pub const AVStreamParseType_AVSTREAM_PARSE_FULL_ONCE: AVStreamParseType = 4;
+pub const AVStreamParseType_AVSTREAM_PARSE_FULL_RAW: AVStreamParseType = 5;
+pub type AVStreamParseType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVStreamInternal {
+    _unused: [u8; 0],
+}
+pub type av_format_control_message = ::std::option::Option<
+    unsafe extern "C" fn(
+        s: *mut AVFormatContext,
+        type_: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+        data_size: size_t,
+    ) -> ::std::os::raw::c_int,
+>;
+pub type AVOpenCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        s: *mut AVFormatContext,
+        pb: *mut *mut AVIOContext,
+        url: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+        int_cb: *const AVIOInterruptCB,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int,
+>;
+pub const AVDurationEstimationMethod_AVFMT_DURATION_FROM_PTS: AVDurationEstimationMethod = 0;
+pub const AVDurationEstimationMethod_AVFMT_DURATION_FROM_STREAM: AVDurationEstimationMethod = 1;
+pub const AVDurationEstimationMethod_AVFMT_DURATION_FROM_BITRATE: AVDurationEstimationMethod = 2;
+pub type AVDurationEstimationMethod = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFormatInternal {
+    _unused: [u8; 0],
+}
+pub const AVTimebaseSource_AVFMT_TBCF_AUTO: AVTimebaseSource = -1;
+pub const AVTimebaseSource_AVFMT_TBCF_DECODER: AVTimebaseSource = 0;
+pub const AVTimebaseSource_AVFMT_TBCF_DEMUXER: AVTimebaseSource = 1;
+pub const AVTimebaseSource_AVFMT_TBCF_R_FRAMERATE: AVTimebaseSource = 2;
+pub type AVTimebaseSource = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterPad {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterFormats {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterChannelLayouts {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterInternal {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterContext {
+    pub av_class: *const AVClass,
+    pub filter: *const AVFilter,
+    pub name: *mut ::std::os::raw::c_char,
+    pub input_pads: *mut AVFilterPad,
+    pub inputs: *mut *mut AVFilterLink,
+    pub nb_inputs: ::std::os::raw::c_uint,
+    pub output_pads: *mut AVFilterPad,
+    pub outputs: *mut *mut AVFilterLink,
+    pub nb_outputs: ::std::os::raw::c_uint,
+    pub priv_: *mut ::std::os::raw::c_void,
+    pub graph: *mut AVFilterGraph,
+    pub thread_type: ::std::os::raw::c_int,
+    pub internal: *mut AVFilterInternal,
+    pub command_queue: *mut AVFilterCommand,
+    pub enable_str: *mut ::std::os::raw::c_char,
+    pub enable: *mut ::std::os::raw::c_void,
+    pub var_values: *mut f64,
+    pub is_disabled: ::std::os::raw::c_int,
+    pub hw_device_ctx: *mut AVBufferRef,
+    pub nb_threads: ::std::os::raw::c_int,
+    pub ready: ::std::os::raw::c_uint,
+    pub extra_hw_frames: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVFilterContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFilterContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFilterContext>(),
+        168usize,
+        concat!("Size of: ", stringify!(AVFilterContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFilterContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFilterContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filter) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(filter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_pads) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(input_pads)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inputs) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(inputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_inputs) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(nb_inputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_pads) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(output_pads)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).outputs) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(outputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_outputs) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(nb_outputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(priv_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).graph) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(graph)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thread_type) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(thread_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).command_queue) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(command_queue)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).enable_str) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(enable_str)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).enable) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(enable)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).var_values) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(var_values)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).is_disabled) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(is_disabled)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_device_ctx) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(hw_device_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_threads) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(nb_threads)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ready) as usize - ptr as usize },
+        156usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(ready)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extra_hw_frames) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterContext),
+            "::",
+            stringify!(extra_hw_frames)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterLink {
+    pub src: *mut AVFilterContext,
+    pub srcpad: *mut AVFilterPad,
+    pub dst: *mut AVFilterContext,
+    pub dstpad: *mut AVFilterPad,
+    pub type_: AVMediaType,
+    pub w: ::std::os::raw::c_int,
+    pub h: ::std::os::raw::c_int,
+    pub sample_aspect_ratio: AVRational,
+    pub channel_layout: u64,
+    pub sample_rate: ::std::os::raw::c_int,
+    pub format: ::std::os::raw::c_int,
+    pub time_base: AVRational,
+    pub incfg: AVFilterFormatsConfig,
+    pub outcfg: AVFilterFormatsConfig,
+    pub init_state: AVFilterLink__bindgen_ty_1,
+    pub graph: *mut AVFilterGraph,
+    pub current_pts: i64,
+    pub current_pts_us: i64,
+    pub age_index: ::std::os::raw::c_int,
+    pub frame_rate: AVRational,
+    pub partial_buf: *mut AVFrame,
+    pub partial_buf_size: ::std::os::raw::c_int,
+    pub min_samples: ::std::os::raw::c_int,
+    pub max_samples: ::std::os::raw::c_int,
+    pub channels: ::std::os::raw::c_int,
+    pub frame_count_in: i64,
+    pub frame_count_out: i64,
+    pub frame_pool: *mut ::std::os::raw::c_void,
+    pub frame_wanted_out: ::std::os::raw::c_int,
+    pub hw_frames_ctx: *mut AVBufferRef,
+    pub reserved: [::std::os::raw::c_char; 61440usize],
+}
+#[test]
+fn bindgen_test_layout_AVFilterLink() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFilterLink> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFilterLink>(),
+        61680usize,
+        concat!("Size of: ", stringify!(AVFilterLink))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFilterLink>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFilterLink))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).src) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(src)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).srcpad) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(srcpad)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dst) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(dst)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dstpad) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(dstpad)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_aspect_ratio) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(sample_aspect_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layout) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(channel_layout)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rate) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(sample_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        68usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(time_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).incfg) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(incfg)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).outcfg) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(outcfg)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init_state) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(init_state)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).graph) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(graph)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).current_pts) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(current_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).current_pts_us) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(current_pts_us)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).age_index) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(age_index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_rate) as usize - ptr as usize },
+        164usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(frame_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).partial_buf) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(partial_buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).partial_buf_size) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(partial_buf_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_samples) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(min_samples)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_samples) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(max_samples)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channels) as usize - ptr as usize },
+        196usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(channels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_count_in) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(frame_count_in)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_count_out) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(frame_count_out)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_pool) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(frame_pool)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_wanted_out) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(frame_wanted_out)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_frames_ctx) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(hw_frames_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reserved) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterLink),
+            "::",
+            stringify!(reserved)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterGraphInternal {
+    _unused: [u8; 0],
+}
+pub type avfilter_action_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        ctx: *mut AVFilterContext,
+        arg: *mut ::std::os::raw::c_void,
+        jobnr: ::std::os::raw::c_int,
+        nb_jobs: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int,
+>;
+pub type avfilter_execute_func = ::std::option::Option<
+    unsafe extern "C" fn(
+        ctx: *mut AVFilterContext,
+        func: avfilter_action_func,
+        arg: *mut ::std::os::raw::c_void,
+        ret: *mut ::std::os::raw::c_int,
+        nb_jobs: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int,
+>;
+pub const AVFILTER_AUTO_CONVERT_ALL: _bindgen_ty_6 = 0;
+pub const AVFILTER_AUTO_CONVERT_NONE: _bindgen_ty_6 = -1;
+pub type _bindgen_ty_6 = ::std::os::raw::c_int;
+pub const AV_BUFFERSRC_FLAG_NO_CHECK_FORMAT: _bindgen_ty_7 = 1;
+pub const AV_BUFFERSRC_FLAG_PUSH: _bindgen_ty_7 = 4;
+pub const AV_BUFFERSRC_FLAG_KEEP_REF: _bindgen_ty_7 = 8;
+pub type _bindgen_ty_7 = ::std::os::raw::c_uint;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_NONE: AVAppToDevMessageType = 1313820229;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_WINDOW_SIZE: AVAppToDevMessageType = 1195724621;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_WINDOW_REPAINT: AVAppToDevMessageType = 1380274241;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_PAUSE: AVAppToDevMessageType = 1346458912;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_PLAY: AVAppToDevMessageType = 1347174745;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_TOGGLE_PAUSE: AVAppToDevMessageType = 1346458964;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_SET_VOLUME: AVAppToDevMessageType = 1398165324;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_MUTE: AVAppToDevMessageType = 541939028;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_UNMUTE: AVAppToDevMessageType = 1431131476;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_TOGGLE_MUTE: AVAppToDevMessageType = 1414354260;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_GET_VOLUME: AVAppToDevMessageType = 1196838732;
+pub const AVAppToDevMessageType_AV_APP_TO_DEV_GET_MUTE: AVAppToDevMessageType = 1196250452;
+pub type AVAppToDevMessageType = ::std::os::raw::c_uint;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_NONE: AVDevToAppMessageType = 1313820229;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_CREATE_WINDOW_BUFFER: AVDevToAppMessageType =
+    1111708229;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_PREPARE_WINDOW_BUFFER: AVDevToAppMessageType =
+    1112560197;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_DISPLAY_WINDOW_BUFFER: AVDevToAppMessageType =
+    1111771475;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_DESTROY_WINDOW_BUFFER: AVDevToAppMessageType =
+    1111770451;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_BUFFER_OVERFLOW: AVDevToAppMessageType = 1112491596;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_BUFFER_UNDERFLOW: AVDevToAppMessageType = 1112884812;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_BUFFER_READABLE: AVDevToAppMessageType = 1112687648;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_BUFFER_WRITABLE: AVDevToAppMessageType = 1113018912;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_MUTE_STATE_CHANGED: AVDevToAppMessageType =
+    1129141588;
+pub const AVDevToAppMessageType_AV_DEV_TO_APP_VOLUME_LEVEL_CHANGED: AVDevToAppMessageType =
+    1129729868;
+pub type AVDevToAppMessageType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SwsContext {
+    _unused: [u8; 0],
+}
+pub const SwrDitherType_SWR_DITHER_NONE: SwrDitherType = 0;
+pub const SwrDitherType_SWR_DITHER_RECTANGULAR: SwrDitherType = 1;
+pub const SwrDitherType_SWR_DITHER_TRIANGULAR: SwrDitherType = 2;
+pub const SwrDitherType_SWR_DITHER_TRIANGULAR_HIGHPASS: SwrDitherType = 3;
+pub const SwrDitherType_SWR_DITHER_NS: SwrDitherType = 64;
+pub const SwrDitherType_SWR_DITHER_NS_LIPSHITZ: SwrDitherType = 65;
+pub const SwrDitherType_SWR_DITHER_NS_F_WEIGHTED: SwrDitherType = 66;
+pub const SwrDitherType_SWR_DITHER_NS_MODIFIED_E_WEIGHTED: SwrDitherType = 67;
+pub const SwrDitherType_SWR_DITHER_NS_IMPROVED_E_WEIGHTED: SwrDitherType = 68;
+pub const SwrDitherType_SWR_DITHER_NS_SHIBATA: SwrDitherType = 69;
+pub const SwrDitherType_SWR_DITHER_NS_LOW_SHIBATA: SwrDitherType = 70;
+pub const SwrDitherType_SWR_DITHER_NS_HIGH_SHIBATA: SwrDitherType = 71;
+pub const SwrDitherType_SWR_DITHER_NB: SwrDitherType = 72;
+pub type SwrDitherType = ::std::os::raw::c_uint;
+pub const SwrEngine_SWR_ENGINE_SWR: SwrEngine = 0;
+pub const SwrEngine_SWR_ENGINE_SOXR: SwrEngine = 1;
+pub const SwrEngine_SWR_ENGINE_NB: SwrEngine = 2;
+pub type SwrEngine = ::std::os::raw::c_uint;
+pub const SwrFilterType_SWR_FILTER_TYPE_CUBIC: SwrFilterType = 0;
+pub const SwrFilterType_SWR_FILTER_TYPE_BLACKMAN_NUTTALL: SwrFilterType = 1;
+pub const SwrFilterType_SWR_FILTER_TYPE_KAISER: SwrFilterType = 2;
+pub type SwrFilterType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SwrContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RcOverride {
+    pub start_frame: ::std::os::raw::c_int,
+    pub end_frame: ::std::os::raw::c_int,
+    pub qscale: ::std::os::raw::c_int,
+    pub quality_factor: f32,
+}
+#[test]
+fn bindgen_test_layout_RcOverride() {
+    const UNINIT: ::std::mem::MaybeUninit<RcOverride> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<RcOverride>(),
+        16usize,
+        concat!("Size of: ", stringify!(RcOverride))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<RcOverride>(),
+        4usize,
+        concat!("Alignment of ", stringify!(RcOverride))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_frame) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(RcOverride),
+            "::",
+            stringify!(start_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end_frame) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(RcOverride),
+            "::",
+            stringify!(end_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qscale) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(RcOverride),
+            "::",
+            stringify!(qscale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).quality_factor) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(RcOverride),
+            "::",
+            stringify!(quality_factor)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVPanScan {
+    pub id: ::std::os::raw::c_int,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+    pub position: [[i16; 2usize]; 3usize],
+}
+#[test]
+fn bindgen_test_layout_AVPanScan() {
+    const UNINIT: ::std::mem::MaybeUninit<AVPanScan> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVPanScan>(),
+        24usize,
+        concat!("Size of: ", stringify!(AVPanScan))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVPanScan>(),
+        4usize,
+        concat!("Alignment of ", stringify!(AVPanScan))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPanScan),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPanScan),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPanScan),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).position) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPanScan),
+            "::",
+            stringify!(position)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCPBProperties {
+    pub max_bitrate: ::std::os::raw::c_int,
+    pub min_bitrate: ::std::os::raw::c_int,
+    pub avg_bitrate: ::std::os::raw::c_int,
+    pub buffer_size: ::std::os::raw::c_int,
+    pub vbv_delay: u64,
+}
+#[test]
+fn bindgen_test_layout_AVCPBProperties() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCPBProperties> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCPBProperties>(),
+        24usize,
+        concat!("Size of: ", stringify!(AVCPBProperties))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCPBProperties>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCPBProperties))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_bitrate) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCPBProperties),
+            "::",
+            stringify!(max_bitrate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_bitrate) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCPBProperties),
+            "::",
+            stringify!(min_bitrate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).avg_bitrate) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCPBProperties),
+            "::",
+            stringify!(avg_bitrate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buffer_size) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCPBProperties),
+            "::",
+            stringify!(buffer_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vbv_delay) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCPBProperties),
+            "::",
+            stringify!(vbv_delay)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVProducerReferenceTime {
+    pub wallclock: i64,
+    pub flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVProducerReferenceTime() {
+    const UNINIT: ::std::mem::MaybeUninit<AVProducerReferenceTime> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVProducerReferenceTime>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVProducerReferenceTime))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVProducerReferenceTime>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVProducerReferenceTime))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wallclock) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProducerReferenceTime),
+            "::",
+            stringify!(wallclock)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProducerReferenceTime),
+            "::",
+            stringify!(flags)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecContext {
+    pub av_class: *const AVClass,
+    pub log_level_offset: ::std::os::raw::c_int,
+    pub codec_type: AVMediaType,
+    pub codec: *const AVCodec,
+    pub codec_id: AVCodecID,
+    pub codec_tag: ::std::os::raw::c_uint,
+    pub priv_data: *mut ::std::os::raw::c_void,
+    pub internal: *mut AVCodecInternal,
+    pub opaque: *mut ::std::os::raw::c_void,
+    pub bit_rate: i64,
+    pub bit_rate_tolerance: ::std::os::raw::c_int,
+    pub global_quality: ::std::os::raw::c_int,
+    pub compression_level: ::std::os::raw::c_int,
+    pub flags: ::std::os::raw::c_int,
+    pub flags2: ::std::os::raw::c_int,
+    pub extradata: *mut u8,
+    pub extradata_size: ::std::os::raw::c_int,
+    pub time_base: AVRational,
+    pub ticks_per_frame: ::std::os::raw::c_int,
+    pub delay: ::std::os::raw::c_int,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+    pub coded_width: ::std::os::raw::c_int,
+    pub coded_height: ::std::os::raw::c_int,
+    pub gop_size: ::std::os::raw::c_int,
+    pub pix_fmt: AVPixelFormat,
+    pub draw_horiz_band: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVCodecContext,
+            src: *const AVFrame,
+            offset: *mut ::std::os::raw::c_int,
+            y: ::std::os::raw::c_int,
+            type_: ::std::os::raw::c_int,
+            height: ::std::os::raw::c_int,
+        ),
+    >,
+    pub get_format: ::std::option::Option<
+        unsafe extern "C" fn(s: *mut AVCodecContext, fmt: *const AVPixelFormat) -> AVPixelFormat,
+    >,
+    pub max_b_frames: ::std::os::raw::c_int,
+    pub b_quant_factor: f32,
+    pub b_frame_strategy: ::std::os::raw::c_int,
+    pub b_quant_offset: f32,
+    pub has_b_frames: ::std::os::raw::c_int,
+    pub mpeg_quant: ::std::os::raw::c_int,
+    pub i_quant_factor: f32,
+    pub i_quant_offset: f32,
+    pub lumi_masking: f32,
+    pub temporal_cplx_masking: f32,
+    pub spatial_cplx_masking: f32,
+    pub p_masking: f32,
+    pub dark_masking: f32,
+    pub slice_count: ::std::os::raw::c_int,
+    pub prediction_method: ::std::os::raw::c_int,
+    pub slice_offset: *mut ::std::os::raw::c_int,
+    pub sample_aspect_ratio: AVRational,
+    pub me_cmp: ::std::os::raw::c_int,
+    pub me_sub_cmp: ::std::os::raw::c_int,
+    pub mb_cmp: ::std::os::raw::c_int,
+    pub ildct_cmp: ::std::os::raw::c_int,
+    pub dia_size: ::std::os::raw::c_int,
+    pub last_predictor_count: ::std::os::raw::c_int,
+    pub pre_me: ::std::os::raw::c_int,
+    pub me_pre_cmp: ::std::os::raw::c_int,
+    pub pre_dia_size: ::std::os::raw::c_int,
+    pub me_subpel_quality: ::std::os::raw::c_int,
+    pub me_range: ::std::os::raw::c_int,
+    pub slice_flags: ::std::os::raw::c_int,
+    pub mb_decision: ::std::os::raw::c_int,
+    pub intra_matrix: *mut u16,
+    pub inter_matrix: *mut u16,
+    pub scenechange_threshold: ::std::os::raw::c_int,
+    pub noise_reduction: ::std::os::raw::c_int,
+    pub intra_dc_precision: ::std::os::raw::c_int,
+    pub skip_top: ::std::os::raw::c_int,
+    pub skip_bottom: ::std::os::raw::c_int,
+    pub mb_lmin: ::std::os::raw::c_int,
+    pub mb_lmax: ::std::os::raw::c_int,
+    pub me_penalty_compensation: ::std::os::raw::c_int,
+    pub bidir_refine: ::std::os::raw::c_int,
+    pub brd_scale: ::std::os::raw::c_int,
+    pub keyint_min: ::std::os::raw::c_int,
+    pub refs: ::std::os::raw::c_int,
+    pub chromaoffset: ::std::os::raw::c_int,
+    pub mv0_threshold: ::std::os::raw::c_int,
+    pub b_sensitivity: ::std::os::raw::c_int,
+    pub color_primaries: AVColorPrimaries,
+    pub color_trc: AVColorTransferCharacteristic,
+    pub colorspace: AVColorSpace,
+    pub color_range: AVColorRange,
+    pub chroma_sample_location: AVChromaLocation,
+    pub slices: ::std::os::raw::c_int,
+    pub field_order: AVFieldOrder,
+    pub sample_rate: ::std::os::raw::c_int,
+    pub channels: ::std::os::raw::c_int,
+    pub sample_fmt: AVSampleFormat,
+    pub frame_size: ::std::os::raw::c_int,
+    pub frame_number: ::std::os::raw::c_int,
+    pub block_align: ::std::os::raw::c_int,
+    pub cutoff: ::std::os::raw::c_int,
+    pub channel_layout: u64,
+    pub request_channel_layout: u64,
+    pub audio_service_type: AVAudioServiceType,
+    pub request_sample_fmt: AVSampleFormat,
+    pub get_buffer2: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVCodecContext,
+            frame: *mut AVFrame,
+            flags: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub refcounted_frames: ::std::os::raw::c_int,
+    pub qcompress: f32,
+    pub qblur: f32,
+    pub qmin: ::std::os::raw::c_int,
+    pub qmax: ::std::os::raw::c_int,
+    pub max_qdiff: ::std::os::raw::c_int,
+    pub rc_buffer_size: ::std::os::raw::c_int,
+    pub rc_override_count: ::std::os::raw::c_int,
+    pub rc_override: *mut RcOverride,
+    pub rc_max_rate: i64,
+    pub rc_min_rate: i64,
+    pub rc_max_available_vbv_use: f32,
+    pub rc_min_vbv_overflow_use: f32,
+    pub rc_initial_buffer_occupancy: ::std::os::raw::c_int,
+    pub coder_type: ::std::os::raw::c_int,
+    pub context_model: ::std::os::raw::c_int,
+    pub frame_skip_threshold: ::std::os::raw::c_int,
+    pub frame_skip_factor: ::std::os::raw::c_int,
+    pub frame_skip_exp: ::std::os::raw::c_int,
+    pub frame_skip_cmp: ::std::os::raw::c_int,
+    pub trellis: ::std::os::raw::c_int,
+    pub min_prediction_order: ::std::os::raw::c_int,
+    pub max_prediction_order: ::std::os::raw::c_int,
+    pub timecode_frame_start: i64,
+    pub rtp_callback: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            data: *mut ::std::os::raw::c_void,
+            size: ::std::os::raw::c_int,
+            mb_nb: ::std::os::raw::c_int,
+        ),
+    >,
+    pub rtp_payload_size: ::std::os::raw::c_int,
+    pub mv_bits: ::std::os::raw::c_int,
+    pub header_bits: ::std::os::raw::c_int,
+    pub i_tex_bits: ::std::os::raw::c_int,
+    pub p_tex_bits: ::std::os::raw::c_int,
+    pub i_count: ::std::os::raw::c_int,
+    pub p_count: ::std::os::raw::c_int,
+    pub skip_count: ::std::os::raw::c_int,
+    pub misc_bits: ::std::os::raw::c_int,
+    pub frame_bits: ::std::os::raw::c_int,
+    pub stats_out: *mut ::std::os::raw::c_char,
+    pub stats_in: *mut ::std::os::raw::c_char,
+    pub workaround_bugs: ::std::os::raw::c_int,
+    pub strict_std_compliance: ::std::os::raw::c_int,
+    pub error_concealment: ::std::os::raw::c_int,
+    pub debug: ::std::os::raw::c_int,
+    pub err_recognition: ::std::os::raw::c_int,
+    pub reordered_opaque: i64,
+    pub hwaccel: *const AVHWAccel,
+    pub hwaccel_context: *mut ::std::os::raw::c_void,
+    pub error: [u64; 8usize],
+    pub dct_algo: ::std::os::raw::c_int,
+    pub idct_algo: ::std::os::raw::c_int,
+    pub bits_per_coded_sample: ::std::os::raw::c_int,
+    pub bits_per_raw_sample: ::std::os::raw::c_int,
+    pub lowres: ::std::os::raw::c_int,
+    pub coded_frame: *mut AVFrame,
+    pub thread_count: ::std::os::raw::c_int,
+    pub thread_type: ::std::os::raw::c_int,
+    pub active_thread_type: ::std::os::raw::c_int,
+    pub thread_safe_callbacks: ::std::os::raw::c_int,
+    pub execute: ::std::option::Option<
+        unsafe extern "C" fn(
+            c: *mut AVCodecContext,
+            func: ::std::option::Option<
+                unsafe extern "C" fn(
+                    c2: *mut AVCodecContext,
+                    arg: *mut ::std::os::raw::c_void,
+                ) -> ::std::os::raw::c_int,
+            >,
+            arg2: *mut ::std::os::raw::c_void,
+            ret: *mut ::std::os::raw::c_int,
+            count: ::std::os::raw::c_int,
+            size: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub execute2: ::std::option::Option<
+        unsafe extern "C" fn(
+            c: *mut AVCodecContext,
+            func: ::std::option::Option<
+                unsafe extern "C" fn(
+                    c2: *mut AVCodecContext,
+                    arg: *mut ::std::os::raw::c_void,
+                    jobnr: ::std::os::raw::c_int,
+                    threadnr: ::std::os::raw::c_int,
+                ) -> ::std::os::raw::c_int,
+            >,
+            arg2: *mut ::std::os::raw::c_void,
+            ret: *mut ::std::os::raw::c_int,
+            count: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub nsse_weight: ::std::os::raw::c_int,
+    pub profile: ::std::os::raw::c_int,
+    pub level: ::std::os::raw::c_int,
+    pub skip_loop_filter: AVDiscard,
+    pub skip_idct: AVDiscard,
+    pub skip_frame: AVDiscard,
+    pub subtitle_header: *mut u8,
+    pub subtitle_header_size: ::std::os::raw::c_int,
+    pub vbv_delay: u64,
+    pub side_data_only_packets: ::std::os::raw::c_int,
+    pub initial_padding: ::std::os::raw::c_int,
+    pub framerate: AVRational,
+    pub sw_pix_fmt: AVPixelFormat,
+    pub pkt_timebase: AVRational,
+    pub codec_descriptor: *const AVCodecDescriptor,
+    pub pts_correction_num_faulty_pts: i64,
+    pub pts_correction_num_faulty_dts: i64,
+    pub pts_correction_last_pts: i64,
+    pub pts_correction_last_dts: i64,
+    pub sub_charenc: *mut ::std::os::raw::c_char,
+    pub sub_charenc_mode: ::std::os::raw::c_int,
+    pub skip_alpha: ::std::os::raw::c_int,
+    pub seek_preroll: ::std::os::raw::c_int,
+    pub debug_mv: ::std::os::raw::c_int,
+    pub chroma_intra_matrix: *mut u16,
+    pub dump_separator: *mut u8,
+    pub codec_whitelist: *mut ::std::os::raw::c_char,
+    pub properties: ::std::os::raw::c_uint,
+    pub coded_side_data: *mut AVPacketSideData,
+    pub nb_coded_side_data: ::std::os::raw::c_int,
+    pub hw_frames_ctx: *mut AVBufferRef,
+    pub sub_text_format: ::std::os::raw::c_int,
+    pub trailing_padding: ::std::os::raw::c_int,
+    pub max_pixels: i64,
+    pub hw_device_ctx: *mut AVBufferRef,
+    pub hwaccel_flags: ::std::os::raw::c_int,
+    pub apply_cropping: ::std::os::raw::c_int,
+    pub extra_hw_frames: ::std::os::raw::c_int,
+    pub discard_damaged_percentage: ::std::os::raw::c_int,
+    pub max_samples: i64,
+    pub export_side_data: ::std::os::raw::c_int,
+    pub get_encode_buffer: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVCodecContext,
+            pkt: *mut AVPacket,
+            flags: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+}
+#[test]
+fn bindgen_test_layout_AVCodecContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodecContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodecContext>(),
+        1080usize,
+        concat!("Size of: ", stringify!(AVCodecContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodecContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCodecContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log_level_offset) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(log_level_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_type) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(codec_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_id) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_tag) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(codec_tag)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(priv_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bit_rate) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(bit_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bit_rate_tolerance) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(bit_rate_tolerance)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).global_quality) as usize - ptr as usize },
+        68usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(global_quality)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).compression_level) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(compression_level)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        76usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags2) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(flags2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extradata) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(extradata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extradata_size) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(extradata_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base) as usize - ptr as usize },
+        100usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(time_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ticks_per_frame) as usize - ptr as usize },
+        108usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(ticks_per_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delay) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(delay)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_width) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(coded_width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_height) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(coded_height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gop_size) as usize - ptr as usize },
+        132usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(gop_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pix_fmt) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pix_fmt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).draw_horiz_band) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(draw_horiz_band)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_format) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(get_format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_b_frames) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(max_b_frames)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).b_quant_factor) as usize - ptr as usize },
+        164usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(b_quant_factor)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).b_frame_strategy) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(b_frame_strategy)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).b_quant_offset) as usize - ptr as usize },
+        172usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(b_quant_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).has_b_frames) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(has_b_frames)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mpeg_quant) as usize - ptr as usize },
+        180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mpeg_quant)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i_quant_factor) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(i_quant_factor)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i_quant_offset) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(i_quant_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).lumi_masking) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(lumi_masking)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temporal_cplx_masking) as usize - ptr as usize },
+        196usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(temporal_cplx_masking)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).spatial_cplx_masking) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(spatial_cplx_masking)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).p_masking) as usize - ptr as usize },
+        204usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(p_masking)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dark_masking) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(dark_masking)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).slice_count) as usize - ptr as usize },
+        212usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(slice_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prediction_method) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(prediction_method)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).slice_offset) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(slice_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_aspect_ratio) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sample_aspect_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).me_cmp) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(me_cmp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).me_sub_cmp) as usize - ptr as usize },
+        244usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(me_sub_cmp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mb_cmp) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mb_cmp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ildct_cmp) as usize - ptr as usize },
+        252usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(ildct_cmp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dia_size) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(dia_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_predictor_count) as usize - ptr as usize },
+        260usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(last_predictor_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pre_me) as usize - ptr as usize },
+        264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pre_me)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).me_pre_cmp) as usize - ptr as usize },
+        268usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(me_pre_cmp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pre_dia_size) as usize - ptr as usize },
+        272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pre_dia_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).me_subpel_quality) as usize - ptr as usize },
+        276usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(me_subpel_quality)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).me_range) as usize - ptr as usize },
+        280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(me_range)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).slice_flags) as usize - ptr as usize },
+        284usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(slice_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mb_decision) as usize - ptr as usize },
+        288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mb_decision)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).intra_matrix) as usize - ptr as usize },
+        296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(intra_matrix)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inter_matrix) as usize - ptr as usize },
+        304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(inter_matrix)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scenechange_threshold) as usize - ptr as usize },
+        312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(scenechange_threshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noise_reduction) as usize - ptr as usize },
+        316usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(noise_reduction)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).intra_dc_precision) as usize - ptr as usize },
+        320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(intra_dc_precision)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_top) as usize - ptr as usize },
+        324usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_top)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_bottom) as usize - ptr as usize },
+        328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_bottom)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mb_lmin) as usize - ptr as usize },
+        332usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mb_lmin)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mb_lmax) as usize - ptr as usize },
+        336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mb_lmax)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).me_penalty_compensation) as usize - ptr as usize },
+        340usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(me_penalty_compensation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bidir_refine) as usize - ptr as usize },
+        344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(bidir_refine)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).brd_scale) as usize - ptr as usize },
+        348usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(brd_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).keyint_min) as usize - ptr as usize },
+        352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(keyint_min)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).refs) as usize - ptr as usize },
+        356usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(refs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chromaoffset) as usize - ptr as usize },
+        360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(chromaoffset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mv0_threshold) as usize - ptr as usize },
+        364usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mv0_threshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).b_sensitivity) as usize - ptr as usize },
+        368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(b_sensitivity)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_primaries) as usize - ptr as usize },
+        372usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(color_primaries)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_trc) as usize - ptr as usize },
+        376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(color_trc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).colorspace) as usize - ptr as usize },
+        380usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(colorspace)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_range) as usize - ptr as usize },
+        384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(color_range)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chroma_sample_location) as usize - ptr as usize },
+        388usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(chroma_sample_location)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).slices) as usize - ptr as usize },
+        392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(slices)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).field_order) as usize - ptr as usize },
+        396usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(field_order)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rate) as usize - ptr as usize },
+        400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sample_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channels) as usize - ptr as usize },
+        404usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(channels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_fmt) as usize - ptr as usize },
+        408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sample_fmt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_size) as usize - ptr as usize },
+        412usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_number) as usize - ptr as usize },
+        416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_number)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).block_align) as usize - ptr as usize },
+        420usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(block_align)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cutoff) as usize - ptr as usize },
+        424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(cutoff)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layout) as usize - ptr as usize },
+        432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(channel_layout)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).request_channel_layout) as usize - ptr as usize },
+        440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(request_channel_layout)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).audio_service_type) as usize - ptr as usize },
+        448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(audio_service_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).request_sample_fmt) as usize - ptr as usize },
+        452usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(request_sample_fmt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_buffer2) as usize - ptr as usize },
+        456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(get_buffer2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).refcounted_frames) as usize - ptr as usize },
+        464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(refcounted_frames)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qcompress) as usize - ptr as usize },
+        468usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(qcompress)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qblur) as usize - ptr as usize },
+        472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(qblur)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qmin) as usize - ptr as usize },
+        476usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(qmin)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qmax) as usize - ptr as usize },
+        480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(qmax)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_qdiff) as usize - ptr as usize },
+        484usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(max_qdiff)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_buffer_size) as usize - ptr as usize },
+        488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_buffer_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_override_count) as usize - ptr as usize },
+        492usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_override_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_override) as usize - ptr as usize },
+        496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_override)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_max_rate) as usize - ptr as usize },
+        504usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_max_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_min_rate) as usize - ptr as usize },
+        512usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_min_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_max_available_vbv_use) as usize - ptr as usize },
+        520usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_max_available_vbv_use)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_min_vbv_overflow_use) as usize - ptr as usize },
+        524usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_min_vbv_overflow_use)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rc_initial_buffer_occupancy) as usize - ptr as usize },
+        528usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rc_initial_buffer_occupancy)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coder_type) as usize - ptr as usize },
+        532usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(coder_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).context_model) as usize - ptr as usize },
+        536usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(context_model)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_skip_threshold) as usize - ptr as usize },
+        540usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_skip_threshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_skip_factor) as usize - ptr as usize },
+        544usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_skip_factor)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_skip_exp) as usize - ptr as usize },
+        548usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_skip_exp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_skip_cmp) as usize - ptr as usize },
+        552usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_skip_cmp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).trellis) as usize - ptr as usize },
+        556usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(trellis)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_prediction_order) as usize - ptr as usize },
+        560usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(min_prediction_order)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_prediction_order) as usize - ptr as usize },
+        564usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(max_prediction_order)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timecode_frame_start) as usize - ptr as usize },
+        568usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(timecode_frame_start)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rtp_callback) as usize - ptr as usize },
+        576usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rtp_callback)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rtp_payload_size) as usize - ptr as usize },
+        584usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(rtp_payload_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mv_bits) as usize - ptr as usize },
+        588usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(mv_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).header_bits) as usize - ptr as usize },
+        592usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(header_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i_tex_bits) as usize - ptr as usize },
+        596usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(i_tex_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).p_tex_bits) as usize - ptr as usize },
+        600usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(p_tex_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i_count) as usize - ptr as usize },
+        604usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(i_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).p_count) as usize - ptr as usize },
+        608usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(p_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_count) as usize - ptr as usize },
+        612usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).misc_bits) as usize - ptr as usize },
+        616usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(misc_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_bits) as usize - ptr as usize },
+        620usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(frame_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stats_out) as usize - ptr as usize },
+        624usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(stats_out)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stats_in) as usize - ptr as usize },
+        632usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(stats_in)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).workaround_bugs) as usize - ptr as usize },
+        640usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(workaround_bugs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).strict_std_compliance) as usize - ptr as usize },
+        644usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(strict_std_compliance)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).error_concealment) as usize - ptr as usize },
+        648usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(error_concealment)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).debug) as usize - ptr as usize },
+        652usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(debug)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).err_recognition) as usize - ptr as usize },
+        656usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(err_recognition)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reordered_opaque) as usize - ptr as usize },
+        664usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(reordered_opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hwaccel) as usize - ptr as usize },
+        672usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(hwaccel)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hwaccel_context) as usize - ptr as usize },
+        680usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(hwaccel_context)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).error) as usize - ptr as usize },
+        688usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(error)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dct_algo) as usize - ptr as usize },
+        752usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(dct_algo)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).idct_algo) as usize - ptr as usize },
+        756usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(idct_algo)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bits_per_coded_sample) as usize - ptr as usize },
+        760usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(bits_per_coded_sample)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bits_per_raw_sample) as usize - ptr as usize },
+        764usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(bits_per_raw_sample)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).lowres) as usize - ptr as usize },
+        768usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(lowres)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_frame) as usize - ptr as usize },
+        776usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(coded_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thread_count) as usize - ptr as usize },
+        784usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(thread_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thread_type) as usize - ptr as usize },
+        788usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(thread_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).active_thread_type) as usize - ptr as usize },
+        792usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(active_thread_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thread_safe_callbacks) as usize - ptr as usize },
+        796usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(thread_safe_callbacks)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).execute) as usize - ptr as usize },
+        800usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(execute)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).execute2) as usize - ptr as usize },
+        808usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(execute2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nsse_weight) as usize - ptr as usize },
+        816usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(nsse_weight)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).profile) as usize - ptr as usize },
+        820usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(profile)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).level) as usize - ptr as usize },
+        824usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(level)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_loop_filter) as usize - ptr as usize },
+        828usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_loop_filter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_idct) as usize - ptr as usize },
+        832usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_idct)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_frame) as usize - ptr as usize },
+        836usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).subtitle_header) as usize - ptr as usize },
+        840usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(subtitle_header)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).subtitle_header_size) as usize - ptr as usize },
+        848usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(subtitle_header_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vbv_delay) as usize - ptr as usize },
+        856usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(vbv_delay)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).side_data_only_packets) as usize - ptr as usize },
+        864usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(side_data_only_packets)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).initial_padding) as usize - ptr as usize },
+        868usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(initial_padding)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).framerate) as usize - ptr as usize },
+        872usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(framerate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sw_pix_fmt) as usize - ptr as usize },
+        880usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sw_pix_fmt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt_timebase) as usize - ptr as usize },
+        884usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pkt_timebase)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_descriptor) as usize - ptr as usize },
+        896usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(codec_descriptor)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).pts_correction_num_faulty_pts) as usize - ptr as usize
+        },
+        904usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pts_correction_num_faulty_pts)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).pts_correction_num_faulty_dts) as usize - ptr as usize
+        },
+        912usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pts_correction_num_faulty_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts_correction_last_pts) as usize - ptr as usize },
+        920usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pts_correction_last_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts_correction_last_dts) as usize - ptr as usize },
+        928usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(pts_correction_last_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sub_charenc) as usize - ptr as usize },
+        936usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sub_charenc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sub_charenc_mode) as usize - ptr as usize },
+        944usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sub_charenc_mode)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_alpha) as usize - ptr as usize },
+        948usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(skip_alpha)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seek_preroll) as usize - ptr as usize },
+        952usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(seek_preroll)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).debug_mv) as usize - ptr as usize },
+        956usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(debug_mv)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chroma_intra_matrix) as usize - ptr as usize },
+        960usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(chroma_intra_matrix)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dump_separator) as usize - ptr as usize },
+        968usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(dump_separator)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_whitelist) as usize - ptr as usize },
+        976usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(codec_whitelist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).properties) as usize - ptr as usize },
+        984usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(properties)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_side_data) as usize - ptr as usize },
+        992usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(coded_side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_coded_side_data) as usize - ptr as usize },
+        1000usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(nb_coded_side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_frames_ctx) as usize - ptr as usize },
+        1008usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(hw_frames_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sub_text_format) as usize - ptr as usize },
+        1016usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(sub_text_format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).trailing_padding) as usize - ptr as usize },
+        1020usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(trailing_padding)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_pixels) as usize - ptr as usize },
+        1024usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(max_pixels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_device_ctx) as usize - ptr as usize },
+        1032usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(hw_device_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hwaccel_flags) as usize - ptr as usize },
+        1040usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(hwaccel_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).apply_cropping) as usize - ptr as usize },
+        1044usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(apply_cropping)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extra_hw_frames) as usize - ptr as usize },
+        1048usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(extra_hw_frames)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).discard_damaged_percentage) as usize - ptr as usize },
+        1052usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(discard_damaged_percentage)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_samples) as usize - ptr as usize },
+        1056usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(max_samples)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).export_side_data) as usize - ptr as usize },
+        1064usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(export_side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_encode_buffer) as usize - ptr as usize },
+        1072usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecContext),
+            "::",
+            stringify!(get_encode_buffer)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVHWAccel {
+    pub name: *const ::std::os::raw::c_char,
+    pub type_: AVMediaType,
+    pub id: AVCodecID,
+    pub pix_fmt: AVPixelFormat,
+    pub capabilities: ::std::os::raw::c_int,
+    pub alloc_frame: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            frame: *mut AVFrame,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub start_frame: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            buf: *const u8,
+            buf_size: u32,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub decode_params: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            type_: ::std::os::raw::c_int,
+            buf: *const u8,
+            buf_size: u32,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub decode_slice: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            buf: *const u8,
+            buf_size: u32,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub end_frame: ::std::option::Option<
+        unsafe extern "C" fn(avctx: *mut AVCodecContext) -> ::std::os::raw::c_int,
+    >,
+    pub frame_priv_data_size: ::std::os::raw::c_int,
+    pub decode_mb: ::std::option::Option<unsafe extern "C" fn(s: *mut MpegEncContext)>,
+    pub init: ::std::option::Option<
+        unsafe extern "C" fn(avctx: *mut AVCodecContext) -> ::std::os::raw::c_int,
+    >,
+    pub uninit: ::std::option::Option<
+        unsafe extern "C" fn(avctx: *mut AVCodecContext) -> ::std::os::raw::c_int,
+    >,
+    pub priv_data_size: ::std::os::raw::c_int,
+    pub caps_internal: ::std::os::raw::c_int,
+    pub frame_params: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            hw_frames_ctx: *mut AVBufferRef,
+        ) -> ::std::os::raw::c_int,
+    >,
+}
+#[test]
+fn bindgen_test_layout_AVHWAccel() {
+    const UNINIT: ::std::mem::MaybeUninit<AVHWAccel> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVHWAccel>(),
+        112usize,
+        concat!("Size of: ", stringify!(AVHWAccel))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVHWAccel>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVHWAccel))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pix_fmt) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(pix_fmt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).capabilities) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(capabilities)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).alloc_frame) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(alloc_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_frame) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(start_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decode_params) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(decode_params)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decode_slice) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(decode_slice)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end_frame) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(end_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_priv_data_size) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(frame_priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decode_mb) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(decode_mb)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(init)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uninit) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(uninit)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data_size) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).caps_internal) as usize - ptr as usize },
+        100usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(caps_internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_params) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWAccel),
+            "::",
+            stringify!(frame_params)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVPicture {
+    pub data: [*mut u8; 8usize],
+    pub linesize: [::std::os::raw::c_int; 8usize],
+}
+#[test]
+fn bindgen_test_layout_AVPicture() {
+    const UNINIT: ::std::mem::MaybeUninit<AVPicture> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVPicture>(),
+        96usize,
+        concat!("Size of: ", stringify!(AVPicture))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVPicture>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVPicture))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPicture),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).linesize) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPicture),
+            "::",
+            stringify!(linesize)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVSubtitleRect {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+    pub w: ::std::os::raw::c_int,
+    pub h: ::std::os::raw::c_int,
+    pub nb_colors: ::std::os::raw::c_int,
+    pub pict: AVPicture,
+    pub data: [*mut u8; 4usize],
+    pub linesize: [::std::os::raw::c_int; 4usize],
+    pub type_: AVSubtitleType,
+    pub text: *mut ::std::os::raw::c_char,
+    pub ass: *mut ::std::os::raw::c_char,
+    pub flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVSubtitleRect() {
+    const UNINIT: ::std::mem::MaybeUninit<AVSubtitleRect> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVSubtitleRect>(),
+        200usize,
+        concat!("Size of: ", stringify!(AVSubtitleRect))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVSubtitleRect>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVSubtitleRect))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_colors) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(nb_colors)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pict) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(pict)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).linesize) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(linesize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).text) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(text)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ass) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(ass)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitleRect),
+            "::",
+            stringify!(flags)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVSubtitle {
+    pub format: u16,
+    pub start_display_time: u32,
+    pub end_display_time: u32,
+    pub num_rects: ::std::os::raw::c_uint,
+    pub rects: *mut *mut AVSubtitleRect,
+    pub pts: i64,
+}
+#[test]
+fn bindgen_test_layout_AVSubtitle() {
+    const UNINIT: ::std::mem::MaybeUninit<AVSubtitle> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVSubtitle>(),
+        32usize,
+        concat!("Size of: ", stringify!(AVSubtitle))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVSubtitle>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVSubtitle))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitle),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_display_time) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitle),
+            "::",
+            stringify!(start_display_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end_display_time) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitle),
+            "::",
+            stringify!(end_display_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num_rects) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitle),
+            "::",
+            stringify!(num_rects)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rects) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitle),
+            "::",
+            stringify!(rects)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVSubtitle),
+            "::",
+            stringify!(pts)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecParserContext {
+    pub priv_data: *mut ::std::os::raw::c_void,
+    pub parser: *mut AVCodecParser,
+    pub frame_offset: i64,
+    pub cur_offset: i64,
+    pub next_frame_offset: i64,
+    pub pict_type: ::std::os::raw::c_int,
+    pub repeat_pict: ::std::os::raw::c_int,
+    pub pts: i64,
+    pub dts: i64,
+    pub last_pts: i64,
+    pub last_dts: i64,
+    pub fetch_timestamp: ::std::os::raw::c_int,
+    pub cur_frame_start_index: ::std::os::raw::c_int,
+    pub cur_frame_offset: [i64; 4usize],
+    pub cur_frame_pts: [i64; 4usize],
+    pub cur_frame_dts: [i64; 4usize],
+    pub flags: ::std::os::raw::c_int,
+    pub offset: i64,
+    pub cur_frame_end: [i64; 4usize],
+    pub key_frame: ::std::os::raw::c_int,
+    pub convergence_duration: i64,
+    pub dts_sync_point: ::std::os::raw::c_int,
+    pub dts_ref_dts_delta: ::std::os::raw::c_int,
+    pub pts_dts_delta: ::std::os::raw::c_int,
+    pub cur_frame_pos: [i64; 4usize],
+    pub pos: i64,
+    pub last_pos: i64,
+    pub duration: ::std::os::raw::c_int,
+    pub field_order: AVFieldOrder,
+    pub picture_structure: AVPictureStructure,
+    pub output_picture_number: ::std::os::raw::c_int,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+    pub coded_width: ::std::os::raw::c_int,
+    pub coded_height: ::std::os::raw::c_int,
+    pub format: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVCodecParserContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodecParserContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodecParserContext>(),
+        352usize,
+        concat!("Size of: ", stringify!(AVCodecParserContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodecParserContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCodecParserContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(priv_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parser) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(parser)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_offset) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(frame_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_offset) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next_frame_offset) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(next_frame_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pict_type) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(pict_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).repeat_pict) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(repeat_pict)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dts) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_pts) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(last_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_dts) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(last_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fetch_timestamp) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(fetch_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_frame_start_index) as usize - ptr as usize },
+        84usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_frame_start_index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_frame_offset) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_frame_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_frame_pts) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_frame_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_frame_dts) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_frame_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).offset) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_frame_end) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_frame_end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).key_frame) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(key_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).convergence_duration) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(convergence_duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dts_sync_point) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(dts_sync_point)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dts_ref_dts_delta) as usize - ptr as usize },
+        252usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(dts_ref_dts_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts_dts_delta) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(pts_dts_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_frame_pos) as usize - ptr as usize },
+        264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(cur_frame_pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pos) as usize - ptr as usize },
+        296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_pos) as usize - ptr as usize },
+        304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(last_pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).duration) as usize - ptr as usize },
+        312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).field_order) as usize - ptr as usize },
+        316usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(field_order)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).picture_structure) as usize - ptr as usize },
+        320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(picture_structure)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_picture_number) as usize - ptr as usize },
+        324usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(output_picture_number)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        332usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_width) as usize - ptr as usize },
+        336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(coded_width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_height) as usize - ptr as usize },
+        340usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(coded_height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParserContext),
+            "::",
+            stringify!(format)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecParser {
+    pub codec_ids: [::std::os::raw::c_int; 5usize],
+    pub priv_data_size: ::std::os::raw::c_int,
+    pub parser_init: ::std::option::Option<
+        unsafe extern "C" fn(s: *mut AVCodecParserContext) -> ::std::os::raw::c_int,
+    >,
+    pub parser_parse: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVCodecParserContext,
+            avctx: *mut AVCodecContext,
+            poutbuf: *mut *const u8,
+            poutbuf_size: *mut ::std::os::raw::c_int,
+            buf: *const u8,
+            buf_size: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub parser_close: ::std::option::Option<unsafe extern "C" fn(s: *mut AVCodecParserContext)>,
+    pub split: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            buf: *const u8,
+            buf_size: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub next: *mut AVCodecParser,
+}
+#[test]
+fn bindgen_test_layout_AVCodecParser() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodecParser> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodecParser>(),
+        64usize,
+        concat!("Size of: ", stringify!(AVCodecParser))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodecParser>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCodecParser))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_ids) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(codec_ids)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data_size) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parser_init) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(parser_init)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parser_parse) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(parser_parse)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parser_close) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(parser_close)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).split) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(split)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParser),
+            "::",
+            stringify!(next)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBitStreamFilterContext {
+    pub priv_data: *mut ::std::os::raw::c_void,
+    pub filter: *const AVBitStreamFilter,
+    pub parser: *mut AVCodecParserContext,
+    pub next: *mut AVBitStreamFilterContext,
+    pub args: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVBitStreamFilterContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVBitStreamFilterContext> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVBitStreamFilterContext>(),
+        40usize,
+        concat!("Size of: ", stringify!(AVBitStreamFilterContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVBitStreamFilterContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVBitStreamFilterContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilterContext),
+            "::",
+            stringify!(priv_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filter) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilterContext),
+            "::",
+            stringify!(filter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parser) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilterContext),
+            "::",
+            stringify!(parser)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilterContext),
+            "::",
+            stringify!(next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).args) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilterContext),
+            "::",
+            stringify!(args)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBSFContext {
+    pub av_class: *const AVClass,
+    pub filter: *const AVBitStreamFilter,
+    pub internal: *mut AVBSFInternal,
+    pub priv_data: *mut ::std::os::raw::c_void,
+    pub par_in: *mut AVCodecParameters,
+    pub par_out: *mut AVCodecParameters,
+    pub time_base_in: AVRational,
+    pub time_base_out: AVRational,
+}
+#[test]
+fn bindgen_test_layout_AVBSFContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVBSFContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVBSFContext>(),
+        64usize,
+        concat!("Size of: ", stringify!(AVBSFContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVBSFContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVBSFContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filter) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(filter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(priv_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).par_in) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(par_in)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).par_out) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(par_out)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base_in) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(time_base_in)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base_out) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBSFContext),
+            "::",
+            stringify!(time_base_out)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBitStreamFilter {
+    pub name: *const ::std::os::raw::c_char,
+    pub codec_ids: *const AVCodecID,
+    pub priv_class: *const AVClass,
+    pub priv_data_size: ::std::os::raw::c_int,
+    pub init: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut AVBSFContext) -> ::std::os::raw::c_int,
+    >,
+    pub filter: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut AVBSFContext, pkt: *mut AVPacket) -> ::std::os::raw::c_int,
+    >,
+    pub close: ::std::option::Option<unsafe extern "C" fn(ctx: *mut AVBSFContext)>,
+    pub flush: ::std::option::Option<unsafe extern "C" fn(ctx: *mut AVBSFContext)>,
+}
+#[test]
+fn bindgen_test_layout_AVBitStreamFilter() {
+    const UNINIT: ::std::mem::MaybeUninit<AVBitStreamFilter> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVBitStreamFilter>(),
+        64usize,
+        concat!("Size of: ", stringify!(AVBitStreamFilter))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVBitStreamFilter>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVBitStreamFilter))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_ids) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(codec_ids)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_class) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(priv_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data_size) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(init)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filter) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(filter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).close) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(close)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flush) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBitStreamFilter),
+            "::",
+            stringify!(flush)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVProfile {
+    pub profile: ::std::os::raw::c_int,
+    pub name: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVProfile() {
+    const UNINIT: ::std::mem::MaybeUninit<AVProfile> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVProfile>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVProfile))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVProfile>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVProfile))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).profile) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProfile),
+            "::",
+            stringify!(profile)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProfile),
+            "::",
+            stringify!(name)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodec {
+    pub name: *const ::std::os::raw::c_char,
+    pub long_name: *const ::std::os::raw::c_char,
+    pub type_: AVMediaType,
+    pub id: AVCodecID,
+    pub capabilities: ::std::os::raw::c_int,
+    pub supported_framerates: *const AVRational,
+    pub pix_fmts: *const AVPixelFormat,
+    pub supported_samplerates: *const ::std::os::raw::c_int,
+    pub sample_fmts: *const AVSampleFormat,
+    pub channel_layouts: *const u64,
+    pub max_lowres: u8,
+    pub priv_class: *const AVClass,
+    pub profiles: *const AVProfile,
+    pub wrapper_name: *const ::std::os::raw::c_char,
+    pub priv_data_size: ::std::os::raw::c_int,
+    pub next: *mut AVCodec,
+    pub update_thread_context: ::std::option::Option<
+        unsafe extern "C" fn(
+            dst: *mut AVCodecContext,
+            src: *const AVCodecContext,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub defaults: *const AVCodecDefault,
+    pub init_static_data: ::std::option::Option<unsafe extern "C" fn(codec: *mut AVCodec)>,
+    pub init: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVCodecContext) -> ::std::os::raw::c_int,
+    >,
+    pub encode_sub: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVCodecContext,
+            buf: *mut u8,
+            buf_size: ::std::os::raw::c_int,
+            sub: *const AVSubtitle,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub encode2: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            avpkt: *mut AVPacket,
+            frame: *const AVFrame,
+            got_packet_ptr: *mut ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub decode: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            outdata: *mut ::std::os::raw::c_void,
+            got_frame_ptr: *mut ::std::os::raw::c_int,
+            avpkt: *mut AVPacket,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub close: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVCodecContext) -> ::std::os::raw::c_int,
+    >,
+    pub receive_packet: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            avpkt: *mut AVPacket,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub receive_frame: ::std::option::Option<
+        unsafe extern "C" fn(
+            avctx: *mut AVCodecContext,
+            frame: *mut AVFrame,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub flush: ::std::option::Option<unsafe extern "C" fn(arg1: *mut AVCodecContext)>,
+    pub caps_internal: ::std::os::raw::c_int,
+    pub bsfs: *const ::std::os::raw::c_char,
+    pub hw_configs: *const *const AVCodecHWConfigInternal,
+    pub codec_tags: *const u32,
+}
+#[test]
+fn bindgen_test_layout_AVCodec() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodec> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodec>(),
+        240usize,
+        concat!("Size of: ", stringify!(AVCodec))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodec>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCodec))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).long_name) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(long_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).capabilities) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(capabilities)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).supported_framerates) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(supported_framerates)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pix_fmts) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(pix_fmts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).supported_samplerates) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(supported_samplerates)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_fmts) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(sample_fmts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layouts) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(channel_layouts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_lowres) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(max_lowres)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_class) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(priv_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).profiles) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(profiles)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wrapper_name) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(wrapper_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data_size) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).update_thread_context) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(update_thread_context)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).defaults) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(defaults)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init_static_data) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(init_static_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(init)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).encode_sub) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(encode_sub)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).encode2) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(encode2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decode) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(decode)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).close) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(close)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).receive_packet) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(receive_packet)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).receive_frame) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(receive_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flush) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(flush)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).caps_internal) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(caps_internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bsfs) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(bsfs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_configs) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(hw_configs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_tags) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodec),
+            "::",
+            stringify!(codec_tags)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecHWConfigInternal {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecHWConfig {
+    pub pix_fmt: AVPixelFormat,
+    pub methods: ::std::os::raw::c_int,
+    pub device_type: AVHWDeviceType,
+}
+#[test]
+fn bindgen_test_layout_AVCodecHWConfig() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodecHWConfig> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodecHWConfig>(),
+        12usize,
+        concat!("Size of: ", stringify!(AVCodecHWConfig))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodecHWConfig>(),
+        4usize,
+        concat!("Alignment of ", stringify!(AVCodecHWConfig))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pix_fmt) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecHWConfig),
+            "::",
+            stringify!(pix_fmt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).methods) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecHWConfig),
+            "::",
+            stringify!(methods)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecHWConfig),
+            "::",
+            stringify!(device_type)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecDescriptor {
+    pub id: AVCodecID,
+    pub type_: AVMediaType,
+    pub name: *const ::std::os::raw::c_char,
+    pub long_name: *const ::std::os::raw::c_char,
+    pub props: ::std::os::raw::c_int,
+    pub mime_types: *const *const ::std::os::raw::c_char,
+    pub profiles: *const AVProfile,
+}
+#[test]
+fn bindgen_test_layout_AVCodecDescriptor() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodecDescriptor> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodecDescriptor>(),
+        48usize,
+        concat!("Size of: ", stringify!(AVCodecDescriptor))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodecDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCodecDescriptor))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).long_name) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(long_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).props) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(props)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mime_types) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(mime_types)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).profiles) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecDescriptor),
+            "::",
+            stringify!(profiles)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVCodecParameters {
+    pub codec_type: AVMediaType,
+    pub codec_id: AVCodecID,
+    pub codec_tag: u32,
+    pub extradata: *mut u8,
+    pub extradata_size: ::std::os::raw::c_int,
+    pub format: ::std::os::raw::c_int,
+    pub bit_rate: i64,
+    pub bits_per_coded_sample: ::std::os::raw::c_int,
+    pub bits_per_raw_sample: ::std::os::raw::c_int,
+    pub profile: ::std::os::raw::c_int,
+    pub level: ::std::os::raw::c_int,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+    pub sample_aspect_ratio: AVRational,
+    pub field_order: AVFieldOrder,
+    pub color_range: AVColorRange,
+    pub color_primaries: AVColorPrimaries,
+    pub color_trc: AVColorTransferCharacteristic,
+    pub color_space: AVColorSpace,
+    pub chroma_location: AVChromaLocation,
+    pub video_delay: ::std::os::raw::c_int,
+    pub channel_layout: u64,
+    pub channels: ::std::os::raw::c_int,
+    pub sample_rate: ::std::os::raw::c_int,
+    pub block_align: ::std::os::raw::c_int,
+    pub frame_size: ::std::os::raw::c_int,
+    pub initial_padding: ::std::os::raw::c_int,
+    pub trailing_padding: ::std::os::raw::c_int,
+    pub seek_preroll: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVCodecParameters() {
+    const UNINIT: ::std::mem::MaybeUninit<AVCodecParameters> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVCodecParameters>(),
+        144usize,
+        concat!("Size of: ", stringify!(AVCodecParameters))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVCodecParameters>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVCodecParameters))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_type) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(codec_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_id) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_tag) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(codec_tag)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extradata) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(extradata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extradata_size) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(extradata_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bit_rate) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(bit_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bits_per_coded_sample) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(bits_per_coded_sample)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bits_per_raw_sample) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(bits_per_raw_sample)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).profile) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(profile)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).level) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(level)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_aspect_ratio) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(sample_aspect_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).field_order) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(field_order)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_range) as usize - ptr as usize },
+        76usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(color_range)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_primaries) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(color_primaries)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_trc) as usize - ptr as usize },
+        84usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(color_trc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_space) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(color_space)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chroma_location) as usize - ptr as usize },
+        92usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(chroma_location)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).video_delay) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(video_delay)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layout) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(channel_layout)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channels) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(channels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rate) as usize - ptr as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(sample_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).block_align) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(block_align)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_size) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(frame_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).initial_padding) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(initial_padding)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).trailing_padding) as usize - ptr as usize },
+        132usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(trailing_padding)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seek_preroll) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVCodecParameters),
+            "::",
+            stringify!(seek_preroll)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVPacketSideData {
+    pub data: *mut u8,
+    pub size: ::std::os::raw::c_int,
+    pub type_: AVPacketSideDataType,
+}
+#[test]
+fn bindgen_test_layout_AVPacketSideData() {
+    const UNINIT: ::std::mem::MaybeUninit<AVPacketSideData> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVPacketSideData>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVPacketSideData))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVPacketSideData>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVPacketSideData))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacketSideData),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacketSideData),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacketSideData),
+            "::",
+            stringify!(type_)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVPacket {
+    pub buf: *mut AVBufferRef,
+    pub pts: i64,
+    pub dts: i64,
+    pub data: *mut u8,
+    pub size: ::std::os::raw::c_int,
+    pub stream_index: ::std::os::raw::c_int,
+    pub flags: ::std::os::raw::c_int,
+    pub side_data: *mut AVPacketSideData,
+    pub side_data_elems: ::std::os::raw::c_int,
+    pub duration: i64,
+    pub pos: i64,
+    pub convergence_duration: i64,
+}
+#[test]
+fn bindgen_test_layout_AVPacket() {
+    const UNINIT: ::std::mem::MaybeUninit<AVPacket> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVPacket>(),
+        88usize,
+        concat!("Size of: ", stringify!(AVPacket))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVPacket>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVPacket))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dts) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stream_index) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(stream_index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).side_data) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).side_data_elems) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(side_data_elems)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).duration) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pos) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).convergence_duration) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacket),
+            "::",
+            stringify!(convergence_duration)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVPacketList {
+    pub pkt: AVPacket,
+    pub next: *mut AVPacketList,
+}
+#[test]
+fn bindgen_test_layout_AVPacketList() {
+    const UNINIT: ::std::mem::MaybeUninit<AVPacketList> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVPacketList>(),
+        96usize,
+        concat!("Size of: ", stringify!(AVPacketList))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVPacketList>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVPacketList))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacketList),
+            "::",
+            stringify!(pkt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPacketList),
+            "::",
+            stringify!(next)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVDeviceRect {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVDeviceRect() {
+    const UNINIT: ::std::mem::MaybeUninit<AVDeviceRect> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVDeviceRect>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVDeviceRect))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVDeviceRect>(),
+        4usize,
+        concat!("Alignment of ", stringify!(AVDeviceRect))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceRect),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceRect),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceRect),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceRect),
+            "::",
+            stringify!(height)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVDeviceCapabilitiesQuery {
+    pub av_class: *const AVClass,
+    pub device_context: *mut AVFormatContext,
+    pub codec: AVCodecID,
+    pub sample_format: AVSampleFormat,
+    pub pixel_format: AVPixelFormat,
+    pub sample_rate: ::std::os::raw::c_int,
+    pub channels: ::std::os::raw::c_int,
+    pub channel_layout: i64,
+    pub window_width: ::std::os::raw::c_int,
+    pub window_height: ::std::os::raw::c_int,
+    pub frame_width: ::std::os::raw::c_int,
+    pub frame_height: ::std::os::raw::c_int,
+    pub fps: AVRational,
+}
+#[test]
+fn bindgen_test_layout_AVDeviceCapabilitiesQuery() {
+    const UNINIT: ::std::mem::MaybeUninit<AVDeviceCapabilitiesQuery> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVDeviceCapabilitiesQuery>(),
+        72usize,
+        concat!("Size of: ", stringify!(AVDeviceCapabilitiesQuery))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVDeviceCapabilitiesQuery>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVDeviceCapabilitiesQuery))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_context) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(device_context)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_format) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(sample_format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pixel_format) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(pixel_format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rate) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(sample_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channels) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(channels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layout) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(channel_layout)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).window_width) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(window_width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).window_height) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(window_height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_width) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(frame_width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_height) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(frame_height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fps) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceCapabilitiesQuery),
+            "::",
+            stringify!(fps)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVDeviceInfo {
+    pub device_name: *mut ::std::os::raw::c_char,
+    pub device_description: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVDeviceInfo() {
+    const UNINIT: ::std::mem::MaybeUninit<AVDeviceInfo> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVDeviceInfo>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVDeviceInfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVDeviceInfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVDeviceInfo))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceInfo),
+            "::",
+            stringify!(device_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_description) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceInfo),
+            "::",
+            stringify!(device_description)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVDeviceInfoList {
+    pub devices: *mut *mut AVDeviceInfo,
+    pub nb_devices: ::std::os::raw::c_int,
+    pub default_device: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVDeviceInfoList() {
+    const UNINIT: ::std::mem::MaybeUninit<AVDeviceInfoList> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVDeviceInfoList>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVDeviceInfoList))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVDeviceInfoList>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVDeviceInfoList))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).devices) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceInfoList),
+            "::",
+            stringify!(devices)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_devices) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceInfoList),
+            "::",
+            stringify!(nb_devices)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).default_device) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDeviceInfoList),
+            "::",
+            stringify!(default_device)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilter {
+    pub name: *const ::std::os::raw::c_char,
+    pub description: *const ::std::os::raw::c_char,
+    pub inputs: *const AVFilterPad,
+    pub outputs: *const AVFilterPad,
+    pub priv_class: *const AVClass,
+    pub flags: ::std::os::raw::c_int,
+    pub preinit: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut AVFilterContext) -> ::std::os::raw::c_int,
+    >,
+    pub init: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut AVFilterContext) -> ::std::os::raw::c_int,
+    >,
+    pub init_dict: ::std::option::Option<
+        unsafe extern "C" fn(
+            ctx: *mut AVFilterContext,
+            options: *mut *mut AVDictionary,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub uninit: ::std::option::Option<unsafe extern "C" fn(ctx: *mut AVFilterContext)>,
+    pub query_formats: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFilterContext) -> ::std::os::raw::c_int,
+    >,
+    pub priv_size: ::std::os::raw::c_int,
+    pub flags_internal: ::std::os::raw::c_int,
+    pub next: *mut AVFilter,
+    pub process_command: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFilterContext,
+            cmd: *const ::std::os::raw::c_char,
+            arg: *const ::std::os::raw::c_char,
+            res: *mut ::std::os::raw::c_char,
+            res_len: ::std::os::raw::c_int,
+            flags: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub init_opaque: ::std::option::Option<
+        unsafe extern "C" fn(
+            ctx: *mut AVFilterContext,
+            opaque: *mut ::std::os::raw::c_void,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub activate: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut AVFilterContext) -> ::std::os::raw::c_int,
+    >,
+}
+#[test]
+fn bindgen_test_layout_AVFilter() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFilter> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFilter>(),
+        128usize,
+        concat!("Size of: ", stringify!(AVFilter))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFilter>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFilter))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).description) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(description)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inputs) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(inputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).outputs) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(outputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_class) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(priv_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).preinit) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(preinit)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(init)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init_dict) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(init_dict)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uninit) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(uninit)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).query_formats) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(query_formats)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_size) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(priv_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags_internal) as usize - ptr as usize },
+        92usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(flags_internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).process_command) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(process_command)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init_opaque) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(init_opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).activate) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilter),
+            "::",
+            stringify!(activate)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterCommand {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterFormatsConfig {
+    pub formats: *mut AVFilterFormats,
+    pub samplerates: *mut AVFilterFormats,
+    pub channel_layouts: *mut AVFilterChannelLayouts,
+}
+#[test]
+fn bindgen_test_layout_AVFilterFormatsConfig() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFilterFormatsConfig> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFilterFormatsConfig>(),
+        24usize,
+        concat!("Size of: ", stringify!(AVFilterFormatsConfig))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFilterFormatsConfig>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFilterFormatsConfig))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).formats) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterFormatsConfig),
+            "::",
+            stringify!(formats)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).samplerates) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterFormatsConfig),
+            "::",
+            stringify!(samplerates)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layouts) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterFormatsConfig),
+            "::",
+            stringify!(channel_layouts)
+        )
+    );
+}
+pub const AVFilterLink__bindgen_ty_1_AVLINK_UNINIT: AVFilterLink__bindgen_ty_1 = 0;
+pub const AVFilterLink__bindgen_ty_1_AVLINK_STARTINIT: AVFilterLink__bindgen_ty_1 = 1;
+pub const AVFilterLink__bindgen_ty_1_AVLINK_INIT: AVFilterLink__bindgen_ty_1 = 2;
+pub type AVFilterLink__bindgen_ty_1 = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterGraph {
+    pub av_class: *const AVClass,
+    pub filters: *mut *mut AVFilterContext,
+    pub nb_filters: ::std::os::raw::c_uint,
+    pub scale_sws_opts: *mut ::std::os::raw::c_char,
+    pub resample_lavr_opts: *mut ::std::os::raw::c_char,
+    pub thread_type: ::std::os::raw::c_int,
+    pub nb_threads: ::std::os::raw::c_int,
+    pub internal: *mut AVFilterGraphInternal,
+    pub opaque: *mut ::std::os::raw::c_void,
+    pub execute: avfilter_execute_func,
+    pub aresample_swr_opts: *mut ::std::os::raw::c_char,
+    pub sink_links: *mut *mut AVFilterLink,
+    pub sink_links_count: ::std::os::raw::c_int,
+    pub disable_auto_convert: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_AVFilterGraph() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFilterGraph> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFilterGraph>(),
+        96usize,
+        concat!("Size of: ", stringify!(AVFilterGraph))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFilterGraph>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFilterGraph))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filters) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(filters)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_filters) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(nb_filters)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_sws_opts) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(scale_sws_opts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).resample_lavr_opts) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(resample_lavr_opts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thread_type) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(thread_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_threads) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(nb_threads)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).execute) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(execute)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).aresample_swr_opts) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(aresample_swr_opts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sink_links) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(sink_links)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sink_links_count) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(sink_links_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).disable_auto_convert) as usize - ptr as usize },
+        92usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterGraph),
+            "::",
+            stringify!(disable_auto_convert)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFilterInOut {
+    pub name: *mut ::std::os::raw::c_char,
+    pub filter_ctx: *mut AVFilterContext,
+    pub pad_idx: ::std::os::raw::c_int,
+    pub next: *mut AVFilterInOut,
+}
+#[test]
+fn bindgen_test_layout_AVFilterInOut() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFilterInOut> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFilterInOut>(),
+        32usize,
+        concat!("Size of: ", stringify!(AVFilterInOut))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFilterInOut>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFilterInOut))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterInOut),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filter_ctx) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterInOut),
+            "::",
+            stringify!(filter_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pad_idx) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterInOut),
+            "::",
+            stringify!(pad_idx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFilterInOut),
+            "::",
+            stringify!(next)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBufferSinkParams {
+    pub pixel_fmts: *const AVPixelFormat,
+}
+#[test]
+fn bindgen_test_layout_AVBufferSinkParams() {
+    const UNINIT: ::std::mem::MaybeUninit<AVBufferSinkParams> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVBufferSinkParams>(),
+        8usize,
+        concat!("Size of: ", stringify!(AVBufferSinkParams))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVBufferSinkParams>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVBufferSinkParams))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pixel_fmts) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSinkParams),
+            "::",
+            stringify!(pixel_fmts)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVABufferSinkParams {
+    pub sample_fmts: *const AVSampleFormat,
+    pub channel_layouts: *const i64,
+    pub channel_counts: *const ::std::os::raw::c_int,
+    pub all_channel_counts: ::std::os::raw::c_int,
+    pub sample_rates: *mut ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVABufferSinkParams() {
+    const UNINIT: ::std::mem::MaybeUninit<AVABufferSinkParams> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVABufferSinkParams>(),
+        40usize,
+        concat!("Size of: ", stringify!(AVABufferSinkParams))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVABufferSinkParams>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVABufferSinkParams))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_fmts) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVABufferSinkParams),
+            "::",
+            stringify!(sample_fmts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layouts) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVABufferSinkParams),
+            "::",
+            stringify!(channel_layouts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_counts) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVABufferSinkParams),
+            "::",
+            stringify!(channel_counts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).all_channel_counts) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVABufferSinkParams),
+            "::",
+            stringify!(all_channel_counts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rates) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVABufferSinkParams),
+            "::",
+            stringify!(sample_rates)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBufferSrcParameters {
+    pub format: ::std::os::raw::c_int,
+    pub time_base: AVRational,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+    pub sample_aspect_ratio: AVRational,
+    pub frame_rate: AVRational,
+    pub hw_frames_ctx: *mut AVBufferRef,
+    pub sample_rate: ::std::os::raw::c_int,
+    pub channel_layout: u64,
+}
+#[test]
+fn bindgen_test_layout_AVBufferSrcParameters() {
+    const UNINIT: ::std::mem::MaybeUninit<AVBufferSrcParameters> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVBufferSrcParameters>(),
+        64usize,
+        concat!("Size of: ", stringify!(AVBufferSrcParameters))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVBufferSrcParameters>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVBufferSrcParameters))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(time_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_aspect_ratio) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(sample_aspect_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_rate) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(frame_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_frames_ctx) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(hw_frames_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rate) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(sample_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layout) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferSrcParameters),
+            "::",
+            stringify!(channel_layout)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVProbeData {
+    pub filename: *const ::std::os::raw::c_char,
+    pub buf: *mut ::std::os::raw::c_uchar,
+    pub buf_size: ::std::os::raw::c_int,
+    pub mime_type: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVProbeData() {
+    const UNINIT: ::std::mem::MaybeUninit<AVProbeData> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVProbeData>(),
+        32usize,
+        concat!("Size of: ", stringify!(AVProbeData))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVProbeData>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVProbeData))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filename) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProbeData),
+            "::",
+            stringify!(filename)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProbeData),
+            "::",
+            stringify!(buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf_size) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProbeData),
+            "::",
+            stringify!(buf_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mime_type) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProbeData),
+            "::",
+            stringify!(mime_type)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVOutputFormat {
+    pub name: *const ::std::os::raw::c_char,
+    pub long_name: *const ::std::os::raw::c_char,
+    pub mime_type: *const ::std::os::raw::c_char,
+    pub extensions: *const ::std::os::raw::c_char,
+    pub audio_codec: AVCodecID,
+    pub video_codec: AVCodecID,
+    pub subtitle_codec: AVCodecID,
+    pub flags: ::std::os::raw::c_int,
+    pub codec_tag: *const *const AVCodecTag,
+    pub priv_class: *const AVClass,
+    pub next: *mut AVOutputFormat,
+    pub priv_data_size: ::std::os::raw::c_int,
+    pub write_header: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub write_packet: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFormatContext,
+            pkt: *mut AVPacket,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub write_trailer: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub interleave_packet: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFormatContext,
+            out: *mut AVPacket,
+            in_: *mut AVPacket,
+            flush: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub query_codec: ::std::option::Option<
+        unsafe extern "C" fn(
+            id: AVCodecID,
+            std_compliance: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub get_output_timestamp: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            stream: ::std::os::raw::c_int,
+            dts: *mut i64,
+            wall: *mut i64,
+        ),
+    >,
+    pub control_message: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            type_: ::std::os::raw::c_int,
+            data: *mut ::std::os::raw::c_void,
+            data_size: size_t,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub write_uncoded_frame: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFormatContext,
+            stream_index: ::std::os::raw::c_int,
+            frame: *mut *mut AVFrame,
+            flags: ::std::os::raw::c_uint,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub get_device_list: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            device_list: *mut AVDeviceInfoList,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub create_device_capabilities: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            caps: *mut AVDeviceCapabilitiesQuery,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub free_device_capabilities: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            caps: *mut AVDeviceCapabilitiesQuery,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub data_codec: AVCodecID,
+    pub init: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub deinit: ::std::option::Option<unsafe extern "C" fn(arg1: *mut AVFormatContext)>,
+    pub check_bitstream: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFormatContext,
+            pkt: *const AVPacket,
+        ) -> ::std::os::raw::c_int,
+    >,
+}
+#[test]
+fn bindgen_test_layout_AVOutputFormat() {
+    const UNINIT: ::std::mem::MaybeUninit<AVOutputFormat> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVOutputFormat>(),
+        200usize,
+        concat!("Size of: ", stringify!(AVOutputFormat))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVOutputFormat>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVOutputFormat))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).long_name) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(long_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mime_type) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(mime_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extensions) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(extensions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).audio_codec) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(audio_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).video_codec) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(video_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).subtitle_codec) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(subtitle_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_tag) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(codec_tag)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_class) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(priv_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data_size) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_header) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(write_header)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_packet) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(write_packet)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_trailer) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(write_trailer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).interleave_packet) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(interleave_packet)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).query_codec) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(query_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_output_timestamp) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(get_output_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).control_message) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(control_message)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_uncoded_frame) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(write_uncoded_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_device_list) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(get_device_list)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).create_device_capabilities) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(create_device_capabilities)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).free_device_capabilities) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(free_device_capabilities)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data_codec) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(data_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).init) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(init)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).deinit) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(deinit)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).check_bitstream) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOutputFormat),
+            "::",
+            stringify!(check_bitstream)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVInputFormat {
+    pub name: *const ::std::os::raw::c_char,
+    pub long_name: *const ::std::os::raw::c_char,
+    pub flags: ::std::os::raw::c_int,
+    pub extensions: *const ::std::os::raw::c_char,
+    pub codec_tag: *const *const AVCodecTag,
+    pub priv_class: *const AVClass,
+    pub mime_type: *const ::std::os::raw::c_char,
+    pub next: *mut AVInputFormat,
+    pub raw_codec_id: ::std::os::raw::c_int,
+    pub priv_data_size: ::std::os::raw::c_int,
+    pub read_probe: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *const AVProbeData) -> ::std::os::raw::c_int,
+    >,
+    pub read_header: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub read_packet: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFormatContext,
+            pkt: *mut AVPacket,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub read_close: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub read_seek: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut AVFormatContext,
+            stream_index: ::std::os::raw::c_int,
+            timestamp: i64,
+            flags: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub read_timestamp: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            stream_index: ::std::os::raw::c_int,
+            pos: *mut i64,
+            pos_limit: i64,
+        ) -> i64,
+    >,
+    pub read_play: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub read_pause: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut AVFormatContext) -> ::std::os::raw::c_int,
+    >,
+    pub read_seek2: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            stream_index: ::std::os::raw::c_int,
+            min_ts: i64,
+            ts: i64,
+            max_ts: i64,
+            flags: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub get_device_list: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            device_list: *mut AVDeviceInfoList,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub create_device_capabilities: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            caps: *mut AVDeviceCapabilitiesQuery,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub free_device_capabilities: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            caps: *mut AVDeviceCapabilitiesQuery,
+        ) -> ::std::os::raw::c_int,
+    >,
+}
+#[test]
+fn bindgen_test_layout_AVInputFormat() {
+    const UNINIT: ::std::mem::MaybeUninit<AVInputFormat> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVInputFormat>(),
+        168usize,
+        concat!("Size of: ", stringify!(AVInputFormat))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVInputFormat>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVInputFormat))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).long_name) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(long_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extensions) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(extensions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_tag) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(codec_tag)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_class) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(priv_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mime_type) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(mime_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).raw_codec_id) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(raw_codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data_size) as usize - ptr as usize },
+        68usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(priv_data_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_probe) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_probe)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_header) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_header)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_packet) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_packet)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_close) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_close)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_seek) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_seek)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_timestamp) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_play) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_play)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_pause) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_pause)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_seek2) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(read_seek2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_device_list) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(get_device_list)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).create_device_capabilities) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(create_device_capabilities)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).free_device_capabilities) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVInputFormat),
+            "::",
+            stringify!(free_device_capabilities)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVIndexEntry {
+    pub pos: i64,
+    pub timestamp: i64,
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+    pub min_distance: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVIndexEntry() {
+    const UNINIT: ::std::mem::MaybeUninit<AVIndexEntry> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVIndexEntry>(),
+        24usize,
+        concat!("Size of: ", stringify!(AVIndexEntry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVIndexEntry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVIndexEntry))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pos) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIndexEntry),
+            "::",
+            stringify!(pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIndexEntry),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_distance) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIndexEntry),
+            "::",
+            stringify!(min_distance)
+        )
+    );
+}
+impl AVIndexEntry {
+    #[inline]
+    pub fn flags(&self) -> ::std::os::raw::c_int {
+        self._bitfield_1.get(0usize, 2u8) as u32 as ::std::os::raw::c_int
+    }
+    #[inline]
+    pub fn set_flags(&mut self, val: ::std::os::raw::c_int) {
+        self._bitfield_1.set(0usize, 2u8, val as u32 as u64)
+    }
+    #[inline]
+    pub fn size(&self) -> ::std::os::raw::c_int {
+        self._bitfield_1.get(2usize, 30u8) as u32 as ::std::os::raw::c_int
+    }
+    #[inline]
+    pub fn set_size(&mut self, val: ::std::os::raw::c_int) {
+        self._bitfield_1.set(2usize, 30u8, val as u32 as u64)
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        flags: ::std::os::raw::c_int,
+        size: ::std::os::raw::c_int,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 2u8, flags as u32 as u64);
+        __bindgen_bitfield_unit.set(2usize, 30u8, size as u32 as u64);
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVStream {
+    pub index: ::std::os::raw::c_int,
+    pub id: ::std::os::raw::c_int,
+    pub codec: *mut AVCodecContext,
+    pub priv_data: *mut ::std::os::raw::c_void,
+    pub time_base: AVRational,
+    pub start_time: i64,
+    pub duration: i64,
+    pub nb_frames: i64,
+    pub disposition: ::std::os::raw::c_int,
+    pub discard: AVDiscard,
+    pub sample_aspect_ratio: AVRational,
+    pub metadata: *mut AVDictionary,
+    pub avg_frame_rate: AVRational,
+    pub attached_pic: AVPacket,
+    pub side_data: *mut AVPacketSideData,
+    pub nb_side_data: ::std::os::raw::c_int,
+    pub event_flags: ::std::os::raw::c_int,
+    pub r_frame_rate: AVRational,
+    pub recommended_encoder_configuration: *mut ::std::os::raw::c_char,
+    pub codecpar: *mut AVCodecParameters,
+    pub unused: *mut ::std::os::raw::c_void,
+    pub pts_wrap_bits: ::std::os::raw::c_int,
+    pub first_dts: i64,
+    pub cur_dts: i64,
+    pub last_IP_pts: i64,
+    pub last_IP_duration: ::std::os::raw::c_int,
+    pub probe_packets: ::std::os::raw::c_int,
+    pub codec_info_nb_frames: ::std::os::raw::c_int,
+    pub need_parsing: AVStreamParseType,
+    pub parser: *mut AVCodecParserContext,
+    pub unused7: *mut ::std::os::raw::c_void,
+    pub unused6: AVProbeData,
+    pub unused5: [i64; 17usize],
+    pub index_entries: *mut AVIndexEntry,
+    pub nb_index_entries: ::std::os::raw::c_int,
+    pub index_entries_allocated_size: ::std::os::raw::c_uint,
+    pub stream_identifier: ::std::os::raw::c_int,
+    pub unused8: ::std::os::raw::c_int,
+    pub unused9: ::std::os::raw::c_int,
+    pub unused10: ::std::os::raw::c_int,
+    pub internal: *mut AVStreamInternal,
+}
+#[test]
+fn bindgen_test_layout_AVStream() {
+    const UNINIT: ::std::mem::MaybeUninit<AVStream> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVStream>(),
+        496usize,
+        concat!("Size of: ", stringify!(AVStream))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVStream>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVStream))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).index) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(priv_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(time_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_time) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(start_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).duration) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_frames) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(nb_frames)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).disposition) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(disposition)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).discard) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(discard)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_aspect_ratio) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(sample_aspect_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(metadata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).avg_frame_rate) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(avg_frame_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).attached_pic) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(attached_pic)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).side_data) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_side_data) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(nb_side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).event_flags) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(event_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).r_frame_rate) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(r_frame_rate)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).recommended_encoder_configuration) as usize - ptr as usize
+        },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(recommended_encoder_configuration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codecpar) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(codecpar)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts_wrap_bits) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(pts_wrap_bits)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).first_dts) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(first_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_dts) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(cur_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_IP_pts) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(last_IP_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_IP_duration) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(last_IP_duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).probe_packets) as usize - ptr as usize },
+        260usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(probe_packets)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_info_nb_frames) as usize - ptr as usize },
+        264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(codec_info_nb_frames)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).need_parsing) as usize - ptr as usize },
+        268usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(need_parsing)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parser) as usize - ptr as usize },
+        272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(parser)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused7) as usize - ptr as usize },
+        280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused7)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused6) as usize - ptr as usize },
+        288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused6)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused5) as usize - ptr as usize },
+        320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused5)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).index_entries) as usize - ptr as usize },
+        456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(index_entries)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_index_entries) as usize - ptr as usize },
+        464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(nb_index_entries)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).index_entries_allocated_size) as usize - ptr as usize
+        },
+        468usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(index_entries_allocated_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stream_identifier) as usize - ptr as usize },
+        472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(stream_identifier)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused8) as usize - ptr as usize },
+        476usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused8)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused9) as usize - ptr as usize },
+        480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused9)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unused10) as usize - ptr as usize },
+        484usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(unused10)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVStream),
+            "::",
+            stringify!(internal)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVProgram {
+    pub id: ::std::os::raw::c_int,
+    pub flags: ::std::os::raw::c_int,
+    pub discard: AVDiscard,
+    pub stream_index: *mut ::std::os::raw::c_uint,
+    pub nb_stream_indexes: ::std::os::raw::c_uint,
+    pub metadata: *mut AVDictionary,
+    pub program_num: ::std::os::raw::c_int,
+    pub pmt_pid: ::std::os::raw::c_int,
+    pub pcr_pid: ::std::os::raw::c_int,
+    pub pmt_version: ::std::os::raw::c_int,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub pts_wrap_reference: i64,
+    pub pts_wrap_behavior: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVProgram() {
+    const UNINIT: ::std::mem::MaybeUninit<AVProgram> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVProgram>(),
+        88usize,
+        concat!("Size of: ", stringify!(AVProgram))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVProgram>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVProgram))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).discard) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(discard)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stream_index) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(stream_index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_stream_indexes) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(nb_stream_indexes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(metadata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).program_num) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(program_num)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pmt_pid) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(pmt_pid)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pcr_pid) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(pcr_pid)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pmt_version) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(pmt_version)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_time) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(start_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end_time) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(end_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts_wrap_reference) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(pts_wrap_reference)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts_wrap_behavior) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVProgram),
+            "::",
+            stringify!(pts_wrap_behavior)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVChapter {
+    pub id: ::std::os::raw::c_int,
+    pub time_base: AVRational,
+    pub start: i64,
+    pub end: i64,
+    pub metadata: *mut AVDictionary,
+}
+#[test]
+fn bindgen_test_layout_AVChapter() {
+    const UNINIT: ::std::mem::MaybeUninit<AVChapter> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVChapter>(),
+        40usize,
+        concat!("Size of: ", stringify!(AVChapter))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVChapter>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVChapter))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVChapter),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_base) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVChapter),
+            "::",
+            stringify!(time_base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVChapter),
+            "::",
+            stringify!(start)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVChapter),
+            "::",
+            stringify!(end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVChapter),
+            "::",
+            stringify!(metadata)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFormatContext {
+    pub av_class: *const AVClass,
+    pub iformat: *mut AVInputFormat,
+    pub oformat: *mut AVOutputFormat,
+    pub priv_data: *mut ::std::os::raw::c_void,
+    pub pb: *mut AVIOContext,
+    pub ctx_flags: ::std::os::raw::c_int,
+    pub nb_streams: ::std::os::raw::c_uint,
+    pub streams: *mut *mut AVStream,
+    pub filename: [::std::os::raw::c_char; 1024usize],
+    pub url: *mut ::std::os::raw::c_char,
+    pub start_time: i64,
+    pub duration: i64,
+    pub bit_rate: i64,
+    pub packet_size: ::std::os::raw::c_uint,
+    pub max_delay: ::std::os::raw::c_int,
+    pub flags: ::std::os::raw::c_int,
+    pub probesize: i64,
+    pub max_analyze_duration: i64,
+    pub key: *const u8,
+    pub keylen: ::std::os::raw::c_int,
+    pub nb_programs: ::std::os::raw::c_uint,
+    pub programs: *mut *mut AVProgram,
+    pub video_codec_id: AVCodecID,
+    pub audio_codec_id: AVCodecID,
+    pub subtitle_codec_id: AVCodecID,
+    pub max_index_size: ::std::os::raw::c_uint,
+    pub max_picture_buffer: ::std::os::raw::c_uint,
+    pub nb_chapters: ::std::os::raw::c_uint,
+    pub chapters: *mut *mut AVChapter,
+    pub metadata: *mut AVDictionary,
+    pub start_time_realtime: i64,
+    pub fps_probe_size: ::std::os::raw::c_int,
+    pub error_recognition: ::std::os::raw::c_int,
+    pub interrupt_callback: AVIOInterruptCB,
+    pub debug: ::std::os::raw::c_int,
+    pub max_interleave_delta: i64,
+    pub strict_std_compliance: ::std::os::raw::c_int,
+    pub event_flags: ::std::os::raw::c_int,
+    pub max_ts_probe: ::std::os::raw::c_int,
+    pub avoid_negative_ts: ::std::os::raw::c_int,
+    pub ts_id: ::std::os::raw::c_int,
+    pub audio_preload: ::std::os::raw::c_int,
+    pub max_chunk_duration: ::std::os::raw::c_int,
+    pub max_chunk_size: ::std::os::raw::c_int,
+    pub use_wallclock_as_timestamps: ::std::os::raw::c_int,
+    pub avio_flags: ::std::os::raw::c_int,
+    pub duration_estimation_method: AVDurationEstimationMethod,
+    pub skip_initial_bytes: i64,
+    pub correct_ts_overflow: ::std::os::raw::c_uint,
+    pub seek2any: ::std::os::raw::c_int,
+    pub flush_packets: ::std::os::raw::c_int,
+    pub probe_score: ::std::os::raw::c_int,
+    pub format_probesize: ::std::os::raw::c_int,
+    pub codec_whitelist: *mut ::std::os::raw::c_char,
+    pub format_whitelist: *mut ::std::os::raw::c_char,
+    pub internal: *mut AVFormatInternal,
+    pub io_repositioned: ::std::os::raw::c_int,
+    pub video_codec: *mut AVCodec,
+    pub audio_codec: *mut AVCodec,
+    pub subtitle_codec: *mut AVCodec,
+    pub data_codec: *mut AVCodec,
+    pub metadata_header_padding: ::std::os::raw::c_int,
+    pub opaque: *mut ::std::os::raw::c_void,
+    pub control_message_cb: av_format_control_message,
+    pub output_ts_offset: i64,
+    pub dump_separator: *mut u8,
+    pub data_codec_id: AVCodecID,
+    pub open_cb: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            p: *mut *mut AVIOContext,
+            url: *const ::std::os::raw::c_char,
+            flags: ::std::os::raw::c_int,
+            int_cb: *const AVIOInterruptCB,
+            options: *mut *mut AVDictionary,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub protocol_whitelist: *mut ::std::os::raw::c_char,
+    pub io_open: ::std::option::Option<
+        unsafe extern "C" fn(
+            s: *mut AVFormatContext,
+            pb: *mut *mut AVIOContext,
+            url: *const ::std::os::raw::c_char,
+            flags: ::std::os::raw::c_int,
+            options: *mut *mut AVDictionary,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub io_close:
+        ::std::option::Option<unsafe extern "C" fn(s: *mut AVFormatContext, pb: *mut AVIOContext)>,
+    pub protocol_blacklist: *mut ::std::os::raw::c_char,
+    pub max_streams: ::std::os::raw::c_int,
+    pub skip_estimate_duration_from_pts: ::std::os::raw::c_int,
+    pub max_probe_packets: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVFormatContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFormatContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFormatContext>(),
+        1504usize,
+        concat!("Size of: ", stringify!(AVFormatContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFormatContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFormatContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iformat) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(iformat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).oformat) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(oformat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).priv_data) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(priv_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pb) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(pb)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ctx_flags) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(ctx_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_streams) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(nb_streams)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).streams) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(streams)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filename) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(filename)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).url) as usize - ptr as usize },
+        1080usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(url)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_time) as usize - ptr as usize },
+        1088usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(start_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).duration) as usize - ptr as usize },
+        1096usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bit_rate) as usize - ptr as usize },
+        1104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(bit_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).packet_size) as usize - ptr as usize },
+        1112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(packet_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_delay) as usize - ptr as usize },
+        1116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_delay)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        1120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).probesize) as usize - ptr as usize },
+        1128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(probesize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_analyze_duration) as usize - ptr as usize },
+        1136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_analyze_duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).key) as usize - ptr as usize },
+        1144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(key)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).keylen) as usize - ptr as usize },
+        1152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(keylen)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_programs) as usize - ptr as usize },
+        1156usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(nb_programs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).programs) as usize - ptr as usize },
+        1160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(programs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).video_codec_id) as usize - ptr as usize },
+        1168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(video_codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).audio_codec_id) as usize - ptr as usize },
+        1172usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(audio_codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).subtitle_codec_id) as usize - ptr as usize },
+        1176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(subtitle_codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_index_size) as usize - ptr as usize },
+        1180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_index_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_picture_buffer) as usize - ptr as usize },
+        1184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_picture_buffer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_chapters) as usize - ptr as usize },
+        1188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(nb_chapters)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chapters) as usize - ptr as usize },
+        1192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(chapters)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata) as usize - ptr as usize },
+        1200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(metadata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_time_realtime) as usize - ptr as usize },
+        1208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(start_time_realtime)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fps_probe_size) as usize - ptr as usize },
+        1216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(fps_probe_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).error_recognition) as usize - ptr as usize },
+        1220usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(error_recognition)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).interrupt_callback) as usize - ptr as usize },
+        1224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(interrupt_callback)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).debug) as usize - ptr as usize },
+        1240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(debug)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_interleave_delta) as usize - ptr as usize },
+        1248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_interleave_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).strict_std_compliance) as usize - ptr as usize },
+        1256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(strict_std_compliance)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).event_flags) as usize - ptr as usize },
+        1260usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(event_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_ts_probe) as usize - ptr as usize },
+        1264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_ts_probe)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).avoid_negative_ts) as usize - ptr as usize },
+        1268usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(avoid_negative_ts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ts_id) as usize - ptr as usize },
+        1272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(ts_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).audio_preload) as usize - ptr as usize },
+        1276usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(audio_preload)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_chunk_duration) as usize - ptr as usize },
+        1280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_chunk_duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_chunk_size) as usize - ptr as usize },
+        1284usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_chunk_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).use_wallclock_as_timestamps) as usize - ptr as usize },
+        1288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(use_wallclock_as_timestamps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).avio_flags) as usize - ptr as usize },
+        1292usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(avio_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).duration_estimation_method) as usize - ptr as usize },
+        1296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(duration_estimation_method)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip_initial_bytes) as usize - ptr as usize },
+        1304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(skip_initial_bytes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).correct_ts_overflow) as usize - ptr as usize },
+        1312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(correct_ts_overflow)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seek2any) as usize - ptr as usize },
+        1316usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(seek2any)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flush_packets) as usize - ptr as usize },
+        1320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(flush_packets)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).probe_score) as usize - ptr as usize },
+        1324usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(probe_score)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format_probesize) as usize - ptr as usize },
+        1328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(format_probesize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).codec_whitelist) as usize - ptr as usize },
+        1336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(codec_whitelist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format_whitelist) as usize - ptr as usize },
+        1344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(format_whitelist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        1352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).io_repositioned) as usize - ptr as usize },
+        1360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(io_repositioned)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).video_codec) as usize - ptr as usize },
+        1368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(video_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).audio_codec) as usize - ptr as usize },
+        1376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(audio_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).subtitle_codec) as usize - ptr as usize },
+        1384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(subtitle_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data_codec) as usize - ptr as usize },
+        1392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(data_codec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata_header_padding) as usize - ptr as usize },
+        1400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(metadata_header_padding)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque) as usize - ptr as usize },
+        1408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).control_message_cb) as usize - ptr as usize },
+        1416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(control_message_cb)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_ts_offset) as usize - ptr as usize },
+        1424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(output_ts_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dump_separator) as usize - ptr as usize },
+        1432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(dump_separator)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data_codec_id) as usize - ptr as usize },
+        1440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(data_codec_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).open_cb) as usize - ptr as usize },
+        1448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(open_cb)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).protocol_whitelist) as usize - ptr as usize },
+        1456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(protocol_whitelist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).io_open) as usize - ptr as usize },
+        1464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(io_open)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).io_close) as usize - ptr as usize },
+        1472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(io_close)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).protocol_blacklist) as usize - ptr as usize },
+        1480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(protocol_blacklist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_streams) as usize - ptr as usize },
+        1488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_streams)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).skip_estimate_duration_from_pts) as usize - ptr as usize
+        },
+        1492usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(skip_estimate_duration_from_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_probe_packets) as usize - ptr as usize },
+        1496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFormatContext),
+            "::",
+            stringify!(max_probe_packets)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVIOInterruptCB {
+    pub callback: ::std::option::Option<
+        unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int,
+    >,
+    pub opaque: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout_AVIOInterruptCB() {
+    const UNINIT: ::std::mem::MaybeUninit<AVIOInterruptCB> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVIOInterruptCB>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVIOInterruptCB))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVIOInterruptCB>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVIOInterruptCB))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).callback) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOInterruptCB),
+            "::",
+            stringify!(callback)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOInterruptCB),
+            "::",
+            stringify!(opaque)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVIODirEntry {
+    pub name: *mut ::std::os::raw::c_char,
+    pub type_: ::std::os::raw::c_int,
+    pub utf8: ::std::os::raw::c_int,
+    pub size: i64,
+    pub modification_timestamp: i64,
+    pub access_timestamp: i64,
+    pub status_change_timestamp: i64,
+    pub user_id: i64,
+    pub group_id: i64,
+    pub filemode: i64,
+}
+#[test]
+fn bindgen_test_layout_AVIODirEntry() {
+    const UNINIT: ::std::mem::MaybeUninit<AVIODirEntry> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVIODirEntry>(),
+        72usize,
+        concat!("Size of: ", stringify!(AVIODirEntry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVIODirEntry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVIODirEntry))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).utf8) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(utf8)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).modification_timestamp) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(modification_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).access_timestamp) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(access_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status_change_timestamp) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(status_change_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).user_id) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(user_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).group_id) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(group_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).filemode) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirEntry),
+            "::",
+            stringify!(filemode)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVIODirContext {
+    pub url_context: *mut URLContext,
+}
+#[test]
+fn bindgen_test_layout_AVIODirContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVIODirContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVIODirContext>(),
+        8usize,
+        concat!("Size of: ", stringify!(AVIODirContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVIODirContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVIODirContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).url_context) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIODirContext),
+            "::",
+            stringify!(url_context)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct URLContext {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVIOContext {
+    pub av_class: *const AVClass,
+    pub buffer: *mut ::std::os::raw::c_uchar,
+    pub buffer_size: ::std::os::raw::c_int,
+    pub buf_ptr: *mut ::std::os::raw::c_uchar,
+    pub buf_end: *mut ::std::os::raw::c_uchar,
+    pub opaque: *mut ::std::os::raw::c_void,
+    pub read_packet: ::std::option::Option<
+        unsafe extern "C" fn(
+            opaque: *mut ::std::os::raw::c_void,
+            buf: *mut u8,
+            buf_size: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub write_packet: ::std::option::Option<
+        unsafe extern "C" fn(
+            opaque: *mut ::std::os::raw::c_void,
+            buf: *mut u8,
+            buf_size: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub seek: ::std::option::Option<
+        unsafe extern "C" fn(
+            opaque: *mut ::std::os::raw::c_void,
+            offset: i64,
+            whence: ::std::os::raw::c_int,
+        ) -> i64,
+    >,
+    pub pos: i64,
+    pub eof_reached: ::std::os::raw::c_int,
+    pub write_flag: ::std::os::raw::c_int,
+    pub max_packet_size: ::std::os::raw::c_int,
+    pub checksum: ::std::os::raw::c_ulong,
+    pub checksum_ptr: *mut ::std::os::raw::c_uchar,
+    pub update_checksum: ::std::option::Option<
+        unsafe extern "C" fn(
+            checksum: ::std::os::raw::c_ulong,
+            buf: *const u8,
+            size: ::std::os::raw::c_uint,
+        ) -> ::std::os::raw::c_ulong,
+    >,
+    pub error: ::std::os::raw::c_int,
+    pub read_pause: ::std::option::Option<
+        unsafe extern "C" fn(
+            opaque: *mut ::std::os::raw::c_void,
+            pause: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub read_seek: ::std::option::Option<
+        unsafe extern "C" fn(
+            opaque: *mut ::std::os::raw::c_void,
+            stream_index: ::std::os::raw::c_int,
+            timestamp: i64,
+            flags: ::std::os::raw::c_int,
+        ) -> i64,
+    >,
+    pub seekable: ::std::os::raw::c_int,
+    pub maxsize: i64,
+    pub direct: ::std::os::raw::c_int,
+    pub bytes_read: i64,
+    pub seek_count: ::std::os::raw::c_int,
+    pub writeout_count: ::std::os::raw::c_int,
+    pub orig_buffer_size: ::std::os::raw::c_int,
+    pub short_seek_threshold: ::std::os::raw::c_int,
+    pub protocol_whitelist: *const ::std::os::raw::c_char,
+    pub protocol_blacklist: *const ::std::os::raw::c_char,
+    pub write_data_type: ::std::option::Option<
+        unsafe extern "C" fn(
+            opaque: *mut ::std::os::raw::c_void,
+            buf: *mut u8,
+            buf_size: ::std::os::raw::c_int,
+            type_: AVIODataMarkerType,
+            time: i64,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub ignore_boundary_point: ::std::os::raw::c_int,
+    pub current_type: AVIODataMarkerType,
+    pub last_time: i64,
+    pub short_seek_get: ::std::option::Option<
+        unsafe extern "C" fn(opaque: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int,
+    >,
+    pub written: i64,
+    pub buf_ptr_max: *mut ::std::os::raw::c_uchar,
+    pub min_packet_size: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVIOContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVIOContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVIOContext>(),
+        264usize,
+        concat!("Size of: ", stringify!(AVIOContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVIOContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVIOContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buffer) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(buffer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buffer_size) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(buffer_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf_ptr) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(buf_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf_end) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(buf_end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_packet) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(read_packet)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_packet) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(write_packet)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seek) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(seek)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pos) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).eof_reached) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(eof_reached)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_flag) as usize - ptr as usize },
+        84usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(write_flag)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_packet_size) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(max_packet_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).checksum) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(checksum)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).checksum_ptr) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(checksum_ptr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).update_checksum) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(update_checksum)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).error) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(error)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_pause) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(read_pause)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read_seek) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(read_seek)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seekable) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(seekable)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).maxsize) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(maxsize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).direct) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(direct)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bytes_read) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(bytes_read)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seek_count) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(seek_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).writeout_count) as usize - ptr as usize },
+        180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(writeout_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).orig_buffer_size) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(orig_buffer_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).short_seek_threshold) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(short_seek_threshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).protocol_whitelist) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(protocol_whitelist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).protocol_blacklist) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(protocol_blacklist)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write_data_type) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(write_data_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ignore_boundary_point) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(ignore_boundary_point)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).current_type) as usize - ptr as usize },
+        220usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(current_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_time) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(last_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).short_seek_get) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(short_seek_get)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).written) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(written)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf_ptr_max) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(buf_ptr_max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_packet_size) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVIOContext),
+            "::",
+            stringify!(min_packet_size)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVBufferRef {
+    pub buffer: *mut AVBuffer,
+    pub data: *mut u8,
+    pub size: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVBufferRef() {
+    const UNINIT: ::std::mem::MaybeUninit<AVBufferRef> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVBufferRef>(),
+        24usize,
+        concat!("Size of: ", stringify!(AVBufferRef))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVBufferRef>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVBufferRef))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buffer) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferRef),
+            "::",
+            stringify!(buffer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferRef),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVBufferRef),
+            "::",
+            stringify!(size)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVDictionaryEntry {
+    pub key: *mut ::std::os::raw::c_char,
+    pub value: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVDictionaryEntry() {
+    const UNINIT: ::std::mem::MaybeUninit<AVDictionaryEntry> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVDictionaryEntry>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVDictionaryEntry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVDictionaryEntry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVDictionaryEntry))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).key) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDictionaryEntry),
+            "::",
+            stringify!(key)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).value) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVDictionaryEntry),
+            "::",
+            stringify!(value)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFifoBuffer {
+    pub buffer: *mut u8,
+    pub rptr: *mut u8,
+    pub wptr: *mut u8,
+    pub end: *mut u8,
+    pub rndx: u32,
+    pub wndx: u32,
+}
+#[test]
+fn bindgen_test_layout_AVFifoBuffer() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFifoBuffer> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFifoBuffer>(),
+        40usize,
+        concat!("Size of: ", stringify!(AVFifoBuffer))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFifoBuffer>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFifoBuffer))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buffer) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFifoBuffer),
+            "::",
+            stringify!(buffer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rptr) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFifoBuffer),
+            "::",
+            stringify!(rptr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wptr) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFifoBuffer),
+            "::",
+            stringify!(wptr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFifoBuffer),
+            "::",
+            stringify!(end)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rndx) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFifoBuffer),
+            "::",
+            stringify!(rndx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wndx) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFifoBuffer),
+            "::",
+            stringify!(wndx)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFrameSideData {
+    pub type_: AVFrameSideDataType,
+    pub data: *mut u8,
+    pub size: ::std::os::raw::c_int,
+    pub metadata: *mut AVDictionary,
+    pub buf: *mut AVBufferRef,
+}
+#[test]
+fn bindgen_test_layout_AVFrameSideData() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFrameSideData> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFrameSideData>(),
+        40usize,
+        concat!("Size of: ", stringify!(AVFrameSideData))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFrameSideData>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFrameSideData))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrameSideData),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrameSideData),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrameSideData),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrameSideData),
+            "::",
+            stringify!(metadata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrameSideData),
+            "::",
+            stringify!(buf)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVRegionOfInterest {
+    pub self_size: u32,
+    pub top: ::std::os::raw::c_int,
+    pub bottom: ::std::os::raw::c_int,
+    pub left: ::std::os::raw::c_int,
+    pub right: ::std::os::raw::c_int,
+    pub qoffset: AVRational,
+}
+#[test]
+fn bindgen_test_layout_AVRegionOfInterest() {
+    const UNINIT: ::std::mem::MaybeUninit<AVRegionOfInterest> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVRegionOfInterest>(),
+        28usize,
+        concat!("Size of: ", stringify!(AVRegionOfInterest))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVRegionOfInterest>(),
+        4usize,
+        concat!("Alignment of ", stringify!(AVRegionOfInterest))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).self_size) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRegionOfInterest),
+            "::",
+            stringify!(self_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).top) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRegionOfInterest),
+            "::",
+            stringify!(top)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bottom) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRegionOfInterest),
+            "::",
+            stringify!(bottom)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).left) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRegionOfInterest),
+            "::",
+            stringify!(left)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).right) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRegionOfInterest),
+            "::",
+            stringify!(right)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qoffset) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRegionOfInterest),
+            "::",
+            stringify!(qoffset)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVFrame {
+    pub data: [*mut u8; 8usize],
+    pub linesize: [::std::os::raw::c_int; 8usize],
+    pub extended_data: *mut *mut u8,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+    pub nb_samples: ::std::os::raw::c_int,
+    pub format: ::std::os::raw::c_int,
+    pub key_frame: ::std::os::raw::c_int,
+    pub pict_type: AVPictureType,
+    pub sample_aspect_ratio: AVRational,
+    pub pts: i64,
+    pub pkt_pts: i64,
+    pub pkt_dts: i64,
+    pub coded_picture_number: ::std::os::raw::c_int,
+    pub display_picture_number: ::std::os::raw::c_int,
+    pub quality: ::std::os::raw::c_int,
+    pub opaque: *mut ::std::os::raw::c_void,
+    pub error: [u64; 8usize],
+    pub repeat_pict: ::std::os::raw::c_int,
+    pub interlaced_frame: ::std::os::raw::c_int,
+    pub top_field_first: ::std::os::raw::c_int,
+    pub palette_has_changed: ::std::os::raw::c_int,
+    pub reordered_opaque: i64,
+    pub sample_rate: ::std::os::raw::c_int,
+    pub channel_layout: u64,
+    pub buf: [*mut AVBufferRef; 8usize],
+    pub extended_buf: *mut *mut AVBufferRef,
+    pub nb_extended_buf: ::std::os::raw::c_int,
+    pub side_data: *mut *mut AVFrameSideData,
+    pub nb_side_data: ::std::os::raw::c_int,
+    pub flags: ::std::os::raw::c_int,
+    pub color_range: AVColorRange,
+    pub color_primaries: AVColorPrimaries,
+    pub color_trc: AVColorTransferCharacteristic,
+    pub colorspace: AVColorSpace,
+    pub chroma_location: AVChromaLocation,
+    pub best_effort_timestamp: i64,
+    pub pkt_pos: i64,
+    pub pkt_duration: i64,
+    pub metadata: *mut AVDictionary,
+    pub decode_error_flags: ::std::os::raw::c_int,
+    pub channels: ::std::os::raw::c_int,
+    pub pkt_size: ::std::os::raw::c_int,
+    pub qscale_table: *mut i8,
+    pub qstride: ::std::os::raw::c_int,
+    pub qscale_type: ::std::os::raw::c_int,
+    pub qp_table_buf: *mut AVBufferRef,
+    pub hw_frames_ctx: *mut AVBufferRef,
+    pub opaque_ref: *mut AVBufferRef,
+    pub crop_top: size_t,
+    pub crop_bottom: size_t,
+    pub crop_left: size_t,
+    pub crop_right: size_t,
+    pub private_ref: *mut AVBufferRef,
+}
+#[test]
+fn bindgen_test_layout_AVFrame() {
+    const UNINIT: ::std::mem::MaybeUninit<AVFrame> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVFrame>(),
+        536usize,
+        concat!("Size of: ", stringify!(AVFrame))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVFrame>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVFrame))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).linesize) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(linesize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extended_data) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(extended_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        108usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_samples) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(nb_samples)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).key_frame) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(key_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pict_type) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pict_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_aspect_ratio) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(sample_aspect_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pts) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt_pts) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pkt_pts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt_dts) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pkt_dts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coded_picture_number) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(coded_picture_number)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).display_picture_number) as usize - ptr as usize },
+        164usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(display_picture_number)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).quality) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(quality)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).error) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(error)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).repeat_pict) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(repeat_pict)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).interlaced_frame) as usize - ptr as usize },
+        252usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(interlaced_frame)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).top_field_first) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(top_field_first)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).palette_has_changed) as usize - ptr as usize },
+        260usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(palette_has_changed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reordered_opaque) as usize - ptr as usize },
+        264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(reordered_opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sample_rate) as usize - ptr as usize },
+        272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(sample_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channel_layout) as usize - ptr as usize },
+        280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(channel_layout)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).buf) as usize - ptr as usize },
+        288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extended_buf) as usize - ptr as usize },
+        352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(extended_buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_extended_buf) as usize - ptr as usize },
+        360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(nb_extended_buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).side_data) as usize - ptr as usize },
+        368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_side_data) as usize - ptr as usize },
+        376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(nb_side_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        380usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_range) as usize - ptr as usize },
+        384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(color_range)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_primaries) as usize - ptr as usize },
+        388usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(color_primaries)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).color_trc) as usize - ptr as usize },
+        392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(color_trc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).colorspace) as usize - ptr as usize },
+        396usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(colorspace)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chroma_location) as usize - ptr as usize },
+        400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(chroma_location)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).best_effort_timestamp) as usize - ptr as usize },
+        408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(best_effort_timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt_pos) as usize - ptr as usize },
+        416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pkt_pos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt_duration) as usize - ptr as usize },
+        424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pkt_duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metadata) as usize - ptr as usize },
+        432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(metadata)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decode_error_flags) as usize - ptr as usize },
+        440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(decode_error_flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).channels) as usize - ptr as usize },
+        444usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(channels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pkt_size) as usize - ptr as usize },
+        448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(pkt_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qscale_table) as usize - ptr as usize },
+        456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(qscale_table)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qstride) as usize - ptr as usize },
+        464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(qstride)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qscale_type) as usize - ptr as usize },
+        468usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(qscale_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qp_table_buf) as usize - ptr as usize },
+        472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(qp_table_buf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hw_frames_ctx) as usize - ptr as usize },
+        480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(hw_frames_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).opaque_ref) as usize - ptr as usize },
+        488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(opaque_ref)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).crop_top) as usize - ptr as usize },
+        496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(crop_top)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).crop_bottom) as usize - ptr as usize },
+        504usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(crop_bottom)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).crop_left) as usize - ptr as usize },
+        512usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(crop_left)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).crop_right) as usize - ptr as usize },
+        520usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(crop_right)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).private_ref) as usize - ptr as usize },
+        528usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVFrame),
+            "::",
+            stringify!(private_ref)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVHWDeviceContext {
+    pub av_class: *const AVClass,
+    pub internal: *mut AVHWDeviceInternal,
+    pub type_: AVHWDeviceType,
+    pub hwctx: *mut ::std::os::raw::c_void,
+    pub free: ::std::option::Option<unsafe extern "C" fn(ctx: *mut AVHWDeviceContext)>,
+    pub user_opaque: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout_AVHWDeviceContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVHWDeviceContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVHWDeviceContext>(),
+        48usize,
+        concat!("Size of: ", stringify!(AVHWDeviceContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVHWDeviceContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVHWDeviceContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWDeviceContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWDeviceContext),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWDeviceContext),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hwctx) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWDeviceContext),
+            "::",
+            stringify!(hwctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).free) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWDeviceContext),
+            "::",
+            stringify!(free)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).user_opaque) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWDeviceContext),
+            "::",
+            stringify!(user_opaque)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVHWFramesContext {
+    pub av_class: *const AVClass,
+    pub internal: *mut AVHWFramesInternal,
+    pub device_ref: *mut AVBufferRef,
+    pub device_ctx: *mut AVHWDeviceContext,
+    pub hwctx: *mut ::std::os::raw::c_void,
+    pub free: ::std::option::Option<unsafe extern "C" fn(ctx: *mut AVHWFramesContext)>,
+    pub user_opaque: *mut ::std::os::raw::c_void,
+    pub pool: *mut AVBufferPool,
+    pub initial_pool_size: ::std::os::raw::c_int,
+    pub format: AVPixelFormat,
+    pub sw_format: AVPixelFormat,
+    pub width: ::std::os::raw::c_int,
+    pub height: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVHWFramesContext() {
+    const UNINIT: ::std::mem::MaybeUninit<AVHWFramesContext> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVHWFramesContext>(),
+        88usize,
+        concat!("Size of: ", stringify!(AVHWFramesContext))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVHWFramesContext>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVHWFramesContext))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).av_class) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(av_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).internal) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(internal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_ref) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(device_ref)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_ctx) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(device_ctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hwctx) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(hwctx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).free) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(free)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).user_opaque) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(user_opaque)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pool) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(pool)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).initial_pool_size) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(initial_pool_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        68usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sw_format) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(sw_format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        76usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesContext),
+            "::",
+            stringify!(height)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVHWFramesConstraints {
+    pub valid_hw_formats: *mut AVPixelFormat,
+    pub valid_sw_formats: *mut AVPixelFormat,
+    pub min_width: ::std::os::raw::c_int,
+    pub min_height: ::std::os::raw::c_int,
+    pub max_width: ::std::os::raw::c_int,
+    pub max_height: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVHWFramesConstraints() {
+    const UNINIT: ::std::mem::MaybeUninit<AVHWFramesConstraints> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVHWFramesConstraints>(),
+        32usize,
+        concat!("Size of: ", stringify!(AVHWFramesConstraints))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVHWFramesConstraints>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVHWFramesConstraints))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).valid_hw_formats) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesConstraints),
+            "::",
+            stringify!(valid_hw_formats)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).valid_sw_formats) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesConstraints),
+            "::",
+            stringify!(valid_sw_formats)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_width) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesConstraints),
+            "::",
+            stringify!(min_width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_height) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesConstraints),
+            "::",
+            stringify!(min_height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_width) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesConstraints),
+            "::",
+            stringify!(max_width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_height) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVHWFramesConstraints),
+            "::",
+            stringify!(max_height)
+        )
+    );
+}
+pub const AVClassCategory_AV_CLASS_CATEGORY_NA: AVClassCategory = 0;
+pub const AVClassCategory_AV_CLASS_CATEGORY_INPUT: AVClassCategory = 1;
+pub const AVClassCategory_AV_CLASS_CATEGORY_OUTPUT: AVClassCategory = 2;
+pub const AVClassCategory_AV_CLASS_CATEGORY_MUXER: AVClassCategory = 3;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEMUXER: AVClassCategory = 4;
+pub const AVClassCategory_AV_CLASS_CATEGORY_ENCODER: AVClassCategory = 5;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DECODER: AVClassCategory = 6;
+pub const AVClassCategory_AV_CLASS_CATEGORY_FILTER: AVClassCategory = 7;
+pub const AVClassCategory_AV_CLASS_CATEGORY_BITSTREAM_FILTER: AVClassCategory = 8;
+pub const AVClassCategory_AV_CLASS_CATEGORY_SWSCALER: AVClassCategory = 9;
+pub const AVClassCategory_AV_CLASS_CATEGORY_SWRESAMPLER: AVClassCategory = 10;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEVICE_VIDEO_OUTPUT: AVClassCategory = 40;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEVICE_VIDEO_INPUT: AVClassCategory = 41;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEVICE_AUDIO_OUTPUT: AVClassCategory = 42;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEVICE_AUDIO_INPUT: AVClassCategory = 43;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEVICE_OUTPUT: AVClassCategory = 44;
+pub const AVClassCategory_AV_CLASS_CATEGORY_DEVICE_INPUT: AVClassCategory = 45;
+pub const AVClassCategory_AV_CLASS_CATEGORY_NB: AVClassCategory = 46;
+pub type AVClassCategory = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVClass {
+    pub class_name: *const ::std::os::raw::c_char,
+    pub item_name: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut ::std::os::raw::c_void) -> *const ::std::os::raw::c_char,
+    >,
+    pub option: *const AVOption,
+    pub version: ::std::os::raw::c_int,
+    pub log_level_offset_offset: ::std::os::raw::c_int,
+    pub parent_log_context_offset: ::std::os::raw::c_int,
+    pub child_next: ::std::option::Option<
+        unsafe extern "C" fn(
+            obj: *mut ::std::os::raw::c_void,
+            prev: *mut ::std::os::raw::c_void,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub child_class_next:
+        ::std::option::Option<unsafe extern "C" fn(prev: *const AVClass) -> *const AVClass>,
+    pub category: AVClassCategory,
+    pub get_category: ::std::option::Option<
+        unsafe extern "C" fn(ctx: *mut ::std::os::raw::c_void) -> AVClassCategory,
+    >,
+    pub query_ranges: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut *mut AVOptionRanges,
+            obj: *mut ::std::os::raw::c_void,
+            key: *const ::std::os::raw::c_char,
+            flags: ::std::os::raw::c_int,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub child_class_iterate: ::std::option::Option<
+        unsafe extern "C" fn(iter: *mut *mut ::std::os::raw::c_void) -> *const AVClass,
+    >,
+}
+#[test]
+fn bindgen_test_layout_AVClass() {
+    const UNINIT: ::std::mem::MaybeUninit<AVClass> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVClass>(),
+        88usize,
+        concat!("Size of: ", stringify!(AVClass))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVClass>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVClass))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).class_name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(class_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).item_name) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(item_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).option) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(option)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(version)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log_level_offset_offset) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(log_level_offset_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parent_log_context_offset) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(parent_log_context_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).child_next) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(child_next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).child_class_next) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(child_class_next)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).category) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(category)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_category) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(get_category)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).query_ranges) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(query_ranges)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).child_class_iterate) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVClass),
+            "::",
+            stringify!(child_class_iterate)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AVOption {
+    pub name: *const ::std::os::raw::c_char,
+    pub help: *const ::std::os::raw::c_char,
+    pub offset: ::std::os::raw::c_int,
+    pub type_: AVOptionType,
+    pub default_val: AVOption__bindgen_ty_1,
+    pub min: f64,
+    pub max: f64,
+    pub flags: ::std::os::raw::c_int,
+    pub unit: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVOption() {
+    const UNINIT: ::std::mem::MaybeUninit<AVOption> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVOption>(),
+        64usize,
+        concat!("Size of: ", stringify!(AVOption))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVOption>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVOption))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).help) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(help)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).offset) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).default_val) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(default_val)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(min)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unit) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption),
+            "::",
+            stringify!(unit)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union AVOption__bindgen_ty_1 {
+    pub i64_: i64,
+    pub dbl: f64,
+    pub str_: *const ::std::os::raw::c_char,
+    pub q: AVRational,
+}
+#[test]
+fn bindgen_test_layout_AVOption__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<AVOption__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVOption__bindgen_ty_1>(),
+        8usize,
+        concat!("Size of: ", stringify!(AVOption__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVOption__bindgen_ty_1>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVOption__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i64_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption__bindgen_ty_1),
+            "::",
+            stringify!(i64_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dbl) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption__bindgen_ty_1),
+            "::",
+            stringify!(dbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).str_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption__bindgen_ty_1),
+            "::",
+            stringify!(str_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).q) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOption__bindgen_ty_1),
+            "::",
+            stringify!(q)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVOptionRange {
+    pub str_: *const ::std::os::raw::c_char,
+    pub value_min: f64,
+    pub value_max: f64,
+    pub component_min: f64,
+    pub component_max: f64,
+    pub is_range: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVOptionRange() {
+    const UNINIT: ::std::mem::MaybeUninit<AVOptionRange> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVOptionRange>(),
+        48usize,
+        concat!("Size of: ", stringify!(AVOptionRange))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVOptionRange>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVOptionRange))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).str_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRange),
+            "::",
+            stringify!(str_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).value_min) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRange),
+            "::",
+            stringify!(value_min)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).value_max) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRange),
+            "::",
+            stringify!(value_max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).component_min) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRange),
+            "::",
+            stringify!(component_min)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).component_max) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRange),
+            "::",
+            stringify!(component_max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).is_range) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRange),
+            "::",
+            stringify!(is_range)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVOptionRanges {
+    pub range: *mut *mut AVOptionRange,
+    pub nb_ranges: ::std::os::raw::c_int,
+    pub nb_components: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVOptionRanges() {
+    const UNINIT: ::std::mem::MaybeUninit<AVOptionRanges> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVOptionRanges>(),
+        16usize,
+        concat!("Size of: ", stringify!(AVOptionRanges))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVOptionRanges>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVOptionRanges))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).range) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRanges),
+            "::",
+            stringify!(range)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_ranges) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRanges),
+            "::",
+            stringify!(nb_ranges)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_components) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVOptionRanges),
+            "::",
+            stringify!(nb_components)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVComponentDescriptor {
+    pub plane: ::std::os::raw::c_int,
+    pub step: ::std::os::raw::c_int,
+    pub offset: ::std::os::raw::c_int,
+    pub shift: ::std::os::raw::c_int,
+    pub depth: ::std::os::raw::c_int,
+    pub step_minus1: ::std::os::raw::c_int,
+    pub depth_minus1: ::std::os::raw::c_int,
+    pub offset_plus1: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVComponentDescriptor() {
+    const UNINIT: ::std::mem::MaybeUninit<AVComponentDescriptor> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVComponentDescriptor>(),
+        32usize,
+        concat!("Size of: ", stringify!(AVComponentDescriptor))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVComponentDescriptor>(),
+        4usize,
+        concat!("Alignment of ", stringify!(AVComponentDescriptor))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).plane) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(plane)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).step) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(step)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).offset) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).shift) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(shift)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).depth) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(depth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).step_minus1) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(step_minus1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).depth_minus1) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(depth_minus1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).offset_plus1) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVComponentDescriptor),
+            "::",
+            stringify!(offset_plus1)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVPixFmtDescriptor {
+    pub name: *const ::std::os::raw::c_char,
+    pub nb_components: u8,
+    pub log2_chroma_w: u8,
+    pub log2_chroma_h: u8,
+    pub flags: u64,
+    pub comp: [AVComponentDescriptor; 4usize],
+    pub alias: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_AVPixFmtDescriptor() {
+    const UNINIT: ::std::mem::MaybeUninit<AVPixFmtDescriptor> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVPixFmtDescriptor>(),
+        160usize,
+        concat!("Size of: ", stringify!(AVPixFmtDescriptor))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVPixFmtDescriptor>(),
+        8usize,
+        concat!("Alignment of ", stringify!(AVPixFmtDescriptor))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nb_components) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(nb_components)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log2_chroma_w) as usize - ptr as usize },
+        9usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(log2_chroma_w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log2_chroma_h) as usize - ptr as usize },
+        10usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(log2_chroma_h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).comp) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(comp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).alias) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVPixFmtDescriptor),
+            "::",
+            stringify!(alias)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AVRational {
+    pub num: ::std::os::raw::c_int,
+    pub den: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_AVRational() {
+    const UNINIT: ::std::mem::MaybeUninit<AVRational> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<AVRational>(),
+        8usize,
+        concat!("Size of: ", stringify!(AVRational))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<AVRational>(),
+        4usize,
+        concat!("Alignment of ", stringify!(AVRational))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRational),
+            "::",
+            stringify!(num)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).den) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(AVRational),
+            "::",
+            stringify!(den)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SwsVector {
+    pub coeff: *mut f64,
+    pub length: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_SwsVector() {
+    const UNINIT: ::std::mem::MaybeUninit<SwsVector> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<SwsVector>(),
+        16usize,
+        concat!("Size of: ", stringify!(SwsVector))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<SwsVector>(),
+        8usize,
+        concat!("Alignment of ", stringify!(SwsVector))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coeff) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(SwsVector),
+            "::",
+            stringify!(coeff)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(SwsVector),
+            "::",
+            stringify!(length)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SwsFilter {
+    pub lumH: *mut SwsVector,
+    pub lumV: *mut SwsVector,
+    pub chrH: *mut SwsVector,
+    pub chrV: *mut SwsVector,
+}
+#[test]
+fn bindgen_test_layout_SwsFilter() {
+    const UNINIT: ::std::mem::MaybeUninit<SwsFilter> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<SwsFilter>(),
+        32usize,
+        concat!("Size of: ", stringify!(SwsFilter))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<SwsFilter>(),
+        8usize,
+        concat!("Alignment of ", stringify!(SwsFilter))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).lumH) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(SwsFilter),
+            "::",
+            stringify!(lumH)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).lumV) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(SwsFilter),
+            "::",
+            stringify!(lumV)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chrH) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(SwsFilter),
+            "::",
+            stringify!(chrH)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).chrV) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(SwsFilter),
+            "::",
+            stringify!(chrV)
+        )
+    );
+}
+pub type __builtin_va_list = [__va_list_tag; 1usize];
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __va_list_tag {
+    pub gp_offset: ::std::os::raw::c_uint,
+    pub fp_offset: ::std::os::raw::c_uint,
+    pub overflow_arg_area: *mut ::std::os::raw::c_void,
+    pub reg_save_area: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout___va_list_tag() {
+    const UNINIT: ::std::mem::MaybeUninit<__va_list_tag> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<__va_list_tag>(),
+        24usize,
+        concat!("Size of: ", stringify!(__va_list_tag))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<__va_list_tag>(),
+        8usize,
+        concat!("Alignment of ", stringify!(__va_list_tag))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gp_offset) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(__va_list_tag),
+            "::",
+            stringify!(gp_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fp_offset) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(__va_list_tag),
+            "::",
+            stringify!(fp_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).overflow_arg_area) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(__va_list_tag),
+            "::",
+            stringify!(overflow_arg_area)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reg_save_area) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(__va_list_tag),
+            "::",
+            stringify!(reg_save_area)
+        )
+    );
+}
+extern "C" {
+    pub static av_device_capabilities: [AVOption; 0usize];
+}
+extern "C" {
+    pub fn avutil_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn av_version_info() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avutil_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avutil_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_media_type_string(media_type: AVMediaType) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_picture_type_char(pict_type: AVPictureType) -> ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_log2(v: ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_log2_16bit(v: ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_strerror(
+        errnum: ::std::os::raw::c_int,
+        errbuf: *mut ::std::os::raw::c_char,
+        errbuf_size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_malloc(size: size_t) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_mallocz(size: size_t) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_malloc_array(nmemb: size_t, size: size_t) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_mallocz_array(nmemb: size_t, size: size_t) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_calloc(nmemb: size_t, size: size_t) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_realloc(
+        ptr: *mut ::std::os::raw::c_void,
+        size: size_t,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_reallocp(ptr: *mut ::std::os::raw::c_void, size: size_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_realloc_f(
+        ptr: *mut ::std::os::raw::c_void,
+        nelem: size_t,
+        elsize: size_t,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_realloc_array(
+        ptr: *mut ::std::os::raw::c_void,
+        nmemb: size_t,
+        size: size_t,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_reallocp_array(
+        ptr: *mut ::std::os::raw::c_void,
+        nmemb: size_t,
+        size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fast_realloc(
+        ptr: *mut ::std::os::raw::c_void,
+        size: *mut ::std::os::raw::c_uint,
+        min_size: size_t,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_fast_malloc(
+        ptr: *mut ::std::os::raw::c_void,
+        size: *mut ::std::os::raw::c_uint,
+        min_size: size_t,
+    );
+}
+extern "C" {
+    pub fn av_fast_mallocz(
+        ptr: *mut ::std::os::raw::c_void,
+        size: *mut ::std::os::raw::c_uint,
+        min_size: size_t,
+    );
+}
+extern "C" {
+    pub fn av_free(ptr: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn av_freep(ptr: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn av_strdup(s: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_strndup(s: *const ::std::os::raw::c_char, len: size_t)
+        -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_memdup(p: *const ::std::os::raw::c_void, size: size_t)
+        -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_memcpy_backptr(dst: *mut u8, back: ::std::os::raw::c_int, cnt: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_dynarray_add(
+        tab_ptr: *mut ::std::os::raw::c_void,
+        nb_ptr: *mut ::std::os::raw::c_int,
+        elem: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn av_dynarray_add_nofree(
+        tab_ptr: *mut ::std::os::raw::c_void,
+        nb_ptr: *mut ::std::os::raw::c_int,
+        elem: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dynarray2_add(
+        tab_ptr: *mut *mut ::std::os::raw::c_void,
+        nb_ptr: *mut ::std::os::raw::c_int,
+        elem_size: size_t,
+        elem_data: *const u8,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_max_alloc(max: size_t);
+}
+extern "C" {
+    pub fn av_reduce(
+        dst_num: *mut ::std::os::raw::c_int,
+        dst_den: *mut ::std::os::raw::c_int,
+        num: i64,
+        den: i64,
+        max: i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_mul_q(b: AVRational, c: AVRational) -> AVRational;
+}
+extern "C" {
+    pub fn av_div_q(b: AVRational, c: AVRational) -> AVRational;
+}
+extern "C" {
+    pub fn av_add_q(b: AVRational, c: AVRational) -> AVRational;
+}
+extern "C" {
+    pub fn av_sub_q(b: AVRational, c: AVRational) -> AVRational;
+}
+extern "C" {
+    pub fn av_d2q(d: f64, max: ::std::os::raw::c_int) -> AVRational;
+}
+extern "C" {
+    pub fn av_nearer_q(q: AVRational, q1: AVRational, q2: AVRational) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_find_nearest_q_idx(q: AVRational, q_list: *const AVRational)
+        -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_q2intfloat(q: AVRational) -> u32;
+}
+extern "C" {
+    pub fn av_gcd_q(
+        a: AVRational,
+        b: AVRational,
+        max_den: ::std::os::raw::c_int,
+        def: AVRational,
+    ) -> AVRational;
+}
+extern "C" {
+    pub fn av_gcd(a: i64, b: i64) -> i64;
+}
+extern "C" {
+    pub fn av_rescale(a: i64, b: i64, c: i64) -> i64;
+}
+extern "C" {
+    pub fn av_rescale_rnd(a: i64, b: i64, c: i64, rnd: AVRounding) -> i64;
+}
+extern "C" {
+    pub fn av_rescale_q(a: i64, bq: AVRational, cq: AVRational) -> i64;
+}
+extern "C" {
+    pub fn av_rescale_q_rnd(a: i64, bq: AVRational, cq: AVRational, rnd: AVRounding) -> i64;
+}
+extern "C" {
+    pub fn av_compare_ts(
+        ts_a: i64,
+        tb_a: AVRational,
+        ts_b: i64,
+        tb_b: AVRational,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_compare_mod(a: u64, b: u64, mod_: u64) -> i64;
+}
+extern "C" {
+    pub fn av_rescale_delta(
+        in_tb: AVRational,
+        in_ts: i64,
+        fs_tb: AVRational,
+        duration: ::std::os::raw::c_int,
+        last: *mut i64,
+        out_tb: AVRational,
+    ) -> i64;
+}
+extern "C" {
+    pub fn av_add_stable(ts_tb: AVRational, ts: i64, inc_tb: AVRational, inc: i64) -> i64;
+}
+extern "C" {
+    pub fn av_log(
+        avcl: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        fmt: *const ::std::os::raw::c_char,
+        ...
+    );
+}
+extern "C" {
+    pub fn av_log_once(
+        avcl: *mut ::std::os::raw::c_void,
+        initial_level: ::std::os::raw::c_int,
+        subsequent_level: ::std::os::raw::c_int,
+        state: *mut ::std::os::raw::c_int,
+        fmt: *const ::std::os::raw::c_char,
+        ...
+    );
+}
+extern "C" {
+    pub fn av_vlog(
+        avcl: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        fmt: *const ::std::os::raw::c_char,
+        vl: *mut __va_list_tag,
+    );
+}
+extern "C" {
+    pub fn av_log_get_level() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_log_set_level(level: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_log_set_callback(
+        callback: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: ::std::os::raw::c_int,
+                arg3: *const ::std::os::raw::c_char,
+                arg4: *mut __va_list_tag,
+            ),
+        >,
+    );
+}
+extern "C" {
+    pub fn av_log_default_callback(
+        avcl: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        fmt: *const ::std::os::raw::c_char,
+        vl: *mut __va_list_tag,
+    );
+}
+extern "C" {
+    pub fn av_default_item_name(ctx: *mut ::std::os::raw::c_void) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_default_get_category(ptr: *mut ::std::os::raw::c_void) -> AVClassCategory;
+}
+extern "C" {
+    pub fn av_log_format_line(
+        ptr: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        fmt: *const ::std::os::raw::c_char,
+        vl: *mut __va_list_tag,
+        line: *mut ::std::os::raw::c_char,
+        line_size: ::std::os::raw::c_int,
+        print_prefix: *mut ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_log_format_line2(
+        ptr: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        fmt: *const ::std::os::raw::c_char,
+        vl: *mut __va_list_tag,
+        line: *mut ::std::os::raw::c_char,
+        line_size: ::std::os::raw::c_int,
+        print_prefix: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_log_set_flags(arg: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_log_get_flags() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_int_list_length_for_size(
+        elsize: ::std::os::raw::c_uint,
+        list: *const ::std::os::raw::c_void,
+        term: u64,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn av_fopen_utf8(
+        path: *const ::std::os::raw::c_char,
+        mode: *const ::std::os::raw::c_char,
+    ) -> *mut FILE;
+}
+extern "C" {
+    pub fn av_get_time_base_q() -> AVRational;
+}
+extern "C" {
+    pub fn av_fourcc_make_string(
+        buf: *mut ::std::os::raw::c_char,
+        fourcc: u32,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_sample_fmt_name(sample_fmt: AVSampleFormat) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_sample_fmt(name: *const ::std::os::raw::c_char) -> AVSampleFormat;
+}
+extern "C" {
+    pub fn av_get_alt_sample_fmt(
+        sample_fmt: AVSampleFormat,
+        planar: ::std::os::raw::c_int,
+    ) -> AVSampleFormat;
+}
+extern "C" {
+    pub fn av_get_packed_sample_fmt(sample_fmt: AVSampleFormat) -> AVSampleFormat;
+}
+extern "C" {
+    pub fn av_get_planar_sample_fmt(sample_fmt: AVSampleFormat) -> AVSampleFormat;
+}
+extern "C" {
+    pub fn av_get_sample_fmt_string(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_bytes_per_sample(sample_fmt: AVSampleFormat) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_sample_fmt_is_planar(sample_fmt: AVSampleFormat) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_samples_get_buffer_size(
+        linesize: *mut ::std::os::raw::c_int,
+        nb_channels: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_samples_fill_arrays(
+        audio_data: *mut *mut u8,
+        linesize: *mut ::std::os::raw::c_int,
+        buf: *const u8,
+        nb_channels: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_samples_alloc(
+        audio_data: *mut *mut u8,
+        linesize: *mut ::std::os::raw::c_int,
+        nb_channels: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_samples_alloc_array_and_samples(
+        audio_data: *mut *mut *mut u8,
+        linesize: *mut ::std::os::raw::c_int,
+        nb_channels: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_samples_copy(
+        dst: *mut *mut u8,
+        src: *const *mut u8,
+        dst_offset: ::std::os::raw::c_int,
+        src_offset: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+        nb_channels: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_samples_set_silence(
+        audio_data: *mut *mut u8,
+        offset: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+        nb_channels: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffer_alloc(size: ::std::os::raw::c_int) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_buffer_allocz(size: ::std::os::raw::c_int) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_buffer_create(
+        data: *mut u8,
+        size: ::std::os::raw::c_int,
+        free: ::std::option::Option<
+            unsafe extern "C" fn(opaque: *mut ::std::os::raw::c_void, data: *mut u8),
+        >,
+        opaque: *mut ::std::os::raw::c_void,
+        flags: ::std::os::raw::c_int,
+    ) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_buffer_default_free(opaque: *mut ::std::os::raw::c_void, data: *mut u8);
+}
+extern "C" {
+    pub fn av_buffer_ref(buf: *mut AVBufferRef) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_buffer_unref(buf: *mut *mut AVBufferRef);
+}
+extern "C" {
+    pub fn av_buffer_is_writable(buf: *const AVBufferRef) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffer_get_opaque(buf: *const AVBufferRef) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_buffer_get_ref_count(buf: *const AVBufferRef) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffer_make_writable(buf: *mut *mut AVBufferRef) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffer_realloc(
+        buf: *mut *mut AVBufferRef,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffer_replace(
+        dst: *mut *mut AVBufferRef,
+        src: *mut AVBufferRef,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffer_pool_init(
+        size: ::std::os::raw::c_int,
+        alloc: ::std::option::Option<
+            unsafe extern "C" fn(size: ::std::os::raw::c_int) -> *mut AVBufferRef,
+        >,
+    ) -> *mut AVBufferPool;
+}
+extern "C" {
+    pub fn av_buffer_pool_init2(
+        size: ::std::os::raw::c_int,
+        opaque: *mut ::std::os::raw::c_void,
+        alloc: ::std::option::Option<
+            unsafe extern "C" fn(
+                opaque: *mut ::std::os::raw::c_void,
+                size: ::std::os::raw::c_int,
+            ) -> *mut AVBufferRef,
+        >,
+        pool_free: ::std::option::Option<unsafe extern "C" fn(opaque: *mut ::std::os::raw::c_void)>,
+    ) -> *mut AVBufferPool;
+}
+extern "C" {
+    pub fn av_buffer_pool_uninit(pool: *mut *mut AVBufferPool);
+}
+extern "C" {
+    pub fn av_buffer_pool_get(pool: *mut AVBufferPool) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_buffer_pool_buffer_get_opaque(ref_: *mut AVBufferRef) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_get_cpu_flags() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_force_cpu_flags(flags: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_set_cpu_flags_mask(mask: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_parse_cpu_flags(s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_parse_cpu_caps(
+        flags: *mut ::std::os::raw::c_uint,
+        s: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_cpu_count() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_cpu_max_align() -> size_t;
+}
+extern "C" {
+    pub fn av_get_channel_layout(name: *const ::std::os::raw::c_char) -> u64;
+}
+extern "C" {
+    pub fn av_get_extended_channel_layout(
+        name: *const ::std::os::raw::c_char,
+        channel_layout: *mut u64,
+        nb_channels: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_channel_layout_string(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+        nb_channels: ::std::os::raw::c_int,
+        channel_layout: u64,
+    );
+}
+extern "C" {
+    pub fn av_bprint_channel_layout(
+        bp: *mut AVBPrint,
+        nb_channels: ::std::os::raw::c_int,
+        channel_layout: u64,
+    );
+}
+extern "C" {
+    pub fn av_get_channel_layout_nb_channels(channel_layout: u64) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_default_channel_layout(nb_channels: ::std::os::raw::c_int) -> i64;
+}
+extern "C" {
+    pub fn av_get_channel_layout_channel_index(
+        channel_layout: u64,
+        channel: u64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_channel_layout_extract_channel(
+        channel_layout: u64,
+        index: ::std::os::raw::c_int,
+    ) -> u64;
+}
+extern "C" {
+    pub fn av_get_channel_name(channel: u64) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_channel_description(channel: u64) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_standard_channel_layout(
+        index: ::std::os::raw::c_uint,
+        layout: *mut u64,
+        name: *mut *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dict_get(
+        m: *const AVDictionary,
+        key: *const ::std::os::raw::c_char,
+        prev: *const AVDictionaryEntry,
+        flags: ::std::os::raw::c_int,
+    ) -> *mut AVDictionaryEntry;
+}
+extern "C" {
+    pub fn av_dict_count(m: *const AVDictionary) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dict_set(
+        pm: *mut *mut AVDictionary,
+        key: *const ::std::os::raw::c_char,
+        value: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dict_set_int(
+        pm: *mut *mut AVDictionary,
+        key: *const ::std::os::raw::c_char,
+        value: i64,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dict_parse_string(
+        pm: *mut *mut AVDictionary,
+        str_: *const ::std::os::raw::c_char,
+        key_val_sep: *const ::std::os::raw::c_char,
+        pairs_sep: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dict_copy(
+        dst: *mut *mut AVDictionary,
+        src: *const AVDictionary,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dict_free(m: *mut *mut AVDictionary);
+}
+extern "C" {
+    pub fn av_dict_get_string(
+        m: *const AVDictionary,
+        buffer: *mut *mut ::std::os::raw::c_char,
+        key_val_sep: ::std::os::raw::c_char,
+        pairs_sep: ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_get_best_effort_timestamp(frame: *const AVFrame) -> i64;
+}
+extern "C" {
+    pub fn av_frame_set_best_effort_timestamp(frame: *mut AVFrame, val: i64);
+}
+extern "C" {
+    pub fn av_frame_get_pkt_duration(frame: *const AVFrame) -> i64;
+}
+extern "C" {
+    pub fn av_frame_set_pkt_duration(frame: *mut AVFrame, val: i64);
+}
+extern "C" {
+    pub fn av_frame_get_pkt_pos(frame: *const AVFrame) -> i64;
+}
+extern "C" {
+    pub fn av_frame_set_pkt_pos(frame: *mut AVFrame, val: i64);
+}
+extern "C" {
+    pub fn av_frame_get_channel_layout(frame: *const AVFrame) -> i64;
+}
+extern "C" {
+    pub fn av_frame_set_channel_layout(frame: *mut AVFrame, val: i64);
+}
+extern "C" {
+    pub fn av_frame_get_channels(frame: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_set_channels(frame: *mut AVFrame, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_frame_get_sample_rate(frame: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_set_sample_rate(frame: *mut AVFrame, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_frame_get_metadata(frame: *const AVFrame) -> *mut AVDictionary;
+}
+extern "C" {
+    pub fn av_frame_set_metadata(frame: *mut AVFrame, val: *mut AVDictionary);
+}
+extern "C" {
+    pub fn av_frame_get_decode_error_flags(frame: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_set_decode_error_flags(frame: *mut AVFrame, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_frame_get_pkt_size(frame: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_set_pkt_size(frame: *mut AVFrame, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_frame_get_qp_table(
+        f: *mut AVFrame,
+        stride: *mut ::std::os::raw::c_int,
+        type_: *mut ::std::os::raw::c_int,
+    ) -> *mut i8;
+}
+extern "C" {
+    pub fn av_frame_set_qp_table(
+        f: *mut AVFrame,
+        buf: *mut AVBufferRef,
+        stride: ::std::os::raw::c_int,
+        type_: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_get_colorspace(frame: *const AVFrame) -> AVColorSpace;
+}
+extern "C" {
+    pub fn av_frame_set_colorspace(frame: *mut AVFrame, val: AVColorSpace);
+}
+extern "C" {
+    pub fn av_frame_get_color_range(frame: *const AVFrame) -> AVColorRange;
+}
+extern "C" {
+    pub fn av_frame_set_color_range(frame: *mut AVFrame, val: AVColorRange);
+}
+extern "C" {
+    pub fn av_get_colorspace_name(val: AVColorSpace) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_frame_alloc() -> *mut AVFrame;
+}
+extern "C" {
+    pub fn av_frame_free(frame: *mut *mut AVFrame);
+}
+extern "C" {
+    pub fn av_frame_ref(dst: *mut AVFrame, src: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_clone(src: *const AVFrame) -> *mut AVFrame;
+}
+extern "C" {
+    pub fn av_frame_unref(frame: *mut AVFrame);
+}
+extern "C" {
+    pub fn av_frame_move_ref(dst: *mut AVFrame, src: *mut AVFrame);
+}
+extern "C" {
+    pub fn av_frame_get_buffer(
+        frame: *mut AVFrame,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_is_writable(frame: *mut AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_make_writable(frame: *mut AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_copy(dst: *mut AVFrame, src: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_copy_props(dst: *mut AVFrame, src: *const AVFrame) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_get_plane_buffer(
+        frame: *mut AVFrame,
+        plane: ::std::os::raw::c_int,
+    ) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_frame_new_side_data(
+        frame: *mut AVFrame,
+        type_: AVFrameSideDataType,
+        size: ::std::os::raw::c_int,
+    ) -> *mut AVFrameSideData;
+}
+extern "C" {
+    pub fn av_frame_new_side_data_from_buf(
+        frame: *mut AVFrame,
+        type_: AVFrameSideDataType,
+        buf: *mut AVBufferRef,
+    ) -> *mut AVFrameSideData;
+}
+extern "C" {
+    pub fn av_frame_get_side_data(
+        frame: *const AVFrame,
+        type_: AVFrameSideDataType,
+    ) -> *mut AVFrameSideData;
+}
+extern "C" {
+    pub fn av_frame_remove_side_data(frame: *mut AVFrame, type_: AVFrameSideDataType);
+}
+extern "C" {
+    pub fn av_frame_apply_cropping(
+        frame: *mut AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_frame_side_data_name(type_: AVFrameSideDataType) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_hwdevice_find_type_by_name(name: *const ::std::os::raw::c_char) -> AVHWDeviceType;
+}
+extern "C" {
+    pub fn av_hwdevice_get_type_name(type_: AVHWDeviceType) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_hwdevice_iterate_types(prev: AVHWDeviceType) -> AVHWDeviceType;
+}
+extern "C" {
+    pub fn av_hwdevice_ctx_alloc(type_: AVHWDeviceType) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_hwdevice_ctx_init(ref_: *mut AVBufferRef) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwdevice_ctx_create(
+        device_ctx: *mut *mut AVBufferRef,
+        type_: AVHWDeviceType,
+        device: *const ::std::os::raw::c_char,
+        opts: *mut AVDictionary,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwdevice_ctx_create_derived(
+        dst_ctx: *mut *mut AVBufferRef,
+        type_: AVHWDeviceType,
+        src_ctx: *mut AVBufferRef,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwdevice_ctx_create_derived_opts(
+        dst_ctx: *mut *mut AVBufferRef,
+        type_: AVHWDeviceType,
+        src_ctx: *mut AVBufferRef,
+        options: *mut AVDictionary,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwframe_ctx_alloc(device_ctx: *mut AVBufferRef) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_hwframe_ctx_init(ref_: *mut AVBufferRef) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwframe_get_buffer(
+        hwframe_ctx: *mut AVBufferRef,
+        frame: *mut AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwframe_transfer_data(
+        dst: *mut AVFrame,
+        src: *const AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwframe_transfer_get_formats(
+        hwframe_ctx: *mut AVBufferRef,
+        dir: AVHWFrameTransferDirection,
+        formats: *mut *mut AVPixelFormat,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwdevice_hwconfig_alloc(device_ctx: *mut AVBufferRef) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_hwdevice_get_hwframe_constraints(
+        ref_: *mut AVBufferRef,
+        hwconfig: *const ::std::os::raw::c_void,
+    ) -> *mut AVHWFramesConstraints;
+}
+extern "C" {
+    pub fn av_hwframe_constraints_free(constraints: *mut *mut AVHWFramesConstraints);
+}
+extern "C" {
+    pub fn av_hwframe_map(
+        dst: *mut AVFrame,
+        src: *const AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hwframe_ctx_create_derived(
+        derived_frame_ctx: *mut *mut AVBufferRef,
+        format: AVPixelFormat,
+        derived_device_ctx: *mut AVBufferRef,
+        source_frame_ctx: *mut AVBufferRef,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_get_type(codec_id: AVCodecID) -> AVMediaType;
+}
+extern "C" {
+    pub fn avcodec_get_name(id: AVCodecID) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avcodec_parameters_alloc() -> *mut AVCodecParameters;
+}
+extern "C" {
+    pub fn avcodec_parameters_free(par: *mut *mut AVCodecParameters);
+}
+extern "C" {
+    pub fn avcodec_parameters_copy(
+        dst: *mut AVCodecParameters,
+        src: *const AVCodecParameters,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_alloc() -> *mut AVPacket;
+}
+extern "C" {
+    pub fn av_packet_clone(src: *const AVPacket) -> *mut AVPacket;
+}
+extern "C" {
+    pub fn av_packet_free(pkt: *mut *mut AVPacket);
+}
+extern "C" {
+    pub fn av_init_packet(pkt: *mut AVPacket);
+}
+extern "C" {
+    pub fn av_new_packet(pkt: *mut AVPacket, size: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_shrink_packet(pkt: *mut AVPacket, size: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_grow_packet(
+        pkt: *mut AVPacket,
+        grow_by: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_from_data(
+        pkt: *mut AVPacket,
+        data: *mut u8,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_dup_packet(pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_copy_packet(dst: *mut AVPacket, src: *const AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_copy_packet_side_data(
+        dst: *mut AVPacket,
+        src: *const AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_free_packet(pkt: *mut AVPacket);
+}
+extern "C" {
+    pub fn av_packet_new_side_data(
+        pkt: *mut AVPacket,
+        type_: AVPacketSideDataType,
+        size: ::std::os::raw::c_int,
+    ) -> *mut u8;
+}
+extern "C" {
+    pub fn av_packet_add_side_data(
+        pkt: *mut AVPacket,
+        type_: AVPacketSideDataType,
+        data: *mut u8,
+        size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_shrink_side_data(
+        pkt: *mut AVPacket,
+        type_: AVPacketSideDataType,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_get_side_data(
+        pkt: *const AVPacket,
+        type_: AVPacketSideDataType,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *mut u8;
+}
+extern "C" {
+    pub fn av_packet_merge_side_data(pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_split_side_data(pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_side_data_name(type_: AVPacketSideDataType) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_packet_pack_dictionary(
+        dict: *mut AVDictionary,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *mut u8;
+}
+extern "C" {
+    pub fn av_packet_unpack_dictionary(
+        data: *const u8,
+        size: ::std::os::raw::c_int,
+        dict: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_free_side_data(pkt: *mut AVPacket);
+}
+extern "C" {
+    pub fn av_packet_ref(dst: *mut AVPacket, src: *const AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_unref(pkt: *mut AVPacket);
+}
+extern "C" {
+    pub fn av_packet_move_ref(dst: *mut AVPacket, src: *mut AVPacket);
+}
+extern "C" {
+    pub fn av_packet_copy_props(dst: *mut AVPacket, src: *const AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_make_refcounted(pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_make_writable(pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_packet_rescale_ts(pkt: *mut AVPacket, tb_src: AVRational, tb_dst: AVRational);
+}
+extern "C" {
+    pub fn av_bsf_get_by_name(name: *const ::std::os::raw::c_char) -> *const AVBitStreamFilter;
+}
+extern "C" {
+    pub fn av_bsf_iterate(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVBitStreamFilter;
+}
+extern "C" {
+    pub fn av_bsf_alloc(
+        filter: *const AVBitStreamFilter,
+        ctx: *mut *mut AVBSFContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_init(ctx: *mut AVBSFContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_send_packet(ctx: *mut AVBSFContext, pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_receive_packet(
+        ctx: *mut AVBSFContext,
+        pkt: *mut AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_flush(ctx: *mut AVBSFContext);
+}
+extern "C" {
+    pub fn av_bsf_free(ctx: *mut *mut AVBSFContext);
+}
+extern "C" {
+    pub fn av_bsf_get_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn av_bsf_list_alloc() -> *mut AVBSFList;
+}
+extern "C" {
+    pub fn av_bsf_list_free(lst: *mut *mut AVBSFList);
+}
+extern "C" {
+    pub fn av_bsf_list_append(lst: *mut AVBSFList, bsf: *mut AVBSFContext)
+        -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_list_append2(
+        lst: *mut AVBSFList,
+        bsf_name: *const ::std::os::raw::c_char,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_list_finalize(
+        lst: *mut *mut AVBSFList,
+        bsf: *mut *mut AVBSFContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_list_parse_str(
+        str_: *const ::std::os::raw::c_char,
+        bsf: *mut *mut AVBSFContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bsf_get_null_filter(bsf: *mut *mut AVBSFContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_codec_iterate(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVCodec;
+}
+extern "C" {
+    pub fn avcodec_find_decoder(id: AVCodecID) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn avcodec_find_decoder_by_name(name: *const ::std::os::raw::c_char) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn avcodec_find_encoder(id: AVCodecID) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn avcodec_find_encoder_by_name(name: *const ::std::os::raw::c_char) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn av_codec_is_encoder(codec: *const AVCodec) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_codec_is_decoder(codec: *const AVCodec) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_get_hw_config(
+        codec: *const AVCodec,
+        index: ::std::os::raw::c_int,
+    ) -> *const AVCodecHWConfig;
+}
+extern "C" {
+    pub fn avcodec_descriptor_get(id: AVCodecID) -> *const AVCodecDescriptor;
+}
+extern "C" {
+    pub fn avcodec_descriptor_next(prev: *const AVCodecDescriptor) -> *const AVCodecDescriptor;
+}
+extern "C" {
+    pub fn avcodec_descriptor_get_by_name(
+        name: *const ::std::os::raw::c_char,
+    ) -> *const AVCodecDescriptor;
+}
+extern "C" {
+    pub fn av_codec_get_pkt_timebase(avctx: *const AVCodecContext) -> AVRational;
+}
+extern "C" {
+    pub fn av_codec_set_pkt_timebase(avctx: *mut AVCodecContext, val: AVRational);
+}
+extern "C" {
+    pub fn av_codec_get_codec_descriptor(avctx: *const AVCodecContext) -> *const AVCodecDescriptor;
+}
+extern "C" {
+    pub fn av_codec_set_codec_descriptor(
+        avctx: *mut AVCodecContext,
+        desc: *const AVCodecDescriptor,
+    );
+}
+extern "C" {
+    pub fn av_codec_get_codec_properties(avctx: *const AVCodecContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn av_codec_get_lowres(avctx: *const AVCodecContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_codec_set_lowres(avctx: *mut AVCodecContext, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_codec_get_seek_preroll(avctx: *const AVCodecContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_codec_set_seek_preroll(avctx: *mut AVCodecContext, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_codec_get_chroma_intra_matrix(avctx: *const AVCodecContext) -> *mut u16;
+}
+extern "C" {
+    pub fn av_codec_set_chroma_intra_matrix(avctx: *mut AVCodecContext, val: *mut u16);
+}
+extern "C" {
+    pub fn av_codec_get_max_lowres(codec: *const AVCodec) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_codec_next(c: *const AVCodec) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn avcodec_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avcodec_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avcodec_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avcodec_register(codec: *mut AVCodec);
+}
+extern "C" {
+    pub fn avcodec_register_all();
+}
+extern "C" {
+    pub fn avcodec_alloc_context3(codec: *const AVCodec) -> *mut AVCodecContext;
+}
+extern "C" {
+    pub fn avcodec_free_context(avctx: *mut *mut AVCodecContext);
+}
+extern "C" {
+    pub fn avcodec_get_context_defaults3(
+        s: *mut AVCodecContext,
+        codec: *const AVCodec,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_get_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn avcodec_get_frame_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn avcodec_get_subtitle_rect_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn avcodec_copy_context(
+        dest: *mut AVCodecContext,
+        src: *const AVCodecContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_parameters_from_context(
+        par: *mut AVCodecParameters,
+        codec: *const AVCodecContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_parameters_to_context(
+        codec: *mut AVCodecContext,
+        par: *const AVCodecParameters,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_open2(
+        avctx: *mut AVCodecContext,
+        codec: *const AVCodec,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_close(avctx: *mut AVCodecContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avsubtitle_free(sub: *mut AVSubtitle);
+}
+extern "C" {
+    pub fn avcodec_default_get_buffer2(
+        s: *mut AVCodecContext,
+        frame: *mut AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_default_get_encode_buffer(
+        s: *mut AVCodecContext,
+        pkt: *mut AVPacket,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_align_dimensions(
+        s: *mut AVCodecContext,
+        width: *mut ::std::os::raw::c_int,
+        height: *mut ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn avcodec_align_dimensions2(
+        s: *mut AVCodecContext,
+        width: *mut ::std::os::raw::c_int,
+        height: *mut ::std::os::raw::c_int,
+        linesize_align: *mut ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn avcodec_enum_to_chroma_pos(
+        xpos: *mut ::std::os::raw::c_int,
+        ypos: *mut ::std::os::raw::c_int,
+        pos: AVChromaLocation,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_chroma_pos_to_enum(
+        xpos: ::std::os::raw::c_int,
+        ypos: ::std::os::raw::c_int,
+    ) -> AVChromaLocation;
+}
+extern "C" {
+    pub fn avcodec_decode_audio4(
+        avctx: *mut AVCodecContext,
+        frame: *mut AVFrame,
+        got_frame_ptr: *mut ::std::os::raw::c_int,
+        avpkt: *const AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_decode_video2(
+        avctx: *mut AVCodecContext,
+        picture: *mut AVFrame,
+        got_picture_ptr: *mut ::std::os::raw::c_int,
+        avpkt: *const AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_decode_subtitle2(
+        avctx: *mut AVCodecContext,
+        sub: *mut AVSubtitle,
+        got_sub_ptr: *mut ::std::os::raw::c_int,
+        avpkt: *mut AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_send_packet(
+        avctx: *mut AVCodecContext,
+        avpkt: *const AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_receive_frame(
+        avctx: *mut AVCodecContext,
+        frame: *mut AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_send_frame(
+        avctx: *mut AVCodecContext,
+        frame: *const AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_receive_packet(
+        avctx: *mut AVCodecContext,
+        avpkt: *mut AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_get_hw_frames_parameters(
+        avctx: *mut AVCodecContext,
+        device_ref: *mut AVBufferRef,
+        hw_pix_fmt: AVPixelFormat,
+        out_frames_ref: *mut *mut AVBufferRef,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_parser_iterate(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVCodecParser;
+}
+extern "C" {
+    pub fn av_parser_next(c: *const AVCodecParser) -> *mut AVCodecParser;
+}
+extern "C" {
+    pub fn av_register_codec_parser(parser: *mut AVCodecParser);
+}
+extern "C" {
+    pub fn av_parser_init(codec_id: ::std::os::raw::c_int) -> *mut AVCodecParserContext;
+}
+extern "C" {
+    pub fn av_parser_parse2(
+        s: *mut AVCodecParserContext,
+        avctx: *mut AVCodecContext,
+        poutbuf: *mut *mut u8,
+        poutbuf_size: *mut ::std::os::raw::c_int,
+        buf: *const u8,
+        buf_size: ::std::os::raw::c_int,
+        pts: i64,
+        dts: i64,
+        pos: i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_parser_change(
+        s: *mut AVCodecParserContext,
+        avctx: *mut AVCodecContext,
+        poutbuf: *mut *mut u8,
+        poutbuf_size: *mut ::std::os::raw::c_int,
+        buf: *const u8,
+        buf_size: ::std::os::raw::c_int,
+        keyframe: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_parser_close(s: *mut AVCodecParserContext);
+}
+extern "C" {
+    pub fn avcodec_encode_audio2(
+        avctx: *mut AVCodecContext,
+        avpkt: *mut AVPacket,
+        frame: *const AVFrame,
+        got_packet_ptr: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_encode_video2(
+        avctx: *mut AVCodecContext,
+        avpkt: *mut AVPacket,
+        frame: *const AVFrame,
+        got_packet_ptr: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_encode_subtitle(
+        avctx: *mut AVCodecContext,
+        buf: *mut u8,
+        buf_size: ::std::os::raw::c_int,
+        sub: *const AVSubtitle,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avpicture_alloc(
+        picture: *mut AVPicture,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avpicture_free(picture: *mut AVPicture);
+}
+extern "C" {
+    pub fn avpicture_fill(
+        picture: *mut AVPicture,
+        ptr: *const u8,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avpicture_layout(
+        src: *const AVPicture,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        dest: *mut ::std::os::raw::c_uchar,
+        dest_size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avpicture_get_size(
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_picture_copy(
+        dst: *mut AVPicture,
+        src: *const AVPicture,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_picture_crop(
+        dst: *mut AVPicture,
+        src: *const AVPicture,
+        pix_fmt: AVPixelFormat,
+        top_band: ::std::os::raw::c_int,
+        left_band: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_picture_pad(
+        dst: *mut AVPicture,
+        src: *const AVPicture,
+        height: ::std::os::raw::c_int,
+        width: ::std::os::raw::c_int,
+        pix_fmt: AVPixelFormat,
+        padtop: ::std::os::raw::c_int,
+        padbottom: ::std::os::raw::c_int,
+        padleft: ::std::os::raw::c_int,
+        padright: ::std::os::raw::c_int,
+        color: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_get_chroma_sub_sample(
+        pix_fmt: AVPixelFormat,
+        h_shift: *mut ::std::os::raw::c_int,
+        v_shift: *mut ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn avcodec_pix_fmt_to_codec_tag(pix_fmt: AVPixelFormat) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avcodec_find_best_pix_fmt_of_list(
+        pix_fmt_list: *const AVPixelFormat,
+        src_pix_fmt: AVPixelFormat,
+        has_alpha: ::std::os::raw::c_int,
+        loss_ptr: *mut ::std::os::raw::c_int,
+    ) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn avcodec_get_pix_fmt_loss(
+        dst_pix_fmt: AVPixelFormat,
+        src_pix_fmt: AVPixelFormat,
+        has_alpha: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_find_best_pix_fmt_of_2(
+        dst_pix_fmt1: AVPixelFormat,
+        dst_pix_fmt2: AVPixelFormat,
+        src_pix_fmt: AVPixelFormat,
+        has_alpha: ::std::os::raw::c_int,
+        loss_ptr: *mut ::std::os::raw::c_int,
+    ) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn avcodec_find_best_pix_fmt2(
+        dst_pix_fmt1: AVPixelFormat,
+        dst_pix_fmt2: AVPixelFormat,
+        src_pix_fmt: AVPixelFormat,
+        has_alpha: ::std::os::raw::c_int,
+        loss_ptr: *mut ::std::os::raw::c_int,
+    ) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn avcodec_default_get_format(
+        s: *mut AVCodecContext,
+        fmt: *const AVPixelFormat,
+    ) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn av_get_codec_tag_string(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: size_t,
+        codec_tag: ::std::os::raw::c_uint,
+    ) -> size_t;
+}
+extern "C" {
+    pub fn avcodec_string(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+        enc: *mut AVCodecContext,
+        encode: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_get_profile_name(
+        codec: *const AVCodec,
+        profile: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avcodec_profile_name(
+        codec_id: AVCodecID,
+        profile: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avcodec_default_execute(
+        c: *mut AVCodecContext,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(
+                c2: *mut AVCodecContext,
+                arg2: *mut ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+        arg: *mut ::std::os::raw::c_void,
+        ret: *mut ::std::os::raw::c_int,
+        count: ::std::os::raw::c_int,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_default_execute2(
+        c: *mut AVCodecContext,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(
+                c2: *mut AVCodecContext,
+                arg2: *mut ::std::os::raw::c_void,
+                arg3: ::std::os::raw::c_int,
+                arg4: ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+        arg: *mut ::std::os::raw::c_void,
+        ret: *mut ::std::os::raw::c_int,
+        count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_fill_audio_frame(
+        frame: *mut AVFrame,
+        nb_channels: ::std::os::raw::c_int,
+        sample_fmt: AVSampleFormat,
+        buf: *const u8,
+        buf_size: ::std::os::raw::c_int,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_flush_buffers(avctx: *mut AVCodecContext);
+}
+extern "C" {
+    pub fn av_get_bits_per_sample(codec_id: AVCodecID) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_pcm_codec(fmt: AVSampleFormat, be: ::std::os::raw::c_int) -> AVCodecID;
+}
+extern "C" {
+    pub fn av_get_exact_bits_per_sample(codec_id: AVCodecID) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_audio_frame_duration(
+        avctx: *mut AVCodecContext,
+        frame_bytes: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_audio_frame_duration2(
+        par: *mut AVCodecParameters,
+        frame_bytes: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_register_bitstream_filter(bsf: *mut AVBitStreamFilter);
+}
+extern "C" {
+    pub fn av_bitstream_filter_init(
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut AVBitStreamFilterContext;
+}
+extern "C" {
+    pub fn av_bitstream_filter_filter(
+        bsfc: *mut AVBitStreamFilterContext,
+        avctx: *mut AVCodecContext,
+        args: *const ::std::os::raw::c_char,
+        poutbuf: *mut *mut u8,
+        poutbuf_size: *mut ::std::os::raw::c_int,
+        buf: *const u8,
+        buf_size: ::std::os::raw::c_int,
+        keyframe: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_bitstream_filter_close(bsf: *mut AVBitStreamFilterContext);
+}
+extern "C" {
+    pub fn av_bitstream_filter_next(f: *const AVBitStreamFilter) -> *const AVBitStreamFilter;
+}
+extern "C" {
+    pub fn av_bsf_next(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVBitStreamFilter;
+}
+extern "C" {
+    pub fn av_fast_padded_malloc(
+        ptr: *mut ::std::os::raw::c_void,
+        size: *mut ::std::os::raw::c_uint,
+        min_size: size_t,
+    );
+}
+extern "C" {
+    pub fn av_fast_padded_mallocz(
+        ptr: *mut ::std::os::raw::c_void,
+        size: *mut ::std::os::raw::c_uint,
+        min_size: size_t,
+    );
+}
+extern "C" {
+    pub fn av_xiphlacing(
+        s: *mut ::std::os::raw::c_uchar,
+        v: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn av_register_hwaccel(hwaccel: *mut AVHWAccel);
+}
+extern "C" {
+    pub fn av_hwaccel_next(hwaccel: *const AVHWAccel) -> *mut AVHWAccel;
+}
+extern "C" {
+    pub fn av_lockmgr_register(
+        cb: ::std::option::Option<
+            unsafe extern "C" fn(
+                mutex: *mut *mut ::std::os::raw::c_void,
+                op: AVLockOp,
+            ) -> ::std::os::raw::c_int,
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avcodec_is_open(s: *mut AVCodecContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_cpb_properties_alloc(size: *mut size_t) -> *mut AVCPBProperties;
+}
+extern "C" {
+    pub fn av_opt_show2(
+        obj: *mut ::std::os::raw::c_void,
+        av_log_obj: *mut ::std::os::raw::c_void,
+        req_flags: ::std::os::raw::c_int,
+        rej_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_defaults(s: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn av_opt_set_defaults2(
+        s: *mut ::std::os::raw::c_void,
+        mask: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_set_options_string(
+        ctx: *mut ::std::os::raw::c_void,
+        opts: *const ::std::os::raw::c_char,
+        key_val_sep: *const ::std::os::raw::c_char,
+        pairs_sep: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_from_string(
+        ctx: *mut ::std::os::raw::c_void,
+        opts: *const ::std::os::raw::c_char,
+        shorthand: *const *const ::std::os::raw::c_char,
+        key_val_sep: *const ::std::os::raw::c_char,
+        pairs_sep: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_free(obj: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn av_opt_flag_is_set(
+        obj: *mut ::std::os::raw::c_void,
+        field_name: *const ::std::os::raw::c_char,
+        flag_name: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_dict(
+        obj: *mut ::std::os::raw::c_void,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_dict2(
+        obj: *mut ::std::os::raw::c_void,
+        options: *mut *mut AVDictionary,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_key_value(
+        ropts: *mut *const ::std::os::raw::c_char,
+        key_val_sep: *const ::std::os::raw::c_char,
+        pairs_sep: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_uint,
+        rkey: *mut *mut ::std::os::raw::c_char,
+        rval: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_eval_flags(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+        val: *const ::std::os::raw::c_char,
+        flags_out: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_eval_int(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+        val: *const ::std::os::raw::c_char,
+        int_out: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_eval_int64(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+        val: *const ::std::os::raw::c_char,
+        int64_out: *mut i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_eval_float(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+        val: *const ::std::os::raw::c_char,
+        float_out: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_eval_double(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+        val: *const ::std::os::raw::c_char,
+        double_out: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_eval_q(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+        val: *const ::std::os::raw::c_char,
+        q_out: *mut AVRational,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_find(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        unit: *const ::std::os::raw::c_char,
+        opt_flags: ::std::os::raw::c_int,
+        search_flags: ::std::os::raw::c_int,
+    ) -> *const AVOption;
+}
+extern "C" {
+    pub fn av_opt_find2(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        unit: *const ::std::os::raw::c_char,
+        opt_flags: ::std::os::raw::c_int,
+        search_flags: ::std::os::raw::c_int,
+        target_obj: *mut *mut ::std::os::raw::c_void,
+    ) -> *const AVOption;
+}
+extern "C" {
+    pub fn av_opt_next(
+        obj: *const ::std::os::raw::c_void,
+        prev: *const AVOption,
+    ) -> *const AVOption;
+}
+extern "C" {
+    pub fn av_opt_child_next(
+        obj: *mut ::std::os::raw::c_void,
+        prev: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_opt_child_class_next(parent: *const AVClass, prev: *const AVClass) -> *const AVClass;
+}
+extern "C" {
+    pub fn av_opt_child_class_iterate(
+        parent: *const AVClass,
+        iter: *mut *mut ::std::os::raw::c_void,
+    ) -> *const AVClass;
+}
+extern "C" {
+    pub fn av_opt_set(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_int(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: i64,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_double(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: f64,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_q(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: AVRational,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_bin(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: *const u8,
+        size: ::std::os::raw::c_int,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_image_size(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_pixel_fmt(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        fmt: AVPixelFormat,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_sample_fmt(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        fmt: AVSampleFormat,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_video_rate(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: AVRational,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_channel_layout(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        ch_layout: i64,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_set_dict_val(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        val: *const AVDictionary,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_val: *mut *mut u8,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_int(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_val: *mut i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_double(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_val: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_q(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_val: *mut AVRational,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_image_size(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        w_out: *mut ::std::os::raw::c_int,
+        h_out: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_pixel_fmt(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_fmt: *mut AVPixelFormat,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_sample_fmt(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_fmt: *mut AVSampleFormat,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_video_rate(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_val: *mut AVRational,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_channel_layout(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        ch_layout: *mut i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_get_dict_val(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+        out_val: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_ptr(
+        avclass: *const AVClass,
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_opt_freep_ranges(ranges: *mut *mut AVOptionRanges);
+}
+extern "C" {
+    pub fn av_opt_query_ranges(
+        arg1: *mut *mut AVOptionRanges,
+        obj: *mut ::std::os::raw::c_void,
+        key: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_copy(
+        dest: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_query_ranges_default(
+        arg1: *mut *mut AVOptionRanges,
+        obj: *mut ::std::os::raw::c_void,
+        key: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_is_set_to_default(
+        obj: *mut ::std::os::raw::c_void,
+        o: *const AVOption,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_is_set_to_default_by_name(
+        obj: *mut ::std::os::raw::c_void,
+        name: *const ::std::os::raw::c_char,
+        search_flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_opt_serialize(
+        obj: *mut ::std::os::raw::c_void,
+        opt_flags: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_int,
+        buffer: *mut *mut ::std::os::raw::c_char,
+        key_val_sep: ::std::os::raw::c_char,
+        pairs_sep: ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_bits_per_pixel(pixdesc: *const AVPixFmtDescriptor) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_padded_bits_per_pixel(
+        pixdesc: *const AVPixFmtDescriptor,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_pix_fmt_desc_get(pix_fmt: AVPixelFormat) -> *const AVPixFmtDescriptor;
+}
+extern "C" {
+    pub fn av_pix_fmt_desc_next(prev: *const AVPixFmtDescriptor) -> *const AVPixFmtDescriptor;
+}
+extern "C" {
+    pub fn av_pix_fmt_desc_get_id(desc: *const AVPixFmtDescriptor) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn av_pix_fmt_get_chroma_sub_sample(
+        pix_fmt: AVPixelFormat,
+        h_shift: *mut ::std::os::raw::c_int,
+        v_shift: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_pix_fmt_count_planes(pix_fmt: AVPixelFormat) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_color_range_name(range: AVColorRange) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_color_range_from_name(name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_color_primaries_name(primaries: AVColorPrimaries) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_color_primaries_from_name(
+        name: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_color_transfer_name(
+        transfer: AVColorTransferCharacteristic,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_color_transfer_from_name(
+        name: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_color_space_name(space: AVColorSpace) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_color_space_from_name(name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_chroma_location_name(location: AVChromaLocation) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_chroma_location_from_name(
+        name: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_pix_fmt(name: *const ::std::os::raw::c_char) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn av_get_pix_fmt_name(pix_fmt: AVPixelFormat) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_get_pix_fmt_string(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+        pix_fmt: AVPixelFormat,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_read_image_line2(
+        dst: *mut ::std::os::raw::c_void,
+        data: *mut *const u8,
+        linesize: *const ::std::os::raw::c_int,
+        desc: *const AVPixFmtDescriptor,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        read_pal_component: ::std::os::raw::c_int,
+        dst_element_size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_read_image_line(
+        dst: *mut u16,
+        data: *mut *const u8,
+        linesize: *const ::std::os::raw::c_int,
+        desc: *const AVPixFmtDescriptor,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        read_pal_component: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_write_image_line2(
+        src: *const ::std::os::raw::c_void,
+        data: *mut *mut u8,
+        linesize: *const ::std::os::raw::c_int,
+        desc: *const AVPixFmtDescriptor,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        src_element_size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_write_image_line(
+        src: *const u16,
+        data: *mut *mut u8,
+        linesize: *const ::std::os::raw::c_int,
+        desc: *const AVPixFmtDescriptor,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_pix_fmt_swap_endianness(pix_fmt: AVPixelFormat) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn av_get_pix_fmt_loss(
+        dst_pix_fmt: AVPixelFormat,
+        src_pix_fmt: AVPixelFormat,
+        has_alpha: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_find_best_pix_fmt_of_2(
+        dst_pix_fmt1: AVPixelFormat,
+        dst_pix_fmt2: AVPixelFormat,
+        src_pix_fmt: AVPixelFormat,
+        has_alpha: ::std::os::raw::c_int,
+        loss_ptr: *mut ::std::os::raw::c_int,
+    ) -> AVPixelFormat;
+}
+extern "C" {
+    pub fn av_image_fill_max_pixsteps(
+        max_pixsteps: *mut ::std::os::raw::c_int,
+        max_pixstep_comps: *mut ::std::os::raw::c_int,
+        pixdesc: *const AVPixFmtDescriptor,
+    );
+}
+extern "C" {
+    pub fn av_image_get_linesize(
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        plane: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_fill_linesizes(
+        linesizes: *mut ::std::os::raw::c_int,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_fill_plane_sizes(
+        size: *mut size_t,
+        pix_fmt: AVPixelFormat,
+        height: ::std::os::raw::c_int,
+        linesizes: *const isize,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_fill_pointers(
+        data: *mut *mut u8,
+        pix_fmt: AVPixelFormat,
+        height: ::std::os::raw::c_int,
+        ptr: *mut u8,
+        linesizes: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_alloc(
+        pointers: *mut *mut u8,
+        linesizes: *mut ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        pix_fmt: AVPixelFormat,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_copy_plane(
+        dst: *mut u8,
+        dst_linesize: ::std::os::raw::c_int,
+        src: *const u8,
+        src_linesize: ::std::os::raw::c_int,
+        bytewidth: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_image_copy(
+        dst_data: *mut *mut u8,
+        dst_linesizes: *mut ::std::os::raw::c_int,
+        src_data: *mut *const u8,
+        src_linesizes: *const ::std::os::raw::c_int,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_image_copy_uc_from(
+        dst_data: *mut *mut u8,
+        dst_linesizes: *const isize,
+        src_data: *mut *const u8,
+        src_linesizes: *const isize,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_image_fill_arrays(
+        dst_data: *mut *mut u8,
+        dst_linesize: *mut ::std::os::raw::c_int,
+        src: *const u8,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_get_buffer_size(
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_copy_to_buffer(
+        dst: *mut u8,
+        dst_size: ::std::os::raw::c_int,
+        src_data: *const *const u8,
+        src_linesize: *const ::std::os::raw::c_int,
+        pix_fmt: AVPixelFormat,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        align: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_check_size(
+        w: ::std::os::raw::c_uint,
+        h: ::std::os::raw::c_uint,
+        log_offset: ::std::os::raw::c_int,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_check_size2(
+        w: ::std::os::raw::c_uint,
+        h: ::std::os::raw::c_uint,
+        max_pixels: i64,
+        pix_fmt: AVPixelFormat,
+        log_offset: ::std::os::raw::c_int,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_check_sar(
+        w: ::std::os::raw::c_uint,
+        h: ::std::os::raw::c_uint,
+        sar: AVRational,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_image_fill_black(
+        dst_data: *mut *mut u8,
+        dst_linesize: *const isize,
+        pix_fmt: AVPixelFormat,
+        range: AVColorRange,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_alloc(size: ::std::os::raw::c_uint) -> *mut AVFifoBuffer;
+}
+extern "C" {
+    pub fn av_fifo_alloc_array(nmemb: size_t, size: size_t) -> *mut AVFifoBuffer;
+}
+extern "C" {
+    pub fn av_fifo_free(f: *mut AVFifoBuffer);
+}
+extern "C" {
+    pub fn av_fifo_freep(f: *mut *mut AVFifoBuffer);
+}
+extern "C" {
+    pub fn av_fifo_reset(f: *mut AVFifoBuffer);
+}
+extern "C" {
+    pub fn av_fifo_size(f: *const AVFifoBuffer) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_space(f: *const AVFifoBuffer) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_generic_peek_at(
+        f: *mut AVFifoBuffer,
+        dest: *mut ::std::os::raw::c_void,
+        offset: ::std::os::raw::c_int,
+        buf_size: ::std::os::raw::c_int,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: *mut ::std::os::raw::c_void,
+                arg3: ::std::os::raw::c_int,
+            ),
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_generic_peek(
+        f: *mut AVFifoBuffer,
+        dest: *mut ::std::os::raw::c_void,
+        buf_size: ::std::os::raw::c_int,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: *mut ::std::os::raw::c_void,
+                arg3: ::std::os::raw::c_int,
+            ),
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_generic_read(
+        f: *mut AVFifoBuffer,
+        dest: *mut ::std::os::raw::c_void,
+        buf_size: ::std::os::raw::c_int,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: *mut ::std::os::raw::c_void,
+                arg3: ::std::os::raw::c_int,
+            ),
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_generic_write(
+        f: *mut AVFifoBuffer,
+        src: *mut ::std::os::raw::c_void,
+        size: ::std::os::raw::c_int,
+        func: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                arg2: *mut ::std::os::raw::c_void,
+                arg3: ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_realloc2(
+        f: *mut AVFifoBuffer,
+        size: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_grow(
+        f: *mut AVFifoBuffer,
+        additional_space: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_fifo_drain(f: *mut AVFifoBuffer, size: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_audio_fifo_free(af: *mut AVAudioFifo);
+}
+extern "C" {
+    pub fn av_audio_fifo_alloc(
+        sample_fmt: AVSampleFormat,
+        channels: ::std::os::raw::c_int,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> *mut AVAudioFifo;
+}
+extern "C" {
+    pub fn av_audio_fifo_realloc(
+        af: *mut AVAudioFifo,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_write(
+        af: *mut AVAudioFifo,
+        data: *mut *mut ::std::os::raw::c_void,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_peek(
+        af: *mut AVAudioFifo,
+        data: *mut *mut ::std::os::raw::c_void,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_peek_at(
+        af: *mut AVAudioFifo,
+        data: *mut *mut ::std::os::raw::c_void,
+        nb_samples: ::std::os::raw::c_int,
+        offset: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_read(
+        af: *mut AVAudioFifo,
+        data: *mut *mut ::std::os::raw::c_void,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_drain(
+        af: *mut AVAudioFifo,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_reset(af: *mut AVAudioFifo);
+}
+extern "C" {
+    pub fn av_audio_fifo_size(af: *mut AVAudioFifo) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_audio_fifo_space(af: *mut AVAudioFifo) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_find_protocol_name(
+        url: *const ::std::os::raw::c_char,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avio_check(
+        url: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avpriv_io_move(
+        url_src: *const ::std::os::raw::c_char,
+        url_dst: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avpriv_io_delete(url: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_open_dir(
+        s: *mut *mut AVIODirContext,
+        url: *const ::std::os::raw::c_char,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_read_dir(
+        s: *mut AVIODirContext,
+        next: *mut *mut AVIODirEntry,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_close_dir(s: *mut *mut AVIODirContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_free_directory_entry(entry: *mut *mut AVIODirEntry);
+}
+extern "C" {
+    pub fn avio_alloc_context(
+        buffer: *mut ::std::os::raw::c_uchar,
+        buffer_size: ::std::os::raw::c_int,
+        write_flag: ::std::os::raw::c_int,
+        opaque: *mut ::std::os::raw::c_void,
+        read_packet: ::std::option::Option<
+            unsafe extern "C" fn(
+                opaque: *mut ::std::os::raw::c_void,
+                buf: *mut u8,
+                buf_size: ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+        write_packet: ::std::option::Option<
+            unsafe extern "C" fn(
+                opaque: *mut ::std::os::raw::c_void,
+                buf: *mut u8,
+                buf_size: ::std::os::raw::c_int,
+            ) -> ::std::os::raw::c_int,
+        >,
+        seek: ::std::option::Option<
+            unsafe extern "C" fn(
+                opaque: *mut ::std::os::raw::c_void,
+                offset: i64,
+                whence: ::std::os::raw::c_int,
+            ) -> i64,
+        >,
+    ) -> *mut AVIOContext;
+}
+extern "C" {
+    pub fn avio_context_free(s: *mut *mut AVIOContext);
+}
+extern "C" {
+    pub fn avio_w8(s: *mut AVIOContext, b: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn avio_write(
+        s: *mut AVIOContext,
+        buf: *const ::std::os::raw::c_uchar,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn avio_wl64(s: *mut AVIOContext, val: u64);
+}
+extern "C" {
+    pub fn avio_wb64(s: *mut AVIOContext, val: u64);
+}
+extern "C" {
+    pub fn avio_wl32(s: *mut AVIOContext, val: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn avio_wb32(s: *mut AVIOContext, val: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn avio_wl24(s: *mut AVIOContext, val: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn avio_wb24(s: *mut AVIOContext, val: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn avio_wl16(s: *mut AVIOContext, val: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn avio_wb16(s: *mut AVIOContext, val: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn avio_put_str(
+        s: *mut AVIOContext,
+        str_: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_put_str16le(
+        s: *mut AVIOContext,
+        str_: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_put_str16be(
+        s: *mut AVIOContext,
+        str_: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_write_marker(s: *mut AVIOContext, time: i64, type_: AVIODataMarkerType);
+}
+extern "C" {
+    pub fn avio_seek(s: *mut AVIOContext, offset: i64, whence: ::std::os::raw::c_int) -> i64;
+}
+extern "C" {
+    pub fn avio_skip(s: *mut AVIOContext, offset: i64) -> i64;
+}
+extern "C" {
+    pub fn avio_size(s: *mut AVIOContext) -> i64;
+}
+extern "C" {
+    pub fn avio_feof(s: *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_printf(
+        s: *mut AVIOContext,
+        fmt: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_print_string_array(
+        s: *mut AVIOContext,
+        strings: *mut *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn avio_flush(s: *mut AVIOContext);
+}
+extern "C" {
+    pub fn avio_read(
+        s: *mut AVIOContext,
+        buf: *mut ::std::os::raw::c_uchar,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_read_partial(
+        s: *mut AVIOContext,
+        buf: *mut ::std::os::raw::c_uchar,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_r8(s: *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_rl16(s: *mut AVIOContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avio_rl24(s: *mut AVIOContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avio_rl32(s: *mut AVIOContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avio_rl64(s: *mut AVIOContext) -> u64;
+}
+extern "C" {
+    pub fn avio_rb16(s: *mut AVIOContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avio_rb24(s: *mut AVIOContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avio_rb32(s: *mut AVIOContext) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avio_rb64(s: *mut AVIOContext) -> u64;
+}
+extern "C" {
+    pub fn avio_get_str(
+        pb: *mut AVIOContext,
+        maxlen: ::std::os::raw::c_int,
+        buf: *mut ::std::os::raw::c_char,
+        buflen: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_get_str16le(
+        pb: *mut AVIOContext,
+        maxlen: ::std::os::raw::c_int,
+        buf: *mut ::std::os::raw::c_char,
+        buflen: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_get_str16be(
+        pb: *mut AVIOContext,
+        maxlen: ::std::os::raw::c_int,
+        buf: *mut ::std::os::raw::c_char,
+        buflen: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_open(
+        s: *mut *mut AVIOContext,
+        url: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_open2(
+        s: *mut *mut AVIOContext,
+        url: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+        int_cb: *const AVIOInterruptCB,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_close(s: *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_closep(s: *mut *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_open_dyn_buf(s: *mut *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_get_dyn_buf(s: *mut AVIOContext, pbuffer: *mut *mut u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_close_dyn_buf(s: *mut AVIOContext, pbuffer: *mut *mut u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_enum_protocols(
+        opaque: *mut *mut ::std::os::raw::c_void,
+        output: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avio_protocol_get_class(name: *const ::std::os::raw::c_char) -> *const AVClass;
+}
+extern "C" {
+    pub fn avio_pause(h: *mut AVIOContext, pause: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_seek_time(
+        h: *mut AVIOContext,
+        stream_index: ::std::os::raw::c_int,
+        timestamp: i64,
+        flags: ::std::os::raw::c_int,
+    ) -> i64;
+}
+extern "C" {
+    pub fn avio_read_to_bprint(
+        h: *mut AVIOContext,
+        pb: *mut AVBPrint,
+        max_size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_accept(s: *mut AVIOContext, c: *mut *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avio_handshake(c: *mut AVIOContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_packet(
+        s: *mut AVIOContext,
+        pkt: *mut AVPacket,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_append_packet(
+        s: *mut AVIOContext,
+        pkt: *mut AVPacket,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_stream_get_r_frame_rate(s: *const AVStream) -> AVRational;
+}
+extern "C" {
+    pub fn av_stream_set_r_frame_rate(s: *mut AVStream, r: AVRational);
+}
+extern "C" {
+    pub fn av_stream_get_recommended_encoder_configuration(
+        s: *const AVStream,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_stream_set_recommended_encoder_configuration(
+        s: *mut AVStream,
+        configuration: *mut ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn av_stream_get_parser(s: *const AVStream) -> *mut AVCodecParserContext;
+}
+extern "C" {
+    pub fn av_stream_get_end_pts(st: *const AVStream) -> i64;
+}
+extern "C" {
+    pub fn av_format_get_probe_score(s: *const AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_format_get_video_codec(s: *const AVFormatContext) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn av_format_set_video_codec(s: *mut AVFormatContext, c: *mut AVCodec);
+}
+extern "C" {
+    pub fn av_format_get_audio_codec(s: *const AVFormatContext) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn av_format_set_audio_codec(s: *mut AVFormatContext, c: *mut AVCodec);
+}
+extern "C" {
+    pub fn av_format_get_subtitle_codec(s: *const AVFormatContext) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn av_format_set_subtitle_codec(s: *mut AVFormatContext, c: *mut AVCodec);
+}
+extern "C" {
+    pub fn av_format_get_data_codec(s: *const AVFormatContext) -> *mut AVCodec;
+}
+extern "C" {
+    pub fn av_format_set_data_codec(s: *mut AVFormatContext, c: *mut AVCodec);
+}
+extern "C" {
+    pub fn av_format_get_metadata_header_padding(
+        s: *const AVFormatContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_format_set_metadata_header_padding(s: *mut AVFormatContext, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_format_get_opaque(s: *const AVFormatContext) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn av_format_set_opaque(s: *mut AVFormatContext, opaque: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn av_format_get_control_message_cb(s: *const AVFormatContext)
+        -> av_format_control_message;
+}
+extern "C" {
+    pub fn av_format_set_control_message_cb(
+        s: *mut AVFormatContext,
+        callback: av_format_control_message,
+    );
+}
+extern "C" {
+    pub fn av_format_get_open_cb(s: *const AVFormatContext) -> AVOpenCallback;
+}
+extern "C" {
+    pub fn av_format_set_open_cb(s: *mut AVFormatContext, callback: AVOpenCallback);
+}
+extern "C" {
+    pub fn av_format_inject_global_side_data(s: *mut AVFormatContext);
+}
+extern "C" {
+    pub fn av_fmt_ctx_get_duration_estimation_method(
+        ctx: *const AVFormatContext,
+    ) -> AVDurationEstimationMethod;
+}
+extern "C" {
+    pub fn avformat_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avformat_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avformat_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn av_register_all();
+}
+extern "C" {
+    pub fn av_register_input_format(format: *mut AVInputFormat);
+}
+extern "C" {
+    pub fn av_register_output_format(format: *mut AVOutputFormat);
+}
+extern "C" {
+    pub fn avformat_network_init() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_network_deinit() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_iformat_next(f: *const AVInputFormat) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_oformat_next(f: *const AVOutputFormat) -> *mut AVOutputFormat;
+}
+extern "C" {
+    pub fn av_muxer_iterate(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVOutputFormat;
+}
+extern "C" {
+    pub fn av_demuxer_iterate(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVInputFormat;
+}
+extern "C" {
+    pub fn avformat_alloc_context() -> *mut AVFormatContext;
+}
+extern "C" {
+    pub fn avformat_free_context(s: *mut AVFormatContext);
+}
+extern "C" {
+    pub fn avformat_get_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn avformat_new_stream(s: *mut AVFormatContext, c: *const AVCodec) -> *mut AVStream;
+}
+extern "C" {
+    pub fn av_stream_add_side_data(
+        st: *mut AVStream,
+        type_: AVPacketSideDataType,
+        data: *mut u8,
+        size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_stream_new_side_data(
+        stream: *mut AVStream,
+        type_: AVPacketSideDataType,
+        size: ::std::os::raw::c_int,
+    ) -> *mut u8;
+}
+extern "C" {
+    pub fn av_stream_get_side_data(
+        stream: *const AVStream,
+        type_: AVPacketSideDataType,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *mut u8;
+}
+extern "C" {
+    pub fn av_new_program(s: *mut AVFormatContext, id: ::std::os::raw::c_int) -> *mut AVProgram;
+}
+extern "C" {
+    pub fn avformat_alloc_output_context2(
+        ctx: *mut *mut AVFormatContext,
+        oformat: *mut AVOutputFormat,
+        format_name: *const ::std::os::raw::c_char,
+        filename: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_find_input_format(short_name: *const ::std::os::raw::c_char) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_probe_input_format(
+        pd: *mut AVProbeData,
+        is_opened: ::std::os::raw::c_int,
+    ) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_probe_input_format2(
+        pd: *mut AVProbeData,
+        is_opened: ::std::os::raw::c_int,
+        score_max: *mut ::std::os::raw::c_int,
+    ) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_probe_input_format3(
+        pd: *mut AVProbeData,
+        is_opened: ::std::os::raw::c_int,
+        score_ret: *mut ::std::os::raw::c_int,
+    ) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_probe_input_buffer2(
+        pb: *mut AVIOContext,
+        fmt: *mut *mut AVInputFormat,
+        url: *const ::std::os::raw::c_char,
+        logctx: *mut ::std::os::raw::c_void,
+        offset: ::std::os::raw::c_uint,
+        max_probe_size: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_probe_input_buffer(
+        pb: *mut AVIOContext,
+        fmt: *mut *mut AVInputFormat,
+        url: *const ::std::os::raw::c_char,
+        logctx: *mut ::std::os::raw::c_void,
+        offset: ::std::os::raw::c_uint,
+        max_probe_size: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_open_input(
+        ps: *mut *mut AVFormatContext,
+        url: *const ::std::os::raw::c_char,
+        fmt: *mut AVInputFormat,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_demuxer_open(ic: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_find_stream_info(
+        ic: *mut AVFormatContext,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_find_program_from_stream(
+        ic: *mut AVFormatContext,
+        last: *mut AVProgram,
+        s: ::std::os::raw::c_int,
+    ) -> *mut AVProgram;
+}
+extern "C" {
+    pub fn av_program_add_stream_index(
+        ac: *mut AVFormatContext,
+        progid: ::std::os::raw::c_int,
+        idx: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn av_find_best_stream(
+        ic: *mut AVFormatContext,
+        type_: AVMediaType,
+        wanted_stream_nb: ::std::os::raw::c_int,
+        related_stream: ::std::os::raw::c_int,
+        decoder_ret: *mut *mut AVCodec,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_read_frame(s: *mut AVFormatContext, pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_seek_frame(
+        s: *mut AVFormatContext,
+        stream_index: ::std::os::raw::c_int,
+        timestamp: i64,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_seek_file(
+        s: *mut AVFormatContext,
+        stream_index: ::std::os::raw::c_int,
+        min_ts: i64,
+        ts: i64,
+        max_ts: i64,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_flush(s: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_read_play(s: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_read_pause(s: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_close_input(s: *mut *mut AVFormatContext);
+}
+extern "C" {
+    pub fn avformat_write_header(
+        s: *mut AVFormatContext,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_init_output(
+        s: *mut AVFormatContext,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_write_frame(s: *mut AVFormatContext, pkt: *mut AVPacket) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_interleaved_write_frame(
+        s: *mut AVFormatContext,
+        pkt: *mut AVPacket,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_write_uncoded_frame(
+        s: *mut AVFormatContext,
+        stream_index: ::std::os::raw::c_int,
+        frame: *mut AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_interleaved_write_uncoded_frame(
+        s: *mut AVFormatContext,
+        stream_index: ::std::os::raw::c_int,
+        frame: *mut AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_write_uncoded_frame_query(
+        s: *mut AVFormatContext,
+        stream_index: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_write_trailer(s: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_guess_format(
+        short_name: *const ::std::os::raw::c_char,
+        filename: *const ::std::os::raw::c_char,
+        mime_type: *const ::std::os::raw::c_char,
+    ) -> *mut AVOutputFormat;
+}
+extern "C" {
+    pub fn av_guess_codec(
+        fmt: *mut AVOutputFormat,
+        short_name: *const ::std::os::raw::c_char,
+        filename: *const ::std::os::raw::c_char,
+        mime_type: *const ::std::os::raw::c_char,
+        type_: AVMediaType,
+    ) -> AVCodecID;
+}
+extern "C" {
+    pub fn av_get_output_timestamp(
+        s: *mut AVFormatContext,
+        stream: ::std::os::raw::c_int,
+        dts: *mut i64,
+        wall: *mut i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_hex_dump(f: *mut FILE, buf: *const u8, size: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn av_hex_dump_log(
+        avcl: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        buf: *const u8,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_pkt_dump2(
+        f: *mut FILE,
+        pkt: *const AVPacket,
+        dump_payload: ::std::os::raw::c_int,
+        st: *const AVStream,
+    );
+}
+extern "C" {
+    pub fn av_pkt_dump_log2(
+        avcl: *mut ::std::os::raw::c_void,
+        level: ::std::os::raw::c_int,
+        pkt: *const AVPacket,
+        dump_payload: ::std::os::raw::c_int,
+        st: *const AVStream,
+    );
+}
+extern "C" {
+    pub fn av_codec_get_id(
+        tags: *const *const AVCodecTag,
+        tag: ::std::os::raw::c_uint,
+    ) -> AVCodecID;
+}
+extern "C" {
+    pub fn av_codec_get_tag(
+        tags: *const *const AVCodecTag,
+        id: AVCodecID,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn av_codec_get_tag2(
+        tags: *const *const AVCodecTag,
+        id: AVCodecID,
+        tag: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_find_default_stream_index(s: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_index_search_timestamp(
+        st: *mut AVStream,
+        timestamp: i64,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_add_index_entry(
+        st: *mut AVStream,
+        pos: i64,
+        timestamp: i64,
+        size: ::std::os::raw::c_int,
+        distance: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_url_split(
+        proto: *mut ::std::os::raw::c_char,
+        proto_size: ::std::os::raw::c_int,
+        authorization: *mut ::std::os::raw::c_char,
+        authorization_size: ::std::os::raw::c_int,
+        hostname: *mut ::std::os::raw::c_char,
+        hostname_size: ::std::os::raw::c_int,
+        port_ptr: *mut ::std::os::raw::c_int,
+        path: *mut ::std::os::raw::c_char,
+        path_size: ::std::os::raw::c_int,
+        url: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn av_dump_format(
+        ic: *mut AVFormatContext,
+        index: ::std::os::raw::c_int,
+        url: *const ::std::os::raw::c_char,
+        is_output: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn av_get_frame_filename2(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+        path: *const ::std::os::raw::c_char,
+        number: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_get_frame_filename(
+        buf: *mut ::std::os::raw::c_char,
+        buf_size: ::std::os::raw::c_int,
+        path: *const ::std::os::raw::c_char,
+        number: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_filename_number_test(
+        filename: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_sdp_create(
+        ac: *mut *mut AVFormatContext,
+        n_files: ::std::os::raw::c_int,
+        buf: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_match_ext(
+        filename: *const ::std::os::raw::c_char,
+        extensions: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_query_codec(
+        ofmt: *const AVOutputFormat,
+        codec_id: AVCodecID,
+        std_compliance: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_get_riff_video_tags() -> *const AVCodecTag;
+}
+extern "C" {
+    pub fn avformat_get_riff_audio_tags() -> *const AVCodecTag;
+}
+extern "C" {
+    pub fn avformat_get_mov_video_tags() -> *const AVCodecTag;
+}
+extern "C" {
+    pub fn avformat_get_mov_audio_tags() -> *const AVCodecTag;
+}
+extern "C" {
+    pub fn av_guess_sample_aspect_ratio(
+        format: *mut AVFormatContext,
+        stream: *mut AVStream,
+        frame: *mut AVFrame,
+    ) -> AVRational;
+}
+extern "C" {
+    pub fn av_guess_frame_rate(
+        ctx: *mut AVFormatContext,
+        stream: *mut AVStream,
+        frame: *mut AVFrame,
+    ) -> AVRational;
+}
+extern "C" {
+    pub fn avformat_match_stream_specifier(
+        s: *mut AVFormatContext,
+        st: *mut AVStream,
+        spec: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_queue_attached_pictures(s: *mut AVFormatContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_apply_bitstream_filters(
+        codec: *mut AVCodecContext,
+        pkt: *mut AVPacket,
+        bsfc: *mut AVBitStreamFilterContext,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avformat_transfer_internal_stream_timing_info(
+        ofmt: *const AVOutputFormat,
+        ost: *mut AVStream,
+        ist: *const AVStream,
+        copy_tb: AVTimebaseSource,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_stream_get_codec_timebase(st: *const AVStream) -> AVRational;
+}
+extern "C" {
+    pub fn avfilter_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avfilter_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avfilter_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avfilter_pad_count(pads: *const AVFilterPad) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_pad_get_name(
+        pads: *const AVFilterPad,
+        pad_idx: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avfilter_pad_get_type(
+        pads: *const AVFilterPad,
+        pad_idx: ::std::os::raw::c_int,
+    ) -> AVMediaType;
+}
+extern "C" {
+    pub fn avfilter_link(
+        src: *mut AVFilterContext,
+        srcpad: ::std::os::raw::c_uint,
+        dst: *mut AVFilterContext,
+        dstpad: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_link_free(link: *mut *mut AVFilterLink);
+}
+extern "C" {
+    pub fn avfilter_link_get_channels(link: *mut AVFilterLink) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_link_set_closed(link: *mut AVFilterLink, closed: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn avfilter_config_links(filter: *mut AVFilterContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_process_command(
+        filter: *mut AVFilterContext,
+        cmd: *const ::std::os::raw::c_char,
+        arg: *const ::std::os::raw::c_char,
+        res: *mut ::std::os::raw::c_char,
+        res_len: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_filter_iterate(opaque: *mut *mut ::std::os::raw::c_void) -> *const AVFilter;
+}
+extern "C" {
+    pub fn avfilter_register_all();
+}
+extern "C" {
+    pub fn avfilter_register(filter: *mut AVFilter) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_next(prev: *const AVFilter) -> *const AVFilter;
+}
+extern "C" {
+    pub fn avfilter_get_by_name(name: *const ::std::os::raw::c_char) -> *const AVFilter;
+}
+extern "C" {
+    pub fn avfilter_init_str(
+        ctx: *mut AVFilterContext,
+        args: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_init_dict(
+        ctx: *mut AVFilterContext,
+        options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_free(filter: *mut AVFilterContext);
+}
+extern "C" {
+    pub fn avfilter_insert_filter(
+        link: *mut AVFilterLink,
+        filt: *mut AVFilterContext,
+        filt_srcpad_idx: ::std::os::raw::c_uint,
+        filt_dstpad_idx: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_get_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn avfilter_graph_alloc() -> *mut AVFilterGraph;
+}
+extern "C" {
+    pub fn avfilter_graph_alloc_filter(
+        graph: *mut AVFilterGraph,
+        filter: *const AVFilter,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut AVFilterContext;
+}
+extern "C" {
+    pub fn avfilter_graph_get_filter(
+        graph: *mut AVFilterGraph,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut AVFilterContext;
+}
+extern "C" {
+    pub fn avfilter_graph_create_filter(
+        filt_ctx: *mut *mut AVFilterContext,
+        filt: *const AVFilter,
+        name: *const ::std::os::raw::c_char,
+        args: *const ::std::os::raw::c_char,
+        opaque: *mut ::std::os::raw::c_void,
+        graph_ctx: *mut AVFilterGraph,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_set_auto_convert(
+        graph: *mut AVFilterGraph,
+        flags: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn avfilter_graph_config(
+        graphctx: *mut AVFilterGraph,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_free(graph: *mut *mut AVFilterGraph);
+}
+extern "C" {
+    pub fn avfilter_inout_alloc() -> *mut AVFilterInOut;
+}
+extern "C" {
+    pub fn avfilter_inout_free(inout: *mut *mut AVFilterInOut);
+}
+extern "C" {
+    pub fn avfilter_graph_parse(
+        graph: *mut AVFilterGraph,
+        filters: *const ::std::os::raw::c_char,
+        inputs: *mut AVFilterInOut,
+        outputs: *mut AVFilterInOut,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_parse_ptr(
+        graph: *mut AVFilterGraph,
+        filters: *const ::std::os::raw::c_char,
+        inputs: *mut *mut AVFilterInOut,
+        outputs: *mut *mut AVFilterInOut,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_parse2(
+        graph: *mut AVFilterGraph,
+        filters: *const ::std::os::raw::c_char,
+        inputs: *mut *mut AVFilterInOut,
+        outputs: *mut *mut AVFilterInOut,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_send_command(
+        graph: *mut AVFilterGraph,
+        target: *const ::std::os::raw::c_char,
+        cmd: *const ::std::os::raw::c_char,
+        arg: *const ::std::os::raw::c_char,
+        res: *mut ::std::os::raw::c_char,
+        res_len: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_queue_command(
+        graph: *mut AVFilterGraph,
+        target: *const ::std::os::raw::c_char,
+        cmd: *const ::std::os::raw::c_char,
+        arg: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+        ts: f64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avfilter_graph_dump(
+        graph: *mut AVFilterGraph,
+        options: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avfilter_graph_request_oldest(graph: *mut AVFilterGraph) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_frame_flags(
+        ctx: *mut AVFilterContext,
+        frame: *mut AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_params_alloc() -> *mut AVBufferSinkParams;
+}
+extern "C" {
+    pub fn av_abuffersink_params_alloc() -> *mut AVABufferSinkParams;
+}
+extern "C" {
+    pub fn av_buffersink_set_frame_size(
+        ctx: *mut AVFilterContext,
+        frame_size: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn av_buffersink_get_type(ctx: *const AVFilterContext) -> AVMediaType;
+}
+extern "C" {
+    pub fn av_buffersink_get_time_base(ctx: *const AVFilterContext) -> AVRational;
+}
+extern "C" {
+    pub fn av_buffersink_get_format(ctx: *const AVFilterContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_frame_rate(ctx: *const AVFilterContext) -> AVRational;
+}
+extern "C" {
+    pub fn av_buffersink_get_w(ctx: *const AVFilterContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_h(ctx: *const AVFilterContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_sample_aspect_ratio(ctx: *const AVFilterContext) -> AVRational;
+}
+extern "C" {
+    pub fn av_buffersink_get_channels(ctx: *const AVFilterContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_channel_layout(ctx: *const AVFilterContext) -> u64;
+}
+extern "C" {
+    pub fn av_buffersink_get_sample_rate(ctx: *const AVFilterContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_hw_frames_ctx(ctx: *const AVFilterContext) -> *mut AVBufferRef;
+}
+extern "C" {
+    pub fn av_buffersink_get_frame(
+        ctx: *mut AVFilterContext,
+        frame: *mut AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersink_get_samples(
+        ctx: *mut AVFilterContext,
+        frame: *mut AVFrame,
+        nb_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersrc_get_nb_failed_requests(
+        buffer_src: *mut AVFilterContext,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn av_buffersrc_parameters_alloc() -> *mut AVBufferSrcParameters;
+}
+extern "C" {
+    pub fn av_buffersrc_parameters_set(
+        ctx: *mut AVFilterContext,
+        param: *mut AVBufferSrcParameters,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersrc_write_frame(
+        ctx: *mut AVFilterContext,
+        frame: *const AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersrc_add_frame(
+        ctx: *mut AVFilterContext,
+        frame: *mut AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersrc_add_frame_flags(
+        buffer_src: *mut AVFilterContext,
+        frame: *mut AVFrame,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn av_buffersrc_close(
+        ctx: *mut AVFilterContext,
+        pts: i64,
+        flags: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avdevice_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn avdevice_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avdevice_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn avdevice_register_all();
+}
+extern "C" {
+    pub fn av_input_audio_device_next(d: *mut AVInputFormat) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_input_video_device_next(d: *mut AVInputFormat) -> *mut AVInputFormat;
+}
+extern "C" {
+    pub fn av_output_audio_device_next(d: *mut AVOutputFormat) -> *mut AVOutputFormat;
+}
+extern "C" {
+    pub fn av_output_video_device_next(d: *mut AVOutputFormat) -> *mut AVOutputFormat;
+}
+extern "C" {
+    pub fn avdevice_app_to_dev_control_message(
+        s: *mut AVFormatContext,
+        type_: AVAppToDevMessageType,
+        data: *mut ::std::os::raw::c_void,
+        data_size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avdevice_dev_to_app_control_message(
+        s: *mut AVFormatContext,
+        type_: AVDevToAppMessageType,
+        data: *mut ::std::os::raw::c_void,
+        data_size: size_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avdevice_capabilities_create(
+        caps: *mut *mut AVDeviceCapabilitiesQuery,
+        s: *mut AVFormatContext,
+        device_options: *mut *mut AVDictionary,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avdevice_capabilities_free(
+        caps: *mut *mut AVDeviceCapabilitiesQuery,
+        s: *mut AVFormatContext,
+    );
+}
+extern "C" {
+    pub fn avdevice_list_devices(
+        s: *mut AVFormatContext,
+        device_list: *mut *mut AVDeviceInfoList,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avdevice_free_list_devices(device_list: *mut *mut AVDeviceInfoList);
+}
+extern "C" {
+    pub fn avdevice_list_input_sources(
+        device: *mut AVInputFormat,
+        device_name: *const ::std::os::raw::c_char,
+        device_options: *mut AVDictionary,
+        device_list: *mut *mut AVDeviceInfoList,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn avdevice_list_output_sinks(
+        device: *mut AVOutputFormat,
+        device_name: *const ::std::os::raw::c_char,
+        device_options: *mut AVDictionary,
+        device_list: *mut *mut AVDeviceInfoList,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swscale_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn swscale_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn swscale_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn sws_getCoefficients(colorspace: ::std::os::raw::c_int) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_isSupportedInput(pix_fmt: AVPixelFormat) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_isSupportedOutput(pix_fmt: AVPixelFormat) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_isSupportedEndiannessConversion(pix_fmt: AVPixelFormat) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_alloc_context() -> *mut SwsContext;
+}
+extern "C" {
+    pub fn sws_init_context(
+        sws_context: *mut SwsContext,
+        srcFilter: *mut SwsFilter,
+        dstFilter: *mut SwsFilter,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_freeContext(swsContext: *mut SwsContext);
+}
+extern "C" {
+    pub fn sws_getContext(
+        srcW: ::std::os::raw::c_int,
+        srcH: ::std::os::raw::c_int,
+        srcFormat: AVPixelFormat,
+        dstW: ::std::os::raw::c_int,
+        dstH: ::std::os::raw::c_int,
+        dstFormat: AVPixelFormat,
+        flags: ::std::os::raw::c_int,
+        srcFilter: *mut SwsFilter,
+        dstFilter: *mut SwsFilter,
+        param: *const f64,
+    ) -> *mut SwsContext;
+}
+extern "C" {
+    pub fn sws_scale(
+        c: *mut SwsContext,
+        srcSlice: *const *const u8,
+        srcStride: *const ::std::os::raw::c_int,
+        srcSliceY: ::std::os::raw::c_int,
+        srcSliceH: ::std::os::raw::c_int,
+        dst: *const *mut u8,
+        dstStride: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_setColorspaceDetails(
+        c: *mut SwsContext,
+        inv_table: *const ::std::os::raw::c_int,
+        srcRange: ::std::os::raw::c_int,
+        table: *const ::std::os::raw::c_int,
+        dstRange: ::std::os::raw::c_int,
+        brightness: ::std::os::raw::c_int,
+        contrast: ::std::os::raw::c_int,
+        saturation: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_getColorspaceDetails(
+        c: *mut SwsContext,
+        inv_table: *mut *mut ::std::os::raw::c_int,
+        srcRange: *mut ::std::os::raw::c_int,
+        table: *mut *mut ::std::os::raw::c_int,
+        dstRange: *mut ::std::os::raw::c_int,
+        brightness: *mut ::std::os::raw::c_int,
+        contrast: *mut ::std::os::raw::c_int,
+        saturation: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn sws_allocVec(length: ::std::os::raw::c_int) -> *mut SwsVector;
+}
+extern "C" {
+    pub fn sws_getGaussianVec(variance: f64, quality: f64) -> *mut SwsVector;
+}
+extern "C" {
+    pub fn sws_scaleVec(a: *mut SwsVector, scalar: f64);
+}
+extern "C" {
+    pub fn sws_normalizeVec(a: *mut SwsVector, height: f64);
+}
+extern "C" {
+    pub fn sws_getConstVec(c: f64, length: ::std::os::raw::c_int) -> *mut SwsVector;
+}
+extern "C" {
+    pub fn sws_getIdentityVec() -> *mut SwsVector;
+}
+extern "C" {
+    pub fn sws_convVec(a: *mut SwsVector, b: *mut SwsVector);
+}
+extern "C" {
+    pub fn sws_addVec(a: *mut SwsVector, b: *mut SwsVector);
+}
+extern "C" {
+    pub fn sws_subVec(a: *mut SwsVector, b: *mut SwsVector);
+}
+extern "C" {
+    pub fn sws_shiftVec(a: *mut SwsVector, shift: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn sws_cloneVec(a: *mut SwsVector) -> *mut SwsVector;
+}
+extern "C" {
+    pub fn sws_printVec2(
+        a: *mut SwsVector,
+        log_ctx: *mut AVClass,
+        log_level: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn sws_freeVec(a: *mut SwsVector);
+}
+extern "C" {
+    pub fn sws_getDefaultFilter(
+        lumaGBlur: f32,
+        chromaGBlur: f32,
+        lumaSharpen: f32,
+        chromaSharpen: f32,
+        chromaHShift: f32,
+        chromaVShift: f32,
+        verbose: ::std::os::raw::c_int,
+    ) -> *mut SwsFilter;
+}
+extern "C" {
+    pub fn sws_freeFilter(filter: *mut SwsFilter);
+}
+extern "C" {
+    pub fn sws_getCachedContext(
+        context: *mut SwsContext,
+        srcW: ::std::os::raw::c_int,
+        srcH: ::std::os::raw::c_int,
+        srcFormat: AVPixelFormat,
+        dstW: ::std::os::raw::c_int,
+        dstH: ::std::os::raw::c_int,
+        dstFormat: AVPixelFormat,
+        flags: ::std::os::raw::c_int,
+        srcFilter: *mut SwsFilter,
+        dstFilter: *mut SwsFilter,
+        param: *const f64,
+    ) -> *mut SwsContext;
+}
+extern "C" {
+    pub fn sws_convertPalette8ToPacked32(
+        src: *const u8,
+        dst: *mut u8,
+        num_pixels: ::std::os::raw::c_int,
+        palette: *const u8,
+    );
+}
+extern "C" {
+    pub fn sws_convertPalette8ToPacked24(
+        src: *const u8,
+        dst: *mut u8,
+        num_pixels: ::std::os::raw::c_int,
+        palette: *const u8,
+    );
+}
+extern "C" {
+    pub fn sws_get_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn swr_get_class() -> *const AVClass;
+}
+extern "C" {
+    pub fn swr_alloc() -> *mut SwrContext;
+}
+extern "C" {
+    pub fn swr_init(s: *mut SwrContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_is_initialized(s: *mut SwrContext) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_alloc_set_opts(
+        s: *mut SwrContext,
+        out_ch_layout: i64,
+        out_sample_fmt: AVSampleFormat,
+        out_sample_rate: ::std::os::raw::c_int,
+        in_ch_layout: i64,
+        in_sample_fmt: AVSampleFormat,
+        in_sample_rate: ::std::os::raw::c_int,
+        log_offset: ::std::os::raw::c_int,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> *mut SwrContext;
+}
+extern "C" {
+    pub fn swr_free(s: *mut *mut SwrContext);
+}
+extern "C" {
+    pub fn swr_close(s: *mut SwrContext);
+}
+extern "C" {
+    pub fn swr_convert(
+        s: *mut SwrContext,
+        out: *mut *mut u8,
+        out_count: ::std::os::raw::c_int,
+        in_: *mut *const u8,
+        in_count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_next_pts(s: *mut SwrContext, pts: i64) -> i64;
+}
+extern "C" {
+    pub fn swr_set_compensation(
+        s: *mut SwrContext,
+        sample_delta: ::std::os::raw::c_int,
+        compensation_distance: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_set_channel_mapping(
+        s: *mut SwrContext,
+        channel_map: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_build_matrix(
+        in_layout: u64,
+        out_layout: u64,
+        center_mix_level: f64,
+        surround_mix_level: f64,
+        lfe_mix_level: f64,
+        rematrix_maxval: f64,
+        rematrix_volume: f64,
+        matrix: *mut f64,
+        stride: ::std::os::raw::c_int,
+        matrix_encoding: AVMatrixEncoding,
+        log_ctx: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_set_matrix(
+        s: *mut SwrContext,
+        matrix: *const f64,
+        stride: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_drop_output(
+        s: *mut SwrContext,
+        count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_inject_silence(
+        s: *mut SwrContext,
+        count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_get_delay(s: *mut SwrContext, base: i64) -> i64;
+}
+extern "C" {
+    pub fn swr_get_out_samples(
+        s: *mut SwrContext,
+        in_samples: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swresample_version() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn swresample_configuration() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn swresample_license() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn swr_convert_frame(
+        swr: *mut SwrContext,
+        output: *mut AVFrame,
+        input: *const AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn swr_config_frame(
+        swr: *mut SwrContext,
+        out: *const AVFrame,
+        in_: *const AVFrame,
+    ) -> ::std::os::raw::c_int;
+}
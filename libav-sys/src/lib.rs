@@ -1,3 +1,25 @@
+//! Raw ffmpeg bindings, see the README for how ffmpeg is found and linked.
+//!
+//! # Vendored builds
+//!
+//! With the `vendored` feature the ffmpeg sources in `ffmpeg/` are built into `OUT_DIR`. The components it is
+//! configured with are chosen by comma separated lists in environment variables: `LIBAV_SYS_ENCODERS`,
+//! `LIBAV_SYS_DECODERS`, `LIBAV_SYS_MUXERS`, `LIBAV_SYS_DEMUXERS`, `LIBAV_SYS_PARSERS`, `LIBAV_SYS_BSFS`,
+//! `LIBAV_SYS_FILTERS` and `LIBAV_SYS_PROTOCOLS`. An unset variable keeps every component of its kind, an empty one
+//! disables them all. Other `configure` flags go into `LIBAV_SYS_CONFIGURE_FLAGS`.
+//!
+//! Cargo passes the `[env]` table of `.cargo/config.toml` to build scripts, so a crate depending on `libav-sys`
+//! can pin its selection next to its sources instead of in the shell:
+//!
+//! ```toml
+//! [env]
+//! LIBAV_SYS_DECODERS = "h264,aac"
+//! LIBAV_SYS_DEMUXERS = "mov"
+//! LIBAV_SYS_PROTOCOLS = "file"
+//! ```
+//!
+//! ffmpeg is reconfigured whenever the selection changes.
+
 pub mod avcodec;
 
 #[cfg(test)]
@@ -0,0 +1 @@
+#include <libavdevice/avdevice.h>
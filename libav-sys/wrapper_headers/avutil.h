@@ -1,4 +1,5 @@
 #include <libavutil/hwcontext.h>
 #include <libavutil/frame.h>
 #include <libavutil/pixdesc.h>
-#include <libavutil/hwcontext_cuda.h>
+#include <libavutil/imgutils.h>
+#include <libavutil/audio_fifo.h>
@@ -0,0 +1 @@
+#include <libavutil/hwcontext_cuda.h>
@@ -0,0 +1 @@
+#include <libswresample/swresample.h>
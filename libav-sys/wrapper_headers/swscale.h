@@ -0,0 +1 @@
+#include <libswscale/swscale.h>
@@ -0,0 +1,203 @@
+/// Sample queue re-chunking audio into frames of a fixed size (AVAudioFifo), e.g. the `frame_size` of an AAC or
+/// Opus encoder.
+///
+/// Pts are in 1/sample_rate units. The pts of a frame written to an empty fifo is carried forward, the pts of later
+/// frames are ignored as long as the fifo holds samples, so output frames are continuous.
+pub struct AudioFifo {
+    internal: *mut avcodec::AVAudioFifo,
+    format: SampleFormat,
+    channel_layout: ChannelLayout,
+    sample_rate: i32,
+    // pts of the first sample in the fifo
+    next_pts: Option<i64>,
+}
+
+impl Drop for AudioFifo {
+    fn drop(&mut self) {
+        unsafe {
+            avcodec::av_audio_fifo_free(self.internal);
+        }
+    }
+}
+
+// the fifo is only used through &mut self
+unsafe impl Send for AudioFifo {}
+
+impl AudioFifo {
+    /// Creates a fifo for samples of the given format and layout, it grows beyond `capacity` as needed.
+    pub fn new(format: SampleFormat, channel_layout: ChannelLayout, sample_rate: i32, capacity: i32) -> Result<Self, AvError> {
+        unsafe {
+            let channels = channel_layout.nb_channels();
+            if channels <= 0 {
+                return Err(AvError::InvalidArgument);
+            }
+            let internal = avcodec::av_audio_fifo_alloc(AVSampleFormat::from(format), channels, capacity.max(1));
+            if internal.is_null() {
+                return Err(AvError::NoMemory);
+            }
+            Ok(Self { internal, format, channel_layout, sample_rate, next_pts: None })
+        }
+    }
+    /// Number of samples per channel in the fifo.
+    pub fn size(&self) -> i32 {
+        unsafe { avcodec::av_audio_fifo_size(self.internal) }
+    }
+    /// Drops all samples.
+    pub fn reset(&mut self) {
+        unsafe { avcodec::av_audio_fifo_reset(self.internal) }
+        self.next_pts = None;
+    }
+
+    // data holds one pointer per plane
+    unsafe fn write_raw(&mut self, data: *const *mut u8, nb_samples: i32, pts: i64) -> Result<(), AvError> {
+        if pts != AV_NOPTS_VALUE && (self.next_pts.is_none() || self.size() == 0) {
+            self.next_pts = Some(pts - self.size() as i64);
+        }
+        let ret = avcodec::av_audio_fifo_write(self.internal, data as _, nb_samples);
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        if ret < nb_samples {
+            return Err(AvError::NoMemory);
+        }
+        return Ok(());
+    }
+
+    /// Appends the samples of a frame, which has to match the format and channel layout of the fifo. Frames with
+    /// an unknown layout, as some decoders output, only have to match the channel count.
+    pub fn write_frame(&mut self, frame: &AVFrame) -> Result<(), AvError> {
+        let layout = frame.channel_layout();
+        let layout_matches = if layout.is_known() {
+            layout == self.channel_layout
+        } else {
+            frame.channels() == self.channel_layout.nb_channels()
+        };
+        if frame.sample_fmt() != self.format || !layout_matches {
+            return Err(AvError::InvalidArgument);
+        }
+        unsafe {
+            let raw = frame.get_internal();
+            self.write_raw(raw.extended_data as *const *mut u8, raw.nb_samples, raw.pts)
+        }
+    }
+    /// Appends interleaved samples, the fifo format has to be the packed format of `T`.
+    pub fn write_interleaved<T: Sample>(&mut self, samples: &[T]) -> Result<(), AvError> {
+        let channels = self.channel_layout.nb_channels() as usize;
+        if self.format != T::PACKED || samples.len() % channels != 0 {
+            return Err(AvError::InvalidArgument);
+        }
+        let data = [samples.as_ptr() as *mut u8];
+        unsafe { self.write_raw(data.as_ptr(), (samples.len() / channels) as i32, AV_NOPTS_VALUE) }
+    }
+    /// Appends one slice of equal length per channel, the fifo format has to be the planar format of `T`.
+    pub fn write_planar<T: Sample>(&mut self, planes: &[&[T]]) -> Result<(), AvError> {
+        let channels = self.channel_layout.nb_channels() as usize;
+        if self.format != T::PLANAR || planes.len() != channels || planes.iter().any(|plane| plane.len() != planes[0].len()) {
+            return Err(AvError::InvalidArgument);
+        }
+        let data: Vec<*mut u8> = planes.iter().map(|plane| plane.as_ptr() as *mut u8).collect();
+        unsafe { self.write_raw(data.as_ptr(), planes[0].len() as i32, AV_NOPTS_VALUE) }
+    }
+
+    /// Takes a frame of exactly `nb_samples` samples, None while the fifo holds fewer. With `nb_samples` 0, e.g.
+    /// the `frame_size` of an encoder accepting any size, all samples are taken.
+    pub fn read_frame(&mut self, nb_samples: i32) -> Result<Option<AVFrame>, AvError> {
+        let nb_samples = if nb_samples <= 0 { self.size() } else { nb_samples };
+        if nb_samples == 0 || self.size() < nb_samples {
+            return Ok(None);
+        }
+        self.read(nb_samples).map(Some)
+    }
+    /// Takes all remaining samples at the end of the stream, None when the fifo is empty.
+    pub fn read_remaining(&mut self) -> Result<Option<AVFrame>, AvError> {
+        if self.size() == 0 {
+            return Ok(None);
+        }
+        self.read(self.size()).map(Some)
+    }
+
+    fn read(&mut self, nb_samples: i32) -> Result<AVFrame, AvError> {
+        let mut frame = AVFrame::new_audio(nb_samples, self.format, self.channel_layout, self.sample_rate)?;
+        unsafe {
+            let ret = avcodec::av_audio_fifo_read(self.internal, frame.get_internal().extended_data as _, nb_samples);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        match self.next_pts {
+            Some(pts) => {
+                frame.set_pts(pts);
+                self.next_pts = Some(pts + nb_samples as i64);
+            }
+            None => frame.set_pts(AV_NOPTS_VALUE),
+        }
+        return Ok(frame);
+    }
+}
+
+#[cfg(test)]
+mod test_audio_fifo {
+    use super::*;
+
+    #[cfg(not(ffmpeg_5_1))]
+    fn clear_layout(frame: &mut AVFrame) {
+        unsafe { frame.get_internal_mut().channel_layout = 0 }
+    }
+    #[cfg(ffmpeg_5_1)]
+    fn clear_layout(frame: &mut AVFrame) {
+        unsafe {
+            let layout = &mut frame.get_internal_mut().ch_layout;
+            let channels = layout.nb_channels;
+            avcodec::av_channel_layout_uninit(layout);
+            layout.order = avcodec::AVChannelOrder_AV_CHANNEL_ORDER_UNSPEC;
+            layout.nb_channels = channels;
+        }
+    }
+
+    #[test]
+    fn rechunk_frames() {
+        let mono = ChannelLayout::MONO;
+        let mut fifo = AudioFifo::new(SampleFormat::S16, mono, 8000, 1024).unwrap();
+        for i in 0..5 {
+            let samples: Vec<i16> = (i * 100..(i + 1) * 100).map(|s| s as i16).collect();
+            let mut frame = AVFrame::from_interleaved(&samples, mono, 8000).unwrap();
+            frame.set_pts(1000 + i as i64 * 100);
+            fifo.write_frame(&frame).unwrap();
+        }
+        assert_eq!(fifo.size(), 500);
+
+        let mut next = 0i16;
+        for expected_pts in &[1000i64, 1160, 1320] {
+            let frame = fifo.read_frame(160).unwrap().unwrap();
+            assert_eq!((frame.nb_samples(), frame.pts()), (160, *expected_pts));
+            for sample in frame.audio_plane::<i16>(0).unwrap() {
+                assert_eq!(*sample, next);
+                next += 1;
+            }
+        }
+        assert!(fifo.read_frame(160).unwrap().is_none());
+        let rest = fifo.read_remaining().unwrap().unwrap();
+        assert_eq!((rest.nb_samples(), rest.pts()), (20, 1480));
+        assert!(fifo.read_remaining().unwrap().is_none());
+
+        fifo.write_interleaved(&[1i16; 10]).unwrap();
+        assert_eq!(fifo.read_frame(0).unwrap().unwrap().nb_samples(), 10);
+        assert_eq!(fifo.write_interleaved(&[1.0f32; 10]).unwrap_err(), AvError::InvalidArgument);
+    }
+
+    #[test]
+    fn unknown_layout() {
+        let mut fifo = AudioFifo::new(SampleFormat::S16, ChannelLayout::STEREO, 48000, 1024).unwrap();
+        let mut frame = AVFrame::from_interleaved(&[0i16; 20], ChannelLayout::STEREO, 48000).unwrap();
+        clear_layout(&mut frame);
+        assert!(!frame.channel_layout().is_known());
+        fifo.write_frame(&frame).unwrap();
+        assert_eq!(fifo.size(), 10);
+
+        let mono = AVFrame::from_interleaved(&[0i16; 20], ChannelLayout::MONO, 48000).unwrap();
+        assert_eq!(fifo.write_frame(&mono).unwrap_err(), AvError::InvalidArgument);
+        let mut unknown_mono = mono.clone();
+        clear_layout(&mut unknown_mono);
+        assert_eq!(fifo.write_frame(&unknown_mono).unwrap_err(), AvError::InvalidArgument);
+    }
+}
@@ -1,15 +1,18 @@
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::Arc;
+
 pub struct AVFilter {
     internal: *const avcodec::AVFilter,
 }
 
 impl AVFilter {
-    pub fn get_by_name(name: &str) -> Result<Self, ()> {
+    pub fn get_by_name(name: &str) -> Result<Self, AvError> {
         unsafe {
             let c_name = av_strdup(name);
             let filter = avcodec::avfilter_get_by_name(c_name);
             av_strfree(c_name);
             if filter.is_null() {
-                return Err(());
+                return Err(AvError::FilterNotFound);
             }
 
             return Ok(Self {
@@ -25,6 +28,11 @@ impl AVFilter {
     }
 }
 
+// filters are static, immutable descriptions
+unsafe impl Send for AVFilter {}
+
+unsafe impl Sync for AVFilter {}
+
 pub struct AVFilterInOut {
     internal: *mut avcodec::AVFilterInOut,
 }
@@ -46,16 +54,20 @@ impl AVFilterInOut {
             };
         }
     }
-    pub fn get_internals(&mut self) -> &mut avcodec::AVFilterInOut {
-        unsafe {
-            return &mut *self.internal;
-        }
+    /// Mutable access to the raw list entry. `name` must be allocated with av_malloc (see `av_strdup`) as it is
+    /// freed with the list, and `filter_ctx` must belong to the graph the list is parsed into.
+    pub unsafe fn get_internals(&mut self) -> &mut avcodec::AVFilterInOut {
+        return &mut *self.internal;
     }
 }
 
+unsafe impl Send for AVFilterInOut {}
+
 
 pub struct AVFilterGraph {
     internal: *mut avcodec::AVFilterGraph,
+    // shared with the contexts of the filters created in the graph, set once it is configured
+    configured: Arc<AtomicBool>,
 }
 
 impl Drop for AVFilterGraph {
@@ -66,25 +78,29 @@ impl Drop for AVFilterGraph {
     }
 }
 
+// a graph may be moved to another thread but not be used from several at once
+unsafe impl Send for AVFilterGraph {}
+
 impl AVFilterGraph {
     pub fn new() -> Self {
         unsafe {
             let internal = avcodec::avfilter_graph_alloc();
             return Self {
                 internal,
+                configured: Arc::new(AtomicBool::new(false)),
             };
         }
     }
-    pub fn create_filter(&mut self, filter: &AVFilter, name: Option<&str>, args: Option<&str>, opaque: *mut c_void) -> Result<AVFilterContext, i32> {
+    pub fn create_filter(&mut self, filter: &AVFilter, name: Option<&str>, args: Option<&str>, opaque: *mut c_void) -> Result<AVFilterContext, AvError> {
         unsafe {
             let mut filter_ctx = null_mut();
             let mut c_name = null_mut();
             let mut c_args = null_mut();
-            if name.is_some() {
-                c_name = av_strdup(name.unwrap())
+            if let Some(name) = name {
+                c_name = av_strdup(name)
             }
-            if args.is_some() {
-                c_args = av_strdup(args.unwrap());
+            if let Some(args) = args {
+                c_args = av_strdup(args);
             }
             let ret = avcodec::avfilter_graph_create_filter(&mut filter_ctx, filter.internal, c_name, c_args, opaque, self.internal);
             if !c_name.is_null() {
@@ -95,9 +111,9 @@ impl AVFilterGraph {
             }
 
             if ret < 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
-            return Ok(AVFilterContext { internal: filter_ctx });
+            return Ok(AVFilterContext { internal: filter_ctx, graph_configured: self.configured.clone() });
         }
     }
     pub fn remove_from_graph(&mut self, ctx: AVFilterContext) {
@@ -105,54 +121,60 @@ impl AVFilterGraph {
             avcodec::avfilter_free(ctx.internal);
         }
     }
-    pub fn parse_str(&self, filters: &str, inputs: &mut AVFilterInOut, outputs: &mut AVFilterInOut) -> Result<(), i32> {
+    pub fn parse_str(&mut self, filters: &str, inputs: &mut AVFilterInOut, outputs: &mut AVFilterInOut) -> Result<(), AvError> {
         unsafe {
             let c_filters = av_strdup(filters);
             let ret = avcodec::avfilter_graph_parse_ptr(self.internal, c_filters, &mut inputs.internal, &mut outputs.internal, null_mut());
             av_strfree(c_filters);
             if ret < 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
             let _ = c_filters;
         }
         return Ok(());
     }
-    pub fn config(&self) -> Result<(), i32> {
+    pub fn config(&mut self) -> Result<(), AvError> {
         unsafe {
             let ret = avcodec::avfilter_graph_config(self.internal, null_mut());
             if ret < 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
+            self.configured.store(true, atomic::Ordering::Relaxed);
             return Ok(());
         }
     }
-    pub fn add_frame_flags(&self, buffersrc_ctx: &mut AVFilterContext, frame: &mut AVFrame, flags: i32) -> Result<(), i32> {
+    pub fn add_frame_flags(&mut self, buffersrc_ctx: &mut AVFilterContext, frame: &AVFrame, flags: i32) -> Result<(), AvError> {
         unsafe {
-            let fr = avcodec::av_frame_alloc();
-            avcodec::av_frame_ref(fr, frame.get_internal());
-            let ret = avcodec::av_buffersrc_add_frame_flags(buffersrc_ctx.get_internal(), fr, flags);
+            let mut fr = avcodec::av_frame_alloc();
+            let ret = avcodec::av_frame_ref(fr, frame.as_ptr());
             if ret < 0 {
-                return Err(ret);
+                avcodec::av_frame_free(&mut fr);
+                return Err(AvError::from(ret));
+            }
+            // the buffer source takes the references of fr, the frame struct itself stays ours
+            let ret = avcodec::av_buffersrc_add_frame_flags(buffersrc_ctx.internal, fr, flags);
+            avcodec::av_frame_free(&mut fr);
+            if ret < 0 {
+                return Err(AvError::from(ret));
             }
         }
         return Ok(());
     }
-    pub fn get_frame_flags(&self, buffersink_ctx: &mut AVFilterContext, frame: &mut AVFrame, flags: i32) -> Result<(), i32> {
+    pub fn get_frame_flags(&mut self, buffersink_ctx: &mut AVFilterContext, frame: &mut AVFrame, flags: i32) -> Result<(), AvError> {
         unsafe {
-            let ret = avcodec::av_buffersink_get_frame_flags(buffersink_ctx.get_internal(), frame.get_internal(), flags);
+            let ret = avcodec::av_buffersink_get_frame_flags(buffersink_ctx.internal, frame.as_mut_ptr(), flags);
             if ret < 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
         }
         return Ok(());
     }
 
-    pub fn buffersrc_set(&self, ctx: &mut AVFilterContext, mut par: AVBufferSrcParameters) -> Result<(), i32> {
+    pub fn buffersrc_set(&mut self, ctx: &mut AVFilterContext, par: &AVBufferSrcParameters) -> Result<(), AvError> {
         unsafe {
             let ret = avcodec::av_buffersrc_parameters_set(ctx.internal, par.internal);
-            par.internal = null_mut() as _;
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(())
             }
@@ -168,6 +190,7 @@ impl AVFilterGraph {
 
 pub struct AVFilterContext {
     internal: *mut avcodec::AVFilterContext,
+    graph_configured: Arc<AtomicBool>,
 }
 
 impl AVFilterContext {
@@ -176,39 +199,25 @@ impl AVFilterContext {
             (*self.internal).hw_device_ctx = avcodec::av_buffer_ref(device_ctx.internal);
         }
     }
-    pub fn get_internal(&mut self) -> &mut avcodec::AVFilterContext {
+    pub fn get_internal(&self) -> &avcodec::AVFilterContext {
         unsafe {
-            return &mut *self.internal;
+            return &*self.internal;
         }
     }
-}
-
-pub fn av_strdup(s: &str) -> *mut c_char {
-    unsafe {
-        let str = CString::new(s).unwrap();
-        let cstr = str.as_c_str();
-        avcodec::av_strdup(cstr.as_ptr())
+    /// Mutable access to the raw filter context, the caller must keep it consistent for the owning graph.
+    pub unsafe fn get_internal_mut(&mut self) -> &mut avcodec::AVFilterContext {
+        return &mut *self.internal;
     }
 }
 
-pub fn av_strfree(s: *mut c_char) {
-    unsafe {
-        avcodec::av_free(s as *mut c_void);
+unsafe impl AVOptions for AVFilterContext {
+    fn options_ptr(&self) -> *mut c_void {
+        self.internal as *mut c_void
     }
-}
-
-impl AVFilterContext {
-    #[allow(unused)]
-    fn opt_set_int_list(&self, opt_name: &str, list: Vec<i32>, search_flags: i32) -> Result<(), i32> {
-        unsafe {
-            let c_name = av_strdup(opt_name);
-            let ret = avcodec::av_opt_set_bin(self.internal as *mut c_void, c_name, list.as_ptr() as *const u8, (list.len() * size_of::<i32>()) as i32, search_flags);
-            av_strfree(c_name);
-            if ret < 0 {
-                return Err(ret);
-            }
-        }
-        return Ok(());
+    /// True once the graph is configured. Filters are initialized on creation, but most options, e.g. the
+    /// "pix_fmts" of a buffersink, are only read when the graph is configured.
+    fn is_in_use(&self) -> bool {
+        self.graph_configured.load(atomic::Ordering::Relaxed)
     }
 }
 
@@ -220,13 +229,17 @@ pub struct AVBufferSrcParameters {
 impl Drop for AVBufferSrcParameters {
     fn drop(&mut self) {
         unsafe {
-            if self.internal != null_mut() {
-                avcodec::av_free(&mut self.internal as *mut _ as *mut c_void)
+            if !self.internal.is_null() {
+                // av_buffersrc_parameters_set takes its own reference, the one set here is still ours
+                avcodec::av_buffer_unref(&mut (*self.internal).hw_frames_ctx);
+                avcodec::av_freep(&mut self.internal as *mut _ as *mut c_void)
             }
         }
     }
 }
 
+unsafe impl Send for AVBufferSrcParameters {}
+
 impl AVBufferSrcParameters {
     pub fn new() -> Self {
         unsafe {
@@ -235,17 +248,20 @@ impl AVBufferSrcParameters {
             }
         }
     }
-    pub fn get_mut(&mut self) -> &mut avcodec::AVBufferSrcParameters {
-        unsafe {
-            &mut *self.internal
-        }
+    /// Mutable access to the raw parameters, `hw_frames_ctx` is unreferenced when the parameters are dropped.
+    pub unsafe fn get_mut(&mut self) -> &mut avcodec::AVBufferSrcParameters {
+        &mut *self.internal
     }
     pub fn set_hw_frames_context(&mut self, buf: &AVBufferRef<AVHWFramesContext>) {
-        unsafe { (*self.internal).hw_frames_ctx = avcodec::av_buffer_ref(buf.internal); }
+        unsafe {
+            avcodec::av_buffer_unref(&mut (*self.internal).hw_frames_ctx);
+            (*self.internal).hw_frames_ctx = avcodec::av_buffer_ref(buf.internal);
+        }
     }
 }
 
-#[cfg(test)]
+// the graph scales on a cuda device
+#[cfg(all(test, feature = "cuda"))]
 pub mod test_filter {
     use super::*;
 
@@ -258,7 +274,7 @@ pub mod test_filter {
                                                  Some(format!("video_size=3840x2160:pix_fmt={}:time_base=1/60", avcodec::AVPixelFormat_AV_PIX_FMT_CUDA).as_str()), null_mut());
         if buffer_src_ctx.is_err() {
             let err = buffer_src_ctx.err().unwrap();
-            println!("error: {}", err_str(err));
+            println!("error: {}", err);
             panic!();
         }
         let mut buffer_src_ctx = buffer_src_ctx.unwrap();
@@ -267,14 +283,14 @@ pub mod test_filter {
         let mut outputs = AVFilterInOut::new();
 
 
-        let out_ = outputs.get_internals();
+        let out_ = unsafe { outputs.get_internals() };
 
         out_.name = av_strdup("in");
         out_.filter_ctx = buffer_src_ctx.internal;
         out_.pad_idx = 0;
         out_.next = null_mut();
 
-        let in_ = inputs.get_internals();
+        let in_ = unsafe { inputs.get_internals() };
         in_.name = av_strdup("out");
         in_.filter_ctx = buffer_sink_ctx.internal;
         in_.pad_idx = 0;
@@ -282,17 +298,13 @@ pub mod test_filter {
 
         let mut device = hwdevice_ctx_create(avcodec::AVHWDeviceType_AV_HWDEVICE_TYPE_CUDA, "", None, 0).unwrap();
         let mut hw_frames_ctx = hwframe_ctx_alloc(&mut device);
-        let mut frame_ctx = hw_frames_ctx.get_data().unwrap();
-        frame_ctx.height = 2560;
-        frame_ctx.width = 1440;
-        frame_ctx.format = avcodec::AVPixelFormat_AV_PIX_FMT_CUDA;
-        frame_ctx.sw_format = avcodec::AVPixelFormat_AV_PIX_FMT_YUV444P;
+        hw_frames_ctx.set_frames_format(PixelFormat::Cuda, PixelFormat::Yuv444p, 1440, 2560).unwrap();
         hwframe_ctx_init(&mut hw_frames_ctx).unwrap();
         graph.parse_str("scale_cuda=1280:720", &mut inputs, &mut outputs).unwrap();
         let mut params_t = AVBufferSrcParameters::new();
         params_t.set_hw_frames_context(&hw_frames_ctx);
-        graph.buffersrc_set(&mut buffer_src_ctx, params_t).unwrap();
-        assert_eq!(0, graph.config().err().unwrap_or(0));
+        graph.buffersrc_set(&mut buffer_src_ctx, &params_t).unwrap();
+        graph.config().unwrap();
         drop(graph);
         println!("hello world after freeee");
     }
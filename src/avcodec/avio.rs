@@ -0,0 +1,194 @@
+use std::any::Any;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+// same as IO_BUFFER_SIZE in libavformat
+const AVIO_BUFFER_SIZE: usize = 32768;
+
+/// Custom I/O for demuxers and muxers (an AVIOContext from avio_alloc_context) backed by a Rust `Read` or `Write`
+/// implementation, see `AVFormatInput::open_io` and `AVFormatOutput::create_io`.
+///
+/// Errors returned by the callbacks reach ffmpeg as AVERROR(EIO) and panics are caught and reported as
+/// AVERROR_EXTERNAL, after a panic the stream is in whatever state the panic left it.
+pub struct AVIOContext {
+    internal: *mut avcodec::AVIOContext,
+    // the Read/Write implementation, the callbacks get a pointer to it as opaque
+    inner: Option<Box<dyn Any + Send>>,
+}
+
+impl Drop for AVIOContext {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+// the inner stream is Send and only used through the context
+unsafe impl Send for AVIOContext {}
+
+impl AVIOContext {
+    /// Input without seeking, demuxers that need to seek (e.g. mp4 with the index at the end) will fail.
+    pub fn from_read<R: Read + Send + 'static>(reader: R) -> Result<Self, AvError> {
+        Self::alloc(reader, false, Some(avio_read_packet::<R>), None, None)
+    }
+    pub fn from_read_seek<R: Read + Seek + Send + 'static>(reader: R) -> Result<Self, AvError> {
+        Self::alloc(reader, false, Some(avio_read_packet::<R>), None, Some(avio_seek::<R>))
+    }
+    /// Output without seeking, for streaming formats like mpegts, flv or fragmented mp4.
+    pub fn from_write<W: Write + Send + 'static>(writer: W) -> Result<Self, AvError> {
+        Self::alloc(writer, true, None, Some(avio_write_packet::<W>), None)
+    }
+    pub fn from_write_seek<W: Write + Seek + Send + 'static>(writer: W) -> Result<Self, AvError> {
+        Self::alloc(writer, true, None, Some(avio_write_packet::<W>), Some(avio_seek::<W>))
+    }
+
+    fn alloc<T: Send + 'static>(inner: T, write_flag: bool,
+                                read_packet: Option<unsafe extern "C" fn(*mut c_void, *mut u8, c_int) -> c_int>,
+                                write_packet: Option<unsafe extern "C" fn(*mut c_void, *mut u8, c_int) -> c_int>,
+                                seek: Option<unsafe extern "C" fn(*mut c_void, i64, c_int) -> i64>) -> Result<Self, AvError> {
+        let mut inner = Box::new(inner);
+        // the heap allocation does not move when the box is stored below
+        let opaque = &mut *inner as *mut T as *mut c_void;
+        unsafe {
+            let buffer = avcodec::av_malloc(AVIO_BUFFER_SIZE as u64) as *mut u8;
+            if buffer.is_null() {
+                return Err(AvError::NoMemory);
+            }
+            let internal = avcodec::avio_alloc_context(buffer, AVIO_BUFFER_SIZE as c_int, write_flag as c_int, opaque,
+                                                       read_packet, write_packet, seek);
+            if internal.is_null() {
+                avcodec::av_free(buffer as *mut c_void);
+                return Err(AvError::NoMemory);
+            }
+            Ok(Self { internal, inner: Some(inner) })
+        }
+    }
+
+    // flushes pending output and frees the context, the inner stream stays alive
+    fn close(&mut self) {
+        if self.internal.is_null() {
+            return;
+        }
+        unsafe {
+            if (*self.internal).write_flag != 0 {
+                avcodec::avio_flush(self.internal);
+            }
+            // the buffer may have been reallocated by avio, free the current one
+            avcodec::av_freep(&mut (*self.internal).buffer as *mut *mut u8 as *mut c_void);
+            avcodec::avio_context_free(&mut self.internal);
+        }
+    }
+
+    /// Flushes pending output and returns the inner stream, None if `T` is not its type.
+    pub fn into_inner<T: 'static>(mut self) -> Option<T> {
+        self.close();
+        self.inner.take()?.downcast::<T>().ok().map(|inner| *inner)
+    }
+
+    pub fn get_internal(&self) -> &avcodec::AVIOContext {
+        return unsafe { &*(self.internal) };
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVIOContext {
+        return self.internal;
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut avcodec::AVIOContext {
+        return self.internal;
+    }
+}
+
+fn io_error_code(result: std::thread::Result<std::io::Result<i64>>) -> i64 {
+    match result {
+        Ok(Ok(value)) => value,
+        Ok(Err(_)) => -(avcodec::EIO as i64),
+        Err(_) => AVERROR_EXTERNAL as i64,
+    }
+}
+
+unsafe extern "C" fn avio_read_packet<R: Read>(opaque: *mut c_void, buf: *mut u8, buf_size: c_int) -> c_int {
+    let reader = &mut *(opaque as *mut R);
+    let buf = std::slice::from_raw_parts_mut(buf, buf_size as usize);
+    let result = catch_unwind(AssertUnwindSafe(|| loop {
+        match reader.read(buf) {
+            // ffmpeg expects AVERROR_EOF instead of 0 at the end of the stream
+            Ok(0) => return Ok(AVERROR_EOF as i64),
+            Ok(read) => return Ok(read as i64),
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }));
+    io_error_code(result) as c_int
+}
+
+// the buffer is const since ffmpeg 7.0, which is not supported yet
+unsafe extern "C" fn avio_write_packet<W: Write>(opaque: *mut c_void, buf: *mut u8, buf_size: c_int) -> c_int {
+    let writer = &mut *(opaque as *mut W);
+    let buf = std::slice::from_raw_parts(buf as *const u8, buf_size as usize);
+    let result = catch_unwind(AssertUnwindSafe(|| writer.write_all(buf).map(|_| buf_size as i64)));
+    io_error_code(result) as c_int
+}
+
+unsafe extern "C" fn avio_seek<S: Seek>(opaque: *mut c_void, offset: i64, whence: c_int) -> i64 {
+    let stream = &mut *(opaque as *mut S);
+    let whence = whence & !(avcodec::AVSEEK_FORCE as c_int);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        // AVSEEK_SIZE asks for the stream size without moving
+        if whence == avcodec::AVSEEK_SIZE as c_int {
+            let pos = stream.stream_position()?;
+            let size = stream.seek(SeekFrom::End(0))?;
+            stream.seek(SeekFrom::Start(pos))?;
+            return Ok(size as i64);
+        }
+        // SEEK_SET, SEEK_CUR and SEEK_END from stdio.h
+        let pos = match whence {
+            0 => SeekFrom::Start(offset as u64),
+            1 => SeekFrom::Current(offset),
+            2 => SeekFrom::End(offset),
+            _ => return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput)),
+        };
+        stream.seek(pos).map(|pos| pos as i64)
+    }));
+    io_error_code(result)
+}
+
+#[cfg(test)]
+mod test_avio {
+    use std::io::Cursor;
+
+    use super::*;
+    use super::test_demux::wav_bytes;
+
+    struct PanicReader;
+
+    impl Read for PanicReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            panic!("reader panicked");
+        }
+    }
+
+    #[test]
+    fn remux_in_memory() {
+        let samples: Vec<i16> = (0..4000).map(|i| (i % 50) as i16).collect();
+        let io = AVIOContext::from_read_seek(Cursor::new(wav_bytes(&samples))).unwrap();
+        let mut input = AVFormatInput::open_io(io, None).unwrap();
+        let stream = input.stream(0).unwrap();
+        let time_base = stream.time_base();
+        let params = stream.codec_parameters().unwrap();
+
+        let io = AVIOContext::from_write_seek(Cursor::new(Vec::<u8>::new())).unwrap();
+        let mut output = AVFormatOutput::create_io(io, "wav").unwrap();
+        let index = output.add_stream(&params, time_base).unwrap();
+        output.write_header(None).unwrap();
+        for pkt in input.packets() {
+            output.write_packet(&mut pkt.unwrap(), index, time_base).unwrap();
+        }
+        output.write_trailer().unwrap();
+        let written = output.into_io().unwrap().into_inner::<Cursor<Vec<u8>>>().unwrap().into_inner();
+
+        let io = AVIOContext::from_read(Cursor::new(written)).unwrap();
+        let mut input = AVFormatInput::open_io(io, None).unwrap();
+        let data: Vec<u8> = input.packets().flat_map(|pkt| pkt.unwrap().get_data().to_vec()).collect();
+        assert_eq!(data, wav_bytes(&samples)[44..]);
+
+        let io = AVIOContext::from_read(PanicReader).unwrap();
+        assert!(AVFormatInput::open_io(io, None).is_err());
+    }
+}
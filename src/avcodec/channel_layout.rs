@@ -0,0 +1,169 @@
+/// A single audio channel, one AV_CH_* bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Channel(u64);
+
+impl Channel {
+    pub const FRONT_LEFT: Channel = Channel(avcodec::AV_CH_FRONT_LEFT as u64);
+    pub const FRONT_RIGHT: Channel = Channel(avcodec::AV_CH_FRONT_RIGHT as u64);
+    pub const FRONT_CENTER: Channel = Channel(avcodec::AV_CH_FRONT_CENTER as u64);
+    pub const LOW_FREQUENCY: Channel = Channel(avcodec::AV_CH_LOW_FREQUENCY as u64);
+    pub const BACK_LEFT: Channel = Channel(avcodec::AV_CH_BACK_LEFT as u64);
+    pub const BACK_RIGHT: Channel = Channel(avcodec::AV_CH_BACK_RIGHT as u64);
+    pub const FRONT_LEFT_OF_CENTER: Channel = Channel(avcodec::AV_CH_FRONT_LEFT_OF_CENTER as u64);
+    pub const FRONT_RIGHT_OF_CENTER: Channel = Channel(avcodec::AV_CH_FRONT_RIGHT_OF_CENTER as u64);
+    pub const BACK_CENTER: Channel = Channel(avcodec::AV_CH_BACK_CENTER as u64);
+    pub const SIDE_LEFT: Channel = Channel(avcodec::AV_CH_SIDE_LEFT as u64);
+    pub const SIDE_RIGHT: Channel = Channel(avcodec::AV_CH_SIDE_RIGHT as u64);
+    pub const TOP_CENTER: Channel = Channel(avcodec::AV_CH_TOP_CENTER as u64);
+    pub const STEREO_LEFT: Channel = Channel(avcodec::AV_CH_STEREO_LEFT as u64);
+    pub const STEREO_RIGHT: Channel = Channel(avcodec::AV_CH_STEREO_RIGHT as u64);
+
+    /// AV_CH_* bit of the channel.
+    pub fn mask(&self) -> u64 {
+        self.0
+    }
+    /// Short name, e.g. "FL".
+    pub fn name(&self) -> &'static str {
+        unsafe { get_str_or_default(avcodec::av_get_channel_name(self.0), "?") }
+    }
+    /// Human readable name, e.g. "front left".
+    pub fn description(&self) -> &'static str {
+        unsafe { get_str_or_default(avcodec::av_get_channel_description(self.0), "?") }
+    }
+}
+
+impl Display for Channel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Audio channel layout: a set of channels stored in the native order of their AV_CH_* bits.
+///
+/// This wraps the uint64_t mask API of ffmpeg 4.x. Layouts in native order are exactly what an `AVChannelLayout`
+/// with AV_CHANNEL_ORDER_NATIVE holds in 5.1 and later, conversions to the struct go through
+/// av_channel_layout_from_mask. Layouts with a custom channel order are not representable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChannelLayout(u64);
+
+impl ChannelLayout {
+    pub const MONO: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_MONO as u64);
+    pub const STEREO: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_STEREO as u64);
+    pub const SURROUND_2_1: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_2POINT1 as u64);
+    pub const SURROUND: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_SURROUND as u64);
+    pub const QUAD: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_QUAD as u64);
+    pub const SURROUND_5_0: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_5POINT0 as u64);
+    pub const SURROUND_5_1: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_5POINT1 as u64);
+    pub const SURROUND_5_1_BACK: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_5POINT1_BACK as u64);
+    pub const SURROUND_7_1: ChannelLayout = ChannelLayout(avcodec::AV_CH_LAYOUT_7POINT1 as u64);
+
+    /// Layout of the channels set in an AV_CH_* mask, 0 is an unknown layout.
+    pub const fn from_mask(mask: u64) -> Self {
+        ChannelLayout(mask)
+    }
+    /// The default layout for a number of channels, e.g. 5.1 for 6, None if ffmpeg has none.
+    pub fn default_for_channels(nb_channels: i32) -> Option<Self> {
+        let mask = unsafe { avcodec::av_get_default_channel_layout(nb_channels) };
+        if mask <= 0 {
+            return None;
+        }
+        Some(ChannelLayout(mask as u64))
+    }
+    pub fn mask(&self) -> u64 {
+        self.0
+    }
+    /// False for the unknown layout, which only carries a channel count elsewhere.
+    pub fn is_known(&self) -> bool {
+        self.0 != 0
+    }
+    pub fn nb_channels(&self) -> i32 {
+        unsafe { avcodec::av_get_channel_layout_nb_channels(self.0) }
+    }
+    /// The channel stored at `index`, e.g. in the index-th plane of a planar frame.
+    pub fn channel(&self, index: usize) -> Option<Channel> {
+        let channel = unsafe { avcodec::av_channel_layout_extract_channel(self.0, index as c_int) };
+        if channel == 0 {
+            return None;
+        }
+        Some(Channel(channel))
+    }
+    /// Position of `channel` in the layout.
+    pub fn index_of(&self, channel: Channel) -> Option<usize> {
+        let index = unsafe { avcodec::av_get_channel_layout_channel_index(self.0, channel.0) };
+        if index < 0 {
+            return None;
+        }
+        Some(index as usize)
+    }
+    pub fn contains(&self, channel: Channel) -> bool {
+        self.0 & channel.0 != 0
+    }
+    /// The channels in storage order.
+    pub fn channels(&self) -> impl Iterator<Item = Channel> {
+        let layout = *self;
+        (0..layout.nb_channels() as usize).filter_map(move |index| layout.channel(index))
+    }
+}
+
+impl From<u64> for ChannelLayout {
+    fn from(mask: u64) -> Self {
+        ChannelLayout(mask)
+    }
+}
+
+impl From<ChannelLayout> for u64 {
+    fn from(layout: ChannelLayout) -> Self {
+        layout.0
+    }
+}
+
+impl Display for ChannelLayout {
+    /// ffmpeg's name of the layout, e.g. "stereo" or "5.1(side)", or the channel names joined by "+".
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buf = [0 as c_char; 128];
+        unsafe {
+            avcodec::av_get_channel_layout_string(buf.as_mut_ptr(), buf.len() as c_int, self.nb_channels(), self.0);
+            write!(f, "{}", get_str_or_default(buf.as_ptr(), ""))
+        }
+    }
+}
+
+impl FromStr for ChannelLayout {
+    type Err = AvError;
+
+    /// Parses a layout name like "stereo" or "5.1", channel names like "FL+FR" or a channel count like "2c".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let c_name = CString::new(s).map_err(|_| AvError::InvalidArgument)?;
+        let mask = unsafe { avcodec::av_get_channel_layout(c_name.as_ptr()) };
+        if mask == 0 {
+            return Err(AvError::InvalidArgument);
+        }
+        Ok(ChannelLayout(mask))
+    }
+}
+
+#[cfg(test)]
+mod test_channel_layout {
+    use super::*;
+
+    #[test]
+    fn channel_layouts() {
+        let layout = ChannelLayout::SURROUND_5_1;
+        assert_eq!(layout.nb_channels(), 6);
+        assert_eq!(layout.to_string(), "5.1(side)");
+        assert_eq!(layout.channel(3), Some(Channel::LOW_FREQUENCY));
+        assert_eq!(layout.index_of(Channel::FRONT_CENTER), Some(2));
+        assert_eq!(layout.index_of(Channel::BACK_LEFT), None);
+        assert_eq!(layout.channels().map(|channel| channel.name()).collect::<Vec<_>>(), ["FL", "FR", "FC", "LFE", "SL", "SR"]);
+        assert_eq!(Channel::FRONT_LEFT.description(), "front left");
+
+        assert_eq!("stereo".parse::<ChannelLayout>().unwrap(), ChannelLayout::STEREO);
+        assert_eq!("FL+FR+LFE".parse::<ChannelLayout>().unwrap().nb_channels(), 3);
+        assert!("no such layout".parse::<ChannelLayout>().is_err());
+        assert_eq!(ChannelLayout::default_for_channels(1), Some(ChannelLayout::MONO));
+
+        let frame = AVFrame::new_audio(16, SampleFormat::Fltp, ChannelLayout::QUAD, 48000).unwrap();
+        assert_eq!(frame.channel_layout(), ChannelLayout::QUAD);
+        assert_eq!(frame.channels(), 4);
+    }
+}
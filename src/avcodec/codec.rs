@@ -1,5 +1,5 @@
 use std::ffi::{CStr, CString};
-use std::os::raw::{c_char, c_void};
+use std::os::raw::{c_char, c_int, c_void};
 use std::ptr::null_mut;
 
 use libav_sys::avcodec;
@@ -7,13 +7,111 @@ use libav_sys::avcodec::avcodec_get_hw_config;
 
 const EMPTY_STR: &str = "";
 
+/// AV_CODEC_CAP_* flags of a codec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodecCapabilities(u32);
+
+impl CodecCapabilities {
+    pub const DRAW_HORIZ_BAND: Self = Self(avcodec::AV_CODEC_CAP_DRAW_HORIZ_BAND);
+    /// Uses get_buffer2 for allocating frames, so custom allocators work.
+    pub const DR1: Self = Self(avcodec::AV_CODEC_CAP_DR1);
+    /// Buffers input, has to be flushed with a NULL frame or packet at the end.
+    pub const DELAY: Self = Self(avcodec::AV_CODEC_CAP_DELAY);
+    /// Accepts a smaller last audio frame.
+    pub const SMALL_LAST_FRAME: Self = Self(avcodec::AV_CODEC_CAP_SMALL_LAST_FRAME);
+    pub const SUBFRAMES: Self = Self(avcodec::AV_CODEC_CAP_SUBFRAMES);
+    /// Needs strict_std_compliance set to experimental to be opened.
+    pub const EXPERIMENTAL: Self = Self(avcodec::AV_CODEC_CAP_EXPERIMENTAL);
+    pub const CHANNEL_CONF: Self = Self(avcodec::AV_CODEC_CAP_CHANNEL_CONF);
+    pub const FRAME_THREADS: Self = Self(avcodec::AV_CODEC_CAP_FRAME_THREADS);
+    pub const SLICE_THREADS: Self = Self(avcodec::AV_CODEC_CAP_SLICE_THREADS);
+    pub const PARAM_CHANGE: Self = Self(avcodec::AV_CODEC_CAP_PARAM_CHANGE);
+    /// Threads in some other way, e.g. inside an external library. AV_CODEC_CAP_AUTO_THREADS before 5.0, the bit
+    /// is the same.
+    pub const OTHER_THREADS: Self = Self(1 << 15);
+    /// Audio encoder accepting any frame size.
+    pub const VARIABLE_FRAME_SIZE: Self = Self(avcodec::AV_CODEC_CAP_VARIABLE_FRAME_SIZE);
+    pub const AVOID_PROBING: Self = Self(avcodec::AV_CODEC_CAP_AVOID_PROBING);
+    /// Backed by hardware, e.g. nvenc.
+    pub const HARDWARE: Self = Self(avcodec::AV_CODEC_CAP_HARDWARE);
+    /// Possibly backed by hardware, e.g. a wrapper around an OS codec API.
+    pub const HYBRID: Self = Self(avcodec::AV_CODEC_CAP_HYBRID);
+    /// Encoder supporting avcodec_flush_buffers, e.g. to restart after a flush.
+    pub const ENCODER_FLUSH: Self = Self(avcodec::AV_CODEC_CAP_ENCODER_FLUSH);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+    /// True when all flags of `other` are set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for CodecCapabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Class of an object with AVOptions, e.g. the private options of a codec.
+pub struct AVClass {
+    pub int_class: *const avcodec::AVClass,
+    pub class_name: &'static str,
+}
+
+impl From<*const avcodec::AVClass> for AVClass {
+    fn from(int_class: *const avcodec::AVClass) -> Self {
+        unsafe {
+            Self {
+                int_class,
+                class_name: get_str_or_default((*int_class).class_name, EMPTY_STR),
+            }
+        }
+    }
+}
+
+// classes are static, immutable descriptions
+unsafe impl Send for AVClass {}
+
+unsafe impl Sync for AVClass {}
+
+/// A profile a codec supports, e.g. "High" for H.264.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Profile {
+    /// FF_PROFILE_* value, as in `profile` of the codec context.
+    pub id: i32,
+    pub name: &'static str,
+}
+
+// copies a list terminated by an element for which `is_end` is true, None for a NULL list
+unsafe fn terminated_list<T: Copy>(mut ptr: *const T, is_end: impl Fn(&T) -> bool) -> Option<Vec<T>> {
+    if ptr.is_null() {
+        return None;
+    }
+    let mut items = Vec::new();
+    while !is_end(&*ptr) {
+        items.push(*ptr);
+        ptr = ptr.add(1);
+    }
+    Some(items)
+}
+
 pub struct AVCodec {
     pub int_codec: *const avcodec::AVCodec,
     pub name: &'static str,
     pub long_name: &'static str,
-    pub media_type: AVMediaType,
-    pub id: AVCodecID,
-    pub capabilities: isize,
+    pub media_type: MediaType,
+    pub id: CodecId,
+    pub capabilities: CodecCapabilities,
     pub wrapper_name: &'static str,
 }
 
@@ -24,9 +122,9 @@ impl From<*const avcodec::AVCodec> for AVCodec {
                 int_codec,
                 name: get_str_or_default((*int_codec).name, EMPTY_STR),
                 long_name: get_str_or_default((*int_codec).long_name, EMPTY_STR),
-                media_type: (*int_codec).type_,
-                id: (*int_codec).id,
-                capabilities: (*int_codec).capabilities as isize,
+                media_type: MediaType::from((*int_codec).type_),
+                id: CodecId::from((*int_codec).id),
+                capabilities: CodecCapabilities::from_bits((*int_codec).capabilities as u32),
                 wrapper_name: get_str_or_default((*int_codec).wrapper_name, EMPTY_STR),
             }
         }
@@ -44,23 +142,24 @@ impl AVCodec {
     pub fn iterator() -> AVCodecIter {
         return AVCodecIter::new();
     }
-    pub fn find_decoder(id: avcodec::AVCodecID) -> Option<Self> {
+    // the find functions return `AVCodec *` before 5.0 and `const AVCodec *` since, the casts cover both
+    pub fn find_decoder(id: CodecId) -> Option<Self> {
         unsafe {
-            let codec = avcodec::avcodec_find_decoder(id);
+            let codec = avcodec::avcodec_find_decoder(AVCodecID::from(id));
             return if codec.is_null() {
                 None
             } else {
-                Some(AVCodec::from(codec))
+                Some(AVCodec::from(codec as *const avcodec::AVCodec))
             };
         }
     }
-    pub fn find_encoder(id: avcodec::AVCodecID) -> Option<Self> {
+    pub fn find_encoder(id: CodecId) -> Option<Self> {
         unsafe {
-            let codec = avcodec::avcodec_find_encoder(id);
+            let codec = avcodec::avcodec_find_encoder(AVCodecID::from(id));
             return if codec.is_null() {
                 None
             } else {
-                Some(AVCodec::from(codec))
+                Some(AVCodec::from(codec as *const avcodec::AVCodec))
             };
         }
     }
@@ -71,7 +170,7 @@ impl AVCodec {
             return if codec.is_null() {
                 None
             } else {
-                Some(AVCodec::from(codec))
+                Some(AVCodec::from(codec as *const avcodec::AVCodec))
             };
         }
     }
@@ -82,7 +181,7 @@ impl AVCodec {
             return if codec.is_null() {
                 None
             } else {
-                Some(AVCodec::from(codec))
+                Some(AVCodec::from(codec as *const avcodec::AVCodec))
             };
         }
     }
@@ -113,9 +212,92 @@ impl AVCodec {
             }
         }
     }
+
+    /// Frame rates an encoder supports, None when it supports any.
+    pub fn supported_framerates(&self) -> Option<Vec<Rational>> {
+        unsafe {
+            let list = terminated_list((*self.int_codec).supported_framerates, |rate| rate.num == 0 && rate.den == 0)?;
+            Some(list.into_iter().map(Rational::from).collect())
+        }
+    }
+    /// Pixel formats an encoder supports, in order of preference. None when unknown, for decoders usually.
+    pub fn pix_fmts(&self) -> Option<Vec<PixelFormat>> {
+        unsafe {
+            let list = terminated_list((*self.int_codec).pix_fmts, |fmt| *fmt == avcodec::AVPixelFormat_AV_PIX_FMT_NONE)?;
+            Some(list.into_iter().map(PixelFormat::from).collect())
+        }
+    }
+    /// Sample rates an audio encoder supports, None when it supports any.
+    pub fn supported_samplerates(&self) -> Option<Vec<i32>> {
+        unsafe { terminated_list((*self.int_codec).supported_samplerates, |rate| *rate == 0) }
+    }
+    /// Sample formats an audio encoder supports, in order of preference.
+    pub fn sample_fmts(&self) -> Option<Vec<SampleFormat>> {
+        unsafe {
+            let list = terminated_list((*self.int_codec).sample_fmts, |fmt| *fmt == avcodec::AVSampleFormat_AV_SAMPLE_FMT_NONE)?;
+            Some(list.into_iter().map(SampleFormat::from).collect())
+        }
+    }
+    /// Channel layouts an audio encoder supports, None when it supports any.
+    #[cfg(not(ffmpeg_5_1))]
+    pub fn channel_layouts(&self) -> Option<Vec<ChannelLayout>> {
+        unsafe {
+            let list = terminated_list((*self.int_codec).channel_layouts, |mask| *mask == 0)?;
+            Some(list.into_iter().map(ChannelLayout::from_mask).collect())
+        }
+    }
+    /// Channel layouts an audio encoder supports, None when it supports any. Layouts in a custom channel order are
+    /// left out.
+    #[cfg(ffmpeg_5_1)]
+    pub fn channel_layouts(&self) -> Option<Vec<ChannelLayout>> {
+        unsafe {
+            let mut ptr = (*self.int_codec).ch_layouts;
+            if ptr.is_null() {
+                return None;
+            }
+            let mut layouts = Vec::new();
+            // terminated by a zeroed layout
+            while (*ptr).nb_channels != 0 {
+                if (*ptr).order == avcodec::AVChannelOrder_AV_CHANNEL_ORDER_NATIVE {
+                    layouts.push(ChannelLayout::from_mask((*ptr).u.mask));
+                }
+                ptr = ptr.add(1);
+            }
+            Some(layouts)
+        }
+    }
+    /// Profiles the codec recognizes, empty when it has none.
+    pub fn profiles(&self) -> Vec<Profile> {
+        unsafe {
+            let list = terminated_list((*self.int_codec).profiles, |profile| profile.profile == avcodec::FF_PROFILE_UNKNOWN);
+            list.unwrap_or_default().iter().map(|profile| Profile {
+                id: profile.profile,
+                name: get_str_or_default(profile.name, EMPTY_STR),
+            }).collect()
+        }
+    }
+    /// Highest `lowres` value of a decoder, which decodes at 1 / 2^lowres of the size. 0 when unsupported.
+    pub fn max_lowres(&self) -> u8 {
+        unsafe { (*self.int_codec).max_lowres }
+    }
+    /// Class of the codec's private options, None when it has none.
+    pub fn priv_class(&self) -> Option<AVClass> {
+        unsafe {
+            let class = (*self.int_codec).priv_class;
+            if class.is_null() {
+                return None;
+            }
+            Some(AVClass::from(class))
+        }
+    }
 }
 
 
+// codecs are static, immutable descriptions
+unsafe impl Send for AVCodec {}
+
+unsafe impl Sync for AVCodec {}
+
 pub struct AVCodecIter {
     opaque: *mut c_void,
 }
@@ -137,7 +319,7 @@ impl Iterator for AVCodecIter {
             if int_codec.is_null() {
                 return None;
             }
-            return Some(AVCodec::from(int_codec as *const avcodec::AVCodec));
+            return Some(AVCodec::from(int_codec));
         }
     }
 }
@@ -145,7 +327,8 @@ impl Iterator for AVCodecIter {
 
 #[cfg(test)]
 mod tests {
-    use libav_sys::avcodec::{av_hwdevice_get_type_name, avcodec_get_hw_config, AVCodecHWConfig, AVCodecID_AV_CODEC_ID_HEVC, AVHWFrameTransferDirection_AV_HWFRAME_TRANSFER_DIRECTION_TO};
+    #[cfg(feature = "cuda")]
+    use libav_sys::avcodec::{av_hwdevice_get_type_name, avcodec_get_hw_config, AVCodecHWConfig, AVHWFrameTransferDirection_AV_HWFRAME_TRANSFER_DIRECTION_TO};
     use libav_sys::avcodec;
 
     use super::*;
@@ -157,19 +340,23 @@ mod tests {
         }
     }
 
+    // the nvenc tests need a cuda build and device
     #[test]
+    #[cfg(feature = "cuda")]
     fn avcodec_find_codec_by_id() {
-        let codec = AVCodec::find_encoder(AVCodecID_AV_CODEC_ID_HEVC).unwrap();
+        let codec = AVCodec::find_encoder(CodecId::Hevc).unwrap();
         println!("name: {} long_name: {}, wrapper_name: {}", codec.name, codec.long_name, codec.wrapper_name);
     }
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn avcodec_find_codec_by_name() {
         let codec = AVCodec::find_encoder_by_name("h264_nvenc").unwrap();
         println!("name: {} long_name: {}, wrapper_name: {}", codec.name, codec.long_name, codec.wrapper_name);
     }
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn avcodec_get_device_type() {
         unsafe {
             let codec = AVCodec::find_encoder_by_name("h264_nvenc").unwrap();
@@ -187,6 +374,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "cuda")]
     fn avcodec_hw_init() {
         let codec = AVCodec::find_encoder_by_name("h264_nvenc").unwrap();
         let hw_config = codec.get_hw_config(0).unwrap();
@@ -204,11 +392,7 @@ mod tests {
             println!("{}", pix_fmt_to_name(fmt))
         }
         let mut hw_frame_ctx = hwframe_ctx_alloc(&mut hw_ctx);
-        let mut frame_ctx = hw_frame_ctx.get_data().unwrap();
-        frame_ctx.height = 2560;
-        frame_ctx.width = 1440;
-        frame_ctx.format = avcodec::AVPixelFormat_AV_PIX_FMT_CUDA;
-        frame_ctx.sw_format = avcodec::AVPixelFormat_AV_PIX_FMT_BGR0;
+        hw_frame_ctx.set_frames_format(PixelFormat::Cuda, PixelFormat::Bgr0, 1440, 2560).unwrap();
         hwframe_ctx_init(&mut hw_frame_ctx).unwrap();
         let mut frame = AVFrame::new();
         hwframe_get_buffer(&mut hw_frame_ctx, &mut frame, 0).unwrap();
@@ -223,6 +407,37 @@ mod tests {
         let pkt = AVPacket::new();
         drop(pkt)
     }
+
+    #[test]
+    fn codec_capabilities() {
+        let mpeg4 = AVCodec::find_encoder(CodecId::Mpeg4).unwrap();
+        assert!(mpeg4.capabilities.contains(CodecCapabilities::DELAY | CodecCapabilities::SLICE_THREADS));
+        assert!(!mpeg4.capabilities.contains(CodecCapabilities::HARDWARE));
+        assert_eq!(mpeg4.pix_fmts(), Some(vec![PixelFormat::Yuv420p]));
+        assert_eq!(mpeg4.supported_framerates(), None);
+        assert_eq!(mpeg4.priv_class().unwrap().class_name, "MPEG4 encoder");
+        assert!(AVCodec::find_encoder(CodecId::Mpeg1Video).unwrap().supported_framerates().unwrap()
+            .contains(&Rational::new(25, 1)));
+
+        let decoder = AVCodec::find_decoder(CodecId::Mpeg4).unwrap();
+        assert!(decoder.max_lowres() > 0);
+        assert!(decoder.profiles().iter().any(|profile| profile.name == "Simple Profile"));
+
+        let mp2 = AVCodec::find_encoder(CodecId::Mp2).unwrap();
+        assert_eq!(mp2.sample_fmts(), Some(vec![SampleFormat::S16]));
+        assert!(mp2.supported_samplerates().unwrap().contains(&44100));
+        assert_eq!(mp2.channel_layouts(), Some(vec![ChannelLayout::MONO, ChannelLayout::STEREO]));
+    }
+
+    #[test]
+    fn frames_and_packets_cross_threads() {
+        let mut pkt = AVPacket::new();
+        unsafe { avcodec::av_new_packet(pkt.as_mut_ptr(), 16) };
+        let frame = AVFrame::new();
+        let handle = std::thread::spawn(move || (pkt.get_data().len(), frame));
+        let (size, _frame) = handle.join().unwrap();
+        assert_eq!(size, 16);
+    }
 }
 
 #[repr(transparent)]
@@ -241,7 +456,7 @@ impl Clone for AVPacket {
     fn clone(&self) -> Self {
         let pkt = Self::new();
         unsafe {
-            let result = avcodec::av_packet_ref(pkt.get_internal(), self.0);
+            let result = avcodec::av_packet_ref(pkt.0, self.0);
             if result < 0 {
                 error!("CRITICAL: unable to clone packet!: {}",err_str(result));
             }
@@ -250,6 +465,11 @@ impl Clone for AVPacket {
     }
 }
 
+// packet data is reference counted atomically, a packet can be sent to another thread and read from several
+unsafe impl Send for AVPacket {}
+
+unsafe impl Sync for AVPacket {}
+
 impl AVPacket {
     #[allow(dead_code)]
     pub fn new() -> Self {
@@ -258,10 +478,53 @@ impl AVPacket {
             Self(packet)
         }
     }
-    pub fn get_internal(&self) -> &mut avcodec::AVPacket {
-        return unsafe { &mut *self.0 };
+    pub fn get_internal(&self) -> &avcodec::AVPacket {
+        return unsafe { &*self.0 };
+    }
+    /// Mutable access to the raw packet. The caller must keep the packet consistent for ffmpeg, e.g. `data` must
+    /// stay backed by `buf`.
+    pub unsafe fn get_internal_mut(&mut self) -> &mut avcodec::AVPacket {
+        return &mut *self.0;
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVPacket {
+        return self.0;
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut avcodec::AVPacket {
+        return self.0;
+    }
+    /// Index of the stream the packet belongs to in its format context.
+    pub fn stream_index(&self) -> usize {
+        self.get_internal().stream_index as usize
+    }
+    pub fn set_stream_index(&mut self, stream_index: usize) {
+        unsafe { self.get_internal_mut().stream_index = stream_index as i32 }
+    }
+    pub fn pts(&self) -> i64 {
+        self.get_internal().pts
+    }
+    pub fn set_pts(&mut self, pts: i64) {
+        unsafe { self.get_internal_mut().pts = pts }
+    }
+    pub fn dts(&self) -> i64 {
+        self.get_internal().dts
+    }
+    pub fn set_dts(&mut self, dts: i64) {
+        unsafe { self.get_internal_mut().dts = dts }
+    }
+    pub fn duration(&self) -> i64 {
+        self.get_internal().duration
+    }
+    pub fn is_key(&self) -> bool {
+        self.get_internal().flags & avcodec::AV_PKT_FLAG_KEY as i32 != 0
+    }
+    /// Converts pts, dts and duration from time base `src` to `dst`.
+    pub fn rescale_ts(&mut self, src: Rational, dst: Rational) {
+        unsafe { avcodec::av_packet_rescale_ts(self.0, src.into(), dst.into()) }
     }
     pub fn get_data(&self) -> &[u8] {
+        if self.get_internal().data.is_null() {
+            return &[];
+        }
         unsafe { &(*slice_from_raw_parts(self.get_internal().data, self.get_internal().size as usize)) }
     }
     pub fn unref(&mut self) {
@@ -19,18 +19,78 @@ impl AVCodecParameters {
         }
     }
 
-    pub fn from(ctx: &mut AVCodecContext) -> Result<Self, i32> {
+    pub fn from(ctx: &AVCodecContext) -> Result<Self, AvError> {
         let params = Self::new();
         unsafe {
             let ret = avcodec::avcodec_parameters_from_context(params.internal, ctx.internal);
             if ret < 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
         }
         return Ok(params);
     }
 }
 
+// parameters are plain data owned by this struct
+unsafe impl Send for AVCodecParameters {}
+
+unsafe impl Sync for AVCodecParameters {}
+
+/// AV_CODEC_FLAG_* flags of a codec context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodecFlags(i32);
+
+impl CodecFlags {
+    /// Fixed quality scale instead of rate control, the quality is taken from the frames.
+    pub const QSCALE: Self = Self(avcodec::AV_CODEC_FLAG_QSCALE as i32);
+    pub const FOUR_MV: Self = Self(avcodec::AV_CODEC_FLAG_4MV as i32);
+    /// Outputs frames even when they are known to be corrupt.
+    pub const OUTPUT_CORRUPT: Self = Self(avcodec::AV_CODEC_FLAG_OUTPUT_CORRUPT as i32);
+    pub const QPEL: Self = Self(avcodec::AV_CODEC_FLAG_QPEL as i32);
+    /// First pass of a two pass encode, writes stats_out.
+    pub const PASS1: Self = Self(avcodec::AV_CODEC_FLAG_PASS1 as i32);
+    /// Second pass of a two pass encode, reads stats_in.
+    pub const PASS2: Self = Self(avcodec::AV_CODEC_FLAG_PASS2 as i32);
+    pub const LOOP_FILTER: Self = Self(avcodec::AV_CODEC_FLAG_LOOP_FILTER as i32);
+    /// Only codes or decodes luma.
+    pub const GRAY: Self = Self(avcodec::AV_CODEC_FLAG_GRAY as i32);
+    /// Computes the PSNR of encoded frames.
+    pub const PSNR: Self = Self(avcodec::AV_CODEC_FLAG_PSNR as i32);
+    pub const INTERLACED_DCT: Self = Self(avcodec::AV_CODEC_FLAG_INTERLACED_DCT as i32);
+    /// Avoids delay, e.g. no B-frames when encoding.
+    pub const LOW_DELAY: Self = Self(avcodec::AV_CODEC_FLAG_LOW_DELAY as i32);
+    /// See `AVCodecContext::set_global_header`.
+    pub const GLOBAL_HEADER: Self = Self(avcodec::AV_CODEC_FLAG_GLOBAL_HEADER as i32);
+    /// Only uses bit exact algorithms, e.g. for tests.
+    pub const BITEXACT: Self = Self(avcodec::AV_CODEC_FLAG_BITEXACT as i32);
+    pub const AC_PRED: Self = Self(avcodec::AV_CODEC_FLAG_AC_PRED as i32);
+    pub const INTERLACED_ME: Self = Self(avcodec::AV_CODEC_FLAG_INTERLACED_ME as i32);
+    /// Every GOP starts with a keyframe no other GOP refers to.
+    pub const CLOSED_GOP: Self = Self(avcodec::AV_CODEC_FLAG_CLOSED_GOP as i32);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+    pub const fn bits(&self) -> i32 {
+        self.0
+    }
+    /// True when all flags of `other` are set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for CodecFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
 pub struct AVCodecContext {
     internal: *mut avcodec::AVCodecContext,
 }
@@ -43,79 +103,166 @@ impl Drop for AVCodecContext {
     }
 }
 
+// a codec context may be moved to another thread but ffmpeg does not allow concurrent calls on it, so it is not Sync
+unsafe impl Send for AVCodecContext {}
+
 impl AVCodecContext {
     pub fn new(codec: &AVCodec) -> Self {
         Self {
-            internal: unsafe { avcodec::avcodec_alloc_context3(codec.int_codec as *const avcodec::AVCodec) }
+            internal: unsafe { avcodec::avcodec_alloc_context3(codec.int_codec) }
         }
     }
-    pub fn set_parameters(&self, parameters: &mut AVCodecParameters) -> Result<i32, i32> {
+    pub fn set_parameters(&mut self, parameters: &AVCodecParameters) -> Result<i32, AvError> {
         unsafe {
             let ret = avcodec::avcodec_parameters_to_context(self.internal, parameters.internal);
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
-    pub fn open2(&self, codec: &AVCodec, dict: Option<&mut AVDictionary>) -> Result<i32, i32> {
+    pub fn open2(&mut self, codec: &AVCodec, dict: Option<&mut AVDictionary>) -> Result<i32, AvError> {
         unsafe {
             let mut r_dict = null_mut();
-            if dict.is_some() {
-                r_dict = &mut dict.unwrap().internal
+            if let Some(dict) = dict {
+                r_dict = &mut dict.internal
             }
             let ret = avcodec::avcodec_open2(self.internal, codec.int_codec, r_dict);
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
-    pub fn send_frame(&self, frame: &AVFrame) -> Result<i32, i32> {
+    /// Puts codec extradata in the container header instead of every keyframe. Must be set before `open2` when
+    /// the muxer needs it, see `AVFormatOutput::needs_global_header`.
+    pub fn set_global_header(&mut self, global_header: bool) {
         unsafe {
-            let ret = avcodec::avcodec_send_frame(self.internal, frame.get_internal());
+            let flag = avcodec::AV_CODEC_FLAG_GLOBAL_HEADER as i32;
+            if global_header {
+                (*self.internal).flags |= flag;
+            } else {
+                (*self.internal).flags &= !flag;
+            }
+        }
+    }
+    pub fn flags(&self) -> CodecFlags {
+        CodecFlags::from_bits(self.get_internal().flags)
+    }
+    pub fn send_frame(&mut self, frame: &AVFrame) -> Result<i32, AvError> {
+        unsafe {
+            let ret = avcodec::avcodec_send_frame(self.internal, frame.as_ptr());
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
-    pub fn send_packet(&self, pkt: &AVPacket) -> Result<i32, i32> {
+    pub fn send_packet(&mut self, pkt: &AVPacket) -> Result<i32, AvError> {
         unsafe {
-            let ret = avcodec::avcodec_send_packet(self.internal, pkt.get_internal());
+            let ret = avcodec::avcodec_send_packet(self.internal, pkt.as_ptr());
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
-    pub fn receive_pkt(&self, pkt: &mut AVPacket) -> Result<i32, i32> {
+    pub fn receive_pkt(&mut self, pkt: &mut AVPacket) -> Result<i32, AvError> {
         unsafe {
-            let ret = avcodec::avcodec_receive_packet(self.internal, pkt.get_internal());
+            let ret = avcodec::avcodec_receive_packet(self.internal, pkt.as_mut_ptr());
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
-    pub fn receive_frame(&self, frame: &mut AVFrame) -> Result<i32, i32> {
+    pub fn receive_frame(&mut self, frame: &mut AVFrame) -> Result<i32, AvError> {
         unsafe {
-            let ret = avcodec::avcodec_receive_frame(self.internal, frame.get_internal());
+            let ret = avcodec::avcodec_receive_frame(self.internal, frame.as_mut_ptr());
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
 
-    pub fn get_internal(&self) -> &mut avcodec::AVCodecContext {
-        return unsafe { &mut *(self.internal) };
+    /// Samples per channel in every audio frame sent to the encoder after `open2`, 0 when any size is accepted.
+    pub fn frame_size(&self) -> i32 {
+        self.get_internal().frame_size
+    }
+    /// Channel layout of an audio codec, not `is_known` when only the channel count is set.
+    #[cfg(not(ffmpeg_5_1))]
+    pub fn channel_layout(&self) -> ChannelLayout {
+        ChannelLayout::from_mask(self.get_internal().channel_layout)
+    }
+    #[cfg(ffmpeg_5_1)]
+    pub fn channel_layout(&self) -> ChannelLayout {
+        let layout = &self.get_internal().ch_layout;
+        if layout.order != avcodec::AVChannelOrder_AV_CHANNEL_ORDER_NATIVE {
+            return ChannelLayout::from_mask(0);
+        }
+        ChannelLayout::from_mask(unsafe { layout.u.mask })
+    }
+    /// Sets the channel layout and count of an audio encoder, must be called before `open2`.
+    #[cfg(not(ffmpeg_5_1))]
+    pub fn set_channel_layout(&mut self, channel_layout: ChannelLayout) {
+        unsafe {
+            (*self.internal).channel_layout = channel_layout.mask();
+            (*self.internal).channels = channel_layout.nb_channels();
+        }
+    }
+    #[cfg(ffmpeg_5_1)]
+    pub fn set_channel_layout(&mut self, channel_layout: ChannelLayout) {
+        unsafe {
+            avcodec::av_channel_layout_uninit(&mut (*self.internal).ch_layout);
+            avcodec::av_channel_layout_from_mask(&mut (*self.internal).ch_layout, channel_layout.mask());
+        }
+    }
+    /// Drops buffered frames and packets and resets the codec, e.g. after seeking the input or draining it.
+    pub fn flush_buffers(&mut self) {
+        unsafe { avcodec::avcodec_flush_buffers(self.internal) }
+    }
+
+    pub fn get_internal(&self) -> &avcodec::AVCodecContext {
+        return unsafe { &*(self.internal) };
+    }
+    /// Mutable access to the raw context, e.g. to set fields before `open2`. The caller must keep the context
+    /// consistent for ffmpeg: pointers must stay valid and owned fields must be allocated with av_malloc.
+    pub unsafe fn get_internal_mut(&mut self) -> &mut avcodec::AVCodecContext {
+        return &mut *(self.internal);
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVCodecContext {
+        return self.internal;
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut avcodec::AVCodecContext {
+        return self.internal;
+    }
+}
+
+unsafe impl AVOptions for AVCodecContext {
+    fn options_ptr(&self) -> *mut c_void {
+        self.internal as *mut c_void
+    }
+    /// True once the codec is opened.
+    fn is_in_use(&self) -> bool {
+        unsafe { avcodec::avcodec_is_open(self.internal) > 0 }
+    }
+}
+
+impl AVCodecContext {
+    /// Options of the codec's private data, e.g. "preset" of libx264. Empty when the codec has none.
+    pub fn priv_options(&self) -> Vec<OptionInfo> {
+        let priv_data = self.get_internal().priv_data;
+        if priv_data.is_null() || unsafe { (*(priv_data as *const *const avcodec::AVClass)).is_null() } {
+            return Vec::new();
+        }
+        unsafe { list_options(priv_data) }
     }
 }
 
@@ -136,19 +283,51 @@ impl<T> Drop for AVBufferRef<T> {
     }
 }
 
+// buffer references are reference counted atomically and the hw contexts they carry are shared between threads by
+// ffmpeg itself, e.g. one hw device context used by several codecs. Other data is not known to be thread safe.
+unsafe impl Send for AVBufferRef<AVHWDeviceContext> {}
+
+unsafe impl Sync for AVBufferRef<AVHWDeviceContext> {}
+
+unsafe impl Send for AVBufferRef<AVHWFramesContext> {}
+
+unsafe impl Sync for AVBufferRef<AVHWFramesContext> {}
+
 impl<T> AVBufferRef<T> {
-    pub fn get_data(&self) -> Option<&mut T> {
+    pub fn get_data(&self) -> Option<&T> {
         unsafe {
             if self.internal.is_null() {
                 return None;
             }
-            let x: *mut T = (*self.internal).data.cast();
-            let x = &mut *x;
-            return Some(x);
+            let x: *const T = (*self.internal).data.cast();
+            return Some(&*x);
+        }
+    }
+    /// Mutable access to the referenced data, None when the buffer is shared with other references. The caller
+    /// must keep the data consistent for ffmpeg, e.g. only configure a frames context before `hwframe_ctx_init`.
+    pub unsafe fn get_data_mut(&mut self) -> Option<&mut T> {
+        if self.internal.is_null() || avcodec::av_buffer_is_writable(self.internal) == 0 {
+            return None;
         }
+        let x: *mut T = (*self.internal).data.cast();
+        return Some(&mut *x);
     }
-    pub fn get_internal(&mut self) -> &mut avcodec::AVBufferRef {
-        return unsafe { &mut *self.internal };
+    pub fn get_internal(&self) -> &avcodec::AVBufferRef {
+        return unsafe { &*self.internal };
+    }
+}
+
+impl AVBufferRef<AVHWFramesContext> {
+    /// Sets the hw and sw pixel formats and the frame size, must be called before `hwframe_ctx_init`.
+    pub fn set_frames_format(&mut self, format: PixelFormat, sw_format: PixelFormat, width: i32, height: i32) -> Result<(), AvError> {
+        unsafe {
+            let frames_ctx = self.get_data_mut().ok_or(AvError::InvalidArgument)?;
+            frames_ctx.format = AVPixelFormat::from(format);
+            frames_ctx.sw_format = AVPixelFormat::from(sw_format);
+            frames_ctx.width = width;
+            frames_ctx.height = height;
+        }
+        return Ok(());
     }
 }
 
@@ -165,14 +344,30 @@ impl Clone for AVFrame {
 }
 
 
+// frame data is reference counted atomically, a frame can be sent to another thread and read from several
+unsafe impl Send for AVFrame {}
+
+unsafe impl Sync for AVFrame {}
+
 impl AVFrame {
     pub fn new() -> Self {
         unsafe {
             Self(avcodec::av_frame_alloc())
         }
     }
-    pub fn get_internal(&self) -> &mut avcodec::AVFrame {
-        return unsafe { &mut *self.0 };
+    pub fn get_internal(&self) -> &avcodec::AVFrame {
+        return unsafe { &*self.0 };
+    }
+    /// Mutable access to the raw frame. The caller must keep the frame consistent for ffmpeg, e.g. data pointers
+    /// must stay backed by `buf` and must not be written to unless the frame is writable.
+    pub unsafe fn get_internal_mut(&mut self) -> &mut avcodec::AVFrame {
+        return &mut *self.0;
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVFrame {
+        return self.0;
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut avcodec::AVFrame {
+        return self.0;
     }
     pub fn unref(&mut self) {
         unsafe {
@@ -187,21 +382,21 @@ impl Drop for AVFrame {
     }
 }
 
-pub fn hwdevice_ctx_create(typ: AVHWDeviceType, device: &str, opts: Option<&AVDictionary>, flags: i32) -> Result<AVBufferRef<AVHWDeviceContext>, i32> {
+pub fn hwdevice_ctx_create(typ: AVHWDeviceType, device: &str, opts: Option<&AVDictionary>, flags: i32) -> Result<AVBufferRef<AVHWDeviceContext>, AvError> {
     unsafe {
         let mut buf = null_mut();
         let mut raw_opts = null_mut();
-        if opts.is_some() {
-            raw_opts = opts.unwrap().internal;
+        if let Some(opts) = opts {
+            raw_opts = opts.internal;
         }
         let mut cdevice = null_mut();
-        if device.len() > 0 {
+        if !device.is_empty() {
             cdevice = av_strdup(device);
         }
         let ret = avcodec::av_hwdevice_ctx_create(&mut buf, typ, cdevice, raw_opts, flags);
         av_strfree(cdevice);
         if ret < 0 {
-            return Err(ret);
+            return Err(AvError::from(ret));
         }
         return Ok(AVBufferRef::from(buf));
     }
@@ -214,11 +409,11 @@ pub fn hwframe_ctx_alloc(hw_device_ctx: &mut AVBufferRef<AVHWDeviceContext>) ->
     }
 }
 
-pub fn hwframe_ctx_init(hw_frame_ctx: &mut AVBufferRef<AVHWFramesContext>) -> Result<i32, i32> {
+pub fn hwframe_ctx_init(hw_frame_ctx: &mut AVBufferRef<AVHWFramesContext>) -> Result<i32, AvError> {
     unsafe {
         let ret = avcodec::av_hwframe_ctx_init(hw_frame_ctx.internal);
         if ret < 0 {
-            Err(ret)
+            Err(AvError::from(ret))
         } else {
             Ok(ret)
         }
@@ -226,11 +421,11 @@ pub fn hwframe_ctx_init(hw_frame_ctx: &mut AVBufferRef<AVHWFramesContext>) -> Re
 }
 
 
-pub fn hwframe_get_buffer(hw_frame_ctx: &mut AVBufferRef<AVHWFramesContext>, frame: &mut AVFrame, flags: i32) -> Result<i32, i32> {
+pub fn hwframe_get_buffer(hw_frame_ctx: &mut AVBufferRef<AVHWFramesContext>, frame: &mut AVFrame, flags: i32) -> Result<i32, AvError> {
     unsafe {
-        let ret = avcodec::av_hwframe_get_buffer(hw_frame_ctx.internal, frame.get_internal(), flags);
+        let ret = avcodec::av_hwframe_get_buffer(hw_frame_ctx.internal, frame.as_mut_ptr(), flags);
         if ret < 0 {
-            Err(ret)
+            Err(AvError::from(ret))
         } else {
             Ok(ret)
         }
@@ -244,8 +439,8 @@ pub fn hwdevice_get_hwframe_constraints(buf_ref: &mut AVBufferRef<AVHWDeviceCont
 }
 
 pub struct AVHWFramesConstraints {
-    pub valid_hw_formats: Vec<AVPixelFormat>,
-    pub valid_sw_formats: Vec<AVPixelFormat>,
+    pub valid_hw_formats: Vec<PixelFormat>,
+    pub valid_sw_formats: Vec<PixelFormat>,
 
     pub min_width: i32,
     pub min_height: i32,
@@ -272,9 +467,12 @@ impl From<*mut avcodec::AVHWFramesConstraints> for AVHWFramesConstraints {
 }
 
 
-fn get_vector(fmts: *mut AVPixelFormat) -> Vec<AVPixelFormat> {
+fn get_vector(fmts: *mut AVPixelFormat) -> Vec<PixelFormat> {
     unsafe {
         let mut out_fmts = Vec::new();
+        if fmts.is_null() {
+            return out_fmts;
+        }
         let data_size = size_of::<AVPixelFormat>();
         let mut idx = 0;
         loop {
@@ -282,31 +480,39 @@ fn get_vector(fmts: *mut AVPixelFormat) -> Vec<AVPixelFormat> {
             if fmt == avcodec::AVPixelFormat_AV_PIX_FMT_NONE {
                 break;
             }
-            out_fmts.push(fmt);
+            out_fmts.push(PixelFormat::from(fmt));
             idx += 1;
         }
         return out_fmts;
     }
 }
 
-pub fn pix_fmt_to_name(pix_fmt: AVPixelFormat) -> String {
-    let mut out = String::new();
-    unsafe {
-        let buf = avcodec::av_get_pix_fmt_name(pix_fmt);
-        let stri = CStr::from_ptr(buf as *mut c_char);
-        out.push_str(stri.to_str().unwrap());
-        return out;
-    }
+pub fn pix_fmt_to_name(pix_fmt: PixelFormat) -> String {
+    return pix_fmt.to_string();
 }
 
 
 pub fn err_str(err: i32) -> String {
     unsafe {
         let size = 128;
-        let new_parse_str: *mut c_char = avcodec::av_malloc((size) as u64) as *mut c_char;
+        let new_parse_str: *mut c_char = avcodec::av_malloc(size) as *mut c_char;
         avcodec::av_strerror(err, new_parse_str, size);
         let out = String::from(CStr::from_ptr(new_parse_str).to_str().unwrap());
         avcodec::av_free(new_parse_str as *mut c_void);
         return out;
     }
 }
+
+pub fn av_strdup(s: &str) -> *mut c_char {
+    unsafe {
+        let str = CString::new(s).unwrap();
+        let cstr = str.as_c_str();
+        avcodec::av_strdup(cstr.as_ptr())
+    }
+}
+
+pub fn av_strfree(s: *mut c_char) {
+    unsafe {
+        avcodec::av_free(s as *mut c_void);
+    }
+}
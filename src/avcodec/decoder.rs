@@ -0,0 +1,231 @@
+/// Threading of a decoder (FF_THREAD_*).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadType {
+    /// Decodes several frames at once, adding one frame of delay per thread.
+    Frame,
+    /// Decodes slices of one frame at once, without delay.
+    Slice,
+    /// Whichever the codec supports, frame threading when it supports both.
+    Any,
+}
+
+impl ThreadType {
+    fn ff_thread_type(&self) -> i32 {
+        let thread_type = match *self {
+            ThreadType::Frame => avcodec::FF_THREAD_FRAME,
+            ThreadType::Slice => avcodec::FF_THREAD_SLICE,
+            ThreadType::Any => avcodec::FF_THREAD_FRAME | avcodec::FF_THREAD_SLICE,
+        };
+        thread_type as i32
+    }
+}
+
+/// Builder of a `Decoder`, see `Decoder::from_parameters` and `Decoder::builder`.
+pub struct DecoderBuilder {
+    codec: AVCodec,
+    ctx: AVCodecContext,
+    options: Vec<(String, String)>,
+}
+
+impl DecoderBuilder {
+    /// Codec specific setup data, e.g. the avcC box of H.264 in mp4. Not needed with `Decoder::from_parameters`.
+    pub fn extradata(mut self, extradata: &[u8]) -> Result<Self, AvError> {
+        unsafe {
+            let raw = self.ctx.get_internal_mut();
+            avcodec::av_freep(&mut raw.extradata as *mut *mut u8 as *mut c_void);
+            raw.extradata_size = 0;
+            // decoders may read AV_INPUT_BUFFER_PADDING_SIZE bytes past the end, which have to be zero
+            let size = extradata.len() + avcodec::AV_INPUT_BUFFER_PADDING_SIZE as usize;
+            let data = avcodec::av_mallocz(size as u64) as *mut u8;
+            if data.is_null() {
+                return Err(AvError::NoMemory);
+            }
+            data.copy_from_nonoverlapping(extradata.as_ptr(), extradata.len());
+            raw.extradata = data;
+            raw.extradata_size = extradata.len() as c_int;
+        }
+        Ok(self)
+    }
+    /// Number of decoding threads, 0 lets ffmpeg pick one per cpu.
+    pub fn thread_count(mut self, thread_count: i32) -> Self {
+        unsafe { self.ctx.get_internal_mut().thread_count = thread_count }
+        self
+    }
+    pub fn thread_type(mut self, thread_type: ThreadType) -> Self {
+        unsafe { self.ctx.get_internal_mut().thread_type = thread_type.ff_thread_type() }
+        self
+    }
+    /// Time base of the packet timestamps, usually the stream time base. Decoded frames keep this time base.
+    pub fn packet_time_base(mut self, time_base: Rational) -> Self {
+        unsafe { self.ctx.get_internal_mut().pkt_timebase = time_base.into() }
+        self
+    }
+    /// Sets a generic or codec private option passed to `open2`. `build` fails with `AvError::UnconsumedOptions`
+    /// when the codec does not know it.
+    pub fn option(mut self, key: &str, value: &str) -> Self {
+        self.options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn build(mut self) -> Result<Decoder, AvError> {
+        let mut dict = AVDictionary::new();
+        for (key, value) in &self.options {
+            dict.set(key, value, 0)?;
+        }
+        self.ctx.open2(&self.codec, Some(&mut dict))?;
+        check_consumed(&dict)?;
+        Ok(Decoder { codec: self.codec, ctx: self.ctx })
+    }
+}
+
+/// An opened decoder. Packets go in with `decode`, frames come out of the returned iterators.
+///
+/// At the end of the stream `flush` returns the buffered frames. After a seek `reset` drops them instead, it also
+/// makes a flushed decoder accept packets again.
+///
+/// An error while receiving frames, e.g. of a hwaccel, is returned as the last item of an iterator, the frames before
+/// it are valid.
+pub struct Decoder {
+    codec: AVCodec,
+    ctx: AVCodecContext,
+}
+
+impl Decoder {
+    pub fn builder(codec: AVCodec) -> DecoderBuilder {
+        let ctx = AVCodecContext::new(&codec);
+        DecoderBuilder { codec, ctx, options: Vec::new() }
+    }
+    /// Decoder for a stream, e.g. from `AVStream::codec_parameters`.
+    pub fn from_parameters(parameters: &AVCodecParameters) -> Result<DecoderBuilder, AvError> {
+        let codec_id = CodecId::from(unsafe { (*parameters.internal).codec_id });
+        let codec = AVCodec::find_decoder(codec_id).ok_or(AvError::DecoderNotFound)?;
+        let mut builder = Self::builder(codec);
+        builder.ctx.set_parameters(parameters)?;
+        Ok(builder)
+    }
+
+    /// Sends a packet and returns the frames which are ready.
+    pub fn decode(&mut self, pkt: &AVPacket) -> Result<impl Iterator<Item = Result<AVFrame, AvError>> + '_, AvError> {
+        let mut backlog = Vec::new();
+        if let Err(err) = self.ctx.send_packet(pkt) {
+            if err != AvError::Again {
+                return Err(err);
+            }
+            // frames of an earlier call were not taken, the packet fits once they are received
+            for frame in (DecodedFrames { ctx: &mut self.ctx, done: false }) {
+                backlog.push(Ok(frame?));
+            }
+            self.ctx.send_packet(pkt)?;
+        }
+        Ok(backlog.into_iter().chain(DecodedFrames { ctx: &mut self.ctx, done: false }))
+    }
+    /// Signals the end of the stream and returns the remaining frames. Packets are only accepted again after
+    /// `reset`.
+    pub fn flush(&mut self) -> Result<impl Iterator<Item = Result<AVFrame, AvError>> + '_, AvError> {
+        unsafe {
+            let ret = avcodec::avcodec_send_packet(self.ctx.as_mut_ptr(), null());
+            // flushing twice returns AVERROR_EOF
+            if ret < 0 && ret != AVERROR_EOF {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(DecodedFrames { ctx: &mut self.ctx, done: false })
+    }
+    /// Drops buffered packets and frames (avcodec_flush_buffers), e.g. after seeking the input.
+    pub fn reset(&mut self) {
+        self.ctx.flush_buffers();
+    }
+
+    pub fn codec(&self) -> &AVCodec {
+        &self.codec
+    }
+    pub fn codec_context(&self) -> &AVCodecContext {
+        &self.ctx
+    }
+    /// The opened context, e.g. for `AVFormatInput::seek_frame_exact`.
+    pub fn codec_context_mut(&mut self) -> &mut AVCodecContext {
+        &mut self.ctx
+    }
+}
+
+// frames received from the decoder until it needs more input or is drained, an error is the last item
+struct DecodedFrames<'a> {
+    ctx: &'a mut AVCodecContext,
+    done: bool,
+}
+
+impl Iterator for DecodedFrames<'_> {
+    type Item = Result<AVFrame, AvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut frame = AVFrame::new();
+        match self.ctx.receive_frame(&mut frame) {
+            Ok(_) => Some(Ok(frame)),
+            Err(AvError::Again) | Err(AvError::Eof) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_decoder {
+    use super::*;
+    use super::test_encoder::{encode_frames, mpeg4_encoder};
+
+    #[test]
+    fn decode_and_drain() {
+        let mut encoder = mpeg4_encoder(false);
+        let packets = encode_frames(&mut encoder, 10);
+        let mut decoder = Decoder::from_parameters(&encoder.parameters().unwrap()).unwrap()
+            .thread_count(2)
+            .thread_type(ThreadType::Frame)
+            .packet_time_base(encoder.time_base())
+            .build()
+            .unwrap();
+        let mut pts = Vec::new();
+        for pkt in &packets {
+            pts.extend(decoder.decode(pkt).unwrap().map(|frame| frame.unwrap().best_effort_timestamp()));
+        }
+        pts.extend(decoder.flush().unwrap().map(|frame| frame.unwrap().best_effort_timestamp()));
+        assert_eq!(pts, (0..10).collect::<Vec<i64>>());
+        assert_eq!(decoder.flush().unwrap().count(), 0);
+
+        // after a reset the decoder restarts at the next keyframe
+        decoder.reset();
+        let mut frames: Vec<AVFrame> = Vec::new();
+        for pkt in packets.iter().filter(|pkt| pkt.pts() >= 5) {
+            frames.extend(decoder.decode(pkt).unwrap().map(Result::unwrap));
+        }
+        frames.extend(decoder.flush().unwrap().map(Result::unwrap));
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[0].best_effort_timestamp(), 5);
+        let luma = frames[0].rows(0).unwrap().next().unwrap()[0] as i32;
+        assert!((luma - 100).abs() <= 4, "frame 5 has luma {}", luma);
+    }
+
+    #[test]
+    fn decode_with_extradata() {
+        let mut encoder = mpeg4_encoder(true);
+        let packets = encode_frames(&mut encoder, 3);
+        let raw = encoder.codec_context().get_internal();
+        let extradata = unsafe { std::slice::from_raw_parts(raw.extradata, raw.extradata_size as usize) };
+        assert!(!extradata.is_empty());
+        let codec = AVCodec::find_decoder(CodecId::Mpeg4).unwrap();
+        let mut decoder = Decoder::builder(codec).extradata(extradata).unwrap().build().unwrap();
+        let mut count = 0;
+        for pkt in &packets {
+            count += decoder.decode(pkt).unwrap().count();
+        }
+        count += decoder.flush().unwrap().count();
+        assert_eq!(count, 3);
+    }
+}
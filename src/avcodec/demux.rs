@@ -0,0 +1,423 @@
+/// Demuxer reading a file or URL (an AVFormatContext opened with avformat_open_input).
+pub struct AVFormatInput {
+    internal: *mut avcodec::AVFormatContext,
+    // custom I/O, dropped after the context is closed
+    #[allow(dead_code)]
+    io: Option<AVIOContext>,
+}
+
+impl Drop for AVFormatInput {
+    fn drop(&mut self) {
+        unsafe {
+            avcodec::avformat_close_input(&mut self.internal);
+        }
+    }
+}
+
+// like the codec context, a demuxer can be moved to another thread but must not be used from several at once
+unsafe impl Send for AVFormatInput {}
+
+impl AVFormatInput {
+    /// Opens `url` and reads the container header. Entries of `dict` consumed by the demuxer or protocol are
+    /// removed from it, the remaining ones were not recognized.
+    pub fn open(url: &str, dict: Option<&mut AVDictionary>) -> Result<Self, AvError> {
+        let c_url = CString::new(url).map_err(|_| AvError::InvalidArgument)?;
+        unsafe {
+            let mut internal = null_mut();
+            let mut r_dict = null_mut();
+            if let Some(dict) = dict {
+                r_dict = &mut dict.internal
+            }
+            // avformat_open_input frees the context on failure
+            let ret = avcodec::avformat_open_input(&mut internal, c_url.as_ptr(), null_mut(), r_dict);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(Self { internal, io: None })
+        }
+    }
+    /// Opens an input reading from custom I/O, the format is probed from the data.
+    pub fn open_io(io: AVIOContext, dict: Option<&mut AVDictionary>) -> Result<Self, AvError> {
+        unsafe {
+            let mut internal = avcodec::avformat_alloc_context();
+            if internal.is_null() {
+                return Err(AvError::NoMemory);
+            }
+            (*internal).pb = io.internal;
+            (*internal).flags |= avcodec::AVFMT_FLAG_CUSTOM_IO as i32;
+            let mut r_dict = null_mut();
+            if let Some(dict) = dict {
+                r_dict = &mut dict.internal
+            }
+            let ret = avcodec::avformat_open_input(&mut internal, null(), null_mut(), r_dict);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(Self { internal, io: Some(io) })
+        }
+    }
+    /// Reads packets to fill in stream parameters the header does not contain, e.g. for MPEG-TS.
+    pub fn find_stream_info(&mut self) -> Result<(), AvError> {
+        unsafe {
+            let ret = avcodec::avformat_find_stream_info(self.internal, null_mut());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(())
+        }
+    }
+    /// Short name of the demuxer, e.g. "mov,mp4,m4a,3gp,3g2,mj2".
+    pub fn format_name(&self) -> &'static str {
+        unsafe { get_str_or_default((*self.get_internal().iformat).name, EMPTY_STR) }
+    }
+    /// Duration of the input in AV_TIME_BASE units, AV_NOPTS_VALUE when unknown.
+    pub fn duration(&self) -> i64 {
+        self.get_internal().duration
+    }
+    /// Position of the first frame in AV_TIME_BASE units, AV_NOPTS_VALUE when unknown.
+    pub fn start_time(&self) -> i64 {
+        self.get_internal().start_time
+    }
+    /// Total bitrate in bit/s, 0 when unknown.
+    pub fn bit_rate(&self) -> i64 {
+        self.get_internal().bit_rate
+    }
+    pub fn metadata(&self) -> AVDictionary {
+        unsafe { AVDictionary::copy_from_raw(self.get_internal().metadata) }
+    }
+    pub fn nb_streams(&self) -> usize {
+        self.get_internal().nb_streams as usize
+    }
+    pub fn stream(&self, index: usize) -> Option<AVStream<'_>> {
+        if index >= self.nb_streams() {
+            return None;
+        }
+        unsafe { Some(AVStream::from(*self.get_internal().streams.add(index))) }
+    }
+    pub fn streams(&self) -> impl Iterator<Item = AVStream<'_>> + '_ {
+        (0..self.nb_streams()).filter_map(move |index| self.stream(index))
+    }
+    /// Index of the stream ffmpeg considers the best of the given type, e.g. the main video stream.
+    pub fn find_best_stream(&self, media_type: MediaType) -> Result<usize, AvError> {
+        unsafe {
+            let ret = avcodec::av_find_best_stream(self.internal, AVMediaType::from(media_type), -1, -1, null_mut(), 0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(ret as usize)
+        }
+    }
+    /// Seeks so that reading continues between `min_ts` and `max_ts`, as close to `ts` as possible
+    /// (avformat_seek_file). Timestamps are in the time base of `stream_index`, or AV_TIME_BASE units when it is None.
+    /// `flags` are AVSEEK_FLAG_*, e.g. AVSEEK_FLAG_BYTE for byte positions or AVSEEK_FLAG_ANY to allow non keyframes.
+    pub fn seek_file(&mut self, stream_index: Option<usize>, min_ts: i64, ts: i64, max_ts: i64, flags: i32) -> Result<(), AvError> {
+        unsafe {
+            let stream_index = stream_index.map_or(-1, |index| index as c_int);
+            let ret = avcodec::avformat_seek_file(self.internal, stream_index, min_ts, ts, max_ts, flags);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(())
+        }
+    }
+    /// Seeks to the last keyframe at or before `ts`, see `seek_file` for the units.
+    pub fn seek(&mut self, stream_index: Option<usize>, ts: i64) -> Result<(), AvError> {
+        self.seek_file(stream_index, i64::MIN, ts, ts, 0)
+    }
+    /// Seeks to the byte position `pos`.
+    pub fn seek_byte(&mut self, pos: i64) -> Result<(), AvError> {
+        self.seek_file(None, i64::MIN, pos, i64::MAX, avcodec::AVSEEK_FLAG_BYTE as i32)
+    }
+    /// Decodes the frame of stream `stream_index` shown at `pts`, in the stream time base. Seeks to the keyframe
+    /// before it and decodes forward with `decoder`, which has to be opened with the parameters of the stream.
+    /// Returns the last frame starting at or before `pts`, or the first frame when the stream starts after it.
+    /// Decoding continues after the returned frame, except when a frame before `pts` is returned: the frame after it
+    /// had to be received from `decoder` to find out that no frame starts at `pts`, and it is dropped.
+    pub fn seek_frame_exact(&mut self, decoder: &mut AVCodecContext, stream_index: usize, pts: i64) -> Result<AVFrame, AvError> {
+        self.seek(Some(stream_index), pts)?;
+        decoder.flush_buffers();
+        let mut candidate: Option<AVFrame> = None;
+        let mut pkt = AVPacket::new();
+        loop {
+            loop {
+                let mut frame = AVFrame::new();
+                match decoder.receive_frame(&mut frame) {
+                    Ok(_) => {
+                        let frame_pts = frame.best_effort_timestamp();
+                        if frame_pts == pts {
+                            return Ok(frame);
+                        }
+                        if frame_pts > pts {
+                            return Ok(candidate.unwrap_or(frame));
+                        }
+                        candidate = Some(frame);
+                    }
+                    Err(AvError::Again) => break,
+                    // the stream ended before pts
+                    Err(AvError::Eof) => return candidate.ok_or(AvError::Eof),
+                    Err(err) => return Err(err),
+                }
+            }
+            match self.read_packet(&mut pkt) {
+                Ok(()) => {
+                    if pkt.stream_index() == stream_index {
+                        decoder.send_packet(&pkt)?;
+                    }
+                    pkt.unref();
+                }
+                // an empty packet drains the decoder
+                Err(AvError::Eof) => {
+                    decoder.send_packet(&AVPacket::new())?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    /// Reads the next packet of any stream into `pkt`, `AvError::Eof` at the end of the input.
+    pub fn read_packet(&mut self, pkt: &mut AVPacket) -> Result<(), AvError> {
+        unsafe {
+            let ret = avcodec::av_read_frame(self.internal, pkt.as_mut_ptr());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(())
+        }
+    }
+    /// Iterator over the remaining packets, ending at the end of the input or after the first error.
+    pub fn packets(&mut self) -> AVPacketIter<'_> {
+        AVPacketIter { input: self, done: false }
+    }
+
+    pub fn get_internal(&self) -> &avcodec::AVFormatContext {
+        return unsafe { &*(self.internal) };
+    }
+    /// Mutable access to the raw context. The caller must keep the context consistent for ffmpeg.
+    pub unsafe fn get_internal_mut(&mut self) -> &mut avcodec::AVFormatContext {
+        return &mut *(self.internal);
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVFormatContext {
+        return self.internal;
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut avcodec::AVFormatContext {
+        return self.internal;
+    }
+}
+
+// the format context has the (de)muxer's private data and the I/O context as children
+unsafe impl AVOptions for AVFormatInput {
+    fn options_ptr(&self) -> *mut c_void {
+        self.internal as *mut c_void
+    }
+    /// Always true, the input is opened on creation. Options for opening it are passed to `open`.
+    fn is_in_use(&self) -> bool {
+        true
+    }
+}
+
+pub struct AVPacketIter<'a> {
+    input: &'a mut AVFormatInput,
+    done: bool,
+}
+
+impl<'a> Iterator for AVPacketIter<'a> {
+    type Item = Result<AVPacket, AvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut pkt = AVPacket::new();
+        match self.input.read_packet(&mut pkt) {
+            Ok(()) => Some(Ok(pkt)),
+            Err(AvError::Eof) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// A stream of a demuxer or muxer, borrowed from its format context.
+#[derive(Clone, Copy)]
+pub struct AVStream<'a> {
+    internal: *mut avcodec::AVStream,
+    phantom: PhantomData<&'a avcodec::AVStream>,
+}
+
+impl<'a> From<*mut avcodec::AVStream> for AVStream<'a> {
+    fn from(internal: *mut avcodec::AVStream) -> Self {
+        Self { internal, phantom: PhantomData }
+    }
+}
+
+impl<'a> AVStream<'a> {
+    /// Position of the stream in the format context, matches `stream_index` of its packets.
+    pub fn index(&self) -> usize {
+        self.get_internal().index as usize
+    }
+    /// Format specific stream id, e.g. the PID in MPEG-TS.
+    pub fn id(&self) -> i32 {
+        self.get_internal().id
+    }
+    pub fn time_base(&self) -> Rational {
+        Rational::from(self.get_internal().time_base)
+    }
+    /// Presentation time of the first frame in `time_base` units, AV_NOPTS_VALUE when unknown.
+    pub fn start_time(&self) -> i64 {
+        self.get_internal().start_time
+    }
+    /// Duration in `time_base` units, AV_NOPTS_VALUE when unknown.
+    pub fn duration(&self) -> i64 {
+        self.get_internal().duration
+    }
+    /// Number of frames if known, 0 otherwise.
+    pub fn nb_frames(&self) -> i64 {
+        self.get_internal().nb_frames
+    }
+    pub fn avg_frame_rate(&self) -> Rational {
+        Rational::from(self.get_internal().avg_frame_rate)
+    }
+    pub fn r_frame_rate(&self) -> Rational {
+        Rational::from(self.get_internal().r_frame_rate)
+    }
+    pub fn media_type(&self) -> MediaType {
+        unsafe { MediaType::from((*self.get_internal().codecpar).codec_type) }
+    }
+    pub fn codec_id(&self) -> CodecId {
+        unsafe { CodecId::from((*self.get_internal().codecpar).codec_id) }
+    }
+    /// Copy of the codec parameters, ready for `AVCodecContext::set_parameters`.
+    pub fn codec_parameters(&self) -> Result<AVCodecParameters, AvError> {
+        let params = AVCodecParameters::new();
+        unsafe {
+            let ret = avcodec::avcodec_parameters_copy(params.internal, self.get_internal().codecpar);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(params);
+    }
+    pub fn metadata(&self) -> AVDictionary {
+        unsafe { AVDictionary::copy_from_raw(self.get_internal().metadata) }
+    }
+
+    pub fn get_internal(&self) -> &'a avcodec::AVStream {
+        return unsafe { &*(self.internal) };
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVStream {
+        return self.internal;
+    }
+}
+
+#[cfg(test)]
+mod test_demux {
+    use super::*;
+    use super::test_frame::flat_frame;
+
+    // 16 bit 8 kHz mono wav file holding `samples`
+    pub fn wav_bytes(samples: &[i16]) -> Vec<u8> {
+        let data_len = (samples.len() * 2) as u32;
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&8000u32.to_le_bytes());
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+        return wav;
+    }
+
+    #[test]
+    fn demux_wav() {
+        let path = std::env::temp_dir().join("ffmpeg_rs_demux.wav");
+        let samples: Vec<i16> = (0..8000).map(|i| (i % 100) as i16).collect();
+        std::fs::write(&path, wav_bytes(&samples)).unwrap();
+
+        let mut input = AVFormatInput::open(path.to_str().unwrap(), None).unwrap();
+        input.find_stream_info().unwrap();
+        assert_eq!(input.format_name(), "wav");
+        assert_eq!(input.nb_streams(), 1);
+        let stream = input.stream(0).unwrap();
+        assert_eq!(stream.media_type(), MediaType::Audio);
+        assert_eq!(stream.codec_id(), CodecId::PcmS16le);
+        assert_eq!(stream.time_base().den, 8000);
+        assert_eq!(input.find_best_stream(MediaType::Audio).unwrap(), 0);
+        assert_eq!(input.find_best_stream(MediaType::Video).unwrap_err(), AvError::StreamNotFound);
+
+        let params = stream.codec_parameters().unwrap();
+        let codec = AVCodec::find_decoder(stream.codec_id()).unwrap();
+        let mut ctx = AVCodecContext::new(&codec);
+        ctx.set_parameters(&params).unwrap();
+
+        let mut size = 0;
+        for pkt in input.packets() {
+            size += pkt.unwrap().get_data().len();
+        }
+        assert_eq!(size, samples.len() * 2);
+        assert!(AVFormatInput::open("/nonexistent/ffmpeg_rs.wav", None).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn seek_exact_frame() {
+        let path = std::env::temp_dir().join("ffmpeg_rs_seek.mkv");
+        let mut output = AVFormatOutput::create(path.to_str().unwrap(), None).unwrap();
+        let codec = AVCodec::find_encoder(CodecId::Mpeg4).unwrap();
+        let mut ctx = AVCodecContext::new(&codec);
+        unsafe {
+            let raw = ctx.get_internal_mut();
+            raw.width = 64;
+            raw.height = 48;
+            raw.pix_fmt = AVPixelFormat::from(PixelFormat::Yuv420p);
+            raw.time_base = Rational::new(1, 25).into();
+            raw.gop_size = 5;
+            raw.max_b_frames = 0;
+        }
+        ctx.set_global_header(output.needs_global_header());
+        ctx.open2(&codec, None).unwrap();
+        let time_base = Rational::from(ctx.get_internal().time_base);
+        let index = output.add_stream(&AVCodecParameters::from(&ctx).unwrap(), time_base).unwrap();
+        output.write_header(None).unwrap();
+        let mut pkt = AVPacket::new();
+        for i in 0..20 {
+            ctx.send_frame(&flat_frame(64, 48, i as u8 * 10, i)).unwrap();
+            while ctx.receive_pkt(&mut pkt).is_ok() {
+                output.write_packet(&mut pkt, index, time_base).unwrap();
+            }
+        }
+        output.write_trailer().unwrap();
+        drop(output);
+
+        let mut input = AVFormatInput::open(path.to_str().unwrap(), None).unwrap();
+        let stream = input.stream(0).unwrap();
+        let stream_time_base = stream.time_base();
+        let params = stream.codec_parameters().unwrap();
+        let codec = AVCodec::find_decoder(CodecId::Mpeg4).unwrap();
+        let mut decoder = AVCodecContext::new(&codec);
+        decoder.set_parameters(&params).unwrap();
+        decoder.open2(&codec, None).unwrap();
+
+        for target in &[7i64, 2, 13] {
+            let pts = unsafe { avcodec::av_rescale_q(*target, time_base.into(), stream_time_base.into()) };
+            let frame = input.seek_frame_exact(&mut decoder, 0, pts).unwrap();
+            assert_eq!(frame.best_effort_timestamp(), pts);
+            let luma = frame.rows(0).unwrap().next().unwrap()[0] as i64;
+            assert!((luma - target * 10).abs() <= 4, "frame {} has luma {}", target, luma);
+        }
+        input.seek_byte(0).unwrap();
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -50,7 +50,7 @@ impl AVDictionary {
         }
     }
 
-    pub fn parse_str(&mut self, parse_str: &str, key_val_sep: &str, pairs_sep: &str, flags: i32) -> Result<i32, i32> {
+    pub fn parse_str(&mut self, parse_str: &str, key_val_sep: &str, pairs_sep: &str, flags: i32) -> Result<i32, AvError> {
         unsafe {
             let new_parse_str: *mut c_char = avcodec::av_malloc((parse_str.len() + 1) as u64) as *mut c_char;
             copy(parse_str.as_ptr() as *const c_char, new_parse_str, parse_str.len());
@@ -70,14 +70,14 @@ impl AVDictionary {
             avcodec::av_free(r_key_val_sep as *mut c_void);
             avcodec::av_free(r_pairs_sep as *mut c_void);
             if ret < 0 {
-                Err(ret)
+                Err(AvError::from(ret))
             } else {
                 Ok(ret)
             }
         }
     }
 
-    pub fn set(&mut self, key: &str, value: &str, flags: i32) -> Result<i32, i32> {
+    pub fn set(&mut self, key: &str, value: &str, flags: i32) -> Result<i32, AvError> {
         unsafe {
             let new_key: *mut c_char = avcodec::av_malloc((key.len() + 1) as u64) as *mut c_char;
             let new_val: *mut c_char = avcodec::av_malloc((value.len() + 1) as u64) as *mut c_char;
@@ -91,11 +91,11 @@ impl AVDictionary {
             if ret >= 0 {
                 Ok(ret)
             } else {
-                Err(ret)
+                Err(AvError::from(ret))
             }
         }
     }
-    pub fn set_int(&mut self, key: &str, value: i64, flags: i32) -> Result<i32, i32> {
+    pub fn set_int(&mut self, key: &str, value: i64, flags: i32) -> Result<i32, AvError> {
         unsafe {
             let new_key: *mut c_char = avcodec::av_malloc((key.len() + 1) as u64) as *mut c_char;
             copy(key.as_ptr() as *const c_char, new_key, key.len());
@@ -105,7 +105,7 @@ impl AVDictionary {
             if ret >= 0 {
                 Ok(ret)
             } else {
-                Err(ret)
+                Err(AvError::from(ret))
             }
         }
     }
@@ -117,8 +117,8 @@ impl AVDictionary {
             terminate_cstr(new_key, key.len());
 
             let mut prev_opt = null_mut();
-            if prev.is_some() {
-                prev_opt = prev.unwrap().internal;
+            if let Some(prev) = prev {
+                prev_opt = prev.internal;
             }
 
             let internal_entry = avcodec::av_dict_get(self.internal, new_key, prev_opt, flags);
@@ -133,12 +133,12 @@ impl AVDictionary {
     }
 
     // get_string generates string representation of the dictionary
-    pub fn get_string(&self, key_val_sep: char, pairs_sep: char) -> Result<String, i32> {
+    pub fn get_string(&self, key_val_sep: char, pairs_sep: char) -> Result<String, AvError> {
         unsafe {
             let mut buffer: *mut c_char = null_mut();
             let ret = avcodec::av_dict_get_string(self.internal, &mut buffer, key_val_sep as i8, pairs_sep as i8);
             if ret < 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
             let output = CStr::from_ptr(buffer).to_str().unwrap();
             let mut out_str = String::new();
@@ -148,17 +148,49 @@ impl AVDictionary {
         }
     }
 
-    pub fn copy(&self, flags: i32) -> Result<Self, i32> {
+    pub fn copy(&self, flags: i32) -> Result<Self, AvError> {
         let mut internal = null_mut();
         unsafe {
             let ret = avcodec::av_dict_copy(&mut internal, self.internal, flags);
             if ret != 0 {
-                return Err(ret);
+                return Err(AvError::from(ret));
             }
         }
         return Ok(Self { internal });
     }
 
+    // copies a dictionary owned by an ffmpeg struct, e.g. stream metadata
+    unsafe fn copy_from_raw(internal: *const avcodec::AVDictionary) -> Self {
+        let mut dict = Self::new();
+        let ret = avcodec::av_dict_copy(&mut dict.internal, internal, 0);
+        if ret < 0 {
+            error!("unable to copy dictionary: {}", err_str(ret));
+        }
+        return dict;
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        unsafe { avcodec::av_dict_count(self.internal) as usize }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Keys of all entries. After `open2` the dictionary only holds the options which were not found.
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+        unsafe {
+            // an empty key with AV_DICT_IGNORE_SUFFIX matches every entry
+            let any_key = b"\0".as_ptr() as *const c_char;
+            let mut entry = avcodec::av_dict_get(self.internal, any_key, null(), avcodec::AV_DICT_IGNORE_SUFFIX as c_int);
+            while !entry.is_null() {
+                keys.push(get_str_or_default((*entry).key, EMPTY_STR).to_string());
+                entry = avcodec::av_dict_get(self.internal, any_key, entry, avcodec::AV_DICT_IGNORE_SUFFIX as c_int);
+            }
+        }
+        keys
+    }
+
     pub unsafe fn get_internal(&self) -> *mut avcodec::AVDictionary {
         return self.internal;
     }
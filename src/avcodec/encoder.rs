@@ -0,0 +1,283 @@
+/// Builder of a `VideoEncoder`, see `VideoEncoder::builder`.
+///
+/// Width, height and either the time base or the frame rate are required. Without a pixel format the first one the
+/// codec supports is used.
+pub struct VideoEncoderBuilder {
+    codec: AVCodec,
+    ctx: AVCodecContext,
+    pix_fmt: Option<PixelFormat>,
+    time_base: Option<Rational>,
+    frame_rate: Option<Rational>,
+    options: Vec<(String, String)>,
+}
+
+impl VideoEncoderBuilder {
+    pub fn size(mut self, width: i32, height: i32) -> Self {
+        unsafe {
+            let raw = self.ctx.get_internal_mut();
+            raw.width = width;
+            raw.height = height;
+        }
+        self
+    }
+    pub fn pix_fmt(mut self, pix_fmt: PixelFormat) -> Self {
+        self.pix_fmt = Some(pix_fmt);
+        self
+    }
+    /// Time base of the frame pts and packet timestamps, defaults to 1 / frame rate.
+    pub fn time_base(mut self, time_base: Rational) -> Self {
+        self.time_base = Some(time_base);
+        self
+    }
+    pub fn frame_rate(mut self, frame_rate: Rational) -> Self {
+        self.frame_rate = Some(frame_rate);
+        self
+    }
+    /// Average bit rate in bits per second.
+    pub fn bit_rate(mut self, bit_rate: i64) -> Self {
+        unsafe { self.ctx.get_internal_mut().bit_rate = bit_rate }
+        self
+    }
+    /// Maximum distance between keyframes in frames.
+    pub fn gop_size(mut self, gop_size: i32) -> Self {
+        unsafe { self.ctx.get_internal_mut().gop_size = gop_size }
+        self
+    }
+    pub fn max_b_frames(mut self, max_b_frames: i32) -> Self {
+        unsafe { self.ctx.get_internal_mut().max_b_frames = max_b_frames }
+        self
+    }
+    pub fn sample_aspect_ratio(mut self, sample_aspect_ratio: Rational) -> Self {
+        unsafe { self.ctx.get_internal_mut().sample_aspect_ratio = sample_aspect_ratio.into() }
+        self
+    }
+    /// Number of encoding threads, 0 lets the codec decide.
+    pub fn thread_count(mut self, thread_count: i32) -> Self {
+        unsafe { self.ctx.get_internal_mut().thread_count = thread_count }
+        self
+    }
+    /// See `AVCodecContext::set_global_header`.
+    pub fn global_header(mut self, global_header: bool) -> Self {
+        self.ctx.set_global_header(global_header);
+        self
+    }
+    /// Adds codec flags, e.g. `CodecFlags::CLOSED_GOP | CodecFlags::LOW_DELAY`.
+    pub fn flags(mut self, flags: CodecFlags) -> Self {
+        unsafe { self.ctx.get_internal_mut().flags |= flags.bits() }
+        self
+    }
+    /// Sets a generic or codec private option passed to `open2`, e.g. ("preset", "veryfast") for libx264. `build`
+    /// fails with `AvError::UnconsumedOptions` when the codec does not know it.
+    pub fn option(mut self, key: &str, value: &str) -> Self {
+        self.options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Validates the parameters against the codec and opens it.
+    pub fn build(mut self) -> Result<VideoEncoder, AvError> {
+        let codec = &self.codec;
+        let raw = self.ctx.get_internal();
+        if raw.width <= 0 {
+            return Err(AvError::MissingParameter("width"));
+        }
+        if raw.height <= 0 {
+            return Err(AvError::MissingParameter("height"));
+        }
+        let pix_fmts = codec.pix_fmts().unwrap_or_default();
+        let pix_fmt = match (self.pix_fmt, pix_fmts.first()) {
+            (Some(pix_fmt), _) => pix_fmt,
+            (None, Some(pix_fmt)) => *pix_fmt,
+            (None, None) => return Err(AvError::MissingParameter("pix_fmt")),
+        };
+        if !pix_fmts.is_empty() && !pix_fmts.contains(&pix_fmt) {
+            return Err(AvError::UnsupportedPixelFormat { codec: codec.name, format: pix_fmt });
+        }
+        let time_base = match (self.time_base, self.frame_rate) {
+            (Some(time_base), _) => time_base,
+            (None, Some(frame_rate)) => frame_rate.invert(),
+            (None, None) => return Err(AvError::MissingParameter("time_base")),
+        };
+        if !time_base.is_valid() || time_base.num <= 0 {
+            return Err(AvError::InvalidArgument);
+        }
+        if let Some(frame_rates) = codec.supported_framerates() {
+            // codecs with fixed frame rates take the rate from the time base when none is set
+            let frame_rate = self.frame_rate.unwrap_or_else(|| time_base.invert());
+            if !frame_rates.contains(&frame_rate) {
+                return Err(AvError::UnsupportedFrameRate { codec: codec.name, num: frame_rate.num, den: frame_rate.den });
+            }
+        }
+
+        unsafe {
+            let raw = self.ctx.get_internal_mut();
+            raw.pix_fmt = AVPixelFormat::from(pix_fmt);
+            raw.time_base = time_base.into();
+            if let Some(frame_rate) = self.frame_rate {
+                raw.framerate = frame_rate.into();
+            }
+        }
+        let mut dict = AVDictionary::new();
+        for (key, value) in &self.options {
+            dict.set(key, value, 0)?;
+        }
+        self.ctx.open2(&self.codec, Some(&mut dict))?;
+        check_consumed(&dict)?;
+        Ok(VideoEncoder { codec: self.codec, ctx: self.ctx })
+    }
+}
+
+/// An opened video encoder. Frames go in with `encode`, packets come out of the returned iterators, in the time base
+/// of the encoder.
+///
+/// An error while receiving packets is returned as the last item of an iterator, the packets before it are valid.
+pub struct VideoEncoder {
+    codec: AVCodec,
+    ctx: AVCodecContext,
+}
+
+impl VideoEncoder {
+    pub fn builder(codec: AVCodec) -> VideoEncoderBuilder {
+        let ctx = AVCodecContext::new(&codec);
+        VideoEncoderBuilder { codec, ctx, pix_fmt: None, time_base: None, frame_rate: None, options: Vec::new() }
+    }
+
+    /// Sends a frame and returns the packets which are ready. Encoders with a delay, e.g. with B-frames, return
+    /// nothing for the first frames and the rest from `flush`.
+    pub fn encode(&mut self, frame: &AVFrame) -> Result<impl Iterator<Item = Result<AVPacket, AvError>> + '_, AvError> {
+        let mut backlog = Vec::new();
+        if let Err(err) = self.ctx.send_frame(frame) {
+            if err != AvError::Again {
+                return Err(err);
+            }
+            // packets of an earlier call were not taken, the frame fits once they are received
+            for pkt in (EncodedPackets { ctx: &mut self.ctx, done: false }) {
+                backlog.push(Ok(pkt?));
+            }
+            self.ctx.send_frame(frame)?;
+        }
+        Ok(backlog.into_iter().chain(EncodedPackets { ctx: &mut self.ctx, done: false }))
+    }
+    /// Signals the end of the stream and returns the remaining packets. Frames can not be encoded afterwards.
+    pub fn flush(&mut self) -> Result<impl Iterator<Item = Result<AVPacket, AvError>> + '_, AvError> {
+        unsafe {
+            let ret = avcodec::avcodec_send_frame(self.ctx.as_mut_ptr(), null());
+            // flushing twice returns AVERROR_EOF
+            if ret < 0 && ret != AVERROR_EOF {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(EncodedPackets { ctx: &mut self.ctx, done: false })
+    }
+
+    pub fn codec(&self) -> &AVCodec {
+        &self.codec
+    }
+    pub fn time_base(&self) -> Rational {
+        Rational::from(self.ctx.get_internal().time_base)
+    }
+    /// Parameters for the stream of a muxer, see `AVFormatOutput::add_stream`.
+    pub fn parameters(&self) -> Result<AVCodecParameters, AvError> {
+        AVCodecParameters::from(&self.ctx)
+    }
+    pub fn codec_context(&self) -> &AVCodecContext {
+        &self.ctx
+    }
+}
+
+// packets received from the encoder until it needs more input or is drained, an error is the last item
+struct EncodedPackets<'a> {
+    ctx: &'a mut AVCodecContext,
+    done: bool,
+}
+
+impl Iterator for EncodedPackets<'_> {
+    type Item = Result<AVPacket, AvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut pkt = AVPacket::new();
+        match self.ctx.receive_pkt(&mut pkt) {
+            Ok(_) => Some(Ok(pkt)),
+            Err(AvError::Again) | Err(AvError::Eof) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_encoder {
+    use super::*;
+    use super::test_frame::flat_frame;
+
+    // 64x48 mpeg4 at 25 fps with closed gops of 5 frames and one b-frame
+    pub fn mpeg4_encoder(global_header: bool) -> VideoEncoder {
+        VideoEncoder::builder(AVCodec::find_encoder(CodecId::Mpeg4).unwrap())
+            .size(64, 48)
+            .frame_rate(Rational::new(25, 1))
+            .gop_size(5)
+            .max_b_frames(1)
+            .flags(CodecFlags::CLOSED_GOP)
+            .option("sc_threshold", "1000000000")
+            .global_header(global_header)
+            .build()
+            .unwrap()
+    }
+
+    // encodes and flushes `count` flat frames, frame i has pts i and the value i * 20
+    pub fn encode_frames(encoder: &mut VideoEncoder, count: i64) -> Vec<AVPacket> {
+        let mut packets = Vec::new();
+        for i in 0..count {
+            packets.extend(encoder.encode(&flat_frame(64, 48, i as u8 * 20, i)).unwrap().map(Result::unwrap));
+        }
+        packets.extend(encoder.flush().unwrap().map(Result::unwrap));
+        return packets;
+    }
+
+    #[test]
+    fn encode_with_builder() {
+        let codec = || AVCodec::find_encoder(CodecId::Mpeg4).unwrap();
+        let mut encoder = VideoEncoder::builder(codec())
+            .size(64, 48)
+            .frame_rate(Rational::new(25, 1))
+            .gop_size(5)
+            .max_b_frames(2)
+            .bit_rate(200_000)
+            .flags(CodecFlags::CLOSED_GOP | CodecFlags::BITEXACT)
+            // mpeg4 only closes gops with scene change detection off
+            .option("sc_threshold", "1000000000")
+            .option("qmin", "2")
+            .build()
+            .unwrap();
+        assert_eq!(encoder.time_base(), Rational::new(1, 25));
+        assert_eq!(encoder.codec_context().get_internal().pix_fmt, AVPixelFormat::from(PixelFormat::Yuv420p));
+        assert!(encoder.codec_context().flags().contains(CodecFlags::CLOSED_GOP));
+
+        let packets = encode_frames(&mut encoder, 10);
+        assert_eq!(encoder.flush().unwrap().count(), 0);
+        assert_eq!(packets.len(), 10);
+        assert!(packets[0].is_key());
+        let mut pts: Vec<i64> = packets.iter().map(|pkt| pkt.pts()).collect();
+        pts.sort_unstable();
+        assert_eq!(pts, (0..10).collect::<Vec<i64>>());
+
+        let err = VideoEncoder::builder(codec()).size(64, 48).frame_rate(Rational::new(25, 1))
+            .pix_fmt(PixelFormat::Rgb24).build().err().unwrap();
+        assert_eq!(err, AvError::UnsupportedPixelFormat { codec: "mpeg4", format: PixelFormat::Rgb24 });
+        let err = VideoEncoder::builder(codec()).frame_rate(Rational::new(25, 1)).build().err().unwrap();
+        assert_eq!(err, AvError::MissingParameter("width"));
+        let mpeg1 = AVCodec::find_encoder(CodecId::Mpeg1Video).unwrap();
+        let err = VideoEncoder::builder(mpeg1).size(64, 48).frame_rate(Rational::new(7, 1)).build().err().unwrap();
+        assert_eq!(err.to_string(), "frame rate 7/1 is not supported by mpeg1video");
+        let mpeg1 = AVCodec::find_encoder(CodecId::Mpeg1Video).unwrap();
+        let err = VideoEncoder::builder(mpeg1).size(64, 48).time_base(Rational::new(1, 90000)).build().err().unwrap();
+        assert_eq!(err, AvError::UnsupportedFrameRate { codec: "mpeg1video", num: 90000, den: 1 });
+    }
+}
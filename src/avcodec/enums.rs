@@ -0,0 +1,376 @@
+// av_enum declares a rust enum over a bindgen integer enum. Values without a variant are kept in `Unknown` so every
+// value converts back to the same sys value. `Unknown` can also be built from a value that has a variant, so equality
+// and hashing go through the sys value.
+macro_rules! av_enum {
+    ($(#[$meta:meta])* $name:ident: $sys:ty { $($variant:ident = $value:path,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(i32),
+        }
+
+        impl From<$sys> for $name {
+            #[allow(non_upper_case_globals)]
+            fn from(value: $sys) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    value => $name::Unknown(value as i32),
+                }
+            }
+        }
+
+        impl From<$name> for $sys {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value as $sys,
+                }
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                <$sys>::from(*self) == <$sys>::from(*other)
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                <$sys>::from(*self).hash(state);
+            }
+        }
+    };
+}
+
+av_enum! {
+    /// Pixel format (AVPixelFormat).
+    PixelFormat: AVPixelFormat {
+        None = avcodec::AVPixelFormat_AV_PIX_FMT_NONE,
+        Yuv420p = avcodec::AVPixelFormat_AV_PIX_FMT_YUV420P,
+        Yuyv422 = avcodec::AVPixelFormat_AV_PIX_FMT_YUYV422,
+        Uyvy422 = avcodec::AVPixelFormat_AV_PIX_FMT_UYVY422,
+        Rgb24 = avcodec::AVPixelFormat_AV_PIX_FMT_RGB24,
+        Bgr24 = avcodec::AVPixelFormat_AV_PIX_FMT_BGR24,
+        Yuv422p = avcodec::AVPixelFormat_AV_PIX_FMT_YUV422P,
+        Yuv444p = avcodec::AVPixelFormat_AV_PIX_FMT_YUV444P,
+        Yuv410p = avcodec::AVPixelFormat_AV_PIX_FMT_YUV410P,
+        Yuv411p = avcodec::AVPixelFormat_AV_PIX_FMT_YUV411P,
+        Yuv440p = avcodec::AVPixelFormat_AV_PIX_FMT_YUV440P,
+        Yuvj420p = avcodec::AVPixelFormat_AV_PIX_FMT_YUVJ420P,
+        Yuvj422p = avcodec::AVPixelFormat_AV_PIX_FMT_YUVJ422P,
+        Yuvj444p = avcodec::AVPixelFormat_AV_PIX_FMT_YUVJ444P,
+        Yuva420p = avcodec::AVPixelFormat_AV_PIX_FMT_YUVA420P,
+        Yuva444p = avcodec::AVPixelFormat_AV_PIX_FMT_YUVA444P,
+        Yuv420p10le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV420P10LE,
+        Yuv422p10le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV422P10LE,
+        Yuv444p10le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV444P10LE,
+        Yuv420p12le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV420P12LE,
+        Yuv444p12le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV444P12LE,
+        Yuv420p16le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV420P16LE,
+        Yuv444p16le = avcodec::AVPixelFormat_AV_PIX_FMT_YUV444P16LE,
+        Gray8 = avcodec::AVPixelFormat_AV_PIX_FMT_GRAY8,
+        Gray10le = avcodec::AVPixelFormat_AV_PIX_FMT_GRAY10LE,
+        Gray12le = avcodec::AVPixelFormat_AV_PIX_FMT_GRAY12LE,
+        Gray16le = avcodec::AVPixelFormat_AV_PIX_FMT_GRAY16LE,
+        Gray16be = avcodec::AVPixelFormat_AV_PIX_FMT_GRAY16BE,
+        Ya8 = avcodec::AVPixelFormat_AV_PIX_FMT_YA8,
+        MonoWhite = avcodec::AVPixelFormat_AV_PIX_FMT_MONOWHITE,
+        MonoBlack = avcodec::AVPixelFormat_AV_PIX_FMT_MONOBLACK,
+        Pal8 = avcodec::AVPixelFormat_AV_PIX_FMT_PAL8,
+        Nv12 = avcodec::AVPixelFormat_AV_PIX_FMT_NV12,
+        Nv21 = avcodec::AVPixelFormat_AV_PIX_FMT_NV21,
+        Nv16 = avcodec::AVPixelFormat_AV_PIX_FMT_NV16,
+        Nv24 = avcodec::AVPixelFormat_AV_PIX_FMT_NV24,
+        Nv42 = avcodec::AVPixelFormat_AV_PIX_FMT_NV42,
+        P010le = avcodec::AVPixelFormat_AV_PIX_FMT_P010LE,
+        P016le = avcodec::AVPixelFormat_AV_PIX_FMT_P016LE,
+        Y210le = avcodec::AVPixelFormat_AV_PIX_FMT_Y210LE,
+        Argb = avcodec::AVPixelFormat_AV_PIX_FMT_ARGB,
+        Rgba = avcodec::AVPixelFormat_AV_PIX_FMT_RGBA,
+        Abgr = avcodec::AVPixelFormat_AV_PIX_FMT_ABGR,
+        Bgra = avcodec::AVPixelFormat_AV_PIX_FMT_BGRA,
+        ZeroRgb = avcodec::AVPixelFormat_AV_PIX_FMT_0RGB,
+        Rgb0 = avcodec::AVPixelFormat_AV_PIX_FMT_RGB0,
+        ZeroBgr = avcodec::AVPixelFormat_AV_PIX_FMT_0BGR,
+        Bgr0 = avcodec::AVPixelFormat_AV_PIX_FMT_BGR0,
+        Rgb565le = avcodec::AVPixelFormat_AV_PIX_FMT_RGB565LE,
+        Rgb555le = avcodec::AVPixelFormat_AV_PIX_FMT_RGB555LE,
+        Rgb48le = avcodec::AVPixelFormat_AV_PIX_FMT_RGB48LE,
+        Rgba64le = avcodec::AVPixelFormat_AV_PIX_FMT_RGBA64LE,
+        Bgra64le = avcodec::AVPixelFormat_AV_PIX_FMT_BGRA64LE,
+        X2rgb10le = avcodec::AVPixelFormat_AV_PIX_FMT_X2RGB10LE,
+        Gbrp = avcodec::AVPixelFormat_AV_PIX_FMT_GBRP,
+        Gbrp10le = avcodec::AVPixelFormat_AV_PIX_FMT_GBRP10LE,
+        Gbrap = avcodec::AVPixelFormat_AV_PIX_FMT_GBRAP,
+        Gbrpf32le = avcodec::AVPixelFormat_AV_PIX_FMT_GBRPF32LE,
+        Gbrapf32le = avcodec::AVPixelFormat_AV_PIX_FMT_GBRAPF32LE,
+        Xyz12le = avcodec::AVPixelFormat_AV_PIX_FMT_XYZ12LE,
+        Vaapi = avcodec::AVPixelFormat_AV_PIX_FMT_VAAPI,
+        Vdpau = avcodec::AVPixelFormat_AV_PIX_FMT_VDPAU,
+        Dxva2Vld = avcodec::AVPixelFormat_AV_PIX_FMT_DXVA2_VLD,
+        D3d11 = avcodec::AVPixelFormat_AV_PIX_FMT_D3D11,
+        Qsv = avcodec::AVPixelFormat_AV_PIX_FMT_QSV,
+        Cuda = avcodec::AVPixelFormat_AV_PIX_FMT_CUDA,
+        VideoToolbox = avcodec::AVPixelFormat_AV_PIX_FMT_VIDEOTOOLBOX,
+        MediaCodec = avcodec::AVPixelFormat_AV_PIX_FMT_MEDIACODEC,
+        DrmPrime = avcodec::AVPixelFormat_AV_PIX_FMT_DRM_PRIME,
+        OpenCl = avcodec::AVPixelFormat_AV_PIX_FMT_OPENCL,
+        Vulkan = avcodec::AVPixelFormat_AV_PIX_FMT_VULKAN,
+    }
+}
+
+av_enum! {
+    /// Audio sample format (AVSampleFormat), the `p` variants are planar.
+    SampleFormat: AVSampleFormat {
+        None = avcodec::AVSampleFormat_AV_SAMPLE_FMT_NONE,
+        U8 = avcodec::AVSampleFormat_AV_SAMPLE_FMT_U8,
+        S16 = avcodec::AVSampleFormat_AV_SAMPLE_FMT_S16,
+        S32 = avcodec::AVSampleFormat_AV_SAMPLE_FMT_S32,
+        S64 = avcodec::AVSampleFormat_AV_SAMPLE_FMT_S64,
+        Flt = avcodec::AVSampleFormat_AV_SAMPLE_FMT_FLT,
+        Dbl = avcodec::AVSampleFormat_AV_SAMPLE_FMT_DBL,
+        U8p = avcodec::AVSampleFormat_AV_SAMPLE_FMT_U8P,
+        S16p = avcodec::AVSampleFormat_AV_SAMPLE_FMT_S16P,
+        S32p = avcodec::AVSampleFormat_AV_SAMPLE_FMT_S32P,
+        S64p = avcodec::AVSampleFormat_AV_SAMPLE_FMT_S64P,
+        Fltp = avcodec::AVSampleFormat_AV_SAMPLE_FMT_FLTP,
+        Dblp = avcodec::AVSampleFormat_AV_SAMPLE_FMT_DBLP,
+    }
+}
+
+av_enum! {
+    /// Type of a codec or stream (AVMediaType).
+    MediaType: AVMediaType {
+        Video = avcodec::AVMediaType_AVMEDIA_TYPE_VIDEO,
+        Audio = avcodec::AVMediaType_AVMEDIA_TYPE_AUDIO,
+        Data = avcodec::AVMediaType_AVMEDIA_TYPE_DATA,
+        Subtitle = avcodec::AVMediaType_AVMEDIA_TYPE_SUBTITLE,
+        Attachment = avcodec::AVMediaType_AVMEDIA_TYPE_ATTACHMENT,
+    }
+}
+
+av_enum! {
+    /// Codec identifier (AVCodecID). Only common codecs have a variant, the others are `Unknown` and still
+    /// print and parse by name.
+    CodecId: AVCodecID {
+        None = avcodec::AVCodecID_AV_CODEC_ID_NONE,
+        H264 = avcodec::AVCodecID_AV_CODEC_ID_H264,
+        Hevc = avcodec::AVCodecID_AV_CODEC_ID_HEVC,
+        Vvc = avcodec::AVCodecID_AV_CODEC_ID_VVC,
+        Av1 = avcodec::AVCodecID_AV_CODEC_ID_AV1,
+        Vp8 = avcodec::AVCodecID_AV_CODEC_ID_VP8,
+        Vp9 = avcodec::AVCodecID_AV_CODEC_ID_VP9,
+        Mpeg1Video = avcodec::AVCodecID_AV_CODEC_ID_MPEG1VIDEO,
+        Mpeg2Video = avcodec::AVCodecID_AV_CODEC_ID_MPEG2VIDEO,
+        Mpeg4 = avcodec::AVCodecID_AV_CODEC_ID_MPEG4,
+        H263 = avcodec::AVCodecID_AV_CODEC_ID_H263,
+        Theora = avcodec::AVCodecID_AV_CODEC_ID_THEORA,
+        Mjpeg = avcodec::AVCodecID_AV_CODEC_ID_MJPEG,
+        Png = avcodec::AVCodecID_AV_CODEC_ID_PNG,
+        Gif = avcodec::AVCodecID_AV_CODEC_ID_GIF,
+        Webp = avcodec::AVCodecID_AV_CODEC_ID_WEBP,
+        Prores = avcodec::AVCodecID_AV_CODEC_ID_PRORES,
+        Dnxhd = avcodec::AVCodecID_AV_CODEC_ID_DNXHD,
+        Ffv1 = avcodec::AVCodecID_AV_CODEC_ID_FFV1,
+        RawVideo = avcodec::AVCodecID_AV_CODEC_ID_RAWVIDEO,
+        Aac = avcodec::AVCodecID_AV_CODEC_ID_AAC,
+        Mp2 = avcodec::AVCodecID_AV_CODEC_ID_MP2,
+        Mp3 = avcodec::AVCodecID_AV_CODEC_ID_MP3,
+        Ac3 = avcodec::AVCodecID_AV_CODEC_ID_AC3,
+        Eac3 = avcodec::AVCodecID_AV_CODEC_ID_EAC3,
+        Dts = avcodec::AVCodecID_AV_CODEC_ID_DTS,
+        TrueHd = avcodec::AVCodecID_AV_CODEC_ID_TRUEHD,
+        Vorbis = avcodec::AVCodecID_AV_CODEC_ID_VORBIS,
+        Opus = avcodec::AVCodecID_AV_CODEC_ID_OPUS,
+        Flac = avcodec::AVCodecID_AV_CODEC_ID_FLAC,
+        Alac = avcodec::AVCodecID_AV_CODEC_ID_ALAC,
+        Speex = avcodec::AVCodecID_AV_CODEC_ID_SPEEX,
+        AmrNb = avcodec::AVCodecID_AV_CODEC_ID_AMR_NB,
+        AmrWb = avcodec::AVCodecID_AV_CODEC_ID_AMR_WB,
+        PcmS16le = avcodec::AVCodecID_AV_CODEC_ID_PCM_S16LE,
+        PcmS16be = avcodec::AVCodecID_AV_CODEC_ID_PCM_S16BE,
+        PcmS24le = avcodec::AVCodecID_AV_CODEC_ID_PCM_S24LE,
+        PcmS32le = avcodec::AVCodecID_AV_CODEC_ID_PCM_S32LE,
+        PcmF32le = avcodec::AVCodecID_AV_CODEC_ID_PCM_F32LE,
+        PcmF64le = avcodec::AVCodecID_AV_CODEC_ID_PCM_F64LE,
+        PcmMulaw = avcodec::AVCodecID_AV_CODEC_ID_PCM_MULAW,
+        PcmAlaw = avcodec::AVCodecID_AV_CODEC_ID_PCM_ALAW,
+        DvdSubtitle = avcodec::AVCodecID_AV_CODEC_ID_DVD_SUBTITLE,
+        DvbSubtitle = avcodec::AVCodecID_AV_CODEC_ID_DVB_SUBTITLE,
+        HdmvPgsSubtitle = avcodec::AVCodecID_AV_CODEC_ID_HDMV_PGS_SUBTITLE,
+        Text = avcodec::AVCodecID_AV_CODEC_ID_TEXT,
+        Ssa = avcodec::AVCodecID_AV_CODEC_ID_SSA,
+        Ass = avcodec::AVCodecID_AV_CODEC_ID_ASS,
+        Subrip = avcodec::AVCodecID_AV_CODEC_ID_SUBRIP,
+        Webvtt = avcodec::AVCodecID_AV_CODEC_ID_WEBVTT,
+        MovText = avcodec::AVCodecID_AV_CODEC_ID_MOV_TEXT,
+    }
+}
+
+impl Display for PixelFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "unknown pixel format {}", AVPixelFormat::from(*self)),
+        }
+    }
+}
+
+impl FromStr for PixelFormat {
+    type Err = AvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = CString::new(s).map_err(|_| AvError::InvalidArgument)?;
+        let fmt = unsafe { avcodec::av_get_pix_fmt(name.as_ptr()) };
+        if fmt == avcodec::AVPixelFormat_AV_PIX_FMT_NONE {
+            return Err(AvError::InvalidArgument);
+        }
+        return Ok(PixelFormat::from(fmt));
+    }
+}
+
+impl PixelFormat {
+    /// ffmpeg's name of the format, None for values unknown to the linked ffmpeg.
+    pub fn name(&self) -> Option<&'static str> {
+        unsafe {
+            let name = avcodec::av_get_pix_fmt_name(AVPixelFormat::from(*self));
+            if name.is_null() {
+                return None;
+            }
+            CStr::from_ptr(name).to_str().ok()
+        }
+    }
+}
+
+impl Display for SampleFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "unknown sample format {}", AVSampleFormat::from(*self)),
+        }
+    }
+}
+
+impl FromStr for SampleFormat {
+    type Err = AvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = CString::new(s).map_err(|_| AvError::InvalidArgument)?;
+        let fmt = unsafe { avcodec::av_get_sample_fmt(name.as_ptr()) };
+        if fmt == avcodec::AVSampleFormat_AV_SAMPLE_FMT_NONE {
+            return Err(AvError::InvalidArgument);
+        }
+        return Ok(SampleFormat::from(fmt));
+    }
+}
+
+impl SampleFormat {
+    pub fn name(&self) -> Option<&'static str> {
+        unsafe {
+            let name = avcodec::av_get_sample_fmt_name(AVSampleFormat::from(*self));
+            if name.is_null() {
+                return None;
+            }
+            CStr::from_ptr(name).to_str().ok()
+        }
+    }
+    pub fn is_planar(&self) -> bool {
+        unsafe { avcodec::av_sample_fmt_is_planar(AVSampleFormat::from(*self)) != 0 }
+    }
+    pub fn bytes_per_sample(&self) -> usize {
+        unsafe { avcodec::av_get_bytes_per_sample(AVSampleFormat::from(*self)).max(0) as usize }
+    }
+    /// The planar variant of a packed format and the other way around.
+    pub fn packed(&self) -> Self {
+        unsafe { SampleFormat::from(avcodec::av_get_packed_sample_fmt(AVSampleFormat::from(*self))) }
+    }
+    pub fn planar(&self) -> Self {
+        unsafe { SampleFormat::from(avcodec::av_get_planar_sample_fmt(AVSampleFormat::from(*self))) }
+    }
+}
+
+impl Display for MediaType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        unsafe {
+            let name = avcodec::av_get_media_type_string(AVMediaType::from(*self));
+            write!(f, "{}", get_str_or_default(name, "unknown"))
+        }
+    }
+}
+
+impl FromStr for MediaType {
+    type Err = AvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "video" => Ok(MediaType::Video),
+            "audio" => Ok(MediaType::Audio),
+            "data" => Ok(MediaType::Data),
+            "subtitle" => Ok(MediaType::Subtitle),
+            "attachment" => Ok(MediaType::Attachment),
+            _ => Err(AvError::InvalidArgument),
+        }
+    }
+}
+
+impl Display for CodecId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        unsafe { write!(f, "{}", get_str_or_default(avcodec::avcodec_get_name(AVCodecID::from(*self)), "unknown_codec")) }
+    }
+}
+
+impl FromStr for CodecId {
+    type Err = AvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = CString::new(s).map_err(|_| AvError::InvalidArgument)?;
+        unsafe {
+            let desc = avcodec::avcodec_descriptor_get_by_name(name.as_ptr());
+            if desc.is_null() {
+                return Err(AvError::InvalidArgument);
+            }
+            return Ok(CodecId::from((*desc).id));
+        }
+    }
+}
+
+impl CodecId {
+    pub fn media_type(&self) -> MediaType {
+        unsafe { MediaType::from(avcodec::avcodec_get_type(AVCodecID::from(*self))) }
+    }
+}
+
+#[cfg(test)]
+mod test_enums {
+    use super::*;
+
+    #[test]
+    fn enum_round_trip() {
+        assert_eq!(PixelFormat::from(avcodec::AVPixelFormat_AV_PIX_FMT_NV12), PixelFormat::Nv12);
+        assert_eq!(AVPixelFormat::from(PixelFormat::Bgr0), avcodec::AVPixelFormat_AV_PIX_FMT_BGR0);
+        let unlisted = avcodec::AVPixelFormat_AV_PIX_FMT_YUV422P12BE;
+        assert_eq!(AVPixelFormat::from(PixelFormat::from(unlisted)), unlisted);
+        assert_eq!(PixelFormat::Unknown(100000).name(), None);
+        let known = PixelFormat::Unknown(avcodec::AVPixelFormat_AV_PIX_FMT_NV12);
+        assert_eq!(known, PixelFormat::Nv12);
+        let formats: std::collections::HashSet<_> = vec![known, PixelFormat::Nv12].into_iter().collect();
+        assert_eq!(formats.len(), 1);
+
+        assert_eq!("yuv420p".parse::<PixelFormat>().unwrap(), PixelFormat::Yuv420p);
+        assert_eq!(PixelFormat::Cuda.to_string(), "cuda");
+        assert!("no_such_format".parse::<PixelFormat>().is_err());
+
+        assert_eq!("fltp".parse::<SampleFormat>().unwrap(), SampleFormat::Fltp);
+        assert_eq!(SampleFormat::S16.planar(), SampleFormat::S16p);
+
+        assert_eq!("h264".parse::<CodecId>().unwrap(), CodecId::H264);
+        assert_eq!(CodecId::Opus.to_string(), "opus");
+        assert_eq!(CodecId::Opus.media_type(), MediaType::Audio);
+        let unlisted = "zmbv".parse::<CodecId>().unwrap();
+        assert_eq!(unlisted.to_string(), "zmbv");
+        assert_eq!(MediaType::Subtitle.to_string(), "subtitle");
+    }
+}
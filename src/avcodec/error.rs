@@ -0,0 +1,131 @@
+// FFERRTAG from libavutil/error.h, bindgen can not evaluate the function-like macro
+const fn fferrtag(a: u8, b: u8, c: u8, d: u8) -> i32 {
+    -((a as i32) | ((b as i32) << 8) | ((c as i32) << 16) | ((d as i32) << 24))
+}
+
+pub const AVERROR_EOF: i32 = fferrtag(b'E', b'O', b'F', b' ');
+pub const AVERROR_DECODER_NOT_FOUND: i32 = fferrtag(0xF8, b'D', b'E', b'C');
+pub const AVERROR_ENCODER_NOT_FOUND: i32 = fferrtag(0xF8, b'E', b'N', b'C');
+pub const AVERROR_FILTER_NOT_FOUND: i32 = fferrtag(0xF8, b'F', b'I', b'L');
+pub const AVERROR_OPTION_NOT_FOUND: i32 = fferrtag(0xF8, b'O', b'P', b'T');
+pub const AVERROR_DEMUXER_NOT_FOUND: i32 = fferrtag(0xF8, b'D', b'E', b'M');
+pub const AVERROR_STREAM_NOT_FOUND: i32 = fferrtag(0xF8, b'S', b'T', b'R');
+pub const AVERROR_EXTERNAL: i32 = fferrtag(b'E', b'X', b'T', b' ');
+pub const AVERROR_EAGAIN: i32 = -(avcodec::EAGAIN as i32);
+pub const AVERROR_EINVAL: i32 = -(avcodec::EINVAL as i32);
+pub const AVERROR_ENOMEM: i32 = -(avcodec::ENOMEM as i32);
+pub const AVERROR_ENOSYS: i32 = -(avcodec::ENOSYS as i32);
+
+/// Error returned by the ffmpeg wrappers, built from the negative AVERROR code of the failed call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AvError {
+    /// Output is not available in this state, more input has to be sent first (AVERROR(EAGAIN)).
+    Again,
+    /// End of stream (AVERROR_EOF).
+    Eof,
+    InvalidArgument,
+    NoMemory,
+    DecoderNotFound,
+    EncoderNotFound,
+    FilterNotFound,
+    OptionNotFound,
+    DemuxerNotFound,
+    StreamNotFound,
+    /// The linked ffmpeg release does not implement the operation, e.g. slice threaded scaling before 5.0
+    /// (AVERROR(ENOSYS)).
+    NotSupported,
+    /// A required parameter, e.g. the width of a video encoder, was not set. Reported as AVERROR(EINVAL).
+    MissingParameter(&'static str),
+    /// The codec does not support the pixel format. Reported as AVERROR(EINVAL).
+    UnsupportedPixelFormat { codec: &'static str, format: PixelFormat },
+    /// The codec only supports a fixed list of frame rates which does not contain this one. Reported as
+    /// AVERROR(EINVAL).
+    UnsupportedFrameRate { codec: &'static str, num: i32, den: i32 },
+    /// Options passed when opening, e.g. to `VideoEncoderBuilder::option`, which were not recognized. Reported as
+    /// AVERROR_OPTION_NOT_FOUND.
+    UnconsumedOptions(Vec<String>),
+    Other(i32),
+}
+
+impl AvError {
+    /// AVERROR code of the error.
+    pub fn code(&self) -> i32 {
+        match *self {
+            AvError::Again => AVERROR_EAGAIN,
+            AvError::Eof => AVERROR_EOF,
+            AvError::InvalidArgument => AVERROR_EINVAL,
+            AvError::NoMemory => AVERROR_ENOMEM,
+            AvError::DecoderNotFound => AVERROR_DECODER_NOT_FOUND,
+            AvError::EncoderNotFound => AVERROR_ENCODER_NOT_FOUND,
+            AvError::FilterNotFound => AVERROR_FILTER_NOT_FOUND,
+            AvError::OptionNotFound => AVERROR_OPTION_NOT_FOUND,
+            AvError::DemuxerNotFound => AVERROR_DEMUXER_NOT_FOUND,
+            AvError::StreamNotFound => AVERROR_STREAM_NOT_FOUND,
+            AvError::NotSupported => AVERROR_ENOSYS,
+            AvError::MissingParameter(_) | AvError::UnsupportedPixelFormat { .. } | AvError::UnsupportedFrameRate { .. } => {
+                AVERROR_EINVAL
+            }
+            AvError::UnconsumedOptions(_) => AVERROR_OPTION_NOT_FOUND,
+            AvError::Other(code) => code,
+        }
+    }
+}
+
+impl From<i32> for AvError {
+    fn from(code: i32) -> Self {
+        match code {
+            AVERROR_EAGAIN => AvError::Again,
+            AVERROR_EOF => AvError::Eof,
+            AVERROR_EINVAL => AvError::InvalidArgument,
+            AVERROR_ENOMEM => AvError::NoMemory,
+            AVERROR_DECODER_NOT_FOUND => AvError::DecoderNotFound,
+            AVERROR_ENCODER_NOT_FOUND => AvError::EncoderNotFound,
+            AVERROR_FILTER_NOT_FOUND => AvError::FilterNotFound,
+            AVERROR_OPTION_NOT_FOUND => AvError::OptionNotFound,
+            AVERROR_DEMUXER_NOT_FOUND => AvError::DemuxerNotFound,
+            AVERROR_STREAM_NOT_FOUND => AvError::StreamNotFound,
+            AVERROR_ENOSYS => AvError::NotSupported,
+            code => AvError::Other(code),
+        }
+    }
+}
+
+impl Display for AvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            AvError::MissingParameter(name) => write!(f, "{} is not set", name),
+            AvError::UnsupportedPixelFormat { codec, format } => {
+                write!(f, "pixel format {} is not supported by {}", format, codec)
+            }
+            AvError::UnsupportedFrameRate { codec, num, den } => {
+                write!(f, "frame rate {}/{} is not supported by {}", num, den, codec)
+            }
+            AvError::UnconsumedOptions(ref keys) => write!(f, "options not found: {}", keys.join(", ")),
+            _ => write!(f, "{}", err_str(self.code())),
+        }
+    }
+}
+
+impl std::error::Error for AvError {}
+
+#[cfg(test)]
+mod test_error {
+    use super::*;
+
+    #[test]
+    fn error_round_trip() {
+        for err in &[AvError::Again, AvError::Eof, AvError::InvalidArgument, AvError::NoMemory, AvError::DecoderNotFound,
+            AvError::EncoderNotFound, AvError::FilterNotFound, AvError::OptionNotFound, AvError::DemuxerNotFound,
+            AvError::StreamNotFound, AvError::NotSupported, AvError::Other(-1234)] {
+            assert_eq!(*err, AvError::from(err.code()));
+        }
+        assert_eq!(AvError::Eof.to_string(), "End of file");
+        assert_eq!(AvError::FilterNotFound.to_string(), "Filter not found");
+        let unsupported = AvError::UnsupportedPixelFormat { codec: "mjpeg", format: PixelFormat::Rgb24 };
+        assert_eq!(unsupported.code(), AVERROR_EINVAL);
+        assert_eq!(unsupported.to_string(), "pixel format rgb24 is not supported by mjpeg");
+        let unconsumed = AvError::UnconsumedOptions(vec![String::from("presset"), String::from("tune")]);
+        assert_eq!(unconsumed.code(), AVERROR_OPTION_NOT_FOUND);
+        assert_eq!(unconsumed.to_string(), "options not found: presset, tune");
+    }
+}
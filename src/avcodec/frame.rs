@@ -0,0 +1,529 @@
+// AV_NOPTS_VALUE from libavutil/avutil.h, bindgen can not evaluate the cast in the macro
+/// Timestamp of an unknown or unset pts.
+pub const AV_NOPTS_VALUE: i64 = i64::MIN;
+
+impl AVFrame {
+    /// Allocates a video frame and its buffers with `av_frame_get_buffer`. An `align` of 0 picks the alignment
+    /// for the current cpu.
+    pub fn new_video(width: i32, height: i32, format: PixelFormat, align: i32) -> Result<Self, AvError> {
+        let mut frame = Self::new();
+        unsafe {
+            let raw = frame.get_internal_mut();
+            raw.width = width;
+            raw.height = height;
+            raw.format = AVPixelFormat::from(format);
+            let ret = avcodec::av_frame_get_buffer(frame.0, align);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(frame);
+    }
+
+    pub fn width(&self) -> i32 {
+        self.get_internal().width
+    }
+    pub fn height(&self) -> i32 {
+        self.get_internal().height
+    }
+    pub fn pix_fmt(&self) -> PixelFormat {
+        PixelFormat::from(self.get_internal().format as AVPixelFormat)
+    }
+    pub fn pts(&self) -> i64 {
+        self.get_internal().pts
+    }
+    pub fn set_pts(&mut self, pts: i64) {
+        unsafe { self.get_internal_mut().pts = pts }
+    }
+    /// Timestamp of a decoded frame estimated from pts and dts, in the packet time base.
+    pub fn best_effort_timestamp(&self) -> i64 {
+        self.get_internal().best_effort_timestamp
+    }
+
+    /// True when the frame data is only referenced by this frame and can be written to.
+    pub fn is_writable(&self) -> bool {
+        unsafe { avcodec::av_frame_is_writable(self.0) > 0 }
+    }
+    /// Makes sure the frame data is writable, copying it into new buffers if it is shared with other frames.
+    pub fn make_writable(&mut self) -> Result<(), AvError> {
+        unsafe {
+            let ret = avcodec::av_frame_make_writable(self.0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(());
+    }
+    /// Copies the metadata (timestamps, color properties, side data, ...) of `src` without touching the data.
+    pub fn copy_props(&mut self, src: &AVFrame) -> Result<(), AvError> {
+        unsafe {
+            let ret = avcodec::av_frame_copy_props(self.0, src.0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(());
+    }
+    /// Copies the data of `src` into the already allocated buffers of this frame, which must have the same
+    /// format and dimensions.
+    pub fn copy_data(&mut self, src: &AVFrame) -> Result<(), AvError> {
+        if !self.is_writable() {
+            return Err(AvError::InvalidArgument);
+        }
+        unsafe {
+            let ret = avcodec::av_frame_copy(self.0, src.0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(());
+    }
+
+    /// Number of data planes of a video frame in system memory, 0 for hardware frames.
+    pub fn plane_count(&self) -> usize {
+        if !self.has_video_data() {
+            return 0;
+        }
+        unsafe { avcodec::av_pix_fmt_count_planes(self.get_internal().format).max(0) as usize }
+    }
+
+    // has_video_data tells if data[] points to cpu accessible video planes
+    fn has_video_data(&self) -> bool {
+        unsafe {
+            let desc = avcodec::av_pix_fmt_desc_get(self.get_internal().format);
+            // audio frames have no dimensions, their format is a sample format
+            if desc.is_null() || self.width() <= 0 || self.get_internal().data[0].is_null() {
+                return false;
+            }
+            (*desc).flags & avcodec::AV_PIX_FMT_FLAG_HWACCEL as u64 == 0
+        }
+    }
+
+    // plane_geometry returns the used bytes per row and the number of rows of a plane, respecting chroma subsampling
+    fn plane_geometry(&self, plane: usize) -> Option<(usize, usize)> {
+        if plane >= self.plane_count() {
+            return None;
+        }
+        unsafe {
+            let desc = avcodec::av_pix_fmt_desc_get(self.get_internal().format);
+            let row_len = avcodec::av_image_get_linesize(self.get_internal().format, self.width(), plane as i32);
+            if row_len < 0 {
+                return None;
+            }
+            let mut rows = self.height();
+            if plane == 1 || plane == 2 {
+                rows = -((-rows) >> (*desc).log2_chroma_h);
+            }
+            Some((row_len as usize, rows as usize))
+        }
+    }
+
+    /// Iterates the rows of a plane. Each row is `av_image_get_linesize` bytes long, line padding is skipped.
+    pub fn rows(&self, plane: usize) -> Option<PlaneRows<'_>> {
+        let (row_len, rows) = self.plane_geometry(plane)?;
+        let raw = self.get_internal();
+        Some(PlaneRows {
+            data: raw.data[plane],
+            linesize: raw.linesize[plane] as isize,
+            row_len,
+            rows,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Iterates the rows of a plane mutably, None when the plane does not exist or the frame is not writable
+    /// (see `make_writable`).
+    pub fn rows_mut(&mut self, plane: usize) -> Option<PlaneRowsMut<'_>> {
+        if !self.is_writable() {
+            return None;
+        }
+        let (row_len, rows) = self.plane_geometry(plane)?;
+        let raw = self.get_internal();
+        Some(PlaneRowsMut {
+            data: raw.data[plane],
+            linesize: raw.linesize[plane] as isize,
+            row_len,
+            rows,
+            phantom: PhantomData,
+        })
+    }
+
+    // byte length of a plane from the first byte of its first row to the last byte of its last row. The padding
+    // after the last row is left out, buffers like those of av_image_fill_arrays do not have it.
+    fn plane_len(&self, plane: usize) -> Option<usize> {
+        let (row_len, rows) = self.plane_geometry(plane)?;
+        let linesize = self.get_internal().linesize[plane];
+        if linesize < 0 {
+            return None;
+        }
+        if rows == 0 {
+            return Some(0);
+        }
+        Some(linesize as usize * (rows - 1) + row_len)
+    }
+
+    /// Whole plane including line padding, `linesize * (rows - 1)` bytes plus the last row. None for planes stored
+    /// bottom-up (negative linesize), use `rows` for those.
+    pub fn plane(&self, plane: usize) -> Option<&[u8]> {
+        let len = self.plane_len(plane)?;
+        unsafe { Some(std::slice::from_raw_parts(self.get_internal().data[plane], len)) }
+    }
+
+    /// Mutable whole plane, see `plane`. None when the frame is not writable.
+    pub fn plane_mut(&mut self, plane: usize) -> Option<&mut [u8]> {
+        if !self.is_writable() {
+            return None;
+        }
+        let len = self.plane_len(plane)?;
+        unsafe { Some(std::slice::from_raw_parts_mut(self.get_internal().data[plane], len)) }
+    }
+}
+
+pub struct PlaneRows<'a> {
+    data: *const u8,
+    linesize: isize,
+    row_len: usize,
+    rows: usize,
+    phantom: PhantomData<&'a AVFrame>,
+}
+
+impl<'a> Iterator for PlaneRows<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+        unsafe {
+            let row = std::slice::from_raw_parts(self.data, self.row_len);
+            self.rows -= 1;
+            if self.rows > 0 {
+                self.data = self.data.offset(self.linesize);
+            }
+            Some(row)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a> ExactSizeIterator for PlaneRows<'a> {}
+
+pub struct PlaneRowsMut<'a> {
+    data: *mut u8,
+    linesize: isize,
+    row_len: usize,
+    rows: usize,
+    phantom: PhantomData<&'a mut AVFrame>,
+}
+
+impl<'a> Iterator for PlaneRowsMut<'a> {
+    type Item = &'a mut [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+        unsafe {
+            // rows never overlap since row_len <= |linesize|
+            let row = std::slice::from_raw_parts_mut(self.data, self.row_len);
+            self.rows -= 1;
+            if self.rows > 0 {
+                self.data = self.data.offset(self.linesize);
+            }
+            Some(row)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a> ExactSizeIterator for PlaneRowsMut<'a> {}
+
+/// Sample types audio frames can be built from and read as, with the matching packed and planar formats.
+pub trait Sample: Copy + 'static {
+    const PACKED: SampleFormat;
+    const PLANAR: SampleFormat;
+}
+
+impl Sample for u8 {
+    const PACKED: SampleFormat = SampleFormat::U8;
+    const PLANAR: SampleFormat = SampleFormat::U8p;
+}
+
+impl Sample for i16 {
+    const PACKED: SampleFormat = SampleFormat::S16;
+    const PLANAR: SampleFormat = SampleFormat::S16p;
+}
+
+impl Sample for i32 {
+    const PACKED: SampleFormat = SampleFormat::S32;
+    const PLANAR: SampleFormat = SampleFormat::S32p;
+}
+
+impl Sample for i64 {
+    const PACKED: SampleFormat = SampleFormat::S64;
+    const PLANAR: SampleFormat = SampleFormat::S64p;
+}
+
+impl Sample for f32 {
+    const PACKED: SampleFormat = SampleFormat::Flt;
+    const PLANAR: SampleFormat = SampleFormat::Fltp;
+}
+
+impl Sample for f64 {
+    const PACKED: SampleFormat = SampleFormat::Dbl;
+    const PLANAR: SampleFormat = SampleFormat::Dblp;
+}
+
+impl AVFrame {
+    /// Allocates an audio frame for `nb_samples` samples per channel.
+    pub fn new_audio(nb_samples: i32, format: SampleFormat, channel_layout: ChannelLayout, sample_rate: i32) -> Result<Self, AvError> {
+        let mut frame = Self::new();
+        frame.set_sample_rate(sample_rate);
+        unsafe {
+            frame.set_layout_fields(channel_layout);
+            let raw = frame.get_internal_mut();
+            raw.nb_samples = nb_samples;
+            raw.format = AVSampleFormat::from(format);
+            let ret = avcodec::av_frame_get_buffer(frame.0, 0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(frame);
+    }
+
+    /// Builds a packed audio frame from interleaved samples, `samples.len()` must be a multiple of the channel count.
+    pub fn from_interleaved<T: Sample>(samples: &[T], channel_layout: ChannelLayout, sample_rate: i32) -> Result<Self, AvError> {
+        let channels = channel_layout.nb_channels() as usize;
+        if channels == 0 || samples.len() % channels != 0 {
+            return Err(AvError::InvalidArgument);
+        }
+        let mut frame = Self::new_audio((samples.len() / channels) as i32, T::PACKED, channel_layout, sample_rate)?;
+        frame.audio_plane_mut::<T>(0).unwrap().copy_from_slice(samples);
+        return Ok(frame);
+    }
+
+    /// Builds a planar audio frame with one slice of equal length per channel.
+    pub fn from_planar<T: Sample>(planes: &[&[T]], channel_layout: ChannelLayout, sample_rate: i32) -> Result<Self, AvError> {
+        let channels = channel_layout.nb_channels() as usize;
+        if channels == 0 || planes.len() != channels || planes.iter().any(|plane| plane.len() != planes[0].len()) {
+            return Err(AvError::InvalidArgument);
+        }
+        let mut frame = Self::new_audio(planes[0].len() as i32, T::PLANAR, channel_layout, sample_rate)?;
+        for (idx, plane) in planes.iter().enumerate() {
+            frame.audio_plane_mut::<T>(idx).unwrap().copy_from_slice(plane);
+        }
+        return Ok(frame);
+    }
+
+    // set_layout_fields sets the channel layout through the api of the linked ffmpeg
+    #[cfg(not(ffmpeg_5_1))]
+    unsafe fn set_layout_fields(&mut self, channel_layout: ChannelLayout) {
+        let raw = self.get_internal_mut();
+        raw.channel_layout = channel_layout.mask();
+        raw.channels = channel_layout.nb_channels();
+    }
+
+    #[cfg(ffmpeg_5_1)]
+    unsafe fn set_layout_fields(&mut self, channel_layout: ChannelLayout) {
+        let raw = self.get_internal_mut();
+        avcodec::av_channel_layout_uninit(&mut raw.ch_layout);
+        avcodec::av_channel_layout_from_mask(&mut raw.ch_layout, channel_layout.mask());
+    }
+
+    pub fn sample_fmt(&self) -> SampleFormat {
+        SampleFormat::from(self.get_internal().format as AVSampleFormat)
+    }
+    pub fn nb_samples(&self) -> i32 {
+        self.get_internal().nb_samples
+    }
+    pub fn sample_rate(&self) -> i32 {
+        self.get_internal().sample_rate
+    }
+    pub fn set_sample_rate(&mut self, sample_rate: i32) {
+        unsafe { self.get_internal_mut().sample_rate = sample_rate }
+    }
+
+    #[cfg(not(ffmpeg_5_1))]
+    pub fn channels(&self) -> i32 {
+        self.get_internal().channels
+    }
+    #[cfg(ffmpeg_5_1)]
+    pub fn channels(&self) -> i32 {
+        self.get_internal().ch_layout.nb_channels
+    }
+
+    /// Layout of the frame's channels, not `is_known` when the frame only carries a channel count.
+    #[cfg(not(ffmpeg_5_1))]
+    pub fn channel_layout(&self) -> ChannelLayout {
+        ChannelLayout::from_mask(self.get_internal().channel_layout)
+    }
+    #[cfg(ffmpeg_5_1)]
+    pub fn channel_layout(&self) -> ChannelLayout {
+        let layout = &self.get_internal().ch_layout;
+        if layout.order != avcodec::AVChannelOrder_AV_CHANNEL_ORDER_NATIVE {
+            return ChannelLayout::from_mask(0);
+        }
+        ChannelLayout::from_mask(unsafe { layout.u.mask })
+    }
+
+    /// Sets the channel layout, only allowed before the buffers are allocated or when the channel count stays
+    /// the same.
+    pub fn set_channel_layout(&mut self, channel_layout: ChannelLayout) -> Result<(), AvError> {
+        let channels = channel_layout.nb_channels();
+        if !self.get_internal().data[0].is_null() && channels != self.channels() {
+            return Err(AvError::InvalidArgument);
+        }
+        unsafe { self.set_layout_fields(channel_layout) };
+        return Ok(());
+    }
+
+    /// Sets the number of samples per channel, once the buffers are allocated only up to their capacity.
+    pub fn set_nb_samples(&mut self, nb_samples: i32) -> Result<(), AvError> {
+        if nb_samples < 0 || (!self.get_internal().data[0].is_null() && nb_samples > self.sample_capacity()) {
+            return Err(AvError::InvalidArgument);
+        }
+        unsafe { self.get_internal_mut().nb_samples = nb_samples };
+        return Ok(());
+    }
+
+    // sample_capacity derives how many samples per channel the allocated audio planes hold from their size
+    fn sample_capacity(&self) -> i32 {
+        unsafe {
+            let bytes = avcodec::av_get_bytes_per_sample(self.get_internal().format);
+            let per_plane = if avcodec::av_sample_fmt_is_planar(self.get_internal().format) != 0 { 1 } else { self.channels() };
+            if bytes <= 0 || per_plane <= 0 {
+                return 0;
+            }
+            self.get_internal().linesize[0] / (bytes * per_plane)
+        }
+    }
+
+    // audio_plane_len returns the number of T in an audio plane, None if T does not match the sample format
+    fn audio_plane_len<T: Sample>(&self, plane: usize) -> Option<usize> {
+        let format = self.sample_fmt();
+        let channels = self.channels().max(0) as usize;
+        let nb_samples = self.get_internal().nb_samples.max(0) as usize;
+        if format == T::PLANAR && plane < channels {
+            Some(nb_samples)
+        } else if format == T::PACKED && plane == 0 {
+            Some(nb_samples * channels)
+        } else {
+            None
+        }
+    }
+
+    // audio_plane_ptr returns the data pointer of an audio plane, planes past the 8 in data[] are in extended_data.
+    // Null when the frame has no buffers.
+    fn audio_plane_ptr(&self, plane: usize) -> *mut u8 {
+        let extended_data = self.get_internal().extended_data;
+        if extended_data.is_null() {
+            return null_mut();
+        }
+        unsafe { *extended_data.add(plane) }
+    }
+
+    /// Samples of a plane: one channel for planar formats, all channels interleaved in plane 0 for packed
+    /// formats. None when `T` does not match the frame's sample format.
+    pub fn audio_plane<T: Sample>(&self, plane: usize) -> Option<&[T]> {
+        let len = self.audio_plane_len::<T>(plane)?;
+        let data = self.audio_plane_ptr(plane);
+        if data.is_null() {
+            return None;
+        }
+        unsafe { Some(std::slice::from_raw_parts(data as *const T, len)) }
+    }
+
+    /// Mutable samples of a plane, see `audio_plane`. None when the frame is not writable.
+    pub fn audio_plane_mut<T: Sample>(&mut self, plane: usize) -> Option<&mut [T]> {
+        if !self.is_writable() {
+            return None;
+        }
+        let len = self.audio_plane_len::<T>(plane)?;
+        let data = self.audio_plane_ptr(plane);
+        if data.is_null() {
+            return None;
+        }
+        unsafe { Some(std::slice::from_raw_parts_mut(data as *mut T, len)) }
+    }
+
+    /// Iterates the samples of one channel for both planar and packed formats.
+    pub fn channel_samples<T: Sample>(&self, channel: usize) -> Option<impl Iterator<Item = T> + '_> {
+        let channels = self.channels().max(0) as usize;
+        if channel >= channels {
+            return None;
+        }
+        // a planar channel is a plane of its own, packed channels are interleaved in plane 0
+        let (plane, first, step) = if self.sample_fmt() == T::PLANAR { (channel, 0, 1) } else { (0, channel, channels) };
+        Some(self.audio_plane::<T>(plane)?.iter().copied().skip(first).step_by(step))
+    }
+}
+
+#[cfg(test)]
+pub mod test_frame {
+    use super::*;
+
+    // yuv420p frame with every byte of every plane set to `value`
+    pub fn flat_frame(width: i32, height: i32, value: u8, pts: i64) -> AVFrame {
+        let mut frame = AVFrame::new_video(width, height, PixelFormat::Yuv420p, 0).unwrap();
+        for plane in 0..3 {
+            for row in frame.rows_mut(plane).unwrap() {
+                row.fill(value);
+            }
+        }
+        frame.set_pts(pts);
+        return frame;
+    }
+
+    #[test]
+    fn video_frame_planes() {
+        let mut frame = AVFrame::new_video(64, 33, PixelFormat::Yuv420p, 0).unwrap();
+        assert_eq!(frame.plane_count(), 3);
+        assert_eq!(frame.rows(0).unwrap().len(), 33);
+        assert_eq!(frame.rows(1).unwrap().len(), 17);
+        assert!(frame.rows(3).is_none());
+        assert!(frame.rows(1).unwrap().all(|row| row.len() == 32));
+        let linesize = frame.get_internal().linesize[1] as usize;
+        assert_eq!(frame.plane(1).unwrap().len(), linesize * 16 + 32);
+
+        for (y, row) in frame.rows_mut(0).unwrap().enumerate() {
+            for byte in row.iter_mut() {
+                *byte = y as u8;
+            }
+        }
+        let copy = frame.clone();
+        assert!(!frame.is_writable());
+        assert!(frame.rows_mut(0).is_none());
+        frame.make_writable().unwrap();
+        assert!(frame.rows_mut(0).is_some());
+        assert_eq!(copy.rows(0).unwrap().nth(20).unwrap()[5], 20);
+    }
+
+    #[test]
+    fn audio_frame_samples() {
+        let stereo = ChannelLayout::STEREO;
+        let interleaved: Vec<i16> = (0..960).collect();
+        let frame = AVFrame::from_interleaved(&interleaved, stereo, 48000).unwrap();
+        assert_eq!(frame.nb_samples(), 480);
+        assert_eq!(frame.channels(), 2);
+        assert_eq!(frame.audio_plane::<i16>(0).unwrap(), &interleaved[..]);
+        assert!(frame.audio_plane::<f32>(0).is_none());
+        let right: Vec<i16> = frame.channel_samples::<i16>(1).unwrap().collect();
+        assert_eq!(right[..3], [1, 3, 5]);
+
+        let left = vec![0.25f32; 1024];
+        let right = vec![-0.5f32; 1024];
+        let mut frame = AVFrame::from_planar(&[&left, &right], stereo, 44100).unwrap();
+        assert_eq!(frame.sample_fmt(), SampleFormat::Fltp);
+        assert_eq!(frame.audio_plane::<f32>(1).unwrap(), &right[..]);
+        assert!(frame.set_nb_samples(4096).is_err());
+        frame.set_nb_samples(512).unwrap();
+        assert_eq!(frame.channel_samples::<f32>(0).unwrap().count(), 512);
+    }
+}
@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 use std::mem::size_of;
 use std::ptr::{null, slice_from_raw_parts};
+use std::str::FromStr;
 
 pub use libav_sys::avcodec::{
     AVCodecHWConfig,
@@ -10,13 +11,37 @@ pub use libav_sys::avcodec::{
     AVHWFramesContext,
     AVMediaType,
     AVPixelFormat,
-    AVRational,
+    AVSampleFormat,
 };
+use libav_sys::avcodec::AVRational;
 #[allow(unused)]
 use log::{error, info};
-use crate::avcodec_sys::_float_const;
 
 include!("codec.rs");
 include!("codec_context.rs");
+include!("frame.rs");
 include!("dict.rs");
+include!("error.rs");
+include!("enums.rs");
+include!("options.rs");
+include!("pixdesc.rs");
+include!("rational.rs");
+include!("encoder.rs");
+include!("decoder.rs");
+include!("channel_layout.rs");
+include!("audio_fifo.rs");
+include!("version.rs");
+#[cfg(feature = "avfilter")]
 include!("avfilter.rs");
+#[cfg(feature = "avformat")]
+include!("demux.rs");
+#[cfg(feature = "avformat")]
+include!("mux.rs");
+#[cfg(feature = "avformat")]
+include!("avio.rs");
+#[cfg(feature = "avformat")]
+include!("probe.rs");
+#[cfg(feature = "swscale")]
+include!("scale.rs");
+#[cfg(feature = "swresample")]
+include!("resample.rs");
@@ -0,0 +1,241 @@
+/// Muxer writing encoded packets into a container (an AVFormatContext allocated with
+/// avformat_alloc_output_context2).
+///
+/// Usage: `create`, `add_stream` for every encoder, `write_header`, `write_packet` for all packets and finally
+/// `write_trailer`. The trailer is not written on drop, without it most containers are left incomplete.
+pub struct AVFormatOutput {
+    internal: *mut avcodec::AVFormatContext,
+    // the AVIOContext was opened by `create` and has to be closed with the context
+    owns_io: bool,
+    // custom I/O, dropped after the context is freed
+    io: Option<AVIOContext>,
+    header_written: bool,
+}
+
+impl Drop for AVFormatOutput {
+    fn drop(&mut self) {
+        unsafe {
+            if self.owns_io {
+                avcodec::avio_closep(&mut (*self.internal).pb);
+            }
+            avcodec::avformat_free_context(self.internal);
+        }
+    }
+}
+
+// like the codec context, a muxer can be moved to another thread but must not be used from several at once
+unsafe impl Send for AVFormatOutput {}
+
+impl AVFormatOutput {
+    /// Creates a muxer writing to `url`. The container is the muxer named `format_name`, e.g. "mp4", "matroska",
+    /// "mpegts", "flv" or "h264", or guessed from the extension of `url` when it is None.
+    pub fn create(url: &str, format_name: Option<&str>) -> Result<Self, AvError> {
+        let mut output = Self::alloc(url, format_name)?;
+        unsafe {
+            // formats like image2 open their files themselves
+            if (*output.get_internal().oformat).flags & avcodec::AVFMT_NOFILE as i32 == 0 {
+                let c_url = CString::new(url).map_err(|_| AvError::InvalidArgument)?;
+                let ret = avcodec::avio_open(&mut (*output.internal).pb, c_url.as_ptr(), avcodec::AVIO_FLAG_WRITE as i32);
+                if ret < 0 {
+                    return Err(AvError::from(ret));
+                }
+                output.owns_io = true;
+            }
+        }
+        return Ok(output);
+    }
+
+    /// Creates a muxer writing to custom I/O. The container has to be named, there is no file name to guess from.
+    pub fn create_io(io: AVIOContext, format_name: &str) -> Result<Self, AvError> {
+        let mut output = Self::alloc("", Some(format_name))?;
+        unsafe {
+            (*output.internal).pb = io.internal;
+            (*output.internal).flags |= avcodec::AVFMT_FLAG_CUSTOM_IO as i32;
+        }
+        output.io = Some(io);
+        return Ok(output);
+    }
+    /// Frees the muxer and returns its custom I/O, e.g. to get the written data with `AVIOContext::into_inner`
+    /// after `write_trailer`.
+    pub fn into_io(mut self) -> Option<AVIOContext> {
+        self.io.take()
+    }
+
+    fn alloc(url: &str, format_name: Option<&str>) -> Result<Self, AvError> {
+        let c_url = CString::new(url).map_err(|_| AvError::InvalidArgument)?;
+        let c_format_name = match format_name {
+            Some(name) => Some(CString::new(name).map_err(|_| AvError::InvalidArgument)?),
+            None => None,
+        };
+        unsafe {
+            let mut internal = null_mut();
+            let ret = avcodec::avformat_alloc_output_context2(&mut internal, null_mut(),
+                                                              c_format_name.as_ref().map_or(null(), |name| name.as_ptr()),
+                                                              c_url.as_ptr());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok(Self { internal, owns_io: false, io: None, header_written: false })
+        }
+    }
+
+    /// Short name of the muxer, e.g. "matroska".
+    pub fn format_name(&self) -> &'static str {
+        unsafe { get_str_or_default((*self.get_internal().oformat).name, EMPTY_STR) }
+    }
+    /// Whether encoders feeding this muxer need `AVCodecContext::set_global_header` before `open2`.
+    pub fn needs_global_header(&self) -> bool {
+        unsafe { (*self.get_internal().oformat).flags & avcodec::AVFMT_GLOBALHEADER as i32 != 0 }
+    }
+    /// Adds a stream with the parameters of an opened encoder, see `AVCodecParameters::from`, and returns its index.
+    /// `time_base` is a hint, the muxer may choose another one in `write_header`.
+    pub fn add_stream(&mut self, parameters: &AVCodecParameters, time_base: Rational) -> Result<usize, AvError> {
+        unsafe {
+            let stream = avcodec::avformat_new_stream(self.internal, null());
+            if stream.is_null() {
+                return Err(AvError::NoMemory);
+            }
+            let ret = avcodec::avcodec_parameters_copy((*stream).codecpar, parameters.internal);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            // a tag taken from another container may not be valid in this one, let the muxer pick it
+            (*(*stream).codecpar).codec_tag = 0;
+            (*stream).time_base = time_base.into();
+            Ok((*stream).index as usize)
+        }
+    }
+    pub fn nb_streams(&self) -> usize {
+        self.get_internal().nb_streams as usize
+    }
+    pub fn stream(&self, index: usize) -> Option<AVStream<'_>> {
+        if index >= self.nb_streams() {
+            return None;
+        }
+        unsafe { Some(AVStream::from(*self.get_internal().streams.add(index))) }
+    }
+    /// Sets a container level metadata entry, e.g. "title". Must be called before `write_header`.
+    pub fn set_metadata(&mut self, key: &str, value: &str) -> Result<(), AvError> {
+        let c_key = CString::new(key).map_err(|_| AvError::InvalidArgument)?;
+        let c_value = CString::new(value).map_err(|_| AvError::InvalidArgument)?;
+        unsafe {
+            let ret = avcodec::av_dict_set(&mut (*self.internal).metadata, c_key.as_ptr(), c_value.as_ptr(), 0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(())
+    }
+    /// Writes the container header. Entries of `dict` consumed by the muxer are removed from it, the remaining ones
+    /// were not recognized.
+    pub fn write_header(&mut self, dict: Option<&mut AVDictionary>) -> Result<(), AvError> {
+        unsafe {
+            let mut r_dict = null_mut();
+            if let Some(dict) = dict {
+                r_dict = &mut dict.internal
+            }
+            let ret = avcodec::avformat_write_header(self.internal, r_dict);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            self.header_written = true;
+            Ok(())
+        }
+    }
+    /// Writes a packet of stream `stream_index` whose timestamps are in `time_base`, usually the encoder's time base.
+    /// Timestamps are rescaled to the stream time base and packets are interleaved by dts across streams. The muxer
+    /// takes the packet data, `pkt` is blank afterwards and can be reused for `receive_pkt`.
+    pub fn write_packet(&mut self, pkt: &mut AVPacket, stream_index: usize, time_base: Rational) -> Result<(), AvError> {
+        let stream_time_base = self.stream(stream_index).ok_or(AvError::InvalidArgument)?.time_base();
+        pkt.set_stream_index(stream_index);
+        pkt.rescale_ts(time_base, stream_time_base);
+        unsafe {
+            let ret = avcodec::av_interleaved_write_frame(self.internal, pkt.as_mut_ptr());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(())
+    }
+    /// Flushes the interleaving queue and writes the container trailer.
+    pub fn write_trailer(&mut self) -> Result<(), AvError> {
+        unsafe {
+            let ret = avcodec::av_write_trailer(self.internal);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_internal(&self) -> &avcodec::AVFormatContext {
+        return unsafe { &*(self.internal) };
+    }
+    /// Mutable access to the raw context. The caller must keep the context consistent for ffmpeg.
+    pub unsafe fn get_internal_mut(&mut self) -> &mut avcodec::AVFormatContext {
+        return &mut *(self.internal);
+    }
+    pub fn as_ptr(&self) -> *const avcodec::AVFormatContext {
+        return self.internal;
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut avcodec::AVFormatContext {
+        return self.internal;
+    }
+}
+
+// the format context has the (de)muxer's private data and the I/O context as children
+unsafe impl AVOptions for AVFormatOutput {
+    fn options_ptr(&self) -> *mut c_void {
+        self.internal as *mut c_void
+    }
+    /// True once the header is written.
+    fn is_in_use(&self) -> bool {
+        self.header_written
+    }
+}
+
+#[cfg(test)]
+mod test_mux {
+    use super::*;
+    use super::test_frame::flat_frame;
+
+    #[test]
+    fn mux_and_demux() {
+        let path = std::env::temp_dir().join("ffmpeg_rs_mux.mkv");
+        let mut output = AVFormatOutput::create(path.to_str().unwrap(), None).unwrap();
+        assert_eq!(output.format_name(), "matroska");
+        assert!(AVFormatOutput::create("out.unknown_extension", None).is_err());
+
+        let codec = AVCodec::find_encoder(CodecId::Ffv1).unwrap();
+        let mut ctx = AVCodecContext::new(&codec);
+        unsafe {
+            let raw = ctx.get_internal_mut();
+            raw.width = 64;
+            raw.height = 48;
+            raw.pix_fmt = AVPixelFormat::from(PixelFormat::Yuv420p);
+            raw.time_base = Rational::new(1, 25).into();
+        }
+        ctx.set_global_header(output.needs_global_header());
+        ctx.open2(&codec, None).unwrap();
+        let time_base = Rational::from(ctx.get_internal().time_base);
+        let index = output.add_stream(&AVCodecParameters::from(&ctx).unwrap(), time_base).unwrap();
+        output.set_metadata("title", "ffmpeg-rs").unwrap();
+        output.write_header(None).unwrap();
+
+        let mut pkt = AVPacket::new();
+        for i in 0..5 {
+            ctx.send_frame(&flat_frame(64, 48, i as u8 * 10, i)).unwrap();
+            while ctx.receive_pkt(&mut pkt).is_ok() {
+                output.write_packet(&mut pkt, index, time_base).unwrap();
+            }
+        }
+        output.write_trailer().unwrap();
+        drop(output);
+
+        let mut input = AVFormatInput::open(path.to_str().unwrap(), None).unwrap();
+        assert_eq!(input.stream(0).unwrap().codec_id(), CodecId::Ffv1);
+        assert_eq!(input.metadata().get("title", None, 0).unwrap().val, "ffmpeg-rs");
+        assert_eq!(input.packets().count(), 5);
+        std::fs::remove_file(path).unwrap();
+    }
+}
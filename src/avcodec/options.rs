@@ -0,0 +1,425 @@
+av_enum! {
+    /// Type of an option (AVOptionType).
+    OptionType: avcodec::AVOptionType {
+        Flags = avcodec::AVOptionType_AV_OPT_TYPE_FLAGS,
+        Int = avcodec::AVOptionType_AV_OPT_TYPE_INT,
+        Int64 = avcodec::AVOptionType_AV_OPT_TYPE_INT64,
+        Double = avcodec::AVOptionType_AV_OPT_TYPE_DOUBLE,
+        Float = avcodec::AVOptionType_AV_OPT_TYPE_FLOAT,
+        String = avcodec::AVOptionType_AV_OPT_TYPE_STRING,
+        Rational = avcodec::AVOptionType_AV_OPT_TYPE_RATIONAL,
+        Binary = avcodec::AVOptionType_AV_OPT_TYPE_BINARY,
+        Dict = avcodec::AVOptionType_AV_OPT_TYPE_DICT,
+        UInt64 = avcodec::AVOptionType_AV_OPT_TYPE_UINT64,
+        Const = avcodec::AVOptionType_AV_OPT_TYPE_CONST,
+        ImageSize = avcodec::AVOptionType_AV_OPT_TYPE_IMAGE_SIZE,
+        PixelFmt = avcodec::AVOptionType_AV_OPT_TYPE_PIXEL_FMT,
+        SampleFmt = avcodec::AVOptionType_AV_OPT_TYPE_SAMPLE_FMT,
+        VideoRate = avcodec::AVOptionType_AV_OPT_TYPE_VIDEO_RATE,
+        Duration = avcodec::AVOptionType_AV_OPT_TYPE_DURATION,
+        Color = avcodec::AVOptionType_AV_OPT_TYPE_COLOR,
+        ChannelLayout = avcodec::AVOptionType_AV_OPT_TYPE_CHANNEL_LAYOUT,
+        Bool = avcodec::AVOptionType_AV_OPT_TYPE_BOOL,
+    }
+}
+
+/// Default value of an option, its variant follows the option type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OptionDefault {
+    /// Integer, flag, bool, format, duration and channel layout options.
+    Int(i64),
+    /// Double, float and rational options.
+    Double(f64),
+    /// String, image size, video rate, color, dictionary and binary options, None when unset.
+    Str(Option<&'static str>),
+    /// Options of a type this crate does not know, e.g. AV_OPT_TYPE_CHLAYOUT of ffmpeg 5.1, whose default can not be
+    /// read without knowing which member of the union holds it.
+    Unknown,
+}
+
+/// A named value of an option, e.g. "veryfast" of the x264 "preset" option or a flag of a flags option.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptionConstant {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub value: i64,
+}
+
+/// Description of an option (AVOption), see `AVOptions::options` and `AVClass::options`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionInfo {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub option_type: OptionType,
+    pub default: OptionDefault,
+    pub min: f64,
+    pub max: f64,
+    /// AV_OPT_FLAG_* flags, e.g. whether the option applies to encoding or decoding.
+    pub flags: i32,
+    /// Name of the group of constants the option accepts, empty when it has none.
+    pub unit: &'static str,
+    pub constants: Vec<OptionConstant>,
+}
+
+impl OptionInfo {
+    unsafe fn from_raw(opt: &avcodec::AVOption) -> Self {
+        let option_type = OptionType::from(opt.type_);
+        let default = match option_type {
+            OptionType::Double | OptionType::Float | OptionType::Rational => OptionDefault::Double(opt.default_val.dbl),
+            OptionType::String | OptionType::ImageSize | OptionType::VideoRate | OptionType::Color | OptionType::Dict
+            | OptionType::Binary => {
+                let value = opt.default_val.str_;
+                OptionDefault::Str(if value.is_null() { None } else { Some(get_str_or_default(value, EMPTY_STR)) })
+            }
+            OptionType::Flags | OptionType::Int | OptionType::Int64 | OptionType::UInt64 | OptionType::Const
+            | OptionType::PixelFmt | OptionType::SampleFmt | OptionType::Duration | OptionType::ChannelLayout
+            | OptionType::Bool => OptionDefault::Int(opt.default_val.i64_),
+            OptionType::Unknown(_) => OptionDefault::Unknown,
+        };
+        Self {
+            name: get_str_or_default(opt.name, EMPTY_STR),
+            help: get_str_or_default(opt.help, EMPTY_STR),
+            option_type,
+            default,
+            min: opt.min,
+            max: opt.max,
+            flags: opt.flags,
+            unit: get_str_or_default(opt.unit, EMPTY_STR),
+            constants: Vec::new(),
+        }
+    }
+}
+
+// lists the options of `obj`, whose first field is an AVClass pointer. Constants are attached to the options of
+// their unit instead of being listed.
+unsafe fn list_options(obj: *const c_void) -> Vec<OptionInfo> {
+    let mut options: Vec<OptionInfo> = Vec::new();
+    let mut constants = Vec::new();
+    let mut opt = avcodec::av_opt_next(obj, null());
+    while !opt.is_null() {
+        let info = OptionInfo::from_raw(&*opt);
+        if info.option_type == OptionType::Const {
+            constants.push((info.unit, OptionConstant { name: info.name, help: info.help, value: (*opt).default_val.i64_ }));
+        } else {
+            options.push(info);
+        }
+        opt = avcodec::av_opt_next(obj, opt);
+    }
+    for option in options.iter_mut().filter(|option| !option.unit.is_empty()) {
+        option.constants = constants.iter().filter(|(unit, _)| *unit == option.unit).map(|(_, constant)| *constant).collect();
+    }
+    return options;
+}
+
+impl AVClass {
+    /// Options of objects of this class, e.g. the private options of a codec before a context is opened.
+    pub fn options(&self) -> Vec<OptionInfo> {
+        // av_opt_next only reads the class pointer, a pointer to it stands in for an object
+        unsafe { list_options(&self.int_class as *const *const avcodec::AVClass as *const c_void) }
+    }
+}
+
+fn option_name(name: &str) -> Result<CString, AvError> {
+    CString::new(name).map_err(|_| AvError::InvalidArgument)
+}
+
+// name of an option about to be set. Objects in use only accept options flagged as runtime parameters, others may
+// already be baked into their state, e.g. the width into the buffers of an opened codec.
+fn settable_option_name<O: AVOptions + ?Sized>(obj: &O, name: &str) -> Result<CString, AvError> {
+    let c_name = option_name(name)?;
+    if obj.is_in_use() {
+        unsafe {
+            let mut target = null_mut();
+            let opt = avcodec::av_opt_find2(obj.options_ptr(), c_name.as_ptr(), null(), 0, SEARCH_CHILDREN, &mut target);
+            if opt.is_null() {
+                return Err(AvError::OptionNotFound);
+            }
+            if (*opt).flags & avcodec::AV_OPT_FLAG_RUNTIME_PARAM as c_int == 0 {
+                return Err(AvError::InvalidArgument);
+            }
+        }
+    }
+    Ok(c_name)
+}
+
+fn check_option(ret: c_int) -> Result<(), AvError> {
+    if ret < 0 {
+        return Err(AvError::from(ret));
+    }
+    Ok(())
+}
+
+const SEARCH_CHILDREN: c_int = avcodec::AV_OPT_SEARCH_CHILDREN as c_int;
+
+/// An ffmpeg object with AVOptions, e.g. a codec, filter or format context.
+///
+/// Options are looked up in the object and its children (AV_OPT_SEARCH_CHILDREN), so the private options of a
+/// codec or muxer can be set through its context. Once the object is in use, e.g. an opened codec context or a filter
+/// of a configured graph, the setters fail with InvalidArgument for options not flagged AV_OPT_FLAG_RUNTIME_PARAM.
+///
+/// # Safety
+/// `options_ptr` has to return a valid object whose first field is an AVClass pointer.
+pub unsafe trait AVOptions {
+    fn options_ptr(&self) -> *mut c_void;
+    /// Whether ffmpeg has started using the object with its current options, see `AVOptions`.
+    fn is_in_use(&self) -> bool {
+        false
+    }
+
+    /// Options of the object itself, without those of its children.
+    fn options(&self) -> Vec<OptionInfo> {
+        unsafe { list_options(self.options_ptr()) }
+    }
+    /// Sets an option from its string form, as on the ffmpeg command line.
+    fn set_option(&mut self, name: &str, value: &str) -> Result<(), AvError> {
+        let c_name = settable_option_name(self, name)?;
+        let c_value = CString::new(value).map_err(|_| AvError::InvalidArgument)?;
+        check_option(unsafe { avcodec::av_opt_set(self.options_ptr(), c_name.as_ptr(), c_value.as_ptr(), SEARCH_CHILDREN) })
+    }
+    fn set_option_int(&mut self, name: &str, value: i64) -> Result<(), AvError> {
+        let c_name = settable_option_name(self, name)?;
+        check_option(unsafe { avcodec::av_opt_set_int(self.options_ptr(), c_name.as_ptr(), value, SEARCH_CHILDREN) })
+    }
+    fn set_option_double(&mut self, name: &str, value: f64) -> Result<(), AvError> {
+        let c_name = settable_option_name(self, name)?;
+        check_option(unsafe { avcodec::av_opt_set_double(self.options_ptr(), c_name.as_ptr(), value, SEARCH_CHILDREN) })
+    }
+    fn set_option_rational(&mut self, name: &str, value: Rational) -> Result<(), AvError> {
+        let c_name = settable_option_name(self, name)?;
+        check_option(unsafe { avcodec::av_opt_set_q(self.options_ptr(), c_name.as_ptr(), value.into(), SEARCH_CHILDREN) })
+    }
+    fn set_option_pix_fmt(&mut self, name: &str, value: PixelFormat) -> Result<(), AvError> {
+        let c_name = settable_option_name(self, name)?;
+        let ret = unsafe {
+            avcodec::av_opt_set_pixel_fmt(self.options_ptr(), c_name.as_ptr(), AVPixelFormat::from(value), SEARCH_CHILDREN)
+        };
+        check_option(ret)
+    }
+    fn set_option_image_size(&mut self, name: &str, width: i32, height: i32) -> Result<(), AvError> {
+        let c_name = settable_option_name(self, name)?;
+        check_option(unsafe { avcodec::av_opt_set_image_size(self.options_ptr(), c_name.as_ptr(), width, height, SEARCH_CHILDREN) })
+    }
+    /// Sets a binary option holding a list of integers, e.g. the "pix_fmts" of a buffersink (av_opt_set_int_list).
+    fn set_option_int_list<T: OptionListValue>(&mut self, name: &str, values: &[T]) -> Result<(), AvError> where Self: Sized {
+        let c_name = settable_option_name(self, name)?;
+        let raw: Vec<T::Raw> = values.iter().map(|value| value.to_raw()).collect();
+        let size = raw.len() * size_of::<T::Raw>();
+        if size > i32::MAX as usize {
+            return Err(AvError::InvalidArgument);
+        }
+        let ret = unsafe {
+            avcodec::av_opt_set_bin(self.options_ptr(), c_name.as_ptr(), raw.as_ptr() as *const u8, size as c_int, SEARCH_CHILDREN)
+        };
+        check_option(ret)
+    }
+
+    /// Reads a binary option holding a list of integers, e.g. the "pix_fmts" of a buffersink, the counterpart of
+    /// `set_option_int_list`. Fails with InvalidArgument for options of other types and when the size of the data
+    /// is not a multiple of the size of `T`.
+    fn get_option_int_list<T: OptionListValue>(&self, name: &str) -> Result<Vec<T>, AvError> where Self: Sized {
+        let c_name = option_name(name)?;
+        unsafe {
+            let mut target = null_mut();
+            let opt = avcodec::av_opt_find2(self.options_ptr(), c_name.as_ptr(), null(), 0, SEARCH_CHILDREN, &mut target);
+            if opt.is_null() {
+                return Err(AvError::OptionNotFound);
+            }
+            if (*opt).type_ != avcodec::AVOptionType_AV_OPT_TYPE_BINARY {
+                return Err(AvError::InvalidArgument);
+            }
+            // a binary option is a data pointer followed by an int length, as av_opt_get reads it
+            let field = (target as *const u8).offset((*opt).offset as isize);
+            let data = *(field as *const *const T::Raw);
+            let size = *(field.add(size_of::<*const u8>()) as *const c_int) as usize;
+            if size % size_of::<T::Raw>() != 0 {
+                return Err(AvError::InvalidArgument);
+            }
+            Ok((0..size / size_of::<T::Raw>()).map(|idx| T::from_raw(data.add(idx).read_unaligned())).collect())
+        }
+    }
+
+    /// The value of an option in its string form.
+    fn get_option(&self, name: &str) -> Result<String, AvError> {
+        let c_name = option_name(name)?;
+        unsafe {
+            let mut value = null_mut();
+            check_option(avcodec::av_opt_get(self.options_ptr(), c_name.as_ptr(), SEARCH_CHILDREN, &mut value))?;
+            let string = get_str_or_default(value as *const c_char, EMPTY_STR).to_string();
+            avcodec::av_free(value as *mut c_void);
+            Ok(string)
+        }
+    }
+    fn get_option_int(&self, name: &str) -> Result<i64, AvError> {
+        let c_name = option_name(name)?;
+        let mut value = 0;
+        check_option(unsafe { avcodec::av_opt_get_int(self.options_ptr(), c_name.as_ptr(), SEARCH_CHILDREN, &mut value) })?;
+        Ok(value)
+    }
+    fn get_option_double(&self, name: &str) -> Result<f64, AvError> {
+        let c_name = option_name(name)?;
+        let mut value = 0.0;
+        check_option(unsafe { avcodec::av_opt_get_double(self.options_ptr(), c_name.as_ptr(), SEARCH_CHILDREN, &mut value) })?;
+        Ok(value)
+    }
+    fn get_option_rational(&self, name: &str) -> Result<Rational, AvError> {
+        let c_name = option_name(name)?;
+        let mut value = AVRational { num: 0, den: 1 };
+        check_option(unsafe { avcodec::av_opt_get_q(self.options_ptr(), c_name.as_ptr(), SEARCH_CHILDREN, &mut value) })?;
+        Ok(Rational::from(value))
+    }
+    fn get_option_pix_fmt(&self, name: &str) -> Result<PixelFormat, AvError> {
+        let c_name = option_name(name)?;
+        let mut value = avcodec::AVPixelFormat_AV_PIX_FMT_NONE;
+        check_option(unsafe { avcodec::av_opt_get_pixel_fmt(self.options_ptr(), c_name.as_ptr(), SEARCH_CHILDREN, &mut value) })?;
+        Ok(PixelFormat::from(value))
+    }
+    fn get_option_image_size(&self, name: &str) -> Result<(i32, i32), AvError> {
+        let c_name = option_name(name)?;
+        let (mut width, mut height) = (0, 0);
+        let ret = unsafe {
+            avcodec::av_opt_get_image_size(self.options_ptr(), c_name.as_ptr(), SEARCH_CHILDREN, &mut width, &mut height)
+        };
+        check_option(ret)?;
+        Ok((width, height))
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Element of an integer list option, see `AVOptions::set_option_int_list` and `AVOptions::get_option_int_list`. Implemented for the integer types ffmpeg
+/// stores in such lists and for the typed enums, which are converted to their ffmpeg values.
+pub trait OptionListValue: sealed::Sealed + Copy {
+    /// The value as ffmpeg reads it from the list.
+    type Raw: Copy;
+
+    fn to_raw(self) -> Self::Raw;
+    fn from_raw(raw: Self::Raw) -> Self;
+}
+
+macro_rules! option_list_value {
+    ($($type:ty => $raw:ty,)*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl OptionListValue for $type {
+                type Raw = $raw;
+
+                fn to_raw(self) -> Self::Raw {
+                    <$raw>::from(self)
+                }
+                fn from_raw(raw: Self::Raw) -> Self {
+                    <$type>::from(raw)
+                }
+            }
+        )*
+    };
+}
+
+option_list_value! {
+    i32 => i32,
+    i64 => i64,
+    u64 => u64,
+    PixelFormat => AVPixelFormat,
+    SampleFormat => AVSampleFormat,
+    ChannelLayout => u64,
+}
+
+// fails with the keys of the options passed to open2 which were not consumed, e.g. misspelled or not supported by
+// the codec
+fn check_consumed(dict: &AVDictionary) -> Result<(), AvError> {
+    if dict.is_empty() {
+        return Ok(());
+    }
+    Err(AvError::UnconsumedOptions(dict.keys()))
+}
+
+#[cfg(test)]
+mod test_options {
+    use super::*;
+
+    #[test]
+    fn codec_context_options() {
+        let codec = AVCodec::find_encoder(CodecId::Mpeg4).unwrap();
+        let private = codec.priv_class().unwrap().options();
+        assert!(private.iter().any(|option| option.name == "data_partitioning" && option.option_type == OptionType::Bool));
+
+        let mut ctx = AVCodecContext::new(&codec);
+        let options = ctx.options();
+        let flags = options.iter().find(|option| option.name == "flags").unwrap();
+        assert_eq!(flags.option_type, OptionType::Flags);
+        assert!(flags.constants.iter().any(|constant| constant.name == "global_header"));
+        let threads = options.iter().find(|option| option.name == "threads").unwrap();
+        assert_eq!(threads.default, OptionDefault::Int(1));
+        assert!(!threads.help.is_empty());
+
+        ctx.set_option("flags", "+global_header").unwrap();
+        assert_eq!(ctx.get_internal().flags & avcodec::AV_CODEC_FLAG_GLOBAL_HEADER as i32, avcodec::AV_CODEC_FLAG_GLOBAL_HEADER as i32);
+        ctx.set_option_int("g", 12).unwrap();
+        assert_eq!(ctx.get_option_int("g").unwrap(), 12);
+        ctx.set_option_image_size("video_size", 64, 48).unwrap();
+        assert_eq!(ctx.get_option_image_size("video_size").unwrap(), (64, 48));
+        ctx.set_option_pix_fmt("pixel_format", PixelFormat::Yuv420p).unwrap();
+        assert_eq!(ctx.get_option("pixel_format").unwrap(), "yuv420p");
+        ctx.set_option_rational("time_base", Rational::new(1, 25)).unwrap();
+        assert_eq!(ctx.get_option_rational("time_base").unwrap(), Rational::new(1, 25));
+        ctx.set_option_double("qcomp", 0.7).unwrap();
+        assert!((ctx.get_option_double("qcomp").unwrap() - 0.7).abs() < 1e-6);
+        // private options are found through the children of the context
+        ctx.set_option_int("data_partitioning", 1).unwrap();
+        assert_eq!(ctx.set_option("no_such_option", "1").unwrap_err(), AvError::OptionNotFound);
+
+        let mut dict = AVDictionary::new();
+        dict.set("no_such_option", "1", 0).unwrap();
+        dict.set("bf", "0", 0).unwrap();
+        ctx.open2(&codec, Some(&mut dict)).unwrap();
+        let unconsumed = AvError::UnconsumedOptions(vec![String::from("no_such_option")]);
+        assert_eq!(check_consumed(&dict).unwrap_err(), unconsumed);
+        assert!(ctx.priv_options().iter().any(|option| option.name == "data_partitioning"));
+
+        // the opened codec has allocated its state for the current size
+        assert!(ctx.is_in_use());
+        assert_eq!(ctx.set_option_int("g", 24).unwrap_err(), AvError::InvalidArgument);
+        assert_eq!(ctx.set_option_image_size("video_size", 128, 96).unwrap_err(), AvError::InvalidArgument);
+        assert_eq!(ctx.get_option_image_size("video_size").unwrap(), (64, 48));
+
+        let err = VideoEncoder::builder(AVCodec::find_encoder(CodecId::Mpeg4).unwrap()).size(64, 48)
+            .frame_rate(Rational::new(25, 1)).option("no_such_option", "1").build().err().unwrap();
+        assert_eq!(err, unconsumed);
+    }
+
+    #[cfg(feature = "avfilter")]
+    #[test]
+    fn buffersink_pix_fmts() {
+        let mut graph = AVFilterGraph::new();
+        let buffer_src = AVFilter::get_by_name("buffer").unwrap();
+        let buffer_sink = AVFilter::get_by_name("buffersink").unwrap();
+        let args = format!("video_size=64x48:pix_fmt={}:time_base=1/25", AVPixelFormat::from(PixelFormat::Yuv420p));
+        let src = graph.create_filter(&buffer_src, Some("in"), Some(args.as_str()), null_mut()).unwrap();
+        let mut sink = graph.create_filter(&buffer_sink, Some("out"), None, null_mut()).unwrap();
+
+        let pix_fmts = [PixelFormat::Gray8, PixelFormat::Rgb24];
+        sink.set_option_int_list("pix_fmts", &pix_fmts).unwrap();
+        // binary options read back as the hex of their bytes
+        let expected: String = pix_fmts.iter()
+            .flat_map(|pix_fmt| AVPixelFormat::from(*pix_fmt).to_ne_bytes())
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        assert_eq!(sink.get_option("pix_fmts").unwrap(), expected);
+        assert_eq!(sink.get_option_int_list::<PixelFormat>("pix_fmts").unwrap(), pix_fmts);
+        assert_eq!(sink.get_option_int_list::<i32>("enable").unwrap_err(), AvError::InvalidArgument);
+
+        unsafe {
+            let ret = avcodec::avfilter_link(src.internal, 0, sink.internal, 0);
+            assert!(ret >= 0);
+        }
+        assert!(!sink.is_in_use());
+        graph.config().unwrap();
+        assert!(sink.is_in_use());
+        assert_eq!(sink.set_option_int_list("pix_fmts", &pix_fmts).unwrap_err(), AvError::InvalidArgument);
+        let format = unsafe { avcodec::av_buffersink_get_format(sink.internal) };
+        // the inserted scaler converts to the listed format losing the least of yuv420p
+        assert_eq!(PixelFormat::from(format as AVPixelFormat), PixelFormat::Rgb24);
+    }
+}
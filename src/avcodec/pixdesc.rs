@@ -0,0 +1,305 @@
+/// Description of a pixel format (AVPixFmtDescriptor). Descriptors are static tables inside libavutil.
+#[derive(Clone, Copy)]
+pub struct PixFmtDescriptor {
+    internal: *const avcodec::AVPixFmtDescriptor,
+}
+
+unsafe impl Send for PixFmtDescriptor {}
+
+unsafe impl Sync for PixFmtDescriptor {}
+
+/// Layout of one component (e.g. luma, red or alpha) of a pixel format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentDescriptor {
+    /// Plane the component is stored in.
+    pub plane: i32,
+    /// Bytes (bits for bitstream formats) between horizontally adjacent pixels.
+    pub step: i32,
+    /// Bytes (bits for bitstream formats) before the first pixel's component.
+    pub offset: i32,
+    /// Bits to shift the value right to get the component.
+    pub shift: i32,
+    /// Bits per component.
+    pub depth: i32,
+}
+
+impl PixelFormat {
+    /// Descriptor of the format, None for `PixelFormat::None` and values unknown to the linked ffmpeg.
+    pub fn descriptor(&self) -> Option<PixFmtDescriptor> {
+        unsafe {
+            let internal = avcodec::av_pix_fmt_desc_get(AVPixelFormat::from(*self));
+            if internal.is_null() {
+                return None;
+            }
+            Some(PixFmtDescriptor { internal })
+        }
+    }
+}
+
+impl PixFmtDescriptor {
+    pub fn get_internal(&self) -> &avcodec::AVPixFmtDescriptor {
+        unsafe { &*self.internal }
+    }
+    pub fn pix_fmt(&self) -> PixelFormat {
+        unsafe { PixelFormat::from(avcodec::av_pix_fmt_desc_get_id(self.internal)) }
+    }
+    pub fn name(&self) -> &'static str {
+        unsafe { get_str_or_default((*self.internal).name, EMPTY_STR) }
+    }
+    pub fn nb_components(&self) -> usize {
+        self.get_internal().nb_components as usize
+    }
+    pub fn components(&self) -> Vec<ComponentDescriptor> {
+        let raw = self.get_internal();
+        raw.comp[..raw.nb_components as usize].iter().map(|comp| ComponentDescriptor {
+            plane: comp.plane,
+            step: comp.step,
+            offset: comp.offset,
+            shift: comp.shift,
+            depth: comp.depth,
+        }).collect()
+    }
+    /// Number of planes the image data is split into.
+    pub fn plane_count(&self) -> usize {
+        unsafe { avcodec::av_pix_fmt_count_planes(AVPixelFormat::from(self.pix_fmt())).max(0) as usize }
+    }
+    /// Amount to shift the luma width right to get the chroma width.
+    pub fn log2_chroma_w(&self) -> u8 {
+        self.get_internal().log2_chroma_w
+    }
+    /// Amount to shift the luma height right to get the chroma height.
+    pub fn log2_chroma_h(&self) -> u8 {
+        self.get_internal().log2_chroma_h
+    }
+    /// Bits per pixel used by the pixel format, ignoring padding.
+    pub fn bits_per_pixel(&self) -> i32 {
+        unsafe { avcodec::av_get_bits_per_pixel(self.internal) }
+    }
+    /// Bits per pixel including padding bits.
+    pub fn padded_bits_per_pixel(&self) -> i32 {
+        unsafe { avcodec::av_get_padded_bits_per_pixel(self.internal) }
+    }
+    /// Raw AV_PIX_FMT_FLAG_* combination.
+    pub fn flags(&self) -> u64 {
+        self.get_internal().flags
+    }
+
+    fn has_flag(&self, flag: u32) -> bool {
+        self.flags() & flag as u64 != 0
+    }
+    pub fn is_big_endian(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_BE)
+    }
+    pub fn is_palette(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_PAL)
+    }
+    /// Components are packed bit-wise, e.g. monowhite.
+    pub fn is_bitstream(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_BITSTREAM)
+    }
+    /// The format is an opaque handle to hardware surfaces.
+    pub fn is_hwaccel(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_HWACCEL)
+    }
+    pub fn is_planar(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_PLANAR)
+    }
+    pub fn is_rgb(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_RGB)
+    }
+    pub fn has_alpha(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_ALPHA)
+    }
+    pub fn is_float(&self) -> bool {
+        self.has_flag(avcodec::AV_PIX_FMT_FLAG_FLOAT)
+    }
+}
+
+impl fmt::Debug for PixFmtDescriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PixFmtDescriptor")
+            .field("name", &self.name())
+            .field("components", &self.components())
+            .field("log2_chroma_w", &self.log2_chroma_w())
+            .field("log2_chroma_h", &self.log2_chroma_h())
+            .field("flags", &self.flags())
+            .finish()
+    }
+}
+
+/// Plane linesizes, byte offsets and sizes of an image stored contiguously in one buffer, see `image_fill_arrays`.
+/// Palette formats like PAL8 have the 256 entry, 1024 byte palette as plane 1, with a linesize of 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageLayout {
+    pub linesize: [i32; 4],
+    pub offset: [usize; 4],
+    pub plane_size: [usize; 4],
+    /// Size of the whole image, the minimal size of the buffer.
+    pub size: usize,
+}
+
+impl ImageLayout {
+    /// Splits a buffer holding an image with this layout into its planes. Fails with InvalidArgument when the
+    /// buffer is shorter than `size`.
+    pub fn planes<'a>(&self, buffer: &'a [u8]) -> Result<Vec<&'a [u8]>, AvError> {
+        if buffer.len() < self.size {
+            return Err(AvError::InvalidArgument);
+        }
+        let mut planes = Vec::new();
+        for idx in 0..4 {
+            if self.plane_size[idx] == 0 {
+                break;
+            }
+            planes.push(&buffer[self.offset[idx]..self.offset[idx] + self.plane_size[idx]]);
+        }
+        return Ok(planes);
+    }
+}
+
+/// Size in bytes of a buffer holding an image with the given format and dimensions, rows aligned to `align`.
+pub fn image_get_buffer_size(pix_fmt: PixelFormat, width: i32, height: i32, align: i32) -> Result<usize, AvError> {
+    unsafe {
+        let ret = avcodec::av_image_get_buffer_size(AVPixelFormat::from(pix_fmt), width, height, align);
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        return Ok(ret as usize);
+    }
+}
+
+/// Computes where the planes of an image start in a contiguous buffer and their linesizes, as av_image_fill_arrays
+/// lays them out.
+pub fn image_fill_arrays(pix_fmt: PixelFormat, width: i32, height: i32, align: i32) -> Result<ImageLayout, AvError> {
+    // av_image_fill_arrays only reports offsets through pointer arithmetic on the buffer, which is not allowed on a
+    // null buffer, so the planes are summed up from their sizes instead
+    if align <= 0 || align & (align - 1) != 0 {
+        return Err(AvError::InvalidArgument);
+    }
+    unsafe {
+        let ret = avcodec::av_image_check_size(width as u32, height as u32, 0, null_mut());
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        let mut linesize = [0i32; 4];
+        let ret = avcodec::av_image_fill_linesizes(linesize.as_mut_ptr(), AVPixelFormat::from(pix_fmt), width);
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        for line in linesize.iter_mut() {
+            *line = (*line + align - 1) & !(align - 1);
+        }
+        let aligned = linesize.map(|line| line as isize);
+        let mut sizes: [avcodec::size_t; 4] = [0; 4];
+        let ret = avcodec::av_image_fill_plane_sizes(sizes.as_mut_ptr(), AVPixelFormat::from(pix_fmt), height, aligned.as_ptr());
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        let mut layout = ImageLayout { linesize, offset: [0; 4], plane_size: [0; 4], size: 0 };
+        for (idx, size) in sizes.iter().enumerate() {
+            layout.offset[idx] = layout.size;
+            layout.plane_size[idx] = *size as usize;
+            layout.size += *size as usize;
+        }
+        return Ok(layout);
+    }
+}
+
+/// Copies an image stored contiguously in `buffer` with rows aligned to `align` into a writable frame, using the
+/// frame's format and dimensions (av_image_copy).
+pub fn image_copy(frame: &mut AVFrame, buffer: &[u8], align: i32) -> Result<(), AvError> {
+    let layout = image_fill_arrays(frame.pix_fmt(), frame.width(), frame.height(), align)?;
+    if buffer.len() < layout.size || frame.plane_count() == 0 || !frame.is_writable() {
+        return Err(AvError::InvalidArgument);
+    }
+    unsafe {
+        let mut src_data: [*const u8; 4] = [null(); 4];
+        for (idx, data) in src_data.iter_mut().enumerate() {
+            if layout.plane_size[idx] != 0 {
+                *data = buffer.as_ptr().add(layout.offset[idx]);
+            }
+        }
+        let raw = frame.get_internal_mut();
+        avcodec::av_image_copy(raw.data.as_mut_ptr(), raw.linesize.as_mut_ptr(), src_data.as_mut_ptr() as _,
+                               layout.linesize.as_ptr(), raw.format, raw.width, raw.height);
+    }
+    return Ok(());
+}
+
+/// Copies the planes of a frame into `buffer` contiguously with rows aligned to `align`, returning the bytes written
+/// (av_image_copy_to_buffer).
+pub fn image_copy_to_buffer(frame: &AVFrame, buffer: &mut [u8], align: i32) -> Result<usize, AvError> {
+    if frame.plane_count() == 0 {
+        return Err(AvError::InvalidArgument);
+    }
+    unsafe {
+        let raw = frame.get_internal();
+        let ret = avcodec::av_image_copy_to_buffer(buffer.as_mut_ptr(), buffer.len().min(i32::MAX as usize) as i32,
+                                                   raw.data.as_ptr() as _, raw.linesize.as_ptr(), raw.format,
+                                                   raw.width, raw.height, align);
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        return Ok(ret as usize);
+    }
+}
+
+#[cfg(test)]
+mod test_pixdesc {
+    use super::*;
+
+    #[test]
+    fn pix_fmt_descriptor() {
+        let desc = PixelFormat::Yuv420p.descriptor().unwrap();
+        assert_eq!(desc.name(), "yuv420p");
+        assert_eq!(desc.nb_components(), 3);
+        assert_eq!(desc.plane_count(), 3);
+        assert_eq!((desc.log2_chroma_w(), desc.log2_chroma_h()), (1, 1));
+        assert!(desc.is_planar() && !desc.is_rgb() && !desc.has_alpha());
+        assert_eq!(desc.components()[2], ComponentDescriptor { plane: 2, step: 1, offset: 0, shift: 0, depth: 8 });
+
+        let desc = PixelFormat::Bgra.descriptor().unwrap();
+        assert!(desc.is_rgb() && desc.has_alpha() && !desc.is_planar());
+        assert_eq!(desc.bits_per_pixel(), 32);
+        assert!(PixelFormat::Cuda.descriptor().unwrap().is_hwaccel());
+        assert!(PixelFormat::None.descriptor().is_none());
+    }
+
+    #[test]
+    fn image_buffers() {
+        let layout = image_fill_arrays(PixelFormat::Yuv420p, 4, 2, 1).unwrap();
+        assert_eq!(layout.linesize, [4, 2, 2, 0]);
+        assert_eq!(layout.offset[..3], [0, 8, 10]);
+        assert_eq!(layout.size, image_get_buffer_size(PixelFormat::Yuv420p, 4, 2, 1).unwrap());
+
+        let buffer: Vec<u8> = (0..layout.size as u8).collect();
+        assert_eq!(layout.planes(&buffer).unwrap()[1], &[8, 9]);
+        assert_eq!(layout.planes(&buffer[..layout.size - 1]), Err(AvError::InvalidArgument));
+        let mut frame = AVFrame::new_video(4, 2, PixelFormat::Yuv420p, 0).unwrap();
+        image_copy(&mut frame, &buffer, 1).unwrap();
+        assert_eq!(frame.rows(0).unwrap().nth(1).unwrap(), &[4, 5, 6, 7]);
+        let mut out = vec![0u8; layout.size];
+        assert_eq!(image_copy_to_buffer(&frame, &mut out, 1).unwrap(), layout.size);
+        assert_eq!(out, buffer);
+    }
+
+    #[test]
+    fn image_buffers_with_palette() {
+        let layout = image_fill_arrays(PixelFormat::Pal8, 4, 2, 1).unwrap();
+        assert_eq!(layout.linesize, [4, 0, 0, 0]);
+        assert_eq!(layout.offset[1], 8);
+        assert_eq!(layout.plane_size[..2], [8, 1024]);
+        assert_eq!(layout.size, image_get_buffer_size(PixelFormat::Pal8, 4, 2, 1).unwrap());
+
+        let buffer: Vec<u8> = (0..layout.size).map(|i| i as u8).collect();
+        let planes = layout.planes(&buffer).unwrap();
+        assert_eq!(planes.len(), 2);
+        assert_eq!(planes[1], &buffer[8..]);
+        let mut frame = AVFrame::new_video(4, 2, PixelFormat::Pal8, 0).unwrap();
+        image_copy(&mut frame, &buffer, 1).unwrap();
+        let palette = unsafe { std::slice::from_raw_parts(frame.get_internal().data[1], 1024) };
+        assert_eq!(palette, &buffer[8..]);
+        let mut out = vec![0u8; layout.size];
+        assert_eq!(image_copy_to_buffer(&frame, &mut out, 1).unwrap(), layout.size);
+        assert_eq!(out, buffer);
+    }
+}
@@ -0,0 +1,210 @@
+// "" for the file name arguments when there is no file
+const EMPTY_CSTR: &[u8] = b"\0";
+
+/// Demuxer description (AVInputFormat), see `AVInputFormat::iterator` and `AVInputFormat::probe`.
+pub struct AVInputFormat {
+    pub int_format: *const avcodec::AVInputFormat,
+    /// Comma separated short names, e.g. "mov,mp4,m4a,3gp,3g2,mj2".
+    pub name: &'static str,
+    pub long_name: &'static str,
+    /// Comma separated file extensions, may be empty.
+    pub extensions: &'static str,
+    /// Comma separated MIME types, may be empty.
+    pub mime_type: &'static str,
+}
+
+impl From<*const avcodec::AVInputFormat> for AVInputFormat {
+    fn from(int_format: *const avcodec::AVInputFormat) -> Self {
+        unsafe {
+            Self {
+                int_format,
+                name: get_str_or_default((*int_format).name, EMPTY_STR),
+                long_name: get_str_or_default((*int_format).long_name, EMPTY_STR),
+                extensions: get_str_or_default((*int_format).extensions, EMPTY_STR),
+                mime_type: get_str_or_default((*int_format).mime_type, EMPTY_STR),
+            }
+        }
+    }
+}
+
+// formats are static, immutable descriptions
+unsafe impl Send for AVInputFormat {}
+
+unsafe impl Sync for AVInputFormat {}
+
+impl AVInputFormat {
+    pub fn iterator() -> AVInputFormatIter {
+        AVInputFormatIter { opaque: null_mut() }
+    }
+    pub fn find(short_name: &str) -> Option<Self> {
+        let c_name = CString::new(short_name).ok()?;
+        unsafe {
+            let format = avcodec::av_find_input_format(c_name.as_ptr());
+            if format.is_null() {
+                return None;
+            }
+            Some(AVInputFormat::from(format as *const avcodec::AVInputFormat))
+        }
+    }
+    /// Guesses the container of `data`, usually the first few KiB of a file, and returns it with its score between
+    /// 1 and AVPROBE_SCORE_MAX (100). `filename` lets the extension count as a hint. None when nothing matched.
+    pub fn probe(data: &[u8], filename: Option<&str>) -> Option<(Self, i32)> {
+        let c_filename = match filename {
+            Some(filename) => Some(CString::new(filename).ok()?),
+            None => None,
+        };
+        // probe functions may read up to AVPROBE_PADDING_SIZE bytes past the end, which have to be zero
+        let mut buf = Vec::with_capacity(data.len() + avcodec::AVPROBE_PADDING_SIZE as usize);
+        buf.extend_from_slice(data);
+        buf.resize(data.len() + avcodec::AVPROBE_PADDING_SIZE as usize, 0);
+        unsafe {
+            let mut probe_data: avcodec::AVProbeData = std::mem::zeroed();
+            probe_data.filename = c_filename.as_ref().map_or(EMPTY_CSTR.as_ptr() as *const c_char, |name| name.as_ptr());
+            probe_data.buf = buf.as_mut_ptr();
+            probe_data.buf_size = data.len() as c_int;
+            let mut score = 0;
+            let format = avcodec::av_probe_input_format3(&mut probe_data, 1, &mut score);
+            if format.is_null() {
+                return None;
+            }
+            Some((AVInputFormat::from(format as *const avcodec::AVInputFormat), score))
+        }
+    }
+    /// Probes the container from custom I/O, reading at most `max_probe_size` bytes (0 for the default). The probed
+    /// data stays buffered, the context can be passed to `AVFormatInput::open_io` afterwards.
+    pub fn probe_io(io: &mut AVIOContext, max_probe_size: u32) -> Result<(Self, i32), AvError> {
+        unsafe {
+            // the out pointer is `AVInputFormat **` before 5.0 and `const AVInputFormat **` since
+            let mut format = std::mem::zeroed();
+            // av_probe_input_buffer2 returns the score, av_probe_input_buffer only 0
+            let ret = avcodec::av_probe_input_buffer2(io.as_mut_ptr(), &mut format, EMPTY_CSTR.as_ptr() as *const c_char,
+                                                      null_mut(), 0, max_probe_size);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            Ok((AVInputFormat::from(format as *const avcodec::AVInputFormat), ret))
+        }
+    }
+}
+
+pub struct AVInputFormatIter {
+    opaque: *mut c_void,
+}
+
+impl Iterator for AVInputFormatIter {
+    type Item = AVInputFormat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let format = avcodec::av_demuxer_iterate(&mut self.opaque);
+            if format.is_null() {
+                return None;
+            }
+            return Some(AVInputFormat::from(format));
+        }
+    }
+}
+
+/// Muxer description (AVOutputFormat), see `AVOutputFormat::iterator` and `AVOutputFormat::guess`.
+pub struct AVOutputFormat {
+    pub int_format: *const avcodec::AVOutputFormat,
+    pub name: &'static str,
+    pub long_name: &'static str,
+    /// Comma separated file extensions, may be empty.
+    pub extensions: &'static str,
+    pub mime_type: &'static str,
+    /// Codecs the muxer uses when none is chosen.
+    pub video_codec: CodecId,
+    pub audio_codec: CodecId,
+    pub subtitle_codec: CodecId,
+}
+
+impl From<*const avcodec::AVOutputFormat> for AVOutputFormat {
+    fn from(int_format: *const avcodec::AVOutputFormat) -> Self {
+        unsafe {
+            Self {
+                int_format,
+                name: get_str_or_default((*int_format).name, EMPTY_STR),
+                long_name: get_str_or_default((*int_format).long_name, EMPTY_STR),
+                extensions: get_str_or_default((*int_format).extensions, EMPTY_STR),
+                mime_type: get_str_or_default((*int_format).mime_type, EMPTY_STR),
+                video_codec: CodecId::from((*int_format).video_codec),
+                audio_codec: CodecId::from((*int_format).audio_codec),
+                subtitle_codec: CodecId::from((*int_format).subtitle_codec),
+            }
+        }
+    }
+}
+
+unsafe impl Send for AVOutputFormat {}
+
+unsafe impl Sync for AVOutputFormat {}
+
+impl AVOutputFormat {
+    pub fn iterator() -> AVOutputFormatIter {
+        AVOutputFormatIter { opaque: null_mut() }
+    }
+    /// Muxer matching the short name, or else the extension of `filename`, or else the MIME type.
+    pub fn guess(short_name: Option<&str>, filename: Option<&str>, mime_type: Option<&str>) -> Option<Self> {
+        let to_cstring = |s: Option<&str>| s.map(CString::new).transpose();
+        let c_name = to_cstring(short_name).ok()?;
+        let c_filename = to_cstring(filename).ok()?;
+        let c_mime_type = to_cstring(mime_type).ok()?;
+        unsafe {
+            let format = avcodec::av_guess_format(c_name.as_ref().map_or(null(), |s| s.as_ptr()),
+                                                  c_filename.as_ref().map_or(null(), |s| s.as_ptr()),
+                                                  c_mime_type.as_ref().map_or(null(), |s| s.as_ptr()));
+            if format.is_null() {
+                return None;
+            }
+            Some(AVOutputFormat::from(format as *const avcodec::AVOutputFormat))
+        }
+    }
+}
+
+pub struct AVOutputFormatIter {
+    opaque: *mut c_void,
+}
+
+impl Iterator for AVOutputFormatIter {
+    type Item = AVOutputFormat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let format = avcodec::av_muxer_iterate(&mut self.opaque);
+            if format.is_null() {
+                return None;
+            }
+            return Some(AVOutputFormat::from(format));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_probe {
+    use std::io::Cursor;
+
+    use super::*;
+    use super::test_demux::wav_bytes;
+
+    #[test]
+    fn probe_and_iterate_formats() {
+        let wav = wav_bytes(&[0i16; 256]);
+        let (format, score) = AVInputFormat::probe(&wav, None).unwrap();
+        assert_eq!(format.name, "wav");
+        assert!(score > 0 && score <= avcodec::AVPROBE_SCORE_MAX as i32);
+        assert!(AVInputFormat::probe(&[0u8; 16], None).is_none());
+
+        let mut io = AVIOContext::from_read(Cursor::new(wav)).unwrap();
+        assert_eq!(AVInputFormat::probe_io(&mut io, 0).unwrap().0.name, "wav");
+        let mut input = AVFormatInput::open_io(io, None).unwrap();
+        assert_eq!(input.packets().map(|pkt| pkt.unwrap().get_data().len()).sum::<usize>(), 512);
+
+        assert!(AVInputFormat::iterator().any(|format| format.name == "matroska,webm"));
+        assert_eq!(AVInputFormat::find("wav").unwrap().name, "wav");
+        let mp4 = AVOutputFormat::iterator().find(|format| format.name == "mp4").unwrap();
+        assert!(mp4.extensions.split(',').any(|ext| ext == "mp4"));
+        assert_eq!(AVOutputFormat::guess(None, Some("out.mkv"), None).unwrap().name, "matroska");
+        assert_eq!(AVOutputFormat::guess(Some("wav"), None, None).unwrap().audio_codec, CodecId::PcmS16le);
+    }
+}
@@ -0,0 +1,289 @@
+use std::cmp::Ordering;
+use std::ops::{Div, Mul};
+use std::time::Duration;
+
+/// A fraction like a time base or frame rate (AVRational).
+///
+/// Values are kept as given, `reduced` brings them to lowest terms. Comparisons are by value, so 1/2 == 2/4.
+/// 0/0 is the "unknown" rational ffmpeg uses for e.g. an unset frame rate, it compares unordered to everything.
+#[derive(Debug, Clone, Copy)]
+pub struct Rational {
+    pub num: i32,
+    pub den: i32,
+}
+
+impl Rational {
+    pub const fn new(num: i32, den: i32) -> Self {
+        Self { num, den }
+    }
+    /// Closest fraction to `value` whose numerator and denominator do not exceed `max` (av_d2q).
+    pub fn from_f64(value: f64, max: i32) -> Self {
+        Rational::from(unsafe { avcodec::av_d2q(value, max) })
+    }
+    /// The fraction in lowest terms with a positive denominator, approximated when it does not fit in i32.
+    pub fn reduced(&self) -> Self {
+        let (mut num, mut den) = (0, 0);
+        unsafe { avcodec::av_reduce(&mut num, &mut den, self.num as i64, self.den as i64, i32::MAX as i64) };
+        Self { num, den }
+    }
+    /// 1 / self.
+    pub fn invert(&self) -> Self {
+        Self { num: self.den, den: self.num }
+    }
+    /// False for fractions with a zero denominator, e.g. the unknown 0/0.
+    pub fn is_valid(&self) -> bool {
+        self.den != 0
+    }
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+    /// The fraction as seconds, e.g. the duration of one tick of a time base. None for negative or invalid values.
+    pub fn to_duration(&self) -> Option<Duration> {
+        if self.den == 0 || (self.num < 0) != (self.den < 0) {
+            return None;
+        }
+        let (num, den) = (self.num.unsigned_abs() as u128, self.den.unsigned_abs() as u128);
+        let nanos = (num * 1_000_000_000 + den / 2) / den;
+        Some(Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32))
+    }
+}
+
+impl From<AVRational> for Rational {
+    fn from(rational: AVRational) -> Self {
+        Self { num: rational.num, den: rational.den }
+    }
+}
+
+impl From<Rational> for AVRational {
+    fn from(rational: Rational) -> Self {
+        AVRational { num: rational.num, den: rational.den }
+    }
+}
+
+impl From<Rational> for f64 {
+    fn from(rational: Rational) -> Self {
+        rational.to_f64()
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Rational {
+    // same as the inline av_cmp_q, which bindgen does not generate
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let diff = self.num as i64 * other.den as i64 - other.num as i64 * self.den as i64;
+        if diff != 0 {
+            // the sign of the difference flips with each negative denominator
+            let negative = ((diff < 0) != (self.den < 0)) != (other.den < 0);
+            return Some(if negative { Ordering::Less } else { Ordering::Greater });
+        }
+        if self.den != 0 && other.den != 0 {
+            return Some(Ordering::Equal);
+        }
+        // +-x/0 are infinities, 0/0 is unordered
+        if self.num != 0 && other.num != 0 {
+            return Some(self.num.signum().cmp(&other.num.signum()));
+        }
+        None
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    /// The reduced product.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Rational::from(unsafe { avcodec::av_mul_q(self.into(), rhs.into()) })
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// The reduced quotient.
+    fn div(self, rhs: Self) -> Self::Output {
+        Rational::from(unsafe { avcodec::av_div_q(self.into(), rhs.into()) })
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+/// How rescaling rounds values that fall between two ticks of the target time base (AV_ROUND_*).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards zero.
+    Zero,
+    /// Away from zero.
+    Inf,
+    /// Towards -infinity.
+    Down,
+    /// Towards +infinity.
+    Up,
+    /// To the nearest tick, halfway cases away from zero.
+    NearInf,
+}
+
+impl Rounding {
+    fn av_rounding(&self) -> avcodec::AVRounding {
+        match *self {
+            Rounding::Zero => avcodec::AVRounding_AV_ROUND_ZERO,
+            Rounding::Inf => avcodec::AVRounding_AV_ROUND_INF,
+            Rounding::Down => avcodec::AVRounding_AV_ROUND_DOWN,
+            Rounding::Up => avcodec::AVRounding_AV_ROUND_UP,
+            Rounding::NearInf => avcodec::AVRounding_AV_ROUND_NEAR_INF,
+        }
+    }
+}
+
+/// A timestamp together with the time base its value counts in.
+///
+/// `value` may be AV_NOPTS_VALUE, such a timestamp stays unset through rescaling and is unordered against any
+/// other. Comparisons between timestamps of different time bases are exact (av_compare_ts).
+#[derive(Debug, Clone, Copy)]
+pub struct Timestamp {
+    pub value: i64,
+    pub time_base: Rational,
+}
+
+impl Timestamp {
+    pub fn new(value: i64, time_base: Rational) -> Self {
+        Self { value, time_base }
+    }
+    /// An unset timestamp.
+    pub fn none(time_base: Rational) -> Self {
+        Self { value: AV_NOPTS_VALUE, time_base }
+    }
+    /// Timestamp of an `Option`, e.g. an optional pts, None becomes AV_NOPTS_VALUE.
+    pub fn from_option(value: Option<i64>, time_base: Rational) -> Self {
+        Self { value: value.unwrap_or(AV_NOPTS_VALUE), time_base }
+    }
+    /// `duration` in ticks of `time_base`, rounded to the nearest tick. Durations beyond i64 nanoseconds, about 292
+    /// years, are clamped.
+    pub fn from_duration(duration: Duration, time_base: Rational) -> Self {
+        let nanos = duration.as_nanos().min(i64::MAX as u128) as i64;
+        let value = unsafe { avcodec::av_rescale_q(nanos, AVRational { num: 1, den: 1_000_000_000 }, time_base.into()) };
+        Timestamp { value, time_base }
+    }
+    pub fn is_none(&self) -> bool {
+        self.value == AV_NOPTS_VALUE
+    }
+    pub fn value(&self) -> Option<i64> {
+        if self.is_none() {
+            return None;
+        }
+        Some(self.value)
+    }
+    /// The same instant in `time_base`, rounded to the nearest tick.
+    pub fn rescale(&self, time_base: Rational) -> Timestamp {
+        self.rescale_rnd(time_base, Rounding::NearInf)
+    }
+    /// The same instant in `time_base` with the given rounding. Unset timestamps stay unset.
+    pub fn rescale_rnd(&self, time_base: Rational, rounding: Rounding) -> Timestamp {
+        // AV_ROUND_PASS_MINMAX passes INT64_MIN, i.e. AV_NOPTS_VALUE, and INT64_MAX through unchanged
+        let rnd = rounding.av_rounding() | avcodec::AVRounding_AV_ROUND_PASS_MINMAX;
+        let value = unsafe { avcodec::av_rescale_q_rnd(self.value, self.time_base.into(), time_base.into(), rnd) };
+        Timestamp { value, time_base }
+    }
+    /// Seconds since zero, None when unset.
+    pub fn to_f64(&self) -> Option<f64> {
+        self.value().map(|value| value as f64 * self.time_base.to_f64())
+    }
+    /// Time since zero, None when unset or negative.
+    pub fn to_duration(&self) -> Option<Duration> {
+        let value = self.value()?;
+        if value < 0 {
+            return None;
+        }
+        let micros = self.rescale(Rational::new(1, 1_000_000)).value;
+        Some(Duration::from_micros(micros as u64))
+    }
+    /// Timestamp `ticks` ticks later, unset timestamps stay unset.
+    pub fn offset(&self, ticks: i64) -> Timestamp {
+        match self.value() {
+            Some(value) => Timestamp::new(value + ticks, self.time_base),
+            None => *self,
+        }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_none() || other.is_none() {
+            return None;
+        }
+        let ret = unsafe { avcodec::av_compare_ts(self.value, self.time_base.into(), other.value, other.time_base.into()) };
+        Some(ret.cmp(&0))
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.value() {
+            Some(value) => write!(f, "{}*{}", value, self.time_base),
+            None => write!(f, "NOPTS"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_rational {
+    use super::*;
+
+    #[test]
+    fn rational_arithmetic() {
+        let ntsc = Rational::new(30000, 1001);
+        assert_eq!(Rational::new(60000, 2002).reduced(), ntsc);
+        assert_eq!(Rational::new(2, -4).reduced().den, 2);
+        assert_eq!(Rational::new(1, 2), Rational::new(2, 4));
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(1, -3) < Rational::new(0, 1));
+        assert!(Rational::new(0, 0).partial_cmp(&Rational::new(1, 2)).is_none());
+        assert_eq!(ntsc * Rational::new(1001, 1000), Rational::new(30, 1));
+        assert_eq!(ntsc / Rational::new(2, 1), Rational::new(15000, 1001));
+        assert!((ntsc.to_f64() - 29.97).abs() < 0.001);
+        assert_eq!(Rational::new(1, 25).to_duration(), Some(Duration::from_millis(40)));
+        assert_eq!(Rational::from_f64(0.5, 100), Rational::new(1, 2));
+        assert_eq!(ntsc.invert().to_string(), "1001/30000");
+    }
+
+    #[test]
+    fn timestamps() {
+        let ms = Rational::new(1, 1000);
+        let ts90k = Rational::new(1, 90000);
+        let ts = Timestamp::new(1001, ms);
+        assert_eq!(ts.rescale(ts90k).value, 90090);
+        assert_eq!(Timestamp::new(1, ts90k).rescale_rnd(ms, Rounding::Up).value, 1);
+        assert_eq!(Timestamp::new(1, ts90k).rescale_rnd(ms, Rounding::Down).value, 0);
+        assert_eq!(Timestamp::new(-1, ts90k).rescale_rnd(ms, Rounding::Down).value, -1);
+
+        assert_eq!(ts, Timestamp::new(90090, ts90k));
+        assert!(ts < Timestamp::new(90091, ts90k));
+        assert!(Timestamp::new(2, Rational::new(1, 25)) > Timestamp::new(79, ms));
+
+        let none = Timestamp::none(ms);
+        assert!(none.rescale(ts90k).is_none());
+        assert!(none.partial_cmp(&ts).is_none());
+        assert!(none != Timestamp::none(ms));
+        assert_eq!(none.offset(5).value(), None);
+
+        assert_eq!(ts.to_duration(), Some(Duration::from_millis(1001)));
+        assert_eq!(Timestamp::from_duration(Duration::from_millis(40), Rational::new(1, 25)).value, 1);
+        // sub-microsecond durations keep their precision
+        assert_eq!(Timestamp::from_duration(Duration::from_nanos(1_500), Rational::new(1, 1_000_000_000)).value, 1_500);
+        assert_eq!(ts.offset(-1).to_string(), "1000*1/1000");
+    }
+}
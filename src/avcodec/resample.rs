@@ -0,0 +1,274 @@
+/// Dithering applied when reducing the sample precision (SWR_DITHER_*).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DitherMethod {
+    None,
+    Rectangular,
+    Triangular,
+    TriangularHighpass,
+    /// Noise shaping methods, only effective for 44.1 and 48 kHz.
+    Lipshitz,
+    Shibata,
+    LowShibata,
+    HighShibata,
+    FWeighted,
+    EWeighted,
+    ImprovedEWeighted,
+}
+
+impl DitherMethod {
+    fn swr_dither(&self) -> i64 {
+        let dither = match *self {
+            DitherMethod::None => avcodec::SwrDitherType_SWR_DITHER_NONE,
+            DitherMethod::Rectangular => avcodec::SwrDitherType_SWR_DITHER_RECTANGULAR,
+            DitherMethod::Triangular => avcodec::SwrDitherType_SWR_DITHER_TRIANGULAR,
+            DitherMethod::TriangularHighpass => avcodec::SwrDitherType_SWR_DITHER_TRIANGULAR_HIGHPASS,
+            DitherMethod::Lipshitz => avcodec::SwrDitherType_SWR_DITHER_NS_LIPSHITZ,
+            DitherMethod::Shibata => avcodec::SwrDitherType_SWR_DITHER_NS_SHIBATA,
+            DitherMethod::LowShibata => avcodec::SwrDitherType_SWR_DITHER_NS_LOW_SHIBATA,
+            DitherMethod::HighShibata => avcodec::SwrDitherType_SWR_DITHER_NS_HIGH_SHIBATA,
+            DitherMethod::FWeighted => avcodec::SwrDitherType_SWR_DITHER_NS_F_WEIGHTED,
+            DitherMethod::EWeighted => avcodec::SwrDitherType_SWR_DITHER_NS_MODIFIED_E_WEIGHTED,
+            DitherMethod::ImprovedEWeighted => avcodec::SwrDitherType_SWR_DITHER_NS_IMPROVED_E_WEIGHTED,
+        };
+        dither as i64
+    }
+}
+
+/// Converts audio frames between sample rates, sample formats and channel layouts (SwrContext).
+///
+/// Input frames have to match the input parameters. Their pts, when set, are taken to be in 1/input rate units and
+/// output pts are in 1/output rate units. The resampler buffers some samples, `flush` returns them at the end of the
+/// stream.
+pub struct Resampler {
+    internal: *mut avcodec::SwrContext,
+    out_format: SampleFormat,
+    out_channel_layout: ChannelLayout,
+    out_sample_rate: i32,
+    in_sample_rate: i32,
+    // options are applied by swr_init, which runs before the next conversion
+    initialized: bool,
+    next_pts: Option<i64>,
+}
+
+impl Drop for Resampler {
+    fn drop(&mut self) {
+        unsafe {
+            avcodec::swr_free(&mut self.internal);
+        }
+    }
+}
+
+// the context is only used through &mut self
+unsafe impl Send for Resampler {}
+
+impl Resampler {
+    pub fn new(in_format: SampleFormat, in_channel_layout: ChannelLayout, in_sample_rate: i32,
+               out_format: SampleFormat, out_channel_layout: ChannelLayout, out_sample_rate: i32) -> Result<Self, AvError> {
+        let internal = unsafe {
+            Self::alloc(in_format, in_channel_layout, in_sample_rate, out_format, out_channel_layout, out_sample_rate)?
+        };
+        Ok(Self {
+            internal,
+            out_format,
+            out_channel_layout,
+            out_sample_rate,
+            in_sample_rate,
+            initialized: false,
+            next_pts: None,
+        })
+    }
+
+    #[cfg(not(ffmpeg_5_1))]
+    unsafe fn alloc(in_format: SampleFormat, in_channel_layout: ChannelLayout, in_sample_rate: i32,
+                    out_format: SampleFormat, out_channel_layout: ChannelLayout, out_sample_rate: i32) -> Result<*mut avcodec::SwrContext, AvError> {
+        let internal = avcodec::swr_alloc_set_opts(null_mut(), out_channel_layout.mask() as i64, AVSampleFormat::from(out_format),
+                                                   out_sample_rate, in_channel_layout.mask() as i64,
+                                                   AVSampleFormat::from(in_format), in_sample_rate, 0, null_mut());
+        if internal.is_null() {
+            return Err(AvError::NoMemory);
+        }
+        Ok(internal)
+    }
+
+    #[cfg(ffmpeg_5_1)]
+    unsafe fn alloc(in_format: SampleFormat, in_channel_layout: ChannelLayout, in_sample_rate: i32,
+                    out_format: SampleFormat, out_channel_layout: ChannelLayout, out_sample_rate: i32) -> Result<*mut avcodec::SwrContext, AvError> {
+        let mut in_layout = std::mem::zeroed();
+        let mut out_layout = std::mem::zeroed();
+        avcodec::av_channel_layout_from_mask(&mut in_layout, in_channel_layout.mask());
+        avcodec::av_channel_layout_from_mask(&mut out_layout, out_channel_layout.mask());
+        let mut internal = null_mut();
+        let ret = avcodec::swr_alloc_set_opts2(&mut internal, &out_layout, AVSampleFormat::from(out_format),
+                                               out_sample_rate, &in_layout, AVSampleFormat::from(in_format),
+                                               in_sample_rate, 0, null_mut());
+        avcodec::av_channel_layout_uninit(&mut in_layout);
+        avcodec::av_channel_layout_uninit(&mut out_layout);
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        Ok(internal)
+    }
+
+    fn set_option_int(&mut self, name: &[u8], value: i64) -> Result<(), AvError> {
+        unsafe {
+            let ret = avcodec::av_opt_set_int(self.internal as *mut c_void, name.as_ptr() as *const c_char, value, 0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        self.initialized = false;
+        Ok(())
+    }
+
+    /// Enables dithering for conversions to lower precision formats, `scale` 1.0 is the default strength.
+    /// Must be set before the first conversion.
+    pub fn set_dither(&mut self, method: DitherMethod, scale: f64) -> Result<(), AvError> {
+        self.set_option_int(b"dither_method\0", method.swr_dither())?;
+        unsafe {
+            let ret = avcodec::av_opt_set_double(self.internal as *mut c_void, b"dither_scale\0".as_ptr() as *const c_char, scale, 0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(())
+    }
+
+    fn init(&mut self) -> Result<(), AvError> {
+        if self.initialized {
+            return Ok(());
+        }
+        unsafe {
+            let ret = avcodec::swr_init(self.internal);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        self.initialized = true;
+        Ok(())
+    }
+
+    /// Stretches or squeezes the output by `sample_delta` samples over the next `compensation_distance` output
+    /// samples to follow a drifting clock, e.g. a capture device running slightly faster than the encoder clock.
+    pub fn set_compensation(&mut self, sample_delta: i32, compensation_distance: i32) -> Result<(), AvError> {
+        self.init()?;
+        unsafe {
+            let ret = avcodec::swr_set_compensation(self.internal, sample_delta, compensation_distance);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        Ok(())
+    }
+
+    /// Samples buffered in the resampler, in output samples. Once flushed, swresample keeps counting the padding it
+    /// appended to drain its filter, so this stays above 0 while `flush` returns None.
+    pub fn delay(&self) -> i64 {
+        if !self.initialized {
+            return 0;
+        }
+        unsafe { avcodec::swr_get_delay(self.internal, self.out_sample_rate as i64) }
+    }
+
+    fn output_frame(&self) -> Result<AVFrame, AvError> {
+        let mut output = AVFrame::new();
+        output.set_sample_rate(self.out_sample_rate);
+        output.set_channel_layout(self.out_channel_layout)?;
+        unsafe { output.get_internal_mut().format = AVSampleFormat::from(self.out_format) };
+        return Ok(output);
+    }
+
+    /// Converts `input` into a new frame, which may hold fewer or more samples as the resampler buffers some.
+    pub fn convert(&mut self, input: &AVFrame) -> Result<AVFrame, AvError> {
+        self.init()?;
+        let mut output = self.output_frame()?;
+        unsafe {
+            if input.pts() != AV_NOPTS_VALUE {
+                // swr_next_pts works in 1/(in_rate * out_rate) units
+                let next = avcodec::swr_next_pts(self.internal, input.pts() * self.out_sample_rate as i64);
+                let in_rate = self.in_sample_rate as i64;
+                self.next_pts = Some((next + in_rate / 2).div_euclid(in_rate));
+            }
+            let ret = avcodec::swr_convert_frame(self.internal, output.as_mut_ptr(), input.as_ptr());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        self.set_output_pts(&mut output);
+        return Ok(output);
+    }
+
+    /// Returns the samples still buffered at the end of the stream, None when there are none.
+    pub fn flush(&mut self) -> Result<Option<AVFrame>, AvError> {
+        if !self.initialized || self.delay() == 0 {
+            return Ok(None);
+        }
+        let mut output = self.output_frame()?;
+        unsafe {
+            let ret = avcodec::swr_convert_frame(self.internal, output.as_mut_ptr(), null());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        if output.nb_samples() == 0 {
+            return Ok(None);
+        }
+        self.set_output_pts(&mut output);
+        return Ok(Some(output));
+    }
+
+    fn set_output_pts(&mut self, output: &mut AVFrame) {
+        match self.next_pts {
+            Some(pts) => {
+                output.set_pts(pts);
+                self.next_pts = Some(pts + output.nb_samples() as i64);
+            }
+            None => output.set_pts(AV_NOPTS_VALUE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_resample {
+    use super::*;
+
+    #[test]
+    fn resample_44100_stereo_to_48000_mono() {
+        let stereo = ChannelLayout::STEREO;
+        let mono = ChannelLayout::MONO;
+        let mut resampler = Resampler::new(SampleFormat::S16, stereo, 44100, SampleFormat::Fltp, mono, 48000).unwrap();
+        resampler.set_dither(DitherMethod::Triangular, 1.0).unwrap();
+
+        let mut total = 0;
+        for i in 0..10 {
+            let samples = vec![1000i16; 441 * 2];
+            let mut input = AVFrame::from_interleaved(&samples, stereo, 44100).unwrap();
+            input.set_pts(i * 441);
+            let output = resampler.convert(&input).unwrap();
+            assert_eq!((output.sample_fmt(), output.sample_rate(), output.channels()), (SampleFormat::Fltp, 48000, 1));
+            if i == 0 {
+                assert_eq!(output.pts(), 0);
+            }
+            total += output.nb_samples();
+        }
+        while let Some(output) = resampler.flush().unwrap() {
+            assert!((output.pts() - total as i64).abs() <= 1);
+            total += output.nb_samples();
+        }
+        // 100 ms of input
+        assert!((total - 4800).abs() <= 2, "resampled to {} samples", total);
+        assert!(resampler.flush().unwrap().is_none());
+    }
+
+    #[test]
+    fn resample_with_compensation() {
+        let mono = ChannelLayout::MONO;
+        let mut resampler = Resampler::new(SampleFormat::Flt, mono, 48000, SampleFormat::Flt, mono, 48000).unwrap();
+        resampler.set_compensation(48, 4800).unwrap();
+        let input = AVFrame::from_interleaved(&[0.5f32; 4800], mono, 48000).unwrap();
+        let mut total = resampler.convert(&input).unwrap().nb_samples();
+        while let Some(output) = resampler.flush().unwrap() {
+            total += output.nb_samples();
+        }
+        assert!((total - 4848).abs() <= 2, "compensated to {} samples", total);
+    }
+}
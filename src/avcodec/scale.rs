@@ -0,0 +1,328 @@
+/// Scaling algorithm of a `Scaler` (SWS_* flags).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleAlgorithm {
+    FastBilinear,
+    Bilinear,
+    Bicubic,
+    /// Experimental.
+    X,
+    /// Nearest neighbor.
+    Point,
+    Area,
+    /// Bicubic for luma, bilinear for chroma.
+    Bicublin,
+    Gauss,
+    Sinc,
+    Lanczos,
+    Spline,
+}
+
+impl ScaleAlgorithm {
+    fn flags(&self) -> i32 {
+        let flag = match *self {
+            ScaleAlgorithm::FastBilinear => avcodec::SWS_FAST_BILINEAR,
+            ScaleAlgorithm::Bilinear => avcodec::SWS_BILINEAR,
+            ScaleAlgorithm::Bicubic => avcodec::SWS_BICUBIC,
+            ScaleAlgorithm::X => avcodec::SWS_X,
+            ScaleAlgorithm::Point => avcodec::SWS_POINT,
+            ScaleAlgorithm::Area => avcodec::SWS_AREA,
+            ScaleAlgorithm::Bicublin => avcodec::SWS_BICUBLIN,
+            ScaleAlgorithm::Gauss => avcodec::SWS_GAUSS,
+            ScaleAlgorithm::Sinc => avcodec::SWS_SINC,
+            ScaleAlgorithm::Lanczos => avcodec::SWS_LANCZOS,
+            ScaleAlgorithm::Spline => avcodec::SWS_SPLINE,
+        };
+        flag as i32
+    }
+}
+
+/// YUV coefficients used by swscale for YUV <-> RGB conversion (SWS_CS_*).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    Bt709,
+    Fcc,
+    /// Also SMPTE 170M, the default.
+    Bt601,
+    Smpte240m,
+    Bt2020,
+}
+
+impl ColorSpace {
+    fn sws_colorspace(&self) -> i32 {
+        let colorspace = match *self {
+            ColorSpace::Bt709 => avcodec::SWS_CS_ITU709,
+            ColorSpace::Fcc => avcodec::SWS_CS_FCC,
+            ColorSpace::Bt601 => avcodec::SWS_CS_ITU601,
+            ColorSpace::Smpte240m => avcodec::SWS_CS_SMPTE240M,
+            ColorSpace::Bt2020 => avcodec::SWS_CS_BT2020,
+        };
+        colorspace as i32
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct ColorDetails {
+    src: ColorSpace,
+    src_full_range: bool,
+    dst: ColorSpace,
+    dst_full_range: bool,
+}
+
+/// Converts and scales video frames to one output size and pixel format (SwsContext).
+///
+/// The context is created for the size and format of the first source frame and recreated with
+/// sws_getCachedContext whenever they change, so one scaler can follow resolution changes of a stream.
+pub struct Scaler {
+    internal: *mut avcodec::SwsContext,
+    dst_width: i32,
+    dst_height: i32,
+    dst_format: PixelFormat,
+    flags: i32,
+    color_details: Option<ColorDetails>,
+    threads: i32,
+    // source width, height and format the context was created for
+    configured: Option<(i32, i32, PixelFormat)>,
+}
+
+impl Drop for Scaler {
+    fn drop(&mut self) {
+        unsafe {
+            avcodec::sws_freeContext(self.internal);
+        }
+    }
+}
+
+// the context is only used through &mut self
+unsafe impl Send for Scaler {}
+
+impl Scaler {
+    pub fn new(dst_width: i32, dst_height: i32, dst_format: PixelFormat, algorithm: ScaleAlgorithm) -> Self {
+        Self {
+            internal: null_mut(),
+            dst_width,
+            dst_height,
+            dst_format,
+            flags: algorithm.flags(),
+            color_details: None,
+            threads: 1,
+            configured: None,
+        }
+    }
+    /// Adds SWS_* flags besides the algorithm, e.g. SWS_ACCURATE_RND or SWS_FULL_CHR_H_INT.
+    pub fn add_flags(&mut self, flags: i32) {
+        self.flags |= flags;
+        self.configured = None;
+    }
+    /// Sets the YUV coefficients and ranges of source and destination (sws_setColorspaceDetails). Full range is
+    /// 0-255 (JPEG), limited range is 16-235 (MPEG, the default). Ranges of RGB formats are always full.
+    pub fn set_colorspace(&mut self, src: ColorSpace, src_full_range: bool, dst: ColorSpace, dst_full_range: bool) {
+        self.color_details = Some(ColorDetails { src, src_full_range, dst, dst_full_range });
+        self.configured = None;
+    }
+    /// Splits every frame into slices scaled by `threads` threads, 0 picks the number of cpus. Slice threads were
+    /// added in ffmpeg 5.0, older releases only accept 1 and fail with NotSupported otherwise.
+    pub fn set_threads(&mut self, threads: i32) -> Result<(), AvError> {
+        if cfg!(not(ffmpeg_5_0)) && threads != 1 {
+            return Err(AvError::NotSupported);
+        }
+        self.threads = threads;
+        self.configured = None;
+        Ok(())
+    }
+
+    fn configure(&mut self, width: i32, height: i32, format: PixelFormat) -> Result<(), AvError> {
+        if self.configured == Some((width, height, format)) {
+            return Ok(());
+        }
+        self.configured = None;
+        unsafe {
+            if self.threads != 1 {
+                self.alloc_threaded(width, height, format)?;
+            } else {
+                // frees the old context when the parameters differ
+                self.internal = avcodec::sws_getCachedContext(self.internal, width, height, AVPixelFormat::from(format),
+                                                              self.dst_width, self.dst_height,
+                                                              AVPixelFormat::from(self.dst_format), self.flags,
+                                                              null_mut(), null_mut(), null());
+                if self.internal.is_null() {
+                    return Err(AvError::InvalidArgument);
+                }
+            }
+            if let Some(details) = self.color_details {
+                let ret = avcodec::sws_setColorspaceDetails(self.internal,
+                                                            avcodec::sws_getCoefficients(details.src.sws_colorspace()),
+                                                            details.src_full_range as c_int,
+                                                            avcodec::sws_getCoefficients(details.dst.sws_colorspace()),
+                                                            details.dst_full_range as c_int,
+                                                            0, 1 << 16, 1 << 16);
+                if ret < 0 {
+                    return Err(AvError::InvalidArgument);
+                }
+            }
+        }
+        self.configured = Some((width, height, format));
+        return Ok(());
+    }
+
+    // slice threads can only be set through options before sws_init_context
+    unsafe fn alloc_threaded(&mut self, width: i32, height: i32, format: PixelFormat) -> Result<(), AvError> {
+        avcodec::sws_freeContext(self.internal);
+        self.internal = avcodec::sws_alloc_context();
+        if self.internal.is_null() {
+            return Err(AvError::NoMemory);
+        }
+        let options: [(&[u8], i64); 8] = [
+            (b"srcw\0", width as i64),
+            (b"srch\0", height as i64),
+            (b"src_format\0", AVPixelFormat::from(format) as i64),
+            (b"dstw\0", self.dst_width as i64),
+            (b"dsth\0", self.dst_height as i64),
+            (b"dst_format\0", AVPixelFormat::from(self.dst_format) as i64),
+            (b"sws_flags\0", self.flags as i64),
+            (b"threads\0", self.threads as i64),
+        ];
+        for (name, value) in options.iter() {
+            let ret = avcodec::av_opt_set_int(self.internal as *mut c_void, name.as_ptr() as *const c_char, *value, 0);
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        let ret = avcodec::sws_init_context(self.internal, null_mut(), null_mut());
+        if ret < 0 {
+            return Err(AvError::from(ret));
+        }
+        return Ok(());
+    }
+
+    // whether the context has slice threads, read back from it since sws_init_context resolves 0 threads to the
+    // number of cpus
+    #[cfg(ffmpeg_5_0)]
+    fn is_threaded(&self) -> bool {
+        let mut threads = 1;
+        unsafe {
+            avcodec::av_opt_get_int(self.internal as *mut c_void, b"threads\0".as_ptr() as *const c_char, 0, &mut threads);
+        }
+        threads != 1
+    }
+
+    /// Scales `src` into `dst`. A `dst` without buffers is allocated with the output size and format, otherwise it
+    /// has to match them and be writable. Frame properties like pts are copied from `src`.
+    pub fn scale(&mut self, src: &AVFrame, dst: &mut AVFrame) -> Result<(), AvError> {
+        if src.plane_count() == 0 {
+            return Err(AvError::InvalidArgument);
+        }
+        self.configure(src.width(), src.height(), src.pix_fmt())?;
+        unsafe {
+            if dst.get_internal().buf[0].is_null() {
+                let raw = dst.get_internal_mut();
+                raw.width = self.dst_width;
+                raw.height = self.dst_height;
+                raw.format = AVPixelFormat::from(self.dst_format);
+                let ret = avcodec::av_frame_get_buffer(dst.as_mut_ptr(), 0);
+                if ret < 0 {
+                    return Err(AvError::from(ret));
+                }
+            } else if (dst.width(), dst.height(), dst.pix_fmt()) != (self.dst_width, self.dst_height, self.dst_format)
+                || !dst.is_writable() {
+                return Err(AvError::InvalidArgument);
+            }
+            let ret = avcodec::av_frame_copy_props(dst.as_mut_ptr(), src.as_ptr());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+            if let Some(details) = self.color_details {
+                dst.get_internal_mut().color_range = if details.dst_full_range {
+                    avcodec::AVColorRange_AVCOL_RANGE_JPEG
+                } else {
+                    avcodec::AVColorRange_AVCOL_RANGE_MPEG
+                };
+            }
+            // sws_scale only runs the first slice context, the slice threads are only used by sws_scale_frame
+            #[cfg(ffmpeg_5_0)]
+            if self.is_threaded() {
+                let ret = avcodec::sws_scale_frame(self.internal, dst.as_mut_ptr(), src.as_ptr());
+                if ret < 0 {
+                    return Err(AvError::from(ret));
+                }
+                return Ok(());
+            }
+            let src_raw = src.get_internal();
+            let dst_raw = dst.get_internal_mut();
+            let ret = avcodec::sws_scale(self.internal, src_raw.data.as_ptr() as *const *const u8, src_raw.linesize.as_ptr(),
+                                         0, src_raw.height, dst_raw.data.as_ptr(), dst_raw.linesize.as_ptr());
+            if ret < 0 {
+                return Err(AvError::from(ret));
+            }
+        }
+        return Ok(());
+    }
+    /// Scales `src` into a newly allocated frame.
+    pub fn scale_new(&mut self, src: &AVFrame) -> Result<AVFrame, AvError> {
+        let mut dst = AVFrame::new();
+        self.scale(src, &mut dst)?;
+        return Ok(dst);
+    }
+}
+
+#[cfg(test)]
+mod test_scale {
+    use super::*;
+
+    fn white_bgr0() -> AVFrame {
+        let mut src = AVFrame::new_video(64, 48, PixelFormat::Bgr0, 0).unwrap();
+        for row in src.rows_mut(0).unwrap() {
+            row.fill(255);
+        }
+        src.set_pts(42);
+        return src;
+    }
+
+    #[test]
+    fn scale_bgr0_to_yuv420p() {
+        let src = white_bgr0();
+        let mut scaler = Scaler::new(32, 24, PixelFormat::Yuv420p, ScaleAlgorithm::Bilinear);
+        let dst = scaler.scale_new(&src).unwrap();
+        assert_eq!((dst.width(), dst.height(), dst.pix_fmt()), (32, 24, PixelFormat::Yuv420p));
+        assert_eq!(dst.pts(), 42);
+        assert_eq!(dst.rows(0).unwrap().next().unwrap()[0], 235);
+
+        scaler.set_colorspace(ColorSpace::Bt709, true, ColorSpace::Bt709, true);
+        let mut dst = AVFrame::new_video(32, 24, PixelFormat::Yuv420p, 0).unwrap();
+        scaler.scale(&src, &mut dst).unwrap();
+        assert_eq!(dst.rows(0).unwrap().next().unwrap()[0], 255);
+
+        let mut wrong_size = AVFrame::new_video(16, 16, PixelFormat::Yuv420p, 0).unwrap();
+        assert_eq!(scaler.scale(&src, &mut wrong_size).unwrap_err(), AvError::InvalidArgument);
+    }
+
+    #[cfg(ffmpeg_5_0)]
+    #[test]
+    fn scale_threaded() {
+        let mut src = AVFrame::new_video(64, 48, PixelFormat::Bgr0, 0).unwrap();
+        for (y, row) in src.rows_mut(0).unwrap().enumerate() {
+            for (x, byte) in row.iter_mut().enumerate() {
+                *byte = (x * 3 + y * 5) as u8;
+            }
+        }
+        let mut single = Scaler::new(640, 480, PixelFormat::Yuv420p, ScaleAlgorithm::Lanczos);
+        let expected = single.scale_new(&src).unwrap();
+        assert!(!single.is_threaded());
+
+        let mut scaler = Scaler::new(640, 480, PixelFormat::Yuv420p, ScaleAlgorithm::Lanczos);
+        scaler.set_threads(4).unwrap();
+        let dst = scaler.scale_new(&src).unwrap();
+        assert!(scaler.is_threaded());
+        for plane in 0..3 {
+            assert!(dst.rows(plane).unwrap().eq(expected.rows(plane).unwrap()), "plane {} differs", plane);
+        }
+    }
+
+    #[cfg(not(ffmpeg_5_0))]
+    #[test]
+    fn scale_threads_unsupported() {
+        let mut scaler = Scaler::new(32, 24, PixelFormat::Yuv420p, ScaleAlgorithm::Bilinear);
+        assert_eq!(scaler.set_threads(4), Err(AvError::NotSupported));
+        scaler.set_threads(1).unwrap();
+        assert!(scaler.scale_new(&white_bgr0()).is_ok());
+    }
+}
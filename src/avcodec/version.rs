@@ -0,0 +1,49 @@
+/// Version of one of the linked ffmpeg libraries, as reported at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LibVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub micro: u32,
+}
+
+impl From<u32> for LibVersion {
+    fn from(version: u32) -> Self {
+        Self {
+            major: version >> 16,
+            minor: (version >> 8) & 0xff,
+            micro: version & 0xff,
+        }
+    }
+}
+
+impl Display for LibVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
+
+pub fn avcodec_version() -> LibVersion {
+    unsafe { LibVersion::from(avcodec::avcodec_version()) }
+}
+
+pub fn avutil_version() -> LibVersion {
+    unsafe { LibVersion::from(avcodec::avutil_version()) }
+}
+
+/// ffmpeg release string of the linked libraries, e.g. "4.4" or "n6.0-3-gabc".
+pub fn ffmpeg_version_info() -> &'static str {
+    unsafe { get_str_or_default(avcodec::av_version_info(), EMPTY_STR) }
+}
+
+#[cfg(test)]
+mod test_version {
+    use super::*;
+
+    #[test]
+    fn linked_version_matches_cfgs() {
+        let version = avcodec_version();
+        println!("ffmpeg {} (libavcodec {}, libavutil {})", ffmpeg_version_info(), version, avutil_version());
+        assert_eq!(cfg!(ffmpeg_5_0), (version.major, version.minor) >= (59, 18));
+        assert_eq!(cfg!(ffmpeg_5_1), (version.major, version.minor) >= (59, 37));
+    }
+}
@@ -1,3 +1,15 @@
+// The bindings are wrapped with explicit returns, argument-less constructors and
+// `From` conversions from raw ffmpeg pointers throughout; unsafe accessors
+// describe their contract in prose. `%` is kept over `is_multiple_of` for older
+// toolchains.
+#![allow(
+    clippy::needless_return,
+    clippy::new_without_default,
+    clippy::not_unsafe_ptr_arg_deref,
+    clippy::missing_safety_doc,
+    clippy::manual_is_multiple_of
+)]
+
 pub use libav_sys::avcodec as avcodec_sys;
 
 pub mod avcodec;